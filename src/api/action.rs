@@ -1,7 +1,15 @@
 use http::Method;
 use serde_json::Value;
 
-use crate::{Result, client::RequestOptions, types::path::PathParam};
+use crate::{
+    Result,
+    client::RequestOptions,
+    types::{
+        action::{Action, ActionListQuery, ActionParameters, ActionResult, ExecuteAction},
+        ids::ActionId,
+        path::PathParam,
+    },
+};
 
 #[cfg(feature = "blocking")]
 use crate::client::BlockingClient;
@@ -20,6 +28,58 @@ impl ActionService {
         Self { client }
     }
 
+    /// GET /api/action/
+    pub async fn list_actions(&self, query: &ActionListQuery) -> Result<Vec<Action>> {
+        let segments = ["api", "action"];
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                Some(query),
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/action/"),
+            )
+            .await
+    }
+
+    /// GET /api/action/{action-id}
+    pub async fn get_action(&self, id: ActionId) -> Result<Action> {
+        let id = PathParam::from(id);
+        let segments = ["api", "action", id.as_str()];
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/action/{action-id}"),
+            )
+            .await
+    }
+
+    /// POST /api/action/{id}/execute
+    ///
+    /// Fails with [`Error::InvalidActionParameter`](crate::Error::InvalidActionParameter)
+    /// before sending if `parameters` do not fit the action; see [`Action::validate`].
+    pub async fn execute_action(
+        &self,
+        action: &Action,
+        parameters: &ActionParameters,
+    ) -> Result<ActionResult> {
+        action.validate(parameters)?;
+        let id = PathParam::from(action.id);
+        let segments = ["api", "action", id.as_str(), "execute"];
+        self.client
+            .request_json(
+                Method::POST,
+                &segments,
+                Option::<&()>::None,
+                Some(&ExecuteAction { parameters }),
+                RequestOptions::default().route("/api/action/{id}/execute"),
+            )
+            .await
+    }
+
     /// GET /api/action/
     pub async fn get(&self, query: Option<&Value>) -> Result<Value> {
         let segments = ["api", "action"];
@@ -29,7 +89,7 @@ impl ActionService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/action/"),
             )
             .await
     }
@@ -43,7 +103,7 @@ impl ActionService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/action/"),
             )
             .await
     }
@@ -57,7 +117,7 @@ impl ActionService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/action/public"),
             )
             .await
     }
@@ -72,11 +132,18 @@ impl ActionService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/action/{action-id}"),
             )
             .await
     }
 
+    /// DELETE /api/action/{action-id}
+    ///
+    /// Typed-id variant of [`Self::delete_by_action_id`].
+    pub async fn delete_by_action_id_typed(&self, action_id: ActionId) -> Result<Value> {
+        self.delete_by_action_id(action_id).await
+    }
+
     /// GET /api/action/{action-id}
     pub async fn get_by_action_id(&self, action_id: impl Into<PathParam>) -> Result<Value> {
         let action_id = action_id.into();
@@ -87,11 +154,18 @@ impl ActionService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/action/{action-id}"),
             )
             .await
     }
 
+    /// GET /api/action/{action-id}
+    ///
+    /// Typed-id variant of [`Self::get_by_action_id`].
+    pub async fn get_by_action_id_typed(&self, action_id: ActionId) -> Result<Value> {
+        self.get_by_action_id(action_id).await
+    }
+
     /// GET /api/action/{action-id}/execute
     pub async fn get_by_action_id_execute(
         &self,
@@ -106,11 +180,22 @@ impl ActionService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/action/{action-id}/execute"),
             )
             .await
     }
 
+    /// GET /api/action/{action-id}/execute
+    ///
+    /// Typed-id variant of [`Self::get_by_action_id_execute`].
+    pub async fn get_by_action_id_execute_typed(
+        &self,
+        action_id: ActionId,
+        query: Option<&Value>,
+    ) -> Result<Value> {
+        self.get_by_action_id_execute(action_id, query).await
+    }
+
     /// PUT /api/action/{id}
     pub async fn put_by_id(&self, id: impl Into<PathParam>, body: Option<&Value>) -> Result<Value> {
         let id = id.into();
@@ -121,11 +206,18 @@ impl ActionService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/action/{id}"),
             )
             .await
     }
 
+    /// PUT /api/action/{id}
+    ///
+    /// Typed-id variant of [`Self::put_by_id`].
+    pub async fn put_by_id_typed(&self, id: ActionId, body: Option<&Value>) -> Result<Value> {
+        self.put_by_id(id, body).await
+    }
+
     /// POST /api/action/{id}/execute
     pub async fn post_by_id_execute(
         &self,
@@ -140,11 +232,22 @@ impl ActionService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/action/{id}/execute"),
             )
             .await
     }
 
+    /// POST /api/action/{id}/execute
+    ///
+    /// Typed-id variant of [`Self::post_by_id_execute`].
+    pub async fn post_by_id_execute_typed(
+        &self,
+        id: ActionId,
+        body: Option<&Value>,
+    ) -> Result<Value> {
+        self.post_by_id_execute(id, body).await
+    }
+
     /// DELETE /api/action/{id}/public_link
     pub async fn delete_by_id_public_link(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -155,11 +258,18 @@ impl ActionService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/action/{id}/public_link"),
             )
             .await
     }
 
+    /// DELETE /api/action/{id}/public_link
+    ///
+    /// Typed-id variant of [`Self::delete_by_id_public_link`].
+    pub async fn delete_by_id_public_link_typed(&self, id: ActionId) -> Result<Value> {
+        self.delete_by_id_public_link(id).await
+    }
+
     /// POST /api/action/{id}/public_link
     pub async fn post_by_id_public_link(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -170,10 +280,17 @@ impl ActionService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/action/{id}/public_link"),
             )
             .await
     }
+
+    /// POST /api/action/{id}/public_link
+    ///
+    /// Typed-id variant of [`Self::post_by_id_public_link`].
+    pub async fn post_by_id_public_link_typed(&self, id: ActionId) -> Result<Value> {
+        self.post_by_id_public_link(id).await
+    }
 }
 
 #[cfg(feature = "blocking")]
@@ -188,6 +305,52 @@ impl BlockingActionService {
         Self { client }
     }
 
+    /// GET /api/action/
+    pub fn list_actions(&self, query: &ActionListQuery) -> Result<Vec<Action>> {
+        let segments = ["api", "action"];
+        self.client.request_json(
+            Method::GET,
+            &segments,
+            Some(query),
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/action/"),
+        )
+    }
+
+    /// GET /api/action/{action-id}
+    pub fn get_action(&self, id: ActionId) -> Result<Action> {
+        let id = PathParam::from(id);
+        let segments = ["api", "action", id.as_str()];
+        self.client.request_json(
+            Method::GET,
+            &segments,
+            Option::<&()>::None,
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/action/{action-id}"),
+        )
+    }
+
+    /// POST /api/action/{id}/execute
+    ///
+    /// Fails with [`Error::InvalidActionParameter`](crate::Error::InvalidActionParameter)
+    /// before sending if `parameters` do not fit the action; see [`Action::validate`].
+    pub fn execute_action(
+        &self,
+        action: &Action,
+        parameters: &ActionParameters,
+    ) -> Result<ActionResult> {
+        action.validate(parameters)?;
+        let id = PathParam::from(action.id);
+        let segments = ["api", "action", id.as_str(), "execute"];
+        self.client.request_json(
+            Method::POST,
+            &segments,
+            Option::<&()>::None,
+            Some(&ExecuteAction { parameters }),
+            RequestOptions::default().route("/api/action/{id}/execute"),
+        )
+    }
+
     /// GET /api/action/
     pub fn get(&self, query: Option<&Value>) -> Result<Value> {
         let segments = ["api", "action"];
@@ -196,7 +359,7 @@ impl BlockingActionService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/action/"),
         )
     }
 
@@ -208,7 +371,7 @@ impl BlockingActionService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/action/"),
         )
     }
 
@@ -220,7 +383,7 @@ impl BlockingActionService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/action/public"),
         )
     }
 
@@ -233,10 +396,17 @@ impl BlockingActionService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/action/{action-id}"),
         )
     }
 
+    /// DELETE /api/action/{action-id}
+    ///
+    /// Typed-id variant of [`Self::delete_by_action_id`].
+    pub fn delete_by_action_id_typed(&self, action_id: ActionId) -> Result<Value> {
+        self.delete_by_action_id(action_id)
+    }
+
     /// GET /api/action/{action-id}
     pub fn get_by_action_id(&self, action_id: impl Into<PathParam>) -> Result<Value> {
         let action_id = action_id.into();
@@ -246,10 +416,17 @@ impl BlockingActionService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/action/{action-id}"),
         )
     }
 
+    /// GET /api/action/{action-id}
+    ///
+    /// Typed-id variant of [`Self::get_by_action_id`].
+    pub fn get_by_action_id_typed(&self, action_id: ActionId) -> Result<Value> {
+        self.get_by_action_id(action_id)
+    }
+
     /// GET /api/action/{action-id}/execute
     pub fn get_by_action_id_execute(
         &self,
@@ -263,10 +440,21 @@ impl BlockingActionService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/action/{action-id}/execute"),
         )
     }
 
+    /// GET /api/action/{action-id}/execute
+    ///
+    /// Typed-id variant of [`Self::get_by_action_id_execute`].
+    pub fn get_by_action_id_execute_typed(
+        &self,
+        action_id: ActionId,
+        query: Option<&Value>,
+    ) -> Result<Value> {
+        self.get_by_action_id_execute(action_id, query)
+    }
+
     /// PUT /api/action/{id}
     pub fn put_by_id(&self, id: impl Into<PathParam>, body: Option<&Value>) -> Result<Value> {
         let id = id.into();
@@ -276,10 +464,17 @@ impl BlockingActionService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/action/{id}"),
         )
     }
 
+    /// PUT /api/action/{id}
+    ///
+    /// Typed-id variant of [`Self::put_by_id`].
+    pub fn put_by_id_typed(&self, id: ActionId, body: Option<&Value>) -> Result<Value> {
+        self.put_by_id(id, body)
+    }
+
     /// POST /api/action/{id}/execute
     pub fn post_by_id_execute(
         &self,
@@ -293,10 +488,17 @@ impl BlockingActionService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/action/{id}/execute"),
         )
     }
 
+    /// POST /api/action/{id}/execute
+    ///
+    /// Typed-id variant of [`Self::post_by_id_execute`].
+    pub fn post_by_id_execute_typed(&self, id: ActionId, body: Option<&Value>) -> Result<Value> {
+        self.post_by_id_execute(id, body)
+    }
+
     /// DELETE /api/action/{id}/public_link
     pub fn delete_by_id_public_link(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -306,10 +508,17 @@ impl BlockingActionService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/action/{id}/public_link"),
         )
     }
 
+    /// DELETE /api/action/{id}/public_link
+    ///
+    /// Typed-id variant of [`Self::delete_by_id_public_link`].
+    pub fn delete_by_id_public_link_typed(&self, id: ActionId) -> Result<Value> {
+        self.delete_by_id_public_link(id)
+    }
+
     /// POST /api/action/{id}/public_link
     pub fn post_by_id_public_link(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -319,7 +528,14 @@ impl BlockingActionService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/action/{id}/public_link"),
         )
     }
+
+    /// POST /api/action/{id}/public_link
+    ///
+    /// Typed-id variant of [`Self::post_by_id_public_link`].
+    pub fn post_by_id_public_link_typed(&self, id: ActionId) -> Result<Value> {
+        self.post_by_id_public_link(id)
+    }
 }
@@ -29,7 +29,7 @@ impl ActivityService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/activity/most_recently_viewed_dashboard"),
             )
             .await
     }
@@ -43,7 +43,7 @@ impl ActivityService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/activity/popular_items"),
             )
             .await
     }
@@ -57,7 +57,7 @@ impl ActivityService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/activity/recent_views"),
             )
             .await
     }
@@ -71,7 +71,7 @@ impl ActivityService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/activity/recents"),
             )
             .await
     }
@@ -85,7 +85,7 @@ impl ActivityService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/activity/recents"),
             )
             .await
     }
@@ -111,7 +111,7 @@ impl BlockingActivityService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/activity/most_recently_viewed_dashboard"),
         )
     }
 
@@ -123,7 +123,7 @@ impl BlockingActivityService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/activity/popular_items"),
         )
     }
 
@@ -135,7 +135,7 @@ impl BlockingActivityService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/activity/recent_views"),
         )
     }
 
@@ -147,7 +147,7 @@ impl BlockingActivityService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/activity/recents"),
         )
     }
 
@@ -159,7 +159,7 @@ impl BlockingActivityService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/activity/recents"),
         )
     }
 }
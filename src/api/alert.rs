@@ -1,7 +1,11 @@
 use http::Method;
 use serde_json::Value;
 
-use crate::{Result, client::RequestOptions, types::path::PathParam};
+use crate::{
+    Result,
+    client::RequestOptions,
+    types::{ids::AlertId, path::PathParam, pulse::Alert},
+};
 
 #[cfg(feature = "blocking")]
 use crate::client::BlockingClient;
@@ -20,6 +24,35 @@ impl AlertService {
         Self { client }
     }
 
+    /// GET /api/alert/
+    pub async fn list_alerts(&self) -> Result<Vec<Alert>> {
+        let segments = ["api", "alert"];
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/alert/"),
+            )
+            .await
+    }
+
+    /// GET /api/alert/{id}
+    pub async fn get_alert(&self, id: AlertId) -> Result<Alert> {
+        let id = PathParam::from(id);
+        let segments = ["api", "alert", id.as_str()];
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/alert/{id}"),
+            )
+            .await
+    }
+
     /// GET /api/alert/
     pub async fn get(&self, query: Option<&Value>) -> Result<Value> {
         let segments = ["api", "alert"];
@@ -29,7 +62,7 @@ impl AlertService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/alert/"),
             )
             .await
     }
@@ -44,11 +77,18 @@ impl AlertService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/alert/{id}"),
             )
             .await
     }
 
+    /// GET /api/alert/{id}
+    ///
+    /// Typed-id variant of [`Self::get_by_id`].
+    pub async fn get_by_id_typed(&self, id: AlertId) -> Result<Value> {
+        self.get_by_id(id).await
+    }
+
     /// DELETE /api/alert/{id}/subscription
     pub async fn delete_by_id_subscription(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -59,10 +99,17 @@ impl AlertService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/alert/{id}/subscription"),
             )
             .await
     }
+
+    /// DELETE /api/alert/{id}/subscription
+    ///
+    /// Typed-id variant of [`Self::delete_by_id_subscription`].
+    pub async fn delete_by_id_subscription_typed(&self, id: AlertId) -> Result<Value> {
+        self.delete_by_id_subscription(id).await
+    }
 }
 
 #[cfg(feature = "blocking")]
@@ -77,6 +124,31 @@ impl BlockingAlertService {
         Self { client }
     }
 
+    /// GET /api/alert/
+    pub fn list_alerts(&self) -> Result<Vec<Alert>> {
+        let segments = ["api", "alert"];
+        self.client.request_json(
+            Method::GET,
+            &segments,
+            Option::<&()>::None,
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/alert/"),
+        )
+    }
+
+    /// GET /api/alert/{id}
+    pub fn get_alert(&self, id: AlertId) -> Result<Alert> {
+        let id = PathParam::from(id);
+        let segments = ["api", "alert", id.as_str()];
+        self.client.request_json(
+            Method::GET,
+            &segments,
+            Option::<&()>::None,
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/alert/{id}"),
+        )
+    }
+
     /// GET /api/alert/
     pub fn get(&self, query: Option<&Value>) -> Result<Value> {
         let segments = ["api", "alert"];
@@ -85,7 +157,7 @@ impl BlockingAlertService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/alert/"),
         )
     }
 
@@ -98,10 +170,17 @@ impl BlockingAlertService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/alert/{id}"),
         )
     }
 
+    /// GET /api/alert/{id}
+    ///
+    /// Typed-id variant of [`Self::get_by_id`].
+    pub fn get_by_id_typed(&self, id: AlertId) -> Result<Value> {
+        self.get_by_id(id)
+    }
+
     /// DELETE /api/alert/{id}/subscription
     pub fn delete_by_id_subscription(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -111,7 +190,14 @@ impl BlockingAlertService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/alert/{id}/subscription"),
         )
     }
+
+    /// DELETE /api/alert/{id}/subscription
+    ///
+    /// Typed-id variant of [`Self::delete_by_id_subscription`].
+    pub fn delete_by_id_subscription_typed(&self, id: AlertId) -> Result<Value> {
+        self.delete_by_id_subscription(id)
+    }
 }
@@ -29,7 +29,7 @@ impl AnalyticsService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/analytics/anonymous-stats"),
             )
             .await
     }
@@ -55,7 +55,7 @@ impl BlockingAnalyticsService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/analytics/anonymous-stats"),
         )
     }
 }
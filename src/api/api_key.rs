@@ -1,7 +1,16 @@
 use http::Method;
+use serde::de::IgnoredAny;
 use serde_json::Value;
 
-use crate::{Result, client::RequestOptions, types::path::PathParam};
+use crate::{
+    Result,
+    client::RequestOptions,
+    types::{
+        api_key::{ApiKey, CreateApiKey, RegeneratedApiKey, UpdateApiKey},
+        ids::ApiKeyId,
+        path::PathParam,
+    },
+};
 
 #[cfg(feature = "blocking")]
 use crate::client::BlockingClient;
@@ -20,6 +29,97 @@ impl ApiKeyService {
         Self { client }
     }
 
+    /// GET /api/api-key/
+    pub async fn list_api_keys(&self) -> Result<Vec<ApiKey>> {
+        let segments = ["api", "api-key"];
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/api-key/"),
+            )
+            .await
+    }
+
+    /// GET /api/api-key/count
+    pub async fn count_api_keys(&self) -> Result<u64> {
+        let segments = ["api", "api-key", "count"];
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/api-key/count"),
+            )
+            .await
+    }
+
+    /// POST /api/api-key/
+    ///
+    /// The response carries the full key in [`ApiKey::unmasked_key`]; store it, since it
+    /// cannot be fetched again.
+    pub async fn create_api_key(&self, key: &CreateApiKey) -> Result<ApiKey> {
+        let segments = ["api", "api-key"];
+        self.client
+            .request_json(
+                Method::POST,
+                &segments,
+                Option::<&()>::None,
+                Some(key),
+                RequestOptions::default().route("/api/api-key/"),
+            )
+            .await
+    }
+
+    /// PUT /api/api-key/{id}
+    pub async fn update_api_key(&self, id: ApiKeyId, update: &UpdateApiKey) -> Result<ApiKey> {
+        let id = PathParam::from(id);
+        let segments = ["api", "api-key", id.as_str()];
+        self.client
+            .request_json(
+                Method::PUT,
+                &segments,
+                Option::<&()>::None,
+                Some(update),
+                RequestOptions::default().route("/api/api-key/{id}"),
+            )
+            .await
+    }
+
+    /// PUT /api/api-key/{id}/regenerate
+    pub async fn regenerate_api_key(&self, id: ApiKeyId) -> Result<RegeneratedApiKey> {
+        let id = PathParam::from(id);
+        let segments = ["api", "api-key", id.as_str(), "regenerate"];
+        self.client
+            .request_json(
+                Method::PUT,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/api-key/{id}/regenerate"),
+            )
+            .await
+    }
+
+    /// DELETE /api/api-key/{id}
+    pub async fn delete_api_key(&self, id: ApiKeyId) -> Result<()> {
+        let id = PathParam::from(id);
+        let segments = ["api", "api-key", id.as_str()];
+        self.client
+            .request_json::<IgnoredAny, _, _>(
+                Method::DELETE,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/api-key/{id}"),
+            )
+            .await
+            .map(|_| ())
+    }
+
     /// GET /api/api-key/
     pub async fn get(&self) -> Result<Value> {
         let segments = ["api", "api-key"];
@@ -29,7 +129,7 @@ impl ApiKeyService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/api-key/"),
             )
             .await
     }
@@ -43,7 +143,7 @@ impl ApiKeyService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/api-key/"),
             )
             .await
     }
@@ -57,7 +157,7 @@ impl ApiKeyService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/api-key/count"),
             )
             .await
     }
@@ -72,11 +172,18 @@ impl ApiKeyService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/api-key/{id}"),
             )
             .await
     }
 
+    /// DELETE /api/api-key/{id}
+    ///
+    /// Typed-id variant of [`Self::delete_by_id`].
+    pub async fn delete_by_id_typed(&self, id: ApiKeyId) -> Result<Value> {
+        self.delete_by_id(id).await
+    }
+
     /// PUT /api/api-key/{id}
     pub async fn put_by_id(&self, id: impl Into<PathParam>, body: Option<&Value>) -> Result<Value> {
         let id = id.into();
@@ -87,11 +194,18 @@ impl ApiKeyService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/api-key/{id}"),
             )
             .await
     }
 
+    /// PUT /api/api-key/{id}
+    ///
+    /// Typed-id variant of [`Self::put_by_id`].
+    pub async fn put_by_id_typed(&self, id: ApiKeyId, body: Option<&Value>) -> Result<Value> {
+        self.put_by_id(id, body).await
+    }
+
     /// PUT /api/api-key/{id}/regenerate
     pub async fn put_by_id_regenerate(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -102,10 +216,17 @@ impl ApiKeyService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/api-key/{id}/regenerate"),
             )
             .await
     }
+
+    /// PUT /api/api-key/{id}/regenerate
+    ///
+    /// Typed-id variant of [`Self::put_by_id_regenerate`].
+    pub async fn put_by_id_regenerate_typed(&self, id: ApiKeyId) -> Result<Value> {
+        self.put_by_id_regenerate(id).await
+    }
 }
 
 #[cfg(feature = "blocking")]
@@ -120,6 +241,86 @@ impl BlockingApiKeyService {
         Self { client }
     }
 
+    /// GET /api/api-key/
+    pub fn list_api_keys(&self) -> Result<Vec<ApiKey>> {
+        let segments = ["api", "api-key"];
+        self.client.request_json(
+            Method::GET,
+            &segments,
+            Option::<&()>::None,
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/api-key/"),
+        )
+    }
+
+    /// GET /api/api-key/count
+    pub fn count_api_keys(&self) -> Result<u64> {
+        let segments = ["api", "api-key", "count"];
+        self.client.request_json(
+            Method::GET,
+            &segments,
+            Option::<&()>::None,
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/api-key/count"),
+        )
+    }
+
+    /// POST /api/api-key/
+    ///
+    /// The response carries the full key in [`ApiKey::unmasked_key`]; store it, since it
+    /// cannot be fetched again.
+    pub fn create_api_key(&self, key: &CreateApiKey) -> Result<ApiKey> {
+        let segments = ["api", "api-key"];
+        self.client.request_json(
+            Method::POST,
+            &segments,
+            Option::<&()>::None,
+            Some(key),
+            RequestOptions::default().route("/api/api-key/"),
+        )
+    }
+
+    /// PUT /api/api-key/{id}
+    pub fn update_api_key(&self, id: ApiKeyId, update: &UpdateApiKey) -> Result<ApiKey> {
+        let id = PathParam::from(id);
+        let segments = ["api", "api-key", id.as_str()];
+        self.client.request_json(
+            Method::PUT,
+            &segments,
+            Option::<&()>::None,
+            Some(update),
+            RequestOptions::default().route("/api/api-key/{id}"),
+        )
+    }
+
+    /// PUT /api/api-key/{id}/regenerate
+    pub fn regenerate_api_key(&self, id: ApiKeyId) -> Result<RegeneratedApiKey> {
+        let id = PathParam::from(id);
+        let segments = ["api", "api-key", id.as_str(), "regenerate"];
+        self.client.request_json(
+            Method::PUT,
+            &segments,
+            Option::<&()>::None,
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/api-key/{id}/regenerate"),
+        )
+    }
+
+    /// DELETE /api/api-key/{id}
+    pub fn delete_api_key(&self, id: ApiKeyId) -> Result<()> {
+        let id = PathParam::from(id);
+        let segments = ["api", "api-key", id.as_str()];
+        self.client
+            .request_json::<IgnoredAny, _, _>(
+                Method::DELETE,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/api-key/{id}"),
+            )
+            .map(|_| ())
+    }
+
     /// GET /api/api-key/
     pub fn get(&self) -> Result<Value> {
         let segments = ["api", "api-key"];
@@ -128,7 +329,7 @@ impl BlockingApiKeyService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/api-key/"),
         )
     }
 
@@ -140,7 +341,7 @@ impl BlockingApiKeyService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/api-key/"),
         )
     }
 
@@ -152,7 +353,7 @@ impl BlockingApiKeyService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/api-key/count"),
         )
     }
 
@@ -165,10 +366,17 @@ impl BlockingApiKeyService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/api-key/{id}"),
         )
     }
 
+    /// DELETE /api/api-key/{id}
+    ///
+    /// Typed-id variant of [`Self::delete_by_id`].
+    pub fn delete_by_id_typed(&self, id: ApiKeyId) -> Result<Value> {
+        self.delete_by_id(id)
+    }
+
     /// PUT /api/api-key/{id}
     pub fn put_by_id(&self, id: impl Into<PathParam>, body: Option<&Value>) -> Result<Value> {
         let id = id.into();
@@ -178,10 +386,17 @@ impl BlockingApiKeyService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/api-key/{id}"),
         )
     }
 
+    /// PUT /api/api-key/{id}
+    ///
+    /// Typed-id variant of [`Self::put_by_id`].
+    pub fn put_by_id_typed(&self, id: ApiKeyId, body: Option<&Value>) -> Result<Value> {
+        self.put_by_id(id, body)
+    }
+
     /// PUT /api/api-key/{id}/regenerate
     pub fn put_by_id_regenerate(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -191,7 +406,14 @@ impl BlockingApiKeyService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/api-key/{id}/regenerate"),
         )
     }
+
+    /// PUT /api/api-key/{id}/regenerate
+    ///
+    /// Typed-id variant of [`Self::put_by_id_regenerate`].
+    pub fn put_by_id_regenerate_typed(&self, id: ApiKeyId) -> Result<Value> {
+        self.put_by_id_regenerate(id)
+    }
 }
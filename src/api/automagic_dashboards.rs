@@ -1,7 +1,14 @@
 use http::Method;
 use serde_json::Value;
 
-use crate::{Result, client::RequestOptions, types::path::PathParam};
+use crate::{
+    Result,
+    client::RequestOptions,
+    types::{
+        ids::{DatabaseId, ModelIndexId},
+        path::PathParam,
+    },
+};
 
 #[cfg(feature = "blocking")]
 use crate::client::BlockingClient;
@@ -36,11 +43,19 @@ impl AutomagicDashboardsService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default()
+                    .route("/api/automagic-dashboards/database/{id}/candidates"),
             )
             .await
     }
 
+    /// GET /api/automagic-dashboards/database/{id}/candidates
+    ///
+    /// Typed-id variant of [`Self::get_database_by_id_candidates`].
+    pub async fn get_database_by_id_candidates_typed(&self, id: DatabaseId) -> Result<Value> {
+        self.get_database_by_id_candidates(id).await
+    }
+
     /// GET /api/automagic-dashboards/model_index/{model-index-id}/primary_key/{pk-id}
     pub async fn get_model_index_by_model_index_id_primary_key_by_pk_id(
         &self,
@@ -63,11 +78,25 @@ impl AutomagicDashboardsService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route(
+                    "/api/automagic-dashboards/model_index/{model-index-id}/primary_key/{pk-id}",
+                ),
             )
             .await
     }
 
+    /// GET /api/automagic-dashboards/model_index/{model-index-id}/primary_key/{pk-id}
+    ///
+    /// Typed-id variant of [`Self::get_model_index_by_model_index_id_primary_key_by_pk_id`].
+    pub async fn get_model_index_by_model_index_id_primary_key_by_pk_id_typed(
+        &self,
+        model_index_id: ModelIndexId,
+        pk_id: impl Into<PathParam>,
+    ) -> Result<Value> {
+        self.get_model_index_by_model_index_id_primary_key_by_pk_id(model_index_id, pk_id)
+            .await
+    }
+
     /// GET /api/automagic-dashboards/{entity}/{entity-id-or-query}
     pub async fn get_by_entity_by_entity_id_or_query(
         &self,
@@ -89,7 +118,8 @@ impl AutomagicDashboardsService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default()
+                    .route("/api/automagic-dashboards/{entity}/{entity-id-or-query}"),
             )
             .await
     }
@@ -119,7 +149,9 @@ impl AutomagicDashboardsService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route(
+                    "/api/automagic-dashboards/{entity}/{entity-id-or-query}/cell/{cell-query}",
+                ),
             )
             .await
     }
@@ -156,7 +188,7 @@ impl AutomagicDashboardsService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/automagic-dashboards/{entity}/{entity-id-or-query}/cell/{cell-query}/compare/{comparison-entity}/{comparison-entity-id-or-query}"),
             )
             .await
     }
@@ -193,7 +225,7 @@ impl AutomagicDashboardsService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/automagic-dashboards/{entity}/{entity-id-or-query}/cell/{cell-query}/rule/{prefix}/{dashboard-template}"),
             )
             .await
     }
@@ -237,7 +269,7 @@ impl AutomagicDashboardsService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/automagic-dashboards/{entity}/{entity-id-or-query}/cell/{cell-query}/rule/{prefix}/{dashboard-template}/compare/{comparison-entity}/{comparison-entity-id-or-query}"),
             )
             .await
     }
@@ -270,7 +302,7 @@ impl AutomagicDashboardsService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/automagic-dashboards/{entity}/{entity-id-or-query}/compare/{comparison-entity}/{comparison-entity-id-or-query}"),
             )
             .await
     }
@@ -296,7 +328,9 @@ impl AutomagicDashboardsService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route(
+                    "/api/automagic-dashboards/{entity}/{entity-id-or-query}/query_metadata",
+                ),
             )
             .await
     }
@@ -329,7 +363,7 @@ impl AutomagicDashboardsService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/automagic-dashboards/{entity}/{entity-id-or-query}/rule/{prefix}/{dashboard-template}"),
             )
             .await
     }
@@ -369,7 +403,7 @@ impl AutomagicDashboardsService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/automagic-dashboards/{entity}/{entity-id-or-query}/rule/{prefix}/{dashboard-template}/compare/{comparison-entity}/{comparison-entity-id-or-query}"),
             )
             .await
     }
@@ -402,10 +436,17 @@ impl BlockingAutomagicDashboardsService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/automagic-dashboards/database/{id}/candidates"),
         )
     }
 
+    /// GET /api/automagic-dashboards/database/{id}/candidates
+    ///
+    /// Typed-id variant of [`Self::get_database_by_id_candidates`].
+    pub fn get_database_by_id_candidates_typed(&self, id: DatabaseId) -> Result<Value> {
+        self.get_database_by_id_candidates(id)
+    }
+
     /// GET /api/automagic-dashboards/model_index/{model-index-id}/primary_key/{pk-id}
     pub fn get_model_index_by_model_index_id_primary_key_by_pk_id(
         &self,
@@ -427,10 +468,23 @@ impl BlockingAutomagicDashboardsService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route(
+                "/api/automagic-dashboards/model_index/{model-index-id}/primary_key/{pk-id}",
+            ),
         )
     }
 
+    /// GET /api/automagic-dashboards/model_index/{model-index-id}/primary_key/{pk-id}
+    ///
+    /// Typed-id variant of [`Self::get_model_index_by_model_index_id_primary_key_by_pk_id`].
+    pub fn get_model_index_by_model_index_id_primary_key_by_pk_id_typed(
+        &self,
+        model_index_id: ModelIndexId,
+        pk_id: impl Into<PathParam>,
+    ) -> Result<Value> {
+        self.get_model_index_by_model_index_id_primary_key_by_pk_id(model_index_id, pk_id)
+    }
+
     /// GET /api/automagic-dashboards/{entity}/{entity-id-or-query}
     pub fn get_by_entity_by_entity_id_or_query(
         &self,
@@ -451,7 +505,8 @@ impl BlockingAutomagicDashboardsService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default()
+                .route("/api/automagic-dashboards/{entity}/{entity-id-or-query}"),
         )
     }
 
@@ -479,7 +534,8 @@ impl BlockingAutomagicDashboardsService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default()
+                .route("/api/automagic-dashboards/{entity}/{entity-id-or-query}/cell/{cell-query}"),
         )
     }
 
@@ -514,7 +570,7 @@ impl BlockingAutomagicDashboardsService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/automagic-dashboards/{entity}/{entity-id-or-query}/cell/{cell-query}/compare/{comparison-entity}/{comparison-entity-id-or-query}"),
         )
     }
 
@@ -549,7 +605,7 @@ impl BlockingAutomagicDashboardsService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/automagic-dashboards/{entity}/{entity-id-or-query}/cell/{cell-query}/rule/{prefix}/{dashboard-template}"),
         )
     }
 
@@ -591,7 +647,7 @@ impl BlockingAutomagicDashboardsService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/automagic-dashboards/{entity}/{entity-id-or-query}/cell/{cell-query}/rule/{prefix}/{dashboard-template}/compare/{comparison-entity}/{comparison-entity-id-or-query}"),
         )
     }
 
@@ -622,7 +678,7 @@ impl BlockingAutomagicDashboardsService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/automagic-dashboards/{entity}/{entity-id-or-query}/compare/{comparison-entity}/{comparison-entity-id-or-query}"),
         )
     }
 
@@ -646,7 +702,8 @@ impl BlockingAutomagicDashboardsService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default()
+                .route("/api/automagic-dashboards/{entity}/{entity-id-or-query}/query_metadata"),
         )
     }
 
@@ -677,7 +734,7 @@ impl BlockingAutomagicDashboardsService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/automagic-dashboards/{entity}/{entity-id-or-query}/rule/{prefix}/{dashboard-template}"),
         )
     }
 
@@ -715,7 +772,7 @@ impl BlockingAutomagicDashboardsService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/automagic-dashboards/{entity}/{entity-id-or-query}/rule/{prefix}/{dashboard-template}/compare/{comparison-entity}/{comparison-entity-id-or-query}"),
         )
     }
 }
@@ -29,7 +29,7 @@ impl BookmarkService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/bookmark/"),
             )
             .await
     }
@@ -43,7 +43,7 @@ impl BookmarkService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/bookmark/ordering"),
             )
             .await
     }
@@ -63,7 +63,7 @@ impl BookmarkService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/bookmark/{model}/{id}"),
             )
             .await
     }
@@ -83,7 +83,7 @@ impl BookmarkService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/bookmark/{model}/{id}"),
             )
             .await
     }
@@ -109,7 +109,7 @@ impl BlockingBookmarkService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/bookmark/"),
         )
     }
 
@@ -121,7 +121,7 @@ impl BlockingBookmarkService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/bookmark/ordering"),
         )
     }
 
@@ -139,7 +139,7 @@ impl BlockingBookmarkService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/bookmark/{model}/{id}"),
         )
     }
 
@@ -157,7 +157,7 @@ impl BlockingBookmarkService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/bookmark/{model}/{id}"),
         )
     }
 }
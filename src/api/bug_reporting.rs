@@ -29,7 +29,7 @@ impl BugReportingService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/bug-reporting/connection-pool-details"),
             )
             .await
     }
@@ -43,7 +43,7 @@ impl BugReportingService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/bug-reporting/details"),
             )
             .await
     }
@@ -69,7 +69,7 @@ impl BlockingBugReportingService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/bug-reporting/connection-pool-details"),
         )
     }
 
@@ -81,7 +81,7 @@ impl BlockingBugReportingService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/bug-reporting/details"),
         )
     }
 }
@@ -1,7 +1,12 @@
 use http::Method;
+use serde::de::IgnoredAny;
 use serde_json::Value;
 
-use crate::{Result, client::RequestOptions};
+use crate::{
+    Result,
+    client::RequestOptions,
+    types::cache::{CacheConfig, CacheConfigQuery, CacheConfigs, CacheModel},
+};
 
 #[cfg(feature = "blocking")]
 use crate::client::BlockingClient;
@@ -20,6 +25,36 @@ impl CacheService {
         Self { client }
     }
 
+    /// GET /api/cache/
+    pub async fn list_cache_configs(&self, models: &[CacheModel]) -> Result<Vec<CacheConfig>> {
+        let segments = ["api", "cache"];
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                Some(&CacheConfigQuery { model: models }),
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/cache/"),
+            )
+            .await
+            .map(|configs: CacheConfigs| configs.data)
+    }
+
+    /// PUT /api/cache/
+    pub async fn set_cache_config(&self, config: &CacheConfig) -> Result<()> {
+        let segments = ["api", "cache"];
+        self.client
+            .request_json::<IgnoredAny, _, _>(
+                Method::PUT,
+                &segments,
+                Option::<&()>::None,
+                Some(config),
+                RequestOptions::default().route("/api/cache/"),
+            )
+            .await
+            .map(|_| ())
+    }
+
     /// DELETE /api/cache/
     pub async fn delete(&self, body: Option<&Value>) -> Result<Value> {
         let segments = ["api", "cache"];
@@ -29,7 +64,7 @@ impl CacheService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/cache/"),
             )
             .await
     }
@@ -43,7 +78,7 @@ impl CacheService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/cache/"),
             )
             .await
     }
@@ -57,7 +92,7 @@ impl CacheService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/cache/"),
             )
             .await
     }
@@ -71,7 +106,7 @@ impl CacheService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/cache/invalidate"),
             )
             .await
     }
@@ -89,6 +124,34 @@ impl BlockingCacheService {
         Self { client }
     }
 
+    /// GET /api/cache/
+    pub fn list_cache_configs(&self, models: &[CacheModel]) -> Result<Vec<CacheConfig>> {
+        let segments = ["api", "cache"];
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                Some(&CacheConfigQuery { model: models }),
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/cache/"),
+            )
+            .map(|configs: CacheConfigs| configs.data)
+    }
+
+    /// PUT /api/cache/
+    pub fn set_cache_config(&self, config: &CacheConfig) -> Result<()> {
+        let segments = ["api", "cache"];
+        self.client
+            .request_json::<IgnoredAny, _, _>(
+                Method::PUT,
+                &segments,
+                Option::<&()>::None,
+                Some(config),
+                RequestOptions::default().route("/api/cache/"),
+            )
+            .map(|_| ())
+    }
+
     /// DELETE /api/cache/
     pub fn delete(&self, body: Option<&Value>) -> Result<Value> {
         let segments = ["api", "cache"];
@@ -97,7 +160,7 @@ impl BlockingCacheService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/cache/"),
         )
     }
 
@@ -109,7 +172,7 @@ impl BlockingCacheService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/cache/"),
         )
     }
 
@@ -121,7 +184,7 @@ impl BlockingCacheService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/cache/"),
         )
     }
 
@@ -133,7 +196,7 @@ impl BlockingCacheService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/cache/invalidate"),
         )
     }
 }
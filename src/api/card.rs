@@ -1,7 +1,15 @@
 use http::Method;
 use serde_json::Value;
 
-use crate::{Result, client::RequestOptions, types::path::PathParam};
+use crate::{
+    Result,
+    client::RequestOptions,
+    types::{
+        card::{Card, CreateCard, UpdateCard},
+        ids::CardId,
+        path::PathParam,
+    },
+};
 
 #[cfg(feature = "blocking")]
 use crate::client::BlockingClient;
@@ -20,6 +28,66 @@ impl CardService {
         Self { client }
     }
 
+    /// GET /api/card/
+    ///
+    /// Typed variant of [`Self::get`]; `query` takes the same filters, e.g. `{"f": "archived"}`.
+    pub async fn list_cards(&self, query: Option<&Value>) -> Result<Vec<Card>> {
+        let segments = ["api", "card"];
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                query,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/card/"),
+            )
+            .await
+    }
+
+    /// GET /api/card/{id}
+    pub async fn get_card(&self, id: CardId) -> Result<Card> {
+        let id = PathParam::from(id);
+        let segments = ["api", "card", id.as_str()];
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/card/{id}"),
+            )
+            .await
+    }
+
+    /// POST /api/card/
+    pub async fn create_card(&self, request: &CreateCard) -> Result<Card> {
+        let segments = ["api", "card"];
+        self.client
+            .request_json(
+                Method::POST,
+                &segments,
+                Option::<&()>::None,
+                Some(request),
+                RequestOptions::default().route("/api/card/"),
+            )
+            .await
+    }
+
+    /// PUT /api/card/{id}
+    pub async fn update_card(&self, id: CardId, request: &UpdateCard) -> Result<Card> {
+        let id = PathParam::from(id);
+        let segments = ["api", "card", id.as_str()];
+        self.client
+            .request_json(
+                Method::PUT,
+                &segments,
+                Option::<&()>::None,
+                Some(request),
+                RequestOptions::default().route("/api/card/{id}"),
+            )
+            .await
+    }
+
     /// GET /api/card/
     pub async fn get(&self, query: Option<&Value>) -> Result<Value> {
         let segments = ["api", "card"];
@@ -29,7 +97,7 @@ impl CardService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/card/"),
             )
             .await
     }
@@ -43,7 +111,7 @@ impl CardService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/card/"),
             )
             .await
     }
@@ -57,7 +125,7 @@ impl CardService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/card/collections"),
             )
             .await
     }
@@ -71,7 +139,7 @@ impl CardService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/card/embeddable"),
             )
             .await
     }
@@ -90,11 +158,22 @@ impl CardService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/card/pivot/{card-id}/query"),
             )
             .await
     }
 
+    /// POST /api/card/pivot/{card-id}/query
+    ///
+    /// Typed-id variant of [`Self::post_pivot_by_card_id_query`].
+    pub async fn post_pivot_by_card_id_query_typed(
+        &self,
+        card_id: CardId,
+        body: Option<&Value>,
+    ) -> Result<Value> {
+        self.post_pivot_by_card_id_query(card_id, body).await
+    }
+
     /// GET /api/card/public
     pub async fn get_public(&self) -> Result<Value> {
         let segments = ["api", "card", "public"];
@@ -104,7 +183,7 @@ impl CardService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/card/public"),
             )
             .await
     }
@@ -134,11 +213,25 @@ impl CardService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default()
+                    .route("/api/card/{card-id}/params/{param-key}/search/{query}"),
             )
             .await
     }
 
+    /// GET /api/card/{card-id}/params/{param-key}/search/{query}
+    ///
+    /// Typed-id variant of [`Self::get_by_card_id_params_by_param_key_search_by_query`].
+    pub async fn get_by_card_id_params_by_param_key_search_by_query_typed(
+        &self,
+        card_id: CardId,
+        param_key: impl Into<PathParam>,
+        query: impl Into<PathParam>,
+    ) -> Result<Value> {
+        self.get_by_card_id_params_by_param_key_search_by_query(card_id, param_key, query)
+            .await
+    }
+
     /// GET /api/card/{card-id}/params/{param-key}/values
     pub async fn get_by_card_id_params_by_param_key_values(
         &self,
@@ -161,11 +254,23 @@ impl CardService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/card/{card-id}/params/{param-key}/values"),
             )
             .await
     }
 
+    /// GET /api/card/{card-id}/params/{param-key}/values
+    ///
+    /// Typed-id variant of [`Self::get_by_card_id_params_by_param_key_values`].
+    pub async fn get_by_card_id_params_by_param_key_values_typed(
+        &self,
+        card_id: CardId,
+        param_key: impl Into<PathParam>,
+    ) -> Result<Value> {
+        self.get_by_card_id_params_by_param_key_values(card_id, param_key)
+            .await
+    }
+
     /// DELETE /api/card/{card-id}/public_link
     pub async fn delete_by_card_id_public_link(
         &self,
@@ -179,11 +284,18 @@ impl CardService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/card/{card-id}/public_link"),
             )
             .await
     }
 
+    /// DELETE /api/card/{card-id}/public_link
+    ///
+    /// Typed-id variant of [`Self::delete_by_card_id_public_link`].
+    pub async fn delete_by_card_id_public_link_typed(&self, card_id: CardId) -> Result<Value> {
+        self.delete_by_card_id_public_link(card_id).await
+    }
+
     /// POST /api/card/{card-id}/public_link
     pub async fn post_by_card_id_public_link(
         &self,
@@ -197,11 +309,18 @@ impl CardService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/card/{card-id}/public_link"),
             )
             .await
     }
 
+    /// POST /api/card/{card-id}/public_link
+    ///
+    /// Typed-id variant of [`Self::post_by_card_id_public_link`].
+    pub async fn post_by_card_id_public_link_typed(&self, card_id: CardId) -> Result<Value> {
+        self.post_by_card_id_public_link(card_id).await
+    }
+
     /// POST /api/card/{card-id}/query
     pub async fn post_by_card_id_query(
         &self,
@@ -216,11 +335,22 @@ impl CardService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/card/{card-id}/query"),
             )
             .await
     }
 
+    /// POST /api/card/{card-id}/query
+    ///
+    /// Typed-id variant of [`Self::post_by_card_id_query`].
+    pub async fn post_by_card_id_query_typed(
+        &self,
+        card_id: CardId,
+        body: Option<&Value>,
+    ) -> Result<Value> {
+        self.post_by_card_id_query(card_id, body).await
+    }
+
     /// POST /api/card/{card-id}/query/{export-format}
     pub async fn post_by_card_id_query_by_export_format(
         &self,
@@ -243,11 +373,24 @@ impl CardService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/card/{card-id}/query/{export-format}"),
             )
             .await
     }
 
+    /// POST /api/card/{card-id}/query/{export-format}
+    ///
+    /// Typed-id variant of [`Self::post_by_card_id_query_by_export_format`].
+    pub async fn post_by_card_id_query_by_export_format_typed(
+        &self,
+        card_id: CardId,
+        export_format: impl Into<PathParam>,
+        body: Option<&Value>,
+    ) -> Result<Vec<u8>> {
+        self.post_by_card_id_query_by_export_format(card_id, export_format, body)
+            .await
+    }
+
     /// DELETE /api/card/{id}
     pub async fn delete_by_id(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -258,11 +401,18 @@ impl CardService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/card/{id}"),
             )
             .await
     }
 
+    /// DELETE /api/card/{id}
+    ///
+    /// Typed-id variant of [`Self::delete_by_id`].
+    pub async fn delete_by_id_typed(&self, id: CardId) -> Result<Value> {
+        self.delete_by_id(id).await
+    }
+
     /// GET /api/card/{id}
     pub async fn get_by_id(
         &self,
@@ -277,11 +427,18 @@ impl CardService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/card/{id}"),
             )
             .await
     }
 
+    /// GET /api/card/{id}
+    ///
+    /// Typed-id variant of [`Self::get_by_id`].
+    pub async fn get_by_id_typed(&self, id: CardId, query: Option<&Value>) -> Result<Value> {
+        self.get_by_id(id, query).await
+    }
+
     /// PUT /api/card/{id}
     pub async fn put_by_id(
         &self,
@@ -297,11 +454,23 @@ impl CardService {
                 &segments,
                 query,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/card/{id}"),
             )
             .await
     }
 
+    /// PUT /api/card/{id}
+    ///
+    /// Typed-id variant of [`Self::put_by_id`].
+    pub async fn put_by_id_typed(
+        &self,
+        id: CardId,
+        query: Option<&Value>,
+        body: Option<&Value>,
+    ) -> Result<Value> {
+        self.put_by_id(id, query, body).await
+    }
+
     /// POST /api/card/{id}/copy
     pub async fn post_by_id_copy(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -312,11 +481,18 @@ impl CardService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/card/{id}/copy"),
             )
             .await
     }
 
+    /// POST /api/card/{id}/copy
+    ///
+    /// Typed-id variant of [`Self::post_by_id_copy`].
+    pub async fn post_by_id_copy_typed(&self, id: CardId) -> Result<Value> {
+        self.post_by_id_copy(id).await
+    }
+
     /// GET /api/card/{id}/dashboards
     pub async fn get_by_id_dashboards(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -327,11 +503,18 @@ impl CardService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/card/{id}/dashboards"),
             )
             .await
     }
 
+    /// GET /api/card/{id}/dashboards
+    ///
+    /// Typed-id variant of [`Self::get_by_id_dashboards`].
+    pub async fn get_by_id_dashboards_typed(&self, id: CardId) -> Result<Value> {
+        self.get_by_id_dashboards(id).await
+    }
+
     /// GET /api/card/{id}/params/{param-key}/remapping
     pub async fn get_by_id_params_by_param_key_remapping(
         &self,
@@ -355,11 +538,24 @@ impl CardService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/card/{id}/params/{param-key}/remapping"),
             )
             .await
     }
 
+    /// GET /api/card/{id}/params/{param-key}/remapping
+    ///
+    /// Typed-id variant of [`Self::get_by_id_params_by_param_key_remapping`].
+    pub async fn get_by_id_params_by_param_key_remapping_typed(
+        &self,
+        id: CardId,
+        param_key: impl Into<PathParam>,
+        query: Option<&Value>,
+    ) -> Result<Value> {
+        self.get_by_id_params_by_param_key_remapping(id, param_key, query)
+            .await
+    }
+
     /// GET /api/card/{id}/query_metadata
     pub async fn get_by_id_query_metadata(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -370,11 +566,18 @@ impl CardService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/card/{id}/query_metadata"),
             )
             .await
     }
 
+    /// GET /api/card/{id}/query_metadata
+    ///
+    /// Typed-id variant of [`Self::get_by_id_query_metadata`].
+    pub async fn get_by_id_query_metadata_typed(&self, id: CardId) -> Result<Value> {
+        self.get_by_id_query_metadata(id).await
+    }
+
     /// GET /api/card/{id}/series
     pub async fn get_by_id_series(
         &self,
@@ -389,10 +592,17 @@ impl CardService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/card/{id}/series"),
             )
             .await
     }
+
+    /// GET /api/card/{id}/series
+    ///
+    /// Typed-id variant of [`Self::get_by_id_series`].
+    pub async fn get_by_id_series_typed(&self, id: CardId, query: Option<&Value>) -> Result<Value> {
+        self.get_by_id_series(id, query).await
+    }
 }
 
 #[cfg(feature = "blocking")]
@@ -407,6 +617,58 @@ impl BlockingCardService {
         Self { client }
     }
 
+    /// GET /api/card/
+    ///
+    /// Typed variant of [`Self::get`]; `query` takes the same filters, e.g. `{"f": "archived"}`.
+    pub fn list_cards(&self, query: Option<&Value>) -> Result<Vec<Card>> {
+        let segments = ["api", "card"];
+        self.client.request_json(
+            Method::GET,
+            &segments,
+            query,
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/card/"),
+        )
+    }
+
+    /// GET /api/card/{id}
+    pub fn get_card(&self, id: CardId) -> Result<Card> {
+        let id = PathParam::from(id);
+        let segments = ["api", "card", id.as_str()];
+        self.client.request_json(
+            Method::GET,
+            &segments,
+            Option::<&()>::None,
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/card/{id}"),
+        )
+    }
+
+    /// POST /api/card/
+    pub fn create_card(&self, request: &CreateCard) -> Result<Card> {
+        let segments = ["api", "card"];
+        self.client.request_json(
+            Method::POST,
+            &segments,
+            Option::<&()>::None,
+            Some(request),
+            RequestOptions::default().route("/api/card/"),
+        )
+    }
+
+    /// PUT /api/card/{id}
+    pub fn update_card(&self, id: CardId, request: &UpdateCard) -> Result<Card> {
+        let id = PathParam::from(id);
+        let segments = ["api", "card", id.as_str()];
+        self.client.request_json(
+            Method::PUT,
+            &segments,
+            Option::<&()>::None,
+            Some(request),
+            RequestOptions::default().route("/api/card/{id}"),
+        )
+    }
+
     /// GET /api/card/
     pub fn get(&self, query: Option<&Value>) -> Result<Value> {
         let segments = ["api", "card"];
@@ -415,7 +677,7 @@ impl BlockingCardService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/card/"),
         )
     }
 
@@ -427,7 +689,7 @@ impl BlockingCardService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/card/"),
         )
     }
 
@@ -439,7 +701,7 @@ impl BlockingCardService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/card/collections"),
         )
     }
 
@@ -451,7 +713,7 @@ impl BlockingCardService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/card/embeddable"),
         )
     }
 
@@ -468,10 +730,21 @@ impl BlockingCardService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/card/pivot/{card-id}/query"),
         )
     }
 
+    /// POST /api/card/pivot/{card-id}/query
+    ///
+    /// Typed-id variant of [`Self::post_pivot_by_card_id_query`].
+    pub fn post_pivot_by_card_id_query_typed(
+        &self,
+        card_id: CardId,
+        body: Option<&Value>,
+    ) -> Result<Value> {
+        self.post_pivot_by_card_id_query(card_id, body)
+    }
+
     /// GET /api/card/public
     pub fn get_public(&self) -> Result<Value> {
         let segments = ["api", "card", "public"];
@@ -480,7 +753,7 @@ impl BlockingCardService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/card/public"),
         )
     }
 
@@ -508,10 +781,23 @@ impl BlockingCardService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default()
+                .route("/api/card/{card-id}/params/{param-key}/search/{query}"),
         )
     }
 
+    /// GET /api/card/{card-id}/params/{param-key}/search/{query}
+    ///
+    /// Typed-id variant of [`Self::get_by_card_id_params_by_param_key_search_by_query`].
+    pub fn get_by_card_id_params_by_param_key_search_by_query_typed(
+        &self,
+        card_id: CardId,
+        param_key: impl Into<PathParam>,
+        query: impl Into<PathParam>,
+    ) -> Result<Value> {
+        self.get_by_card_id_params_by_param_key_search_by_query(card_id, param_key, query)
+    }
+
     /// GET /api/card/{card-id}/params/{param-key}/values
     pub fn get_by_card_id_params_by_param_key_values(
         &self,
@@ -533,10 +819,21 @@ impl BlockingCardService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/card/{card-id}/params/{param-key}/values"),
         )
     }
 
+    /// GET /api/card/{card-id}/params/{param-key}/values
+    ///
+    /// Typed-id variant of [`Self::get_by_card_id_params_by_param_key_values`].
+    pub fn get_by_card_id_params_by_param_key_values_typed(
+        &self,
+        card_id: CardId,
+        param_key: impl Into<PathParam>,
+    ) -> Result<Value> {
+        self.get_by_card_id_params_by_param_key_values(card_id, param_key)
+    }
+
     /// DELETE /api/card/{card-id}/public_link
     pub fn delete_by_card_id_public_link(&self, card_id: impl Into<PathParam>) -> Result<Value> {
         let card_id = card_id.into();
@@ -546,10 +843,17 @@ impl BlockingCardService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/card/{card-id}/public_link"),
         )
     }
 
+    /// DELETE /api/card/{card-id}/public_link
+    ///
+    /// Typed-id variant of [`Self::delete_by_card_id_public_link`].
+    pub fn delete_by_card_id_public_link_typed(&self, card_id: CardId) -> Result<Value> {
+        self.delete_by_card_id_public_link(card_id)
+    }
+
     /// POST /api/card/{card-id}/public_link
     pub fn post_by_card_id_public_link(&self, card_id: impl Into<PathParam>) -> Result<Value> {
         let card_id = card_id.into();
@@ -559,10 +863,17 @@ impl BlockingCardService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/card/{card-id}/public_link"),
         )
     }
 
+    /// POST /api/card/{card-id}/public_link
+    ///
+    /// Typed-id variant of [`Self::post_by_card_id_public_link`].
+    pub fn post_by_card_id_public_link_typed(&self, card_id: CardId) -> Result<Value> {
+        self.post_by_card_id_public_link(card_id)
+    }
+
     /// POST /api/card/{card-id}/query
     pub fn post_by_card_id_query(
         &self,
@@ -576,10 +887,21 @@ impl BlockingCardService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/card/{card-id}/query"),
         )
     }
 
+    /// POST /api/card/{card-id}/query
+    ///
+    /// Typed-id variant of [`Self::post_by_card_id_query`].
+    pub fn post_by_card_id_query_typed(
+        &self,
+        card_id: CardId,
+        body: Option<&Value>,
+    ) -> Result<Value> {
+        self.post_by_card_id_query(card_id, body)
+    }
+
     /// POST /api/card/{card-id}/query/{export-format}
     pub fn post_by_card_id_query_by_export_format(
         &self,
@@ -601,10 +923,22 @@ impl BlockingCardService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/card/{card-id}/query/{export-format}"),
         )
     }
 
+    /// POST /api/card/{card-id}/query/{export-format}
+    ///
+    /// Typed-id variant of [`Self::post_by_card_id_query_by_export_format`].
+    pub fn post_by_card_id_query_by_export_format_typed(
+        &self,
+        card_id: CardId,
+        export_format: impl Into<PathParam>,
+        body: Option<&Value>,
+    ) -> Result<Vec<u8>> {
+        self.post_by_card_id_query_by_export_format(card_id, export_format, body)
+    }
+
     /// DELETE /api/card/{id}
     pub fn delete_by_id(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -614,10 +948,17 @@ impl BlockingCardService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/card/{id}"),
         )
     }
 
+    /// DELETE /api/card/{id}
+    ///
+    /// Typed-id variant of [`Self::delete_by_id`].
+    pub fn delete_by_id_typed(&self, id: CardId) -> Result<Value> {
+        self.delete_by_id(id)
+    }
+
     /// GET /api/card/{id}
     pub fn get_by_id(&self, id: impl Into<PathParam>, query: Option<&Value>) -> Result<Value> {
         let id = id.into();
@@ -627,10 +968,17 @@ impl BlockingCardService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/card/{id}"),
         )
     }
 
+    /// GET /api/card/{id}
+    ///
+    /// Typed-id variant of [`Self::get_by_id`].
+    pub fn get_by_id_typed(&self, id: CardId, query: Option<&Value>) -> Result<Value> {
+        self.get_by_id(id, query)
+    }
+
     /// PUT /api/card/{id}
     pub fn put_by_id(
         &self,
@@ -645,10 +993,22 @@ impl BlockingCardService {
             &segments,
             query,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/card/{id}"),
         )
     }
 
+    /// PUT /api/card/{id}
+    ///
+    /// Typed-id variant of [`Self::put_by_id`].
+    pub fn put_by_id_typed(
+        &self,
+        id: CardId,
+        query: Option<&Value>,
+        body: Option<&Value>,
+    ) -> Result<Value> {
+        self.put_by_id(id, query, body)
+    }
+
     /// POST /api/card/{id}/copy
     pub fn post_by_id_copy(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -658,10 +1018,17 @@ impl BlockingCardService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/card/{id}/copy"),
         )
     }
 
+    /// POST /api/card/{id}/copy
+    ///
+    /// Typed-id variant of [`Self::post_by_id_copy`].
+    pub fn post_by_id_copy_typed(&self, id: CardId) -> Result<Value> {
+        self.post_by_id_copy(id)
+    }
+
     /// GET /api/card/{id}/dashboards
     pub fn get_by_id_dashboards(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -671,10 +1038,17 @@ impl BlockingCardService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/card/{id}/dashboards"),
         )
     }
 
+    /// GET /api/card/{id}/dashboards
+    ///
+    /// Typed-id variant of [`Self::get_by_id_dashboards`].
+    pub fn get_by_id_dashboards_typed(&self, id: CardId) -> Result<Value> {
+        self.get_by_id_dashboards(id)
+    }
+
     /// GET /api/card/{id}/params/{param-key}/remapping
     pub fn get_by_id_params_by_param_key_remapping(
         &self,
@@ -697,10 +1071,22 @@ impl BlockingCardService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/card/{id}/params/{param-key}/remapping"),
         )
     }
 
+    /// GET /api/card/{id}/params/{param-key}/remapping
+    ///
+    /// Typed-id variant of [`Self::get_by_id_params_by_param_key_remapping`].
+    pub fn get_by_id_params_by_param_key_remapping_typed(
+        &self,
+        id: CardId,
+        param_key: impl Into<PathParam>,
+        query: Option<&Value>,
+    ) -> Result<Value> {
+        self.get_by_id_params_by_param_key_remapping(id, param_key, query)
+    }
+
     /// GET /api/card/{id}/query_metadata
     pub fn get_by_id_query_metadata(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -710,10 +1096,17 @@ impl BlockingCardService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/card/{id}/query_metadata"),
         )
     }
 
+    /// GET /api/card/{id}/query_metadata
+    ///
+    /// Typed-id variant of [`Self::get_by_id_query_metadata`].
+    pub fn get_by_id_query_metadata_typed(&self, id: CardId) -> Result<Value> {
+        self.get_by_id_query_metadata(id)
+    }
+
     /// GET /api/card/{id}/series
     pub fn get_by_id_series(
         &self,
@@ -727,7 +1120,14 @@ impl BlockingCardService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/card/{id}/series"),
         )
     }
+
+    /// GET /api/card/{id}/series
+    ///
+    /// Typed-id variant of [`Self::get_by_id_series`].
+    pub fn get_by_id_series_typed(&self, id: CardId, query: Option<&Value>) -> Result<Value> {
+        self.get_by_id_series(id, query)
+    }
 }
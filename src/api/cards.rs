@@ -29,7 +29,7 @@ impl CardsService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/cards/dashboards"),
             )
             .await
     }
@@ -43,7 +43,7 @@ impl CardsService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/cards/move"),
             )
             .await
     }
@@ -69,7 +69,7 @@ impl BlockingCardsService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/cards/dashboards"),
         )
     }
 
@@ -81,7 +81,7 @@ impl BlockingCardsService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/cards/move"),
         )
     }
 }
@@ -1,7 +1,11 @@
 use http::Method;
 use serde_json::Value;
 
-use crate::{Result, client::RequestOptions, types::path::PathParam};
+use crate::{
+    Result,
+    client::RequestOptions,
+    types::{ids::ChannelId, path::PathParam},
+};
 
 #[cfg(feature = "blocking")]
 use crate::client::BlockingClient;
@@ -29,7 +33,7 @@ impl ChannelService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/channel/"),
             )
             .await
     }
@@ -43,7 +47,7 @@ impl ChannelService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/channel/"),
             )
             .await
     }
@@ -57,7 +61,7 @@ impl ChannelService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/channel/test"),
             )
             .await
     }
@@ -72,11 +76,18 @@ impl ChannelService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/channel/{id}"),
             )
             .await
     }
 
+    /// GET /api/channel/{id}
+    ///
+    /// Typed-id variant of [`Self::get_by_id`].
+    pub async fn get_by_id_typed(&self, id: ChannelId) -> Result<Value> {
+        self.get_by_id(id).await
+    }
+
     /// PUT /api/channel/{id}
     pub async fn put_by_id(&self, id: impl Into<PathParam>, body: Option<&Value>) -> Result<Value> {
         let id = id.into();
@@ -87,10 +98,17 @@ impl ChannelService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/channel/{id}"),
             )
             .await
     }
+
+    /// PUT /api/channel/{id}
+    ///
+    /// Typed-id variant of [`Self::put_by_id`].
+    pub async fn put_by_id_typed(&self, id: ChannelId, body: Option<&Value>) -> Result<Value> {
+        self.put_by_id(id, body).await
+    }
 }
 
 #[cfg(feature = "blocking")]
@@ -113,7 +131,7 @@ impl BlockingChannelService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/channel/"),
         )
     }
 
@@ -125,7 +143,7 @@ impl BlockingChannelService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/channel/"),
         )
     }
 
@@ -137,7 +155,7 @@ impl BlockingChannelService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/channel/test"),
         )
     }
 
@@ -150,10 +168,17 @@ impl BlockingChannelService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/channel/{id}"),
         )
     }
 
+    /// GET /api/channel/{id}
+    ///
+    /// Typed-id variant of [`Self::get_by_id`].
+    pub fn get_by_id_typed(&self, id: ChannelId) -> Result<Value> {
+        self.get_by_id(id)
+    }
+
     /// PUT /api/channel/{id}
     pub fn put_by_id(&self, id: impl Into<PathParam>, body: Option<&Value>) -> Result<Value> {
         let id = id.into();
@@ -163,7 +188,14 @@ impl BlockingChannelService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/channel/{id}"),
         )
     }
+
+    /// PUT /api/channel/{id}
+    ///
+    /// Typed-id variant of [`Self::put_by_id`].
+    pub fn put_by_id_typed(&self, id: ChannelId, body: Option<&Value>) -> Result<Value> {
+        self.put_by_id(id, body)
+    }
 }
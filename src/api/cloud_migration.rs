@@ -29,7 +29,7 @@ impl CloudMigrationService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/cloud-migration/"),
             )
             .await
     }
@@ -43,7 +43,7 @@ impl CloudMigrationService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/cloud-migration/"),
             )
             .await
     }
@@ -57,7 +57,7 @@ impl CloudMigrationService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/cloud-migration/cancel"),
             )
             .await
     }
@@ -83,7 +83,7 @@ impl BlockingCloudMigrationService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/cloud-migration/"),
         )
     }
 
@@ -95,7 +95,7 @@ impl BlockingCloudMigrationService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/cloud-migration/"),
         )
     }
 
@@ -107,7 +107,7 @@ impl BlockingCloudMigrationService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/cloud-migration/cancel"),
         )
     }
 }
@@ -1,8 +1,24 @@
 use http::Method;
 use serde_json::Value;
 
-use crate::{Result, client::RequestOptions, types::path::PathParam};
+use crate::{
+    Result,
+    client::RequestOptions,
+    types::{
+        collection::{
+            Collection, CollectionItems, CollectionItemsQuery, CollectionPermissionsChanges,
+            CollectionPermissionsGraph, CollectionPermissionsGraphUpdate, CollectionRef,
+            CollectionTreeNode,
+        },
+        ids::CollectionId,
+        path::PathParam,
+    },
+};
 
+#[cfg(feature = "async")]
+use super::permissions::apply_graph_changes;
+#[cfg(feature = "blocking")]
+use super::permissions::apply_graph_changes_blocking;
 #[cfg(feature = "blocking")]
 use crate::client::BlockingClient;
 #[cfg(feature = "async")]
@@ -20,6 +36,137 @@ impl CollectionService {
         Self { client }
     }
 
+    /// GET /api/collection/graph
+    pub async fn get_collection_permissions_graph(&self) -> Result<CollectionPermissionsGraph> {
+        let segments = ["api", "collection", "graph"];
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/collection/graph"),
+            )
+            .await
+    }
+
+    /// PUT /api/collection/graph
+    ///
+    /// Send `changes` made against graph `revision`. Fails with
+    /// [`Error::Conflict`](crate::Error::Conflict) when the graph has been edited since; see
+    /// [`apply_collection_permissions_changes`](Self::apply_collection_permissions_changes).
+    pub async fn put_collection_permissions_changes(
+        &self,
+        revision: i64,
+        changes: &CollectionPermissionsChanges,
+    ) -> Result<CollectionPermissionsGraph> {
+        let segments = ["api", "collection", "graph"];
+        self.client
+            .request_json(
+                Method::PUT,
+                &segments,
+                Option::<&()>::None,
+                Some(&CollectionPermissionsGraphUpdate::new(revision, changes)),
+                RequestOptions::default().route("/api/collection/graph"),
+            )
+            .await
+    }
+
+    /// PUT /api/collection/graph
+    ///
+    /// Send `changes` made against graph `revision`, rebasing and resending them on conflicts
+    /// the same way `apply_permissions_changes` does for the data permissions graph.
+    pub async fn apply_collection_permissions_changes(
+        &self,
+        revision: i64,
+        changes: &CollectionPermissionsChanges,
+    ) -> Result<CollectionPermissionsGraph> {
+        apply_graph_changes(
+            revision,
+            changes,
+            |revision, changes| async move {
+                self.put_collection_permissions_changes(revision, &changes)
+                    .await
+            },
+            || self.get_collection_permissions_graph(),
+        )
+        .await
+    }
+
+    /// GET /api/collection/{id}
+    ///
+    /// Pass [`CollectionRef::Root`] for `GET /api/collection/root`.
+    pub async fn get_collection(&self, id: impl Into<CollectionRef>) -> Result<Collection> {
+        let id = id.into();
+        let route = match id {
+            CollectionRef::Root => "/api/collection/root",
+            CollectionRef::Id(_) => "/api/collection/{id}",
+        };
+        let id = PathParam::from(id);
+        let segments = ["api", "collection", id.as_str()];
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route(route),
+            )
+            .await
+    }
+
+    /// GET /api/collection/{id}/items
+    pub async fn collection_items(
+        &self,
+        id: impl Into<CollectionRef>,
+        query: &CollectionItemsQuery,
+    ) -> Result<CollectionItems> {
+        let id = id.into();
+        let route = match id {
+            CollectionRef::Root => "/api/collection/root/items",
+            CollectionRef::Id(_) => "/api/collection/{id}/items",
+        };
+        let id = PathParam::from(id);
+        let segments = ["api", "collection", id.as_str(), "items"];
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                Some(query),
+                Option::<&()>::None,
+                RequestOptions::default().route(route),
+            )
+            .await
+    }
+
+    /// GET /api/collection/tree
+    pub async fn collection_tree(&self, query: Option<&Value>) -> Result<Vec<CollectionTreeNode>> {
+        let segments = ["api", "collection", "tree"];
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                query,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/collection/tree"),
+            )
+            .await
+    }
+
+    /// GET /api/collection/trash
+    pub async fn trash_collection(&self) -> Result<Collection> {
+        let segments = ["api", "collection", "trash"];
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/collection/trash"),
+            )
+            .await
+    }
+
     /// GET /api/collection/
     pub async fn get(&self, query: Option<&Value>) -> Result<Value> {
         let segments = ["api", "collection"];
@@ -29,7 +176,7 @@ impl CollectionService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/collection/"),
             )
             .await
     }
@@ -43,7 +190,7 @@ impl CollectionService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/collection/"),
             )
             .await
     }
@@ -57,7 +204,7 @@ impl CollectionService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/collection/graph"),
             )
             .await
     }
@@ -71,7 +218,7 @@ impl CollectionService {
                 &segments,
                 query,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/collection/graph"),
             )
             .await
     }
@@ -85,7 +232,7 @@ impl CollectionService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/collection/root"),
             )
             .await
     }
@@ -99,7 +246,8 @@ impl CollectionService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default()
+                    .route("/api/collection/root/dashboard-question-candidates"),
             )
             .await
     }
@@ -113,7 +261,7 @@ impl CollectionService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/collection/root/items"),
             )
             .await
     }
@@ -135,7 +283,8 @@ impl CollectionService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default()
+                    .route("/api/collection/root/move-dashboard-question-candidates"),
             )
             .await
     }
@@ -149,7 +298,7 @@ impl CollectionService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/collection/trash"),
             )
             .await
     }
@@ -163,7 +312,7 @@ impl CollectionService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/collection/tree"),
             )
             .await
     }
@@ -178,11 +327,18 @@ impl CollectionService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/collection/{id}"),
             )
             .await
     }
 
+    /// DELETE /api/collection/{id}
+    ///
+    /// Typed-id variant of [`Self::delete_by_id`].
+    pub async fn delete_by_id_typed(&self, id: CollectionId) -> Result<Value> {
+        self.delete_by_id(id).await
+    }
+
     /// GET /api/collection/{id}
     pub async fn get_by_id(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -193,11 +349,18 @@ impl CollectionService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/collection/{id}"),
             )
             .await
     }
 
+    /// GET /api/collection/{id}
+    ///
+    /// Typed-id variant of [`Self::get_by_id`].
+    pub async fn get_by_id_typed(&self, id: CollectionId) -> Result<Value> {
+        self.get_by_id(id).await
+    }
+
     /// PUT /api/collection/{id}
     pub async fn put_by_id(&self, id: impl Into<PathParam>, body: Option<&Value>) -> Result<Value> {
         let id = id.into();
@@ -208,11 +371,18 @@ impl CollectionService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/collection/{id}"),
             )
             .await
     }
 
+    /// PUT /api/collection/{id}
+    ///
+    /// Typed-id variant of [`Self::put_by_id`].
+    pub async fn put_by_id_typed(&self, id: CollectionId, body: Option<&Value>) -> Result<Value> {
+        self.put_by_id(id, body).await
+    }
+
     /// GET /api/collection/{id}/dashboard-question-candidates
     pub async fn get_by_id_dashboard_question_candidates(
         &self,
@@ -231,11 +401,22 @@ impl CollectionService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default()
+                    .route("/api/collection/{id}/dashboard-question-candidates"),
             )
             .await
     }
 
+    /// GET /api/collection/{id}/dashboard-question-candidates
+    ///
+    /// Typed-id variant of [`Self::get_by_id_dashboard_question_candidates`].
+    pub async fn get_by_id_dashboard_question_candidates_typed(
+        &self,
+        id: CollectionId,
+    ) -> Result<Value> {
+        self.get_by_id_dashboard_question_candidates(id).await
+    }
+
     /// GET /api/collection/{id}/items
     pub async fn get_by_id_items(
         &self,
@@ -250,11 +431,22 @@ impl CollectionService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/collection/{id}/items"),
             )
             .await
     }
 
+    /// GET /api/collection/{id}/items
+    ///
+    /// Typed-id variant of [`Self::get_by_id_items`].
+    pub async fn get_by_id_items_typed(
+        &self,
+        id: CollectionId,
+        query: Option<&Value>,
+    ) -> Result<Value> {
+        self.get_by_id_items(id, query).await
+    }
+
     /// POST /api/collection/{id}/move-dashboard-question-candidates
     pub async fn post_by_id_move_dashboard_question_candidates(
         &self,
@@ -274,10 +466,23 @@ impl CollectionService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default()
+                    .route("/api/collection/{id}/move-dashboard-question-candidates"),
             )
             .await
     }
+
+    /// POST /api/collection/{id}/move-dashboard-question-candidates
+    ///
+    /// Typed-id variant of [`Self::post_by_id_move_dashboard_question_candidates`].
+    pub async fn post_by_id_move_dashboard_question_candidates_typed(
+        &self,
+        id: CollectionId,
+        body: Option<&Value>,
+    ) -> Result<Value> {
+        self.post_by_id_move_dashboard_question_candidates(id, body)
+            .await
+    }
 }
 
 #[cfg(feature = "blocking")]
@@ -292,6 +497,121 @@ impl BlockingCollectionService {
         Self { client }
     }
 
+    /// GET /api/collection/graph
+    pub fn get_collection_permissions_graph(&self) -> Result<CollectionPermissionsGraph> {
+        let segments = ["api", "collection", "graph"];
+        self.client.request_json(
+            Method::GET,
+            &segments,
+            Option::<&()>::None,
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/collection/graph"),
+        )
+    }
+
+    /// PUT /api/collection/graph
+    ///
+    /// Send `changes` made against graph `revision`. Fails with
+    /// [`Error::Conflict`](crate::Error::Conflict) when the graph has been edited since; see
+    /// [`apply_collection_permissions_changes`](Self::apply_collection_permissions_changes).
+    pub fn put_collection_permissions_changes(
+        &self,
+        revision: i64,
+        changes: &CollectionPermissionsChanges,
+    ) -> Result<CollectionPermissionsGraph> {
+        let segments = ["api", "collection", "graph"];
+        self.client.request_json(
+            Method::PUT,
+            &segments,
+            Option::<&()>::None,
+            Some(&CollectionPermissionsGraphUpdate::new(revision, changes)),
+            RequestOptions::default().route("/api/collection/graph"),
+        )
+    }
+
+    /// PUT /api/collection/graph
+    ///
+    /// Send `changes` made against graph `revision`, rebasing and resending them on conflicts
+    /// the same way `apply_permissions_changes` does for the data permissions graph.
+    pub fn apply_collection_permissions_changes(
+        &self,
+        revision: i64,
+        changes: &CollectionPermissionsChanges,
+    ) -> Result<CollectionPermissionsGraph> {
+        apply_graph_changes_blocking(
+            revision,
+            changes,
+            |revision, changes| self.put_collection_permissions_changes(revision, changes),
+            || self.get_collection_permissions_graph(),
+        )
+    }
+
+    /// GET /api/collection/{id}
+    ///
+    /// Pass [`CollectionRef::Root`] for `GET /api/collection/root`.
+    pub fn get_collection(&self, id: impl Into<CollectionRef>) -> Result<Collection> {
+        let id = id.into();
+        let route = match id {
+            CollectionRef::Root => "/api/collection/root",
+            CollectionRef::Id(_) => "/api/collection/{id}",
+        };
+        let id = PathParam::from(id);
+        let segments = ["api", "collection", id.as_str()];
+        self.client.request_json(
+            Method::GET,
+            &segments,
+            Option::<&()>::None,
+            Option::<&()>::None,
+            RequestOptions::default().route(route),
+        )
+    }
+
+    /// GET /api/collection/{id}/items
+    pub fn collection_items(
+        &self,
+        id: impl Into<CollectionRef>,
+        query: &CollectionItemsQuery,
+    ) -> Result<CollectionItems> {
+        let id = id.into();
+        let route = match id {
+            CollectionRef::Root => "/api/collection/root/items",
+            CollectionRef::Id(_) => "/api/collection/{id}/items",
+        };
+        let id = PathParam::from(id);
+        let segments = ["api", "collection", id.as_str(), "items"];
+        self.client.request_json(
+            Method::GET,
+            &segments,
+            Some(query),
+            Option::<&()>::None,
+            RequestOptions::default().route(route),
+        )
+    }
+
+    /// GET /api/collection/tree
+    pub fn collection_tree(&self, query: Option<&Value>) -> Result<Vec<CollectionTreeNode>> {
+        let segments = ["api", "collection", "tree"];
+        self.client.request_json(
+            Method::GET,
+            &segments,
+            query,
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/collection/tree"),
+        )
+    }
+
+    /// GET /api/collection/trash
+    pub fn trash_collection(&self) -> Result<Collection> {
+        let segments = ["api", "collection", "trash"];
+        self.client.request_json(
+            Method::GET,
+            &segments,
+            Option::<&()>::None,
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/collection/trash"),
+        )
+    }
+
     /// GET /api/collection/
     pub fn get(&self, query: Option<&Value>) -> Result<Value> {
         let segments = ["api", "collection"];
@@ -300,7 +620,7 @@ impl BlockingCollectionService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/collection/"),
         )
     }
 
@@ -312,7 +632,7 @@ impl BlockingCollectionService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/collection/"),
         )
     }
 
@@ -324,7 +644,7 @@ impl BlockingCollectionService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/collection/graph"),
         )
     }
 
@@ -336,7 +656,7 @@ impl BlockingCollectionService {
             &segments,
             query,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/collection/graph"),
         )
     }
 
@@ -348,7 +668,7 @@ impl BlockingCollectionService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/collection/root"),
         )
     }
 
@@ -360,7 +680,7 @@ impl BlockingCollectionService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/collection/root/dashboard-question-candidates"),
         )
     }
 
@@ -372,7 +692,7 @@ impl BlockingCollectionService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/collection/root/items"),
         )
     }
 
@@ -392,7 +712,8 @@ impl BlockingCollectionService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default()
+                .route("/api/collection/root/move-dashboard-question-candidates"),
         )
     }
 
@@ -404,7 +725,7 @@ impl BlockingCollectionService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/collection/trash"),
         )
     }
 
@@ -416,7 +737,7 @@ impl BlockingCollectionService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/collection/tree"),
         )
     }
 
@@ -429,10 +750,17 @@ impl BlockingCollectionService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/collection/{id}"),
         )
     }
 
+    /// DELETE /api/collection/{id}
+    ///
+    /// Typed-id variant of [`Self::delete_by_id`].
+    pub fn delete_by_id_typed(&self, id: CollectionId) -> Result<Value> {
+        self.delete_by_id(id)
+    }
+
     /// GET /api/collection/{id}
     pub fn get_by_id(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -442,10 +770,17 @@ impl BlockingCollectionService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/collection/{id}"),
         )
     }
 
+    /// GET /api/collection/{id}
+    ///
+    /// Typed-id variant of [`Self::get_by_id`].
+    pub fn get_by_id_typed(&self, id: CollectionId) -> Result<Value> {
+        self.get_by_id(id)
+    }
+
     /// PUT /api/collection/{id}
     pub fn put_by_id(&self, id: impl Into<PathParam>, body: Option<&Value>) -> Result<Value> {
         let id = id.into();
@@ -455,10 +790,17 @@ impl BlockingCollectionService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/collection/{id}"),
         )
     }
 
+    /// PUT /api/collection/{id}
+    ///
+    /// Typed-id variant of [`Self::put_by_id`].
+    pub fn put_by_id_typed(&self, id: CollectionId, body: Option<&Value>) -> Result<Value> {
+        self.put_by_id(id, body)
+    }
+
     /// GET /api/collection/{id}/dashboard-question-candidates
     pub fn get_by_id_dashboard_question_candidates(
         &self,
@@ -476,10 +818,17 @@ impl BlockingCollectionService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/collection/{id}/dashboard-question-candidates"),
         )
     }
 
+    /// GET /api/collection/{id}/dashboard-question-candidates
+    ///
+    /// Typed-id variant of [`Self::get_by_id_dashboard_question_candidates`].
+    pub fn get_by_id_dashboard_question_candidates_typed(&self, id: CollectionId) -> Result<Value> {
+        self.get_by_id_dashboard_question_candidates(id)
+    }
+
     /// GET /api/collection/{id}/items
     pub fn get_by_id_items(
         &self,
@@ -493,10 +842,17 @@ impl BlockingCollectionService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/collection/{id}/items"),
         )
     }
 
+    /// GET /api/collection/{id}/items
+    ///
+    /// Typed-id variant of [`Self::get_by_id_items`].
+    pub fn get_by_id_items_typed(&self, id: CollectionId, query: Option<&Value>) -> Result<Value> {
+        self.get_by_id_items(id, query)
+    }
+
     /// POST /api/collection/{id}/move-dashboard-question-candidates
     pub fn post_by_id_move_dashboard_question_candidates(
         &self,
@@ -515,7 +871,19 @@ impl BlockingCollectionService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default()
+                .route("/api/collection/{id}/move-dashboard-question-candidates"),
         )
     }
+
+    /// POST /api/collection/{id}/move-dashboard-question-candidates
+    ///
+    /// Typed-id variant of [`Self::post_by_id_move_dashboard_question_candidates`].
+    pub fn post_by_id_move_dashboard_question_candidates_typed(
+        &self,
+        id: CollectionId,
+        body: Option<&Value>,
+    ) -> Result<Value> {
+        self.post_by_id_move_dashboard_question_candidates(id, body)
+    }
 }
@@ -1,7 +1,15 @@
 use http::Method;
 use serde_json::Value;
 
-use crate::{Result, client::RequestOptions, types::path::PathParam};
+use crate::{
+    Result,
+    client::RequestOptions,
+    types::{
+        dashboard::{CopyDashboard, CreateDashboard, Dashboard, DashboardCards, UpdateDashboard},
+        ids::{CardId, CollectionId, DashboardId, DashcardId},
+        path::PathParam,
+    },
+};
 
 #[cfg(feature = "blocking")]
 use crate::client::BlockingClient;
@@ -20,6 +28,94 @@ impl DashboardService {
         Self { client }
     }
 
+    /// GET /api/dashboard/{id}
+    pub async fn get_dashboard(&self, id: DashboardId) -> Result<Dashboard> {
+        let id = PathParam::from(id);
+        let segments = ["api", "dashboard", id.as_str()];
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/dashboard/{id}"),
+            )
+            .await
+    }
+
+    /// POST /api/dashboard/
+    pub async fn create_dashboard(&self, request: &CreateDashboard) -> Result<Dashboard> {
+        let segments = ["api", "dashboard"];
+        self.client
+            .request_json(
+                Method::POST,
+                &segments,
+                Option::<&()>::None,
+                Some(request),
+                RequestOptions::default().route("/api/dashboard/"),
+            )
+            .await
+    }
+
+    /// PUT /api/dashboard/{id}
+    pub async fn update_dashboard(
+        &self,
+        id: DashboardId,
+        request: &UpdateDashboard,
+    ) -> Result<Dashboard> {
+        let id = PathParam::from(id);
+        let segments = ["api", "dashboard", id.as_str()];
+        self.client
+            .request_json(
+                Method::PUT,
+                &segments,
+                Option::<&()>::None,
+                Some(request),
+                RequestOptions::default().route("/api/dashboard/{id}"),
+            )
+            .await
+    }
+
+    /// POST /api/dashboard/{from-dashboard-id}/copy
+    pub async fn copy_dashboard(
+        &self,
+        from: DashboardId,
+        request: &CopyDashboard,
+    ) -> Result<Dashboard> {
+        let from = PathParam::from(from);
+        let segments = ["api", "dashboard", from.as_str(), "copy"];
+        self.client
+            .request_json(
+                Method::POST,
+                &segments,
+                Option::<&()>::None,
+                Some(request),
+                RequestOptions::default().route("/api/dashboard/{from-dashboard-id}/copy"),
+            )
+            .await
+    }
+
+    /// PUT /api/dashboard/{id}/cards
+    ///
+    /// Replaces every dashcard (and tab, when given) on the dashboard.
+    pub async fn update_dashboard_cards(
+        &self,
+        id: DashboardId,
+        request: &DashboardCards,
+    ) -> Result<DashboardCards> {
+        let id = PathParam::from(id);
+        let segments = ["api", "dashboard", id.as_str(), "cards"];
+        self.client
+            .request_json(
+                Method::PUT,
+                &segments,
+                Option::<&()>::None,
+                Some(request),
+                RequestOptions::default().route("/api/dashboard/{id}/cards"),
+            )
+            .await
+    }
+
     /// GET /api/dashboard/
     pub async fn get(&self, query: Option<&Value>) -> Result<Value> {
         let segments = ["api", "dashboard"];
@@ -29,7 +125,7 @@ impl DashboardService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/dashboard/"),
             )
             .await
     }
@@ -43,7 +139,7 @@ impl DashboardService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/dashboard/"),
             )
             .await
     }
@@ -57,7 +153,7 @@ impl DashboardService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/dashboard/embeddable"),
             )
             .await
     }
@@ -71,7 +167,7 @@ impl DashboardService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/dashboard/params/valid-filter-fields"),
             )
             .await
     }
@@ -104,11 +200,30 @@ impl DashboardService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/dashboard/pivot/{dashboard-id}/dashcard/{dashcard-id}/card/{card-id}/query"),
             )
             .await
     }
 
+    /// POST /api/dashboard/pivot/{dashboard-id}/dashcard/{dashcard-id}/card/{card-id}/query
+    ///
+    /// Typed-id variant of [`Self::post_pivot_by_dashboard_id_dashcard_by_dashcard_id_card_by_card_id_query`].
+    pub async fn post_pivot_by_dashboard_id_dashcard_by_dashcard_id_card_by_card_id_query_typed(
+        &self,
+        dashboard_id: DashboardId,
+        dashcard_id: DashcardId,
+        card_id: CardId,
+        body: Option<&Value>,
+    ) -> Result<Value> {
+        self.post_pivot_by_dashboard_id_dashcard_by_dashcard_id_card_by_card_id_query(
+            dashboard_id,
+            dashcard_id,
+            card_id,
+            body,
+        )
+        .await
+    }
+
     /// GET /api/dashboard/public
     pub async fn get_public(&self) -> Result<Value> {
         let segments = ["api", "dashboard", "public"];
@@ -118,7 +233,7 @@ impl DashboardService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/dashboard/public"),
             )
             .await
     }
@@ -132,7 +247,7 @@ impl DashboardService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/dashboard/save"),
             )
             .await
     }
@@ -156,11 +271,23 @@ impl DashboardService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default()
+                    .route("/api/dashboard/save/collection/{parent-collection-id}"),
             )
             .await
     }
 
+    /// POST /api/dashboard/save/collection/{parent-collection-id}
+    ///
+    /// Typed-id variant of [`Self::post_save_collection_by_parent_collection_id`].
+    pub async fn post_save_collection_by_parent_collection_id_typed(
+        &self,
+        parent_collection_id: CollectionId,
+    ) -> Result<Value> {
+        self.post_save_collection_by_parent_collection_id(parent_collection_id)
+            .await
+    }
+
     /// POST /api/dashboard/{dashboard-id}/dashcard/{dashcard-id}/card/{card-id}/query
     pub async fn post_by_dashboard_id_dashcard_by_dashcard_id_card_by_card_id_query(
         &self,
@@ -188,11 +315,32 @@ impl DashboardService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route(
+                    "/api/dashboard/{dashboard-id}/dashcard/{dashcard-id}/card/{card-id}/query",
+                ),
             )
             .await
     }
 
+    /// POST /api/dashboard/{dashboard-id}/dashcard/{dashcard-id}/card/{card-id}/query
+    ///
+    /// Typed-id variant of [`Self::post_by_dashboard_id_dashcard_by_dashcard_id_card_by_card_id_query`].
+    pub async fn post_by_dashboard_id_dashcard_by_dashcard_id_card_by_card_id_query_typed(
+        &self,
+        dashboard_id: DashboardId,
+        dashcard_id: DashcardId,
+        card_id: CardId,
+        body: Option<&Value>,
+    ) -> Result<Value> {
+        self.post_by_dashboard_id_dashcard_by_dashcard_id_card_by_card_id_query(
+            dashboard_id,
+            dashcard_id,
+            card_id,
+            body,
+        )
+        .await
+    }
+
     /// POST /api/dashboard/{dashboard-id}/dashcard/{dashcard-id}/card/{card-id}/query/{export-format}
     pub async fn post_by_dashboard_id_dashcard_by_dashcard_id_card_by_card_id_query_by_export_format(
         &self,
@@ -223,11 +371,32 @@ impl DashboardService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/dashboard/{dashboard-id}/dashcard/{dashcard-id}/card/{card-id}/query/{export-format}"),
             )
             .await
     }
 
+    /// POST /api/dashboard/{dashboard-id}/dashcard/{dashcard-id}/card/{card-id}/query/{export-format}
+    ///
+    /// Typed-id variant of [`Self::post_by_dashboard_id_dashcard_by_dashcard_id_card_by_card_id_query_by_export_format`].
+    pub async fn post_by_dashboard_id_dashcard_by_dashcard_id_card_by_card_id_query_by_export_format_typed(
+        &self,
+        dashboard_id: DashboardId,
+        dashcard_id: DashcardId,
+        card_id: CardId,
+        export_format: impl Into<PathParam>,
+        body: Option<&Value>,
+    ) -> Result<Vec<u8>> {
+        self.post_by_dashboard_id_dashcard_by_dashcard_id_card_by_card_id_query_by_export_format(
+            dashboard_id,
+            dashcard_id,
+            card_id,
+            export_format,
+            body,
+        )
+        .await
+    }
+
     /// GET /api/dashboard/{dashboard-id}/dashcard/{dashcard-id}/execute
     pub async fn get_by_dashboard_id_dashcard_by_dashcard_id_execute(
         &self,
@@ -251,11 +420,25 @@ impl DashboardService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default()
+                    .route("/api/dashboard/{dashboard-id}/dashcard/{dashcard-id}/execute"),
             )
             .await
     }
 
+    /// GET /api/dashboard/{dashboard-id}/dashcard/{dashcard-id}/execute
+    ///
+    /// Typed-id variant of [`Self::get_by_dashboard_id_dashcard_by_dashcard_id_execute`].
+    pub async fn get_by_dashboard_id_dashcard_by_dashcard_id_execute_typed(
+        &self,
+        dashboard_id: DashboardId,
+        dashcard_id: DashcardId,
+        query: Option<&Value>,
+    ) -> Result<Value> {
+        self.get_by_dashboard_id_dashcard_by_dashcard_id_execute(dashboard_id, dashcard_id, query)
+            .await
+    }
+
     /// POST /api/dashboard/{dashboard-id}/dashcard/{dashcard-id}/execute
     pub async fn post_by_dashboard_id_dashcard_by_dashcard_id_execute(
         &self,
@@ -279,11 +462,25 @@ impl DashboardService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default()
+                    .route("/api/dashboard/{dashboard-id}/dashcard/{dashcard-id}/execute"),
             )
             .await
     }
 
+    /// POST /api/dashboard/{dashboard-id}/dashcard/{dashcard-id}/execute
+    ///
+    /// Typed-id variant of [`Self::post_by_dashboard_id_dashcard_by_dashcard_id_execute`].
+    pub async fn post_by_dashboard_id_dashcard_by_dashcard_id_execute_typed(
+        &self,
+        dashboard_id: DashboardId,
+        dashcard_id: DashcardId,
+        body: Option<&Value>,
+    ) -> Result<Value> {
+        self.post_by_dashboard_id_dashcard_by_dashcard_id_execute(dashboard_id, dashcard_id, body)
+            .await
+    }
+
     /// DELETE /api/dashboard/{dashboard-id}/public_link
     pub async fn delete_by_dashboard_id_public_link(
         &self,
@@ -297,11 +494,21 @@ impl DashboardService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/dashboard/{dashboard-id}/public_link"),
             )
             .await
     }
 
+    /// DELETE /api/dashboard/{dashboard-id}/public_link
+    ///
+    /// Typed-id variant of [`Self::delete_by_dashboard_id_public_link`].
+    pub async fn delete_by_dashboard_id_public_link_typed(
+        &self,
+        dashboard_id: DashboardId,
+    ) -> Result<Value> {
+        self.delete_by_dashboard_id_public_link(dashboard_id).await
+    }
+
     /// POST /api/dashboard/{dashboard-id}/public_link
     pub async fn post_by_dashboard_id_public_link(
         &self,
@@ -315,11 +522,21 @@ impl DashboardService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/dashboard/{dashboard-id}/public_link"),
             )
             .await
     }
 
+    /// POST /api/dashboard/{dashboard-id}/public_link
+    ///
+    /// Typed-id variant of [`Self::post_by_dashboard_id_public_link`].
+    pub async fn post_by_dashboard_id_public_link_typed(
+        &self,
+        dashboard_id: DashboardId,
+    ) -> Result<Value> {
+        self.post_by_dashboard_id_public_link(dashboard_id).await
+    }
+
     /// POST /api/dashboard/{from-dashboard-id}/copy
     pub async fn post_by_from_dashboard_id_copy(
         &self,
@@ -334,11 +551,23 @@ impl DashboardService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/dashboard/{from-dashboard-id}/copy"),
             )
             .await
     }
 
+    /// POST /api/dashboard/{from-dashboard-id}/copy
+    ///
+    /// Typed-id variant of [`Self::post_by_from_dashboard_id_copy`].
+    pub async fn post_by_from_dashboard_id_copy_typed(
+        &self,
+        from_dashboard_id: DashboardId,
+        body: Option<&Value>,
+    ) -> Result<Value> {
+        self.post_by_from_dashboard_id_copy(from_dashboard_id, body)
+            .await
+    }
+
     /// DELETE /api/dashboard/{id}
     pub async fn delete_by_id(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -349,11 +578,18 @@ impl DashboardService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/dashboard/{id}"),
             )
             .await
     }
 
+    /// DELETE /api/dashboard/{id}
+    ///
+    /// Typed-id variant of [`Self::delete_by_id`].
+    pub async fn delete_by_id_typed(&self, id: DashboardId) -> Result<Value> {
+        self.delete_by_id(id).await
+    }
+
     /// GET /api/dashboard/{id}
     pub async fn get_by_id(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -364,11 +600,18 @@ impl DashboardService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/dashboard/{id}"),
             )
             .await
     }
 
+    /// GET /api/dashboard/{id}
+    ///
+    /// Typed-id variant of [`Self::get_by_id`].
+    pub async fn get_by_id_typed(&self, id: DashboardId) -> Result<Value> {
+        self.get_by_id(id).await
+    }
+
     /// PUT /api/dashboard/{id}
     pub async fn put_by_id(&self, id: impl Into<PathParam>, body: Option<&Value>) -> Result<Value> {
         let id = id.into();
@@ -379,11 +622,18 @@ impl DashboardService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/dashboard/{id}"),
             )
             .await
     }
 
+    /// PUT /api/dashboard/{id}
+    ///
+    /// Typed-id variant of [`Self::put_by_id`].
+    pub async fn put_by_id_typed(&self, id: DashboardId, body: Option<&Value>) -> Result<Value> {
+        self.put_by_id(id, body).await
+    }
+
     /// PUT /api/dashboard/{id}/cards
     pub async fn put_by_id_cards(
         &self,
@@ -398,11 +648,22 @@ impl DashboardService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/dashboard/{id}/cards"),
             )
             .await
     }
 
+    /// PUT /api/dashboard/{id}/cards
+    ///
+    /// Typed-id variant of [`Self::put_by_id_cards`].
+    pub async fn put_by_id_cards_typed(
+        &self,
+        id: DashboardId,
+        body: Option<&Value>,
+    ) -> Result<Value> {
+        self.put_by_id_cards(id, body).await
+    }
+
     /// GET /api/dashboard/{id}/items
     pub async fn get_by_id_items(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -413,11 +674,18 @@ impl DashboardService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/dashboard/{id}/items"),
             )
             .await
     }
 
+    /// GET /api/dashboard/{id}/items
+    ///
+    /// Typed-id variant of [`Self::get_by_id_items`].
+    pub async fn get_by_id_items_typed(&self, id: DashboardId) -> Result<Value> {
+        self.get_by_id_items(id).await
+    }
+
     /// GET /api/dashboard/{id}/params/{param-key}/remapping
     pub async fn get_by_id_params_by_param_key_remapping(
         &self,
@@ -441,11 +709,24 @@ impl DashboardService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/dashboard/{id}/params/{param-key}/remapping"),
             )
             .await
     }
 
+    /// GET /api/dashboard/{id}/params/{param-key}/remapping
+    ///
+    /// Typed-id variant of [`Self::get_by_id_params_by_param_key_remapping`].
+    pub async fn get_by_id_params_by_param_key_remapping_typed(
+        &self,
+        id: DashboardId,
+        param_key: impl Into<PathParam>,
+        query: Option<&Value>,
+    ) -> Result<Value> {
+        self.get_by_id_params_by_param_key_remapping(id, param_key, query)
+            .await
+    }
+
     /// GET /api/dashboard/{id}/params/{param-key}/search/{query}
     pub async fn get_by_id_params_by_param_key_search_by_query(
         &self,
@@ -471,11 +752,25 @@ impl DashboardService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default()
+                    .route("/api/dashboard/{id}/params/{param-key}/search/{query}"),
             )
             .await
     }
 
+    /// GET /api/dashboard/{id}/params/{param-key}/search/{query}
+    ///
+    /// Typed-id variant of [`Self::get_by_id_params_by_param_key_search_by_query`].
+    pub async fn get_by_id_params_by_param_key_search_by_query_typed(
+        &self,
+        id: DashboardId,
+        param_key: impl Into<PathParam>,
+        query: impl Into<PathParam>,
+    ) -> Result<Value> {
+        self.get_by_id_params_by_param_key_search_by_query(id, param_key, query)
+            .await
+    }
+
     /// GET /api/dashboard/{id}/params/{param-key}/values
     pub async fn get_by_id_params_by_param_key_values(
         &self,
@@ -498,11 +793,23 @@ impl DashboardService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/dashboard/{id}/params/{param-key}/values"),
             )
             .await
     }
 
+    /// GET /api/dashboard/{id}/params/{param-key}/values
+    ///
+    /// Typed-id variant of [`Self::get_by_id_params_by_param_key_values`].
+    pub async fn get_by_id_params_by_param_key_values_typed(
+        &self,
+        id: DashboardId,
+        param_key: impl Into<PathParam>,
+    ) -> Result<Value> {
+        self.get_by_id_params_by_param_key_values(id, param_key)
+            .await
+    }
+
     /// GET /api/dashboard/{id}/query_metadata
     pub async fn get_by_id_query_metadata(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -513,11 +820,18 @@ impl DashboardService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/dashboard/{id}/query_metadata"),
             )
             .await
     }
 
+    /// GET /api/dashboard/{id}/query_metadata
+    ///
+    /// Typed-id variant of [`Self::get_by_id_query_metadata`].
+    pub async fn get_by_id_query_metadata_typed(&self, id: DashboardId) -> Result<Value> {
+        self.get_by_id_query_metadata(id).await
+    }
+
     /// GET /api/dashboard/{id}/related
     pub async fn get_by_id_related(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -528,10 +842,17 @@ impl DashboardService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/dashboard/{id}/related"),
             )
             .await
     }
+
+    /// GET /api/dashboard/{id}/related
+    ///
+    /// Typed-id variant of [`Self::get_by_id_related`].
+    pub async fn get_by_id_related_typed(&self, id: DashboardId) -> Result<Value> {
+        self.get_by_id_related(id).await
+    }
 }
 
 #[cfg(feature = "blocking")]
@@ -546,6 +867,80 @@ impl BlockingDashboardService {
         Self { client }
     }
 
+    /// GET /api/dashboard/{id}
+    pub fn get_dashboard(&self, id: DashboardId) -> Result<Dashboard> {
+        let id = PathParam::from(id);
+        let segments = ["api", "dashboard", id.as_str()];
+        self.client.request_json(
+            Method::GET,
+            &segments,
+            Option::<&()>::None,
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/dashboard/{id}"),
+        )
+    }
+
+    /// POST /api/dashboard/
+    pub fn create_dashboard(&self, request: &CreateDashboard) -> Result<Dashboard> {
+        let segments = ["api", "dashboard"];
+        self.client.request_json(
+            Method::POST,
+            &segments,
+            Option::<&()>::None,
+            Some(request),
+            RequestOptions::default().route("/api/dashboard/"),
+        )
+    }
+
+    /// PUT /api/dashboard/{id}
+    pub fn update_dashboard(
+        &self,
+        id: DashboardId,
+        request: &UpdateDashboard,
+    ) -> Result<Dashboard> {
+        let id = PathParam::from(id);
+        let segments = ["api", "dashboard", id.as_str()];
+        self.client.request_json(
+            Method::PUT,
+            &segments,
+            Option::<&()>::None,
+            Some(request),
+            RequestOptions::default().route("/api/dashboard/{id}"),
+        )
+    }
+
+    /// POST /api/dashboard/{from-dashboard-id}/copy
+    pub fn copy_dashboard(&self, from: DashboardId, request: &CopyDashboard) -> Result<Dashboard> {
+        let from = PathParam::from(from);
+        let segments = ["api", "dashboard", from.as_str(), "copy"];
+        self.client.request_json(
+            Method::POST,
+            &segments,
+            Option::<&()>::None,
+            Some(request),
+            RequestOptions::default().route("/api/dashboard/{from-dashboard-id}/copy"),
+        )
+    }
+
+    /// PUT /api/dashboard/{id}/cards
+    ///
+    /// Replaces every dashcard (and tab, when given) on the dashboard.
+    pub fn update_dashboard_cards(
+        &self,
+        id: DashboardId,
+        request: &DashboardCards,
+    ) -> Result<DashboardCards> {
+        let id = PathParam::from(id);
+        let segments = ["api", "dashboard", id.as_str(), "cards"];
+        self.client.request_json(
+            Method::PUT,
+            &segments,
+            Option::<&()>::None,
+            Some(request),
+            RequestOptions::default().route("/api/dashboard/{id}/cards"),
+        )
+    }
+
     /// GET /api/dashboard/
     pub fn get(&self, query: Option<&Value>) -> Result<Value> {
         let segments = ["api", "dashboard"];
@@ -554,7 +949,7 @@ impl BlockingDashboardService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/dashboard/"),
         )
     }
 
@@ -566,7 +961,7 @@ impl BlockingDashboardService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/dashboard/"),
         )
     }
 
@@ -578,7 +973,7 @@ impl BlockingDashboardService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/dashboard/embeddable"),
         )
     }
 
@@ -590,7 +985,7 @@ impl BlockingDashboardService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/dashboard/params/valid-filter-fields"),
         )
     }
 
@@ -621,7 +1016,27 @@ impl BlockingDashboardService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route(
+                "/api/dashboard/pivot/{dashboard-id}/dashcard/{dashcard-id}/card/{card-id}/query",
+            ),
+        )
+    }
+
+    /// POST /api/dashboard/pivot/{dashboard-id}/dashcard/{dashcard-id}/card/{card-id}/query
+    ///
+    /// Typed-id variant of [`Self::post_pivot_by_dashboard_id_dashcard_by_dashcard_id_card_by_card_id_query`].
+    pub fn post_pivot_by_dashboard_id_dashcard_by_dashcard_id_card_by_card_id_query_typed(
+        &self,
+        dashboard_id: DashboardId,
+        dashcard_id: DashcardId,
+        card_id: CardId,
+        body: Option<&Value>,
+    ) -> Result<Value> {
+        self.post_pivot_by_dashboard_id_dashcard_by_dashcard_id_card_by_card_id_query(
+            dashboard_id,
+            dashcard_id,
+            card_id,
+            body,
         )
     }
 
@@ -633,7 +1048,7 @@ impl BlockingDashboardService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/dashboard/public"),
         )
     }
 
@@ -645,7 +1060,7 @@ impl BlockingDashboardService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/dashboard/save"),
         )
     }
 
@@ -667,10 +1082,21 @@ impl BlockingDashboardService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default()
+                .route("/api/dashboard/save/collection/{parent-collection-id}"),
         )
     }
 
+    /// POST /api/dashboard/save/collection/{parent-collection-id}
+    ///
+    /// Typed-id variant of [`Self::post_save_collection_by_parent_collection_id`].
+    pub fn post_save_collection_by_parent_collection_id_typed(
+        &self,
+        parent_collection_id: CollectionId,
+    ) -> Result<Value> {
+        self.post_save_collection_by_parent_collection_id(parent_collection_id)
+    }
+
     /// POST /api/dashboard/{dashboard-id}/dashcard/{dashcard-id}/card/{card-id}/query
     pub fn post_by_dashboard_id_dashcard_by_dashcard_id_card_by_card_id_query(
         &self,
@@ -697,7 +1123,26 @@ impl BlockingDashboardService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default()
+                .route("/api/dashboard/{dashboard-id}/dashcard/{dashcard-id}/card/{card-id}/query"),
+        )
+    }
+
+    /// POST /api/dashboard/{dashboard-id}/dashcard/{dashcard-id}/card/{card-id}/query
+    ///
+    /// Typed-id variant of [`Self::post_by_dashboard_id_dashcard_by_dashcard_id_card_by_card_id_query`].
+    pub fn post_by_dashboard_id_dashcard_by_dashcard_id_card_by_card_id_query_typed(
+        &self,
+        dashboard_id: DashboardId,
+        dashcard_id: DashcardId,
+        card_id: CardId,
+        body: Option<&Value>,
+    ) -> Result<Value> {
+        self.post_by_dashboard_id_dashcard_by_dashcard_id_card_by_card_id_query(
+            dashboard_id,
+            dashcard_id,
+            card_id,
+            body,
         )
     }
 
@@ -730,7 +1175,27 @@ impl BlockingDashboardService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/dashboard/{dashboard-id}/dashcard/{dashcard-id}/card/{card-id}/query/{export-format}"),
+        )
+    }
+
+    /// POST /api/dashboard/{dashboard-id}/dashcard/{dashcard-id}/card/{card-id}/query/{export-format}
+    ///
+    /// Typed-id variant of [`Self::post_by_dashboard_id_dashcard_by_dashcard_id_card_by_card_id_query_by_export_format`].
+    pub fn post_by_dashboard_id_dashcard_by_dashcard_id_card_by_card_id_query_by_export_format_typed(
+        &self,
+        dashboard_id: DashboardId,
+        dashcard_id: DashcardId,
+        card_id: CardId,
+        export_format: impl Into<PathParam>,
+        body: Option<&Value>,
+    ) -> Result<Vec<u8>> {
+        self.post_by_dashboard_id_dashcard_by_dashcard_id_card_by_card_id_query_by_export_format(
+            dashboard_id,
+            dashcard_id,
+            card_id,
+            export_format,
+            body,
         )
     }
 
@@ -756,10 +1221,23 @@ impl BlockingDashboardService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default()
+                .route("/api/dashboard/{dashboard-id}/dashcard/{dashcard-id}/execute"),
         )
     }
 
+    /// GET /api/dashboard/{dashboard-id}/dashcard/{dashcard-id}/execute
+    ///
+    /// Typed-id variant of [`Self::get_by_dashboard_id_dashcard_by_dashcard_id_execute`].
+    pub fn get_by_dashboard_id_dashcard_by_dashcard_id_execute_typed(
+        &self,
+        dashboard_id: DashboardId,
+        dashcard_id: DashcardId,
+        query: Option<&Value>,
+    ) -> Result<Value> {
+        self.get_by_dashboard_id_dashcard_by_dashcard_id_execute(dashboard_id, dashcard_id, query)
+    }
+
     /// POST /api/dashboard/{dashboard-id}/dashcard/{dashcard-id}/execute
     pub fn post_by_dashboard_id_dashcard_by_dashcard_id_execute(
         &self,
@@ -782,10 +1260,23 @@ impl BlockingDashboardService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default()
+                .route("/api/dashboard/{dashboard-id}/dashcard/{dashcard-id}/execute"),
         )
     }
 
+    /// POST /api/dashboard/{dashboard-id}/dashcard/{dashcard-id}/execute
+    ///
+    /// Typed-id variant of [`Self::post_by_dashboard_id_dashcard_by_dashcard_id_execute`].
+    pub fn post_by_dashboard_id_dashcard_by_dashcard_id_execute_typed(
+        &self,
+        dashboard_id: DashboardId,
+        dashcard_id: DashcardId,
+        body: Option<&Value>,
+    ) -> Result<Value> {
+        self.post_by_dashboard_id_dashcard_by_dashcard_id_execute(dashboard_id, dashcard_id, body)
+    }
+
     /// DELETE /api/dashboard/{dashboard-id}/public_link
     pub fn delete_by_dashboard_id_public_link(
         &self,
@@ -798,10 +1289,20 @@ impl BlockingDashboardService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/dashboard/{dashboard-id}/public_link"),
         )
     }
 
+    /// DELETE /api/dashboard/{dashboard-id}/public_link
+    ///
+    /// Typed-id variant of [`Self::delete_by_dashboard_id_public_link`].
+    pub fn delete_by_dashboard_id_public_link_typed(
+        &self,
+        dashboard_id: DashboardId,
+    ) -> Result<Value> {
+        self.delete_by_dashboard_id_public_link(dashboard_id)
+    }
+
     /// POST /api/dashboard/{dashboard-id}/public_link
     pub fn post_by_dashboard_id_public_link(
         &self,
@@ -814,10 +1315,20 @@ impl BlockingDashboardService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/dashboard/{dashboard-id}/public_link"),
         )
     }
 
+    /// POST /api/dashboard/{dashboard-id}/public_link
+    ///
+    /// Typed-id variant of [`Self::post_by_dashboard_id_public_link`].
+    pub fn post_by_dashboard_id_public_link_typed(
+        &self,
+        dashboard_id: DashboardId,
+    ) -> Result<Value> {
+        self.post_by_dashboard_id_public_link(dashboard_id)
+    }
+
     /// POST /api/dashboard/{from-dashboard-id}/copy
     pub fn post_by_from_dashboard_id_copy(
         &self,
@@ -831,10 +1342,21 @@ impl BlockingDashboardService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/dashboard/{from-dashboard-id}/copy"),
         )
     }
 
+    /// POST /api/dashboard/{from-dashboard-id}/copy
+    ///
+    /// Typed-id variant of [`Self::post_by_from_dashboard_id_copy`].
+    pub fn post_by_from_dashboard_id_copy_typed(
+        &self,
+        from_dashboard_id: DashboardId,
+        body: Option<&Value>,
+    ) -> Result<Value> {
+        self.post_by_from_dashboard_id_copy(from_dashboard_id, body)
+    }
+
     /// DELETE /api/dashboard/{id}
     pub fn delete_by_id(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -844,10 +1366,17 @@ impl BlockingDashboardService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/dashboard/{id}"),
         )
     }
 
+    /// DELETE /api/dashboard/{id}
+    ///
+    /// Typed-id variant of [`Self::delete_by_id`].
+    pub fn delete_by_id_typed(&self, id: DashboardId) -> Result<Value> {
+        self.delete_by_id(id)
+    }
+
     /// GET /api/dashboard/{id}
     pub fn get_by_id(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -857,10 +1386,17 @@ impl BlockingDashboardService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/dashboard/{id}"),
         )
     }
 
+    /// GET /api/dashboard/{id}
+    ///
+    /// Typed-id variant of [`Self::get_by_id`].
+    pub fn get_by_id_typed(&self, id: DashboardId) -> Result<Value> {
+        self.get_by_id(id)
+    }
+
     /// PUT /api/dashboard/{id}
     pub fn put_by_id(&self, id: impl Into<PathParam>, body: Option<&Value>) -> Result<Value> {
         let id = id.into();
@@ -870,10 +1406,17 @@ impl BlockingDashboardService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/dashboard/{id}"),
         )
     }
 
+    /// PUT /api/dashboard/{id}
+    ///
+    /// Typed-id variant of [`Self::put_by_id`].
+    pub fn put_by_id_typed(&self, id: DashboardId, body: Option<&Value>) -> Result<Value> {
+        self.put_by_id(id, body)
+    }
+
     /// PUT /api/dashboard/{id}/cards
     pub fn put_by_id_cards(&self, id: impl Into<PathParam>, body: Option<&Value>) -> Result<Value> {
         let id = id.into();
@@ -883,10 +1426,17 @@ impl BlockingDashboardService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/dashboard/{id}/cards"),
         )
     }
 
+    /// PUT /api/dashboard/{id}/cards
+    ///
+    /// Typed-id variant of [`Self::put_by_id_cards`].
+    pub fn put_by_id_cards_typed(&self, id: DashboardId, body: Option<&Value>) -> Result<Value> {
+        self.put_by_id_cards(id, body)
+    }
+
     /// GET /api/dashboard/{id}/items
     pub fn get_by_id_items(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -896,10 +1446,17 @@ impl BlockingDashboardService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/dashboard/{id}/items"),
         )
     }
 
+    /// GET /api/dashboard/{id}/items
+    ///
+    /// Typed-id variant of [`Self::get_by_id_items`].
+    pub fn get_by_id_items_typed(&self, id: DashboardId) -> Result<Value> {
+        self.get_by_id_items(id)
+    }
+
     /// GET /api/dashboard/{id}/params/{param-key}/remapping
     pub fn get_by_id_params_by_param_key_remapping(
         &self,
@@ -922,10 +1479,22 @@ impl BlockingDashboardService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/dashboard/{id}/params/{param-key}/remapping"),
         )
     }
 
+    /// GET /api/dashboard/{id}/params/{param-key}/remapping
+    ///
+    /// Typed-id variant of [`Self::get_by_id_params_by_param_key_remapping`].
+    pub fn get_by_id_params_by_param_key_remapping_typed(
+        &self,
+        id: DashboardId,
+        param_key: impl Into<PathParam>,
+        query: Option<&Value>,
+    ) -> Result<Value> {
+        self.get_by_id_params_by_param_key_remapping(id, param_key, query)
+    }
+
     /// GET /api/dashboard/{id}/params/{param-key}/search/{query}
     pub fn get_by_id_params_by_param_key_search_by_query(
         &self,
@@ -950,10 +1519,23 @@ impl BlockingDashboardService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default()
+                .route("/api/dashboard/{id}/params/{param-key}/search/{query}"),
         )
     }
 
+    /// GET /api/dashboard/{id}/params/{param-key}/search/{query}
+    ///
+    /// Typed-id variant of [`Self::get_by_id_params_by_param_key_search_by_query`].
+    pub fn get_by_id_params_by_param_key_search_by_query_typed(
+        &self,
+        id: DashboardId,
+        param_key: impl Into<PathParam>,
+        query: impl Into<PathParam>,
+    ) -> Result<Value> {
+        self.get_by_id_params_by_param_key_search_by_query(id, param_key, query)
+    }
+
     /// GET /api/dashboard/{id}/params/{param-key}/values
     pub fn get_by_id_params_by_param_key_values(
         &self,
@@ -975,10 +1557,21 @@ impl BlockingDashboardService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/dashboard/{id}/params/{param-key}/values"),
         )
     }
 
+    /// GET /api/dashboard/{id}/params/{param-key}/values
+    ///
+    /// Typed-id variant of [`Self::get_by_id_params_by_param_key_values`].
+    pub fn get_by_id_params_by_param_key_values_typed(
+        &self,
+        id: DashboardId,
+        param_key: impl Into<PathParam>,
+    ) -> Result<Value> {
+        self.get_by_id_params_by_param_key_values(id, param_key)
+    }
+
     /// GET /api/dashboard/{id}/query_metadata
     pub fn get_by_id_query_metadata(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -988,10 +1581,17 @@ impl BlockingDashboardService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/dashboard/{id}/query_metadata"),
         )
     }
 
+    /// GET /api/dashboard/{id}/query_metadata
+    ///
+    /// Typed-id variant of [`Self::get_by_id_query_metadata`].
+    pub fn get_by_id_query_metadata_typed(&self, id: DashboardId) -> Result<Value> {
+        self.get_by_id_query_metadata(id)
+    }
+
     /// GET /api/dashboard/{id}/related
     pub fn get_by_id_related(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -1001,7 +1601,14 @@ impl BlockingDashboardService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/dashboard/{id}/related"),
         )
     }
+
+    /// GET /api/dashboard/{id}/related
+    ///
+    /// Typed-id variant of [`Self::get_by_id_related`].
+    pub fn get_by_id_related_typed(&self, id: DashboardId) -> Result<Value> {
+        self.get_by_id_related(id)
+    }
 }
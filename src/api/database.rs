@@ -1,7 +1,18 @@
 use http::Method;
 use serde_json::Value;
 
-use crate::{Result, client::RequestOptions, types::path::PathParam};
+use crate::{
+    Result,
+    client::RequestOptions,
+    types::{
+        database::{
+            CreateDatabase, Database, DatabaseDetails, DatabaseList, DatabaseValidation,
+            ValidateDatabaseRequest,
+        },
+        ids::DatabaseId,
+        path::PathParam,
+    },
+};
 
 #[cfg(feature = "blocking")]
 use crate::client::BlockingClient;
@@ -20,6 +31,69 @@ impl DatabaseService {
         Self { client }
     }
 
+    /// GET /api/database/
+    pub async fn list_databases(&self, query: Option<&Value>) -> Result<DatabaseList> {
+        let segments = ["api", "database"];
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                query,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/database/"),
+            )
+            .await
+    }
+
+    /// GET /api/database/{id}
+    pub async fn get_database(&self, id: DatabaseId) -> Result<Database> {
+        let id = PathParam::from(id);
+        let segments = ["api", "database", id.as_str()];
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/database/{id}"),
+            )
+            .await
+    }
+
+    /// POST /api/database/
+    pub async fn create_database(&self, request: &CreateDatabase) -> Result<Database> {
+        request.schedules.validate()?;
+        let segments = ["api", "database"];
+        self.client
+            .request_json(
+                Method::POST,
+                &segments,
+                Option::<&()>::None,
+                Some(request),
+                RequestOptions::default().route("/api/database/"),
+            )
+            .await
+    }
+
+    /// POST /api/database/validate
+    ///
+    /// Test connection `details` without saving them. A rejected connection is returned
+    /// as `valid: false` rather than as an error.
+    pub async fn validate_database(&self, details: &DatabaseDetails) -> Result<DatabaseValidation> {
+        let segments = ["api", "database", "validate"];
+        let response = self
+            .client
+            .request_json(
+                Method::POST,
+                &segments,
+                Option::<&()>::None,
+                Some(&ValidateDatabaseRequest::new(details)),
+                RequestOptions::default().route("/api/database/validate"),
+            )
+            .await;
+        DatabaseValidation::from_response(response)
+    }
+
     /// GET /api/database/
     pub async fn get(&self, query: Option<&Value>) -> Result<Value> {
         let segments = ["api", "database"];
@@ -29,7 +103,7 @@ impl DatabaseService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/database/"),
             )
             .await
     }
@@ -43,7 +117,7 @@ impl DatabaseService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/database/"),
             )
             .await
     }
@@ -57,7 +131,7 @@ impl DatabaseService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/database/sample_database"),
             )
             .await
     }
@@ -71,7 +145,7 @@ impl DatabaseService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/database/validate"),
             )
             .await
     }
@@ -86,11 +160,18 @@ impl DatabaseService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/database/{id}"),
             )
             .await
     }
 
+    /// DELETE /api/database/{id}
+    ///
+    /// Typed-id variant of [`Self::delete_by_id`].
+    pub async fn delete_by_id_typed(&self, id: DatabaseId) -> Result<Value> {
+        self.delete_by_id(id).await
+    }
+
     /// GET /api/database/{id}
     pub async fn get_by_id(
         &self,
@@ -105,11 +186,18 @@ impl DatabaseService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/database/{id}"),
             )
             .await
     }
 
+    /// GET /api/database/{id}
+    ///
+    /// Typed-id variant of [`Self::get_by_id`].
+    pub async fn get_by_id_typed(&self, id: DatabaseId, query: Option<&Value>) -> Result<Value> {
+        self.get_by_id(id, query).await
+    }
+
     /// PUT /api/database/{id}
     pub async fn put_by_id(&self, id: impl Into<PathParam>, body: Option<&Value>) -> Result<Value> {
         let id = id.into();
@@ -120,11 +208,18 @@ impl DatabaseService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/database/{id}"),
             )
             .await
     }
 
+    /// PUT /api/database/{id}
+    ///
+    /// Typed-id variant of [`Self::put_by_id`].
+    pub async fn put_by_id_typed(&self, id: DatabaseId, body: Option<&Value>) -> Result<Value> {
+        self.put_by_id(id, body).await
+    }
+
     /// GET /api/database/{id}/autocomplete_suggestions
     pub async fn get_by_id_autocomplete_suggestions(
         &self,
@@ -139,11 +234,22 @@ impl DatabaseService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/database/{id}/autocomplete_suggestions"),
             )
             .await
     }
 
+    /// GET /api/database/{id}/autocomplete_suggestions
+    ///
+    /// Typed-id variant of [`Self::get_by_id_autocomplete_suggestions`].
+    pub async fn get_by_id_autocomplete_suggestions_typed(
+        &self,
+        id: DatabaseId,
+        query: Option<&Value>,
+    ) -> Result<Value> {
+        self.get_by_id_autocomplete_suggestions(id, query).await
+    }
+
     /// GET /api/database/{id}/card_autocomplete_suggestions
     pub async fn get_by_id_card_autocomplete_suggestions(
         &self,
@@ -163,11 +269,23 @@ impl DatabaseService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/database/{id}/card_autocomplete_suggestions"),
             )
             .await
     }
 
+    /// GET /api/database/{id}/card_autocomplete_suggestions
+    ///
+    /// Typed-id variant of [`Self::get_by_id_card_autocomplete_suggestions`].
+    pub async fn get_by_id_card_autocomplete_suggestions_typed(
+        &self,
+        id: DatabaseId,
+        query: Option<&Value>,
+    ) -> Result<Value> {
+        self.get_by_id_card_autocomplete_suggestions(id, query)
+            .await
+    }
+
     /// POST /api/database/{id}/discard_values
     pub async fn post_by_id_discard_values(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -178,11 +296,18 @@ impl DatabaseService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/database/{id}/discard_values"),
             )
             .await
     }
 
+    /// POST /api/database/{id}/discard_values
+    ///
+    /// Typed-id variant of [`Self::post_by_id_discard_values`].
+    pub async fn post_by_id_discard_values_typed(&self, id: DatabaseId) -> Result<Value> {
+        self.post_by_id_discard_values(id).await
+    }
+
     /// POST /api/database/{id}/dismiss_spinner
     pub async fn post_by_id_dismiss_spinner(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -193,11 +318,18 @@ impl DatabaseService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/database/{id}/dismiss_spinner"),
             )
             .await
     }
 
+    /// POST /api/database/{id}/dismiss_spinner
+    ///
+    /// Typed-id variant of [`Self::post_by_id_dismiss_spinner`].
+    pub async fn post_by_id_dismiss_spinner_typed(&self, id: DatabaseId) -> Result<Value> {
+        self.post_by_id_dismiss_spinner(id).await
+    }
+
     /// GET /api/database/{id}/fields
     pub async fn get_by_id_fields(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -208,11 +340,18 @@ impl DatabaseService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/database/{id}/fields"),
             )
             .await
     }
 
+    /// GET /api/database/{id}/fields
+    ///
+    /// Typed-id variant of [`Self::get_by_id_fields`].
+    pub async fn get_by_id_fields_typed(&self, id: DatabaseId) -> Result<Value> {
+        self.get_by_id_fields(id).await
+    }
+
     /// GET /api/database/{id}/healthcheck
     pub async fn get_by_id_healthcheck(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -223,11 +362,18 @@ impl DatabaseService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/database/{id}/healthcheck"),
             )
             .await
     }
 
+    /// GET /api/database/{id}/healthcheck
+    ///
+    /// Typed-id variant of [`Self::get_by_id_healthcheck`].
+    pub async fn get_by_id_healthcheck_typed(&self, id: DatabaseId) -> Result<Value> {
+        self.get_by_id_healthcheck(id).await
+    }
+
     /// GET /api/database/{id}/idfields
     pub async fn get_by_id_idfields(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -238,11 +384,18 @@ impl DatabaseService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/database/{id}/idfields"),
             )
             .await
     }
 
+    /// GET /api/database/{id}/idfields
+    ///
+    /// Typed-id variant of [`Self::get_by_id_idfields`].
+    pub async fn get_by_id_idfields_typed(&self, id: DatabaseId) -> Result<Value> {
+        self.get_by_id_idfields(id).await
+    }
+
     /// GET /api/database/{id}/metadata
     pub async fn get_by_id_metadata(
         &self,
@@ -257,11 +410,22 @@ impl DatabaseService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/database/{id}/metadata"),
             )
             .await
     }
 
+    /// GET /api/database/{id}/metadata
+    ///
+    /// Typed-id variant of [`Self::get_by_id_metadata`].
+    pub async fn get_by_id_metadata_typed(
+        &self,
+        id: DatabaseId,
+        query: Option<&Value>,
+    ) -> Result<Value> {
+        self.get_by_id_metadata(id, query).await
+    }
+
     /// POST /api/database/{id}/rescan_values
     pub async fn post_by_id_rescan_values(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -272,11 +436,18 @@ impl DatabaseService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/database/{id}/rescan_values"),
             )
             .await
     }
 
+    /// POST /api/database/{id}/rescan_values
+    ///
+    /// Typed-id variant of [`Self::post_by_id_rescan_values`].
+    pub async fn post_by_id_rescan_values_typed(&self, id: DatabaseId) -> Result<Value> {
+        self.post_by_id_rescan_values(id).await
+    }
+
     /// GET /api/database/{id}/schema/
     pub async fn get_by_id_schema(
         &self,
@@ -291,11 +462,22 @@ impl DatabaseService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/database/{id}/schema/"),
             )
             .await
     }
 
+    /// GET /api/database/{id}/schema/
+    ///
+    /// Typed-id variant of [`Self::get_by_id_schema`].
+    pub async fn get_by_id_schema_typed(
+        &self,
+        id: DatabaseId,
+        query: Option<&Value>,
+    ) -> Result<Value> {
+        self.get_by_id_schema(id, query).await
+    }
+
     /// GET /api/database/{id}/schema/{schema}
     pub async fn get_by_id_schema_by_schema(
         &self,
@@ -312,11 +494,23 @@ impl DatabaseService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/database/{id}/schema/{schema}"),
             )
             .await
     }
 
+    /// GET /api/database/{id}/schema/{schema}
+    ///
+    /// Typed-id variant of [`Self::get_by_id_schema_by_schema`].
+    pub async fn get_by_id_schema_by_schema_typed(
+        &self,
+        id: DatabaseId,
+        schema: impl Into<PathParam>,
+        query: Option<&Value>,
+    ) -> Result<Value> {
+        self.get_by_id_schema_by_schema(id, schema, query).await
+    }
+
     /// GET /api/database/{id}/schemas
     pub async fn get_by_id_schemas(
         &self,
@@ -331,11 +525,22 @@ impl DatabaseService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/database/{id}/schemas"),
             )
             .await
     }
 
+    /// GET /api/database/{id}/schemas
+    ///
+    /// Typed-id variant of [`Self::get_by_id_schemas`].
+    pub async fn get_by_id_schemas_typed(
+        &self,
+        id: DatabaseId,
+        query: Option<&Value>,
+    ) -> Result<Value> {
+        self.get_by_id_schemas(id, query).await
+    }
+
     /// GET /api/database/{id}/settings-available
     pub async fn get_by_id_settings_available(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -346,11 +551,18 @@ impl DatabaseService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/database/{id}/settings-available"),
             )
             .await
     }
 
+    /// GET /api/database/{id}/settings-available
+    ///
+    /// Typed-id variant of [`Self::get_by_id_settings_available`].
+    pub async fn get_by_id_settings_available_typed(&self, id: DatabaseId) -> Result<Value> {
+        self.get_by_id_settings_available(id).await
+    }
+
     /// POST /api/database/{id}/sync_schema
     pub async fn post_by_id_sync_schema(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -361,11 +573,18 @@ impl DatabaseService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/database/{id}/sync_schema"),
             )
             .await
     }
 
+    /// POST /api/database/{id}/sync_schema
+    ///
+    /// Typed-id variant of [`Self::post_by_id_sync_schema`].
+    pub async fn post_by_id_sync_schema_typed(&self, id: DatabaseId) -> Result<Value> {
+        self.post_by_id_sync_schema(id).await
+    }
+
     /// GET /api/database/{id}/syncable_schemas
     pub async fn get_by_id_syncable_schemas(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -376,11 +595,18 @@ impl DatabaseService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/database/{id}/syncable_schemas"),
             )
             .await
     }
 
+    /// GET /api/database/{id}/syncable_schemas
+    ///
+    /// Typed-id variant of [`Self::get_by_id_syncable_schemas`].
+    pub async fn get_by_id_syncable_schemas_typed(&self, id: DatabaseId) -> Result<Value> {
+        self.get_by_id_syncable_schemas(id).await
+    }
+
     /// GET /api/database/{id}/usage_info
     pub async fn get_by_id_usage_info(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -391,11 +617,18 @@ impl DatabaseService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/database/{id}/usage_info"),
             )
             .await
     }
 
+    /// GET /api/database/{id}/usage_info
+    ///
+    /// Typed-id variant of [`Self::get_by_id_usage_info`].
+    pub async fn get_by_id_usage_info_typed(&self, id: DatabaseId) -> Result<Value> {
+        self.get_by_id_usage_info(id).await
+    }
+
     /// GET /api/database/{virtual-db}/datasets
     pub async fn get_by_virtual_db_datasets(
         &self,
@@ -409,7 +642,7 @@ impl DatabaseService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/database/{virtual-db}/datasets"),
             )
             .await
     }
@@ -435,7 +668,7 @@ impl DatabaseService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/database/{virtual-db}/datasets/{schema}"),
             )
             .await
     }
@@ -453,7 +686,7 @@ impl DatabaseService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/database/{virtual-db}/metadata"),
             )
             .await
     }
@@ -479,7 +712,7 @@ impl DatabaseService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/database/{virtual-db}/schema/{schema}"),
             )
             .await
     }
@@ -497,7 +730,7 @@ impl DatabaseService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/database/{virtual-db}/schemas"),
             )
             .await
     }
@@ -515,6 +748,60 @@ impl BlockingDatabaseService {
         Self { client }
     }
 
+    /// GET /api/database/
+    pub fn list_databases(&self, query: Option<&Value>) -> Result<DatabaseList> {
+        let segments = ["api", "database"];
+        self.client.request_json(
+            Method::GET,
+            &segments,
+            query,
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/database/"),
+        )
+    }
+
+    /// GET /api/database/{id}
+    pub fn get_database(&self, id: DatabaseId) -> Result<Database> {
+        let id = PathParam::from(id);
+        let segments = ["api", "database", id.as_str()];
+        self.client.request_json(
+            Method::GET,
+            &segments,
+            Option::<&()>::None,
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/database/{id}"),
+        )
+    }
+
+    /// POST /api/database/
+    pub fn create_database(&self, request: &CreateDatabase) -> Result<Database> {
+        request.schedules.validate()?;
+        let segments = ["api", "database"];
+        self.client.request_json(
+            Method::POST,
+            &segments,
+            Option::<&()>::None,
+            Some(request),
+            RequestOptions::default().route("/api/database/"),
+        )
+    }
+
+    /// POST /api/database/validate
+    ///
+    /// Test connection `details` without saving them. A rejected connection is returned
+    /// as `valid: false` rather than as an error.
+    pub fn validate_database(&self, details: &DatabaseDetails) -> Result<DatabaseValidation> {
+        let segments = ["api", "database", "validate"];
+        let response = self.client.request_json(
+            Method::POST,
+            &segments,
+            Option::<&()>::None,
+            Some(&ValidateDatabaseRequest::new(details)),
+            RequestOptions::default().route("/api/database/validate"),
+        );
+        DatabaseValidation::from_response(response)
+    }
+
     /// GET /api/database/
     pub fn get(&self, query: Option<&Value>) -> Result<Value> {
         let segments = ["api", "database"];
@@ -523,7 +810,7 @@ impl BlockingDatabaseService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/database/"),
         )
     }
 
@@ -535,7 +822,7 @@ impl BlockingDatabaseService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/database/"),
         )
     }
 
@@ -547,7 +834,7 @@ impl BlockingDatabaseService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/database/sample_database"),
         )
     }
 
@@ -559,7 +846,7 @@ impl BlockingDatabaseService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/database/validate"),
         )
     }
 
@@ -572,10 +859,17 @@ impl BlockingDatabaseService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/database/{id}"),
         )
     }
 
+    /// DELETE /api/database/{id}
+    ///
+    /// Typed-id variant of [`Self::delete_by_id`].
+    pub fn delete_by_id_typed(&self, id: DatabaseId) -> Result<Value> {
+        self.delete_by_id(id)
+    }
+
     /// GET /api/database/{id}
     pub fn get_by_id(&self, id: impl Into<PathParam>, query: Option<&Value>) -> Result<Value> {
         let id = id.into();
@@ -585,10 +879,17 @@ impl BlockingDatabaseService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/database/{id}"),
         )
     }
 
+    /// GET /api/database/{id}
+    ///
+    /// Typed-id variant of [`Self::get_by_id`].
+    pub fn get_by_id_typed(&self, id: DatabaseId, query: Option<&Value>) -> Result<Value> {
+        self.get_by_id(id, query)
+    }
+
     /// PUT /api/database/{id}
     pub fn put_by_id(&self, id: impl Into<PathParam>, body: Option<&Value>) -> Result<Value> {
         let id = id.into();
@@ -598,10 +899,17 @@ impl BlockingDatabaseService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/database/{id}"),
         )
     }
 
+    /// PUT /api/database/{id}
+    ///
+    /// Typed-id variant of [`Self::put_by_id`].
+    pub fn put_by_id_typed(&self, id: DatabaseId, body: Option<&Value>) -> Result<Value> {
+        self.put_by_id(id, body)
+    }
+
     /// GET /api/database/{id}/autocomplete_suggestions
     pub fn get_by_id_autocomplete_suggestions(
         &self,
@@ -615,10 +923,21 @@ impl BlockingDatabaseService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/database/{id}/autocomplete_suggestions"),
         )
     }
 
+    /// GET /api/database/{id}/autocomplete_suggestions
+    ///
+    /// Typed-id variant of [`Self::get_by_id_autocomplete_suggestions`].
+    pub fn get_by_id_autocomplete_suggestions_typed(
+        &self,
+        id: DatabaseId,
+        query: Option<&Value>,
+    ) -> Result<Value> {
+        self.get_by_id_autocomplete_suggestions(id, query)
+    }
+
     /// GET /api/database/{id}/card_autocomplete_suggestions
     pub fn get_by_id_card_autocomplete_suggestions(
         &self,
@@ -637,10 +956,21 @@ impl BlockingDatabaseService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/database/{id}/card_autocomplete_suggestions"),
         )
     }
 
+    /// GET /api/database/{id}/card_autocomplete_suggestions
+    ///
+    /// Typed-id variant of [`Self::get_by_id_card_autocomplete_suggestions`].
+    pub fn get_by_id_card_autocomplete_suggestions_typed(
+        &self,
+        id: DatabaseId,
+        query: Option<&Value>,
+    ) -> Result<Value> {
+        self.get_by_id_card_autocomplete_suggestions(id, query)
+    }
+
     /// POST /api/database/{id}/discard_values
     pub fn post_by_id_discard_values(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -650,10 +980,17 @@ impl BlockingDatabaseService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/database/{id}/discard_values"),
         )
     }
 
+    /// POST /api/database/{id}/discard_values
+    ///
+    /// Typed-id variant of [`Self::post_by_id_discard_values`].
+    pub fn post_by_id_discard_values_typed(&self, id: DatabaseId) -> Result<Value> {
+        self.post_by_id_discard_values(id)
+    }
+
     /// POST /api/database/{id}/dismiss_spinner
     pub fn post_by_id_dismiss_spinner(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -663,10 +1000,17 @@ impl BlockingDatabaseService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/database/{id}/dismiss_spinner"),
         )
     }
 
+    /// POST /api/database/{id}/dismiss_spinner
+    ///
+    /// Typed-id variant of [`Self::post_by_id_dismiss_spinner`].
+    pub fn post_by_id_dismiss_spinner_typed(&self, id: DatabaseId) -> Result<Value> {
+        self.post_by_id_dismiss_spinner(id)
+    }
+
     /// GET /api/database/{id}/fields
     pub fn get_by_id_fields(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -676,10 +1020,17 @@ impl BlockingDatabaseService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/database/{id}/fields"),
         )
     }
 
+    /// GET /api/database/{id}/fields
+    ///
+    /// Typed-id variant of [`Self::get_by_id_fields`].
+    pub fn get_by_id_fields_typed(&self, id: DatabaseId) -> Result<Value> {
+        self.get_by_id_fields(id)
+    }
+
     /// GET /api/database/{id}/healthcheck
     pub fn get_by_id_healthcheck(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -689,10 +1040,17 @@ impl BlockingDatabaseService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/database/{id}/healthcheck"),
         )
     }
 
+    /// GET /api/database/{id}/healthcheck
+    ///
+    /// Typed-id variant of [`Self::get_by_id_healthcheck`].
+    pub fn get_by_id_healthcheck_typed(&self, id: DatabaseId) -> Result<Value> {
+        self.get_by_id_healthcheck(id)
+    }
+
     /// GET /api/database/{id}/idfields
     pub fn get_by_id_idfields(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -702,10 +1060,17 @@ impl BlockingDatabaseService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/database/{id}/idfields"),
         )
     }
 
+    /// GET /api/database/{id}/idfields
+    ///
+    /// Typed-id variant of [`Self::get_by_id_idfields`].
+    pub fn get_by_id_idfields_typed(&self, id: DatabaseId) -> Result<Value> {
+        self.get_by_id_idfields(id)
+    }
+
     /// GET /api/database/{id}/metadata
     pub fn get_by_id_metadata(
         &self,
@@ -719,10 +1084,17 @@ impl BlockingDatabaseService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/database/{id}/metadata"),
         )
     }
 
+    /// GET /api/database/{id}/metadata
+    ///
+    /// Typed-id variant of [`Self::get_by_id_metadata`].
+    pub fn get_by_id_metadata_typed(&self, id: DatabaseId, query: Option<&Value>) -> Result<Value> {
+        self.get_by_id_metadata(id, query)
+    }
+
     /// POST /api/database/{id}/rescan_values
     pub fn post_by_id_rescan_values(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -732,10 +1104,17 @@ impl BlockingDatabaseService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/database/{id}/rescan_values"),
         )
     }
 
+    /// POST /api/database/{id}/rescan_values
+    ///
+    /// Typed-id variant of [`Self::post_by_id_rescan_values`].
+    pub fn post_by_id_rescan_values_typed(&self, id: DatabaseId) -> Result<Value> {
+        self.post_by_id_rescan_values(id)
+    }
+
     /// GET /api/database/{id}/schema/
     pub fn get_by_id_schema(
         &self,
@@ -749,10 +1128,17 @@ impl BlockingDatabaseService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/database/{id}/schema/"),
         )
     }
 
+    /// GET /api/database/{id}/schema/
+    ///
+    /// Typed-id variant of [`Self::get_by_id_schema`].
+    pub fn get_by_id_schema_typed(&self, id: DatabaseId, query: Option<&Value>) -> Result<Value> {
+        self.get_by_id_schema(id, query)
+    }
+
     /// GET /api/database/{id}/schema/{schema}
     pub fn get_by_id_schema_by_schema(
         &self,
@@ -768,10 +1154,22 @@ impl BlockingDatabaseService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/database/{id}/schema/{schema}"),
         )
     }
 
+    /// GET /api/database/{id}/schema/{schema}
+    ///
+    /// Typed-id variant of [`Self::get_by_id_schema_by_schema`].
+    pub fn get_by_id_schema_by_schema_typed(
+        &self,
+        id: DatabaseId,
+        schema: impl Into<PathParam>,
+        query: Option<&Value>,
+    ) -> Result<Value> {
+        self.get_by_id_schema_by_schema(id, schema, query)
+    }
+
     /// GET /api/database/{id}/schemas
     pub fn get_by_id_schemas(
         &self,
@@ -785,10 +1183,17 @@ impl BlockingDatabaseService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/database/{id}/schemas"),
         )
     }
 
+    /// GET /api/database/{id}/schemas
+    ///
+    /// Typed-id variant of [`Self::get_by_id_schemas`].
+    pub fn get_by_id_schemas_typed(&self, id: DatabaseId, query: Option<&Value>) -> Result<Value> {
+        self.get_by_id_schemas(id, query)
+    }
+
     /// GET /api/database/{id}/settings-available
     pub fn get_by_id_settings_available(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -798,10 +1203,17 @@ impl BlockingDatabaseService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/database/{id}/settings-available"),
         )
     }
 
+    /// GET /api/database/{id}/settings-available
+    ///
+    /// Typed-id variant of [`Self::get_by_id_settings_available`].
+    pub fn get_by_id_settings_available_typed(&self, id: DatabaseId) -> Result<Value> {
+        self.get_by_id_settings_available(id)
+    }
+
     /// POST /api/database/{id}/sync_schema
     pub fn post_by_id_sync_schema(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -811,10 +1223,17 @@ impl BlockingDatabaseService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/database/{id}/sync_schema"),
         )
     }
 
+    /// POST /api/database/{id}/sync_schema
+    ///
+    /// Typed-id variant of [`Self::post_by_id_sync_schema`].
+    pub fn post_by_id_sync_schema_typed(&self, id: DatabaseId) -> Result<Value> {
+        self.post_by_id_sync_schema(id)
+    }
+
     /// GET /api/database/{id}/syncable_schemas
     pub fn get_by_id_syncable_schemas(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -824,10 +1243,17 @@ impl BlockingDatabaseService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/database/{id}/syncable_schemas"),
         )
     }
 
+    /// GET /api/database/{id}/syncable_schemas
+    ///
+    /// Typed-id variant of [`Self::get_by_id_syncable_schemas`].
+    pub fn get_by_id_syncable_schemas_typed(&self, id: DatabaseId) -> Result<Value> {
+        self.get_by_id_syncable_schemas(id)
+    }
+
     /// GET /api/database/{id}/usage_info
     pub fn get_by_id_usage_info(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -837,10 +1263,17 @@ impl BlockingDatabaseService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/database/{id}/usage_info"),
         )
     }
 
+    /// GET /api/database/{id}/usage_info
+    ///
+    /// Typed-id variant of [`Self::get_by_id_usage_info`].
+    pub fn get_by_id_usage_info_typed(&self, id: DatabaseId) -> Result<Value> {
+        self.get_by_id_usage_info(id)
+    }
+
     /// GET /api/database/{virtual-db}/datasets
     pub fn get_by_virtual_db_datasets(&self, virtual_db: impl Into<PathParam>) -> Result<Value> {
         let virtual_db = virtual_db.into();
@@ -850,7 +1283,7 @@ impl BlockingDatabaseService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/database/{virtual-db}/datasets"),
         )
     }
 
@@ -874,7 +1307,7 @@ impl BlockingDatabaseService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/database/{virtual-db}/datasets/{schema}"),
         )
     }
 
@@ -887,7 +1320,7 @@ impl BlockingDatabaseService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/database/{virtual-db}/metadata"),
         )
     }
 
@@ -911,7 +1344,7 @@ impl BlockingDatabaseService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/database/{virtual-db}/schema/{schema}"),
         )
     }
 
@@ -924,7 +1357,7 @@ impl BlockingDatabaseService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/database/{virtual-db}/schemas"),
         )
     }
 }
@@ -29,7 +29,7 @@ impl DatasetService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/dataset/"),
             )
             .await
     }
@@ -43,7 +43,7 @@ impl DatasetService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/dataset/native"),
             )
             .await
     }
@@ -57,7 +57,7 @@ impl DatasetService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/dataset/parameter/remapping"),
             )
             .await
     }
@@ -76,7 +76,7 @@ impl DatasetService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/dataset/parameter/search/{query}"),
             )
             .await
     }
@@ -90,7 +90,7 @@ impl DatasetService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/dataset/parameter/values"),
             )
             .await
     }
@@ -104,7 +104,7 @@ impl DatasetService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/dataset/pivot"),
             )
             .await
     }
@@ -118,7 +118,7 @@ impl DatasetService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/dataset/query_metadata"),
             )
             .await
     }
@@ -137,7 +137,7 @@ impl DatasetService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/dataset/{export-format}"),
             )
             .await
     }
@@ -163,7 +163,7 @@ impl BlockingDatasetService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/dataset/"),
         )
     }
 
@@ -175,7 +175,7 @@ impl BlockingDatasetService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/dataset/native"),
         )
     }
 
@@ -187,7 +187,7 @@ impl BlockingDatasetService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/dataset/parameter/remapping"),
         )
     }
 
@@ -204,7 +204,7 @@ impl BlockingDatasetService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/dataset/parameter/search/{query}"),
         )
     }
 
@@ -216,7 +216,7 @@ impl BlockingDatasetService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/dataset/parameter/values"),
         )
     }
 
@@ -228,7 +228,7 @@ impl BlockingDatasetService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/dataset/pivot"),
         )
     }
 
@@ -240,7 +240,7 @@ impl BlockingDatasetService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/dataset/query_metadata"),
         )
     }
 
@@ -257,7 +257,7 @@ impl BlockingDatasetService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/dataset/{export-format}"),
         )
     }
 }
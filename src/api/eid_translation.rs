@@ -29,7 +29,7 @@ impl EidTranslationService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/eid-translation/translate"),
             )
             .await
     }
@@ -55,7 +55,7 @@ impl BlockingEidTranslationService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/eid-translation/translate"),
         )
     }
 }
@@ -29,7 +29,7 @@ impl EmailService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/email/"),
             )
             .await
     }
@@ -43,7 +43,7 @@ impl EmailService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/email/"),
             )
             .await
     }
@@ -57,7 +57,7 @@ impl EmailService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/email/test"),
             )
             .await
     }
@@ -83,7 +83,7 @@ impl BlockingEmailService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/email/"),
         )
     }
 
@@ -95,7 +95,7 @@ impl BlockingEmailService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/email/"),
         )
     }
 
@@ -107,7 +107,7 @@ impl BlockingEmailService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/email/test"),
         )
     }
 }
@@ -1,7 +1,14 @@
 use http::Method;
 use serde_json::Value;
 
-use crate::{Result, client::RequestOptions, types::path::PathParam};
+use crate::{
+    Result,
+    client::RequestOptions,
+    types::{
+        ids::{CardId, DashcardId},
+        path::PathParam,
+    },
+};
 
 #[cfg(feature = "blocking")]
 use crate::client::BlockingClient;
@@ -30,7 +37,7 @@ impl EmbedService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/embed/card/{token}"),
             )
             .await
     }
@@ -59,7 +66,8 @@ impl EmbedService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default()
+                    .route("/api/embed/card/{token}/params/{param-key}/remapping"),
             )
             .await
     }
@@ -90,7 +98,8 @@ impl EmbedService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default()
+                    .route("/api/embed/card/{token}/params/{param-key}/search/{prefix}"),
             )
             .await
     }
@@ -118,7 +127,8 @@ impl EmbedService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default()
+                    .route("/api/embed/card/{token}/params/{param-key}/values"),
             )
             .await
     }
@@ -133,7 +143,7 @@ impl EmbedService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/embed/card/{token}/query"),
             )
             .await
     }
@@ -161,7 +171,7 @@ impl EmbedService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/embed/card/{token}/query/{export-format}"),
             )
             .await
     }
@@ -176,7 +186,7 @@ impl EmbedService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/embed/dashboard/{token}"),
             )
             .await
     }
@@ -207,11 +217,29 @@ impl EmbedService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default()
+                    .route("/api/embed/dashboard/{token}/dashcard/{dashcard-id}/card/{card-id}"),
             )
             .await
     }
 
+    /// GET /api/embed/dashboard/{token}/dashcard/{dashcard-id}/card/{card-id}
+    ///
+    /// Typed-id variant of [`Self::get_dashboard_by_token_dashcard_by_dashcard_id_card_by_card_id`].
+    pub async fn get_dashboard_by_token_dashcard_by_dashcard_id_card_by_card_id_typed(
+        &self,
+        token: impl Into<PathParam>,
+        dashcard_id: DashcardId,
+        card_id: CardId,
+    ) -> Result<Value> {
+        self.get_dashboard_by_token_dashcard_by_dashcard_id_card_by_card_id(
+            token,
+            dashcard_id,
+            card_id,
+        )
+        .await
+    }
+
     /// GET /api/embed/dashboard/{token}/dashcard/{dashcard-id}/card/{card-id}/{export-format}
     pub async fn get_dashboard_by_token_dashcard_by_dashcard_id_card_by_card_id_by_export_format(
         &self,
@@ -242,11 +270,32 @@ impl EmbedService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/embed/dashboard/{token}/dashcard/{dashcard-id}/card/{card-id}/{export-format}"),
             )
             .await
     }
 
+    /// GET /api/embed/dashboard/{token}/dashcard/{dashcard-id}/card/{card-id}/{export-format}
+    ///
+    /// Typed-id variant of [`Self::get_dashboard_by_token_dashcard_by_dashcard_id_card_by_card_id_by_export_format`].
+    pub async fn get_dashboard_by_token_dashcard_by_dashcard_id_card_by_card_id_by_export_format_typed(
+        &self,
+        token: impl Into<PathParam>,
+        dashcard_id: DashcardId,
+        card_id: CardId,
+        export_format: impl Into<PathParam>,
+        query: Option<&Value>,
+    ) -> Result<Vec<u8>> {
+        self.get_dashboard_by_token_dashcard_by_dashcard_id_card_by_card_id_by_export_format(
+            token,
+            dashcard_id,
+            card_id,
+            export_format,
+            query,
+        )
+        .await
+    }
+
     /// GET /api/embed/dashboard/{token}/params/{param-key}/remapping
     pub async fn get_dashboard_by_token_params_by_param_key_remapping(
         &self,
@@ -270,7 +319,8 @@ impl EmbedService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default()
+                    .route("/api/embed/dashboard/{token}/params/{param-key}/remapping"),
             )
             .await
     }
@@ -301,7 +351,8 @@ impl EmbedService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default()
+                    .route("/api/embed/dashboard/{token}/params/{param-key}/search/{prefix}"),
             )
             .await
     }
@@ -329,7 +380,8 @@ impl EmbedService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default()
+                    .route("/api/embed/dashboard/{token}/params/{param-key}/values"),
             )
             .await
     }
@@ -347,7 +399,7 @@ impl EmbedService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/embed/pivot/card/{token}/query"),
             )
             .await
     }
@@ -379,11 +431,30 @@ impl EmbedService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route(
+                    "/api/embed/pivot/dashboard/{token}/dashcard/{dashcard-id}/card/{card-id}",
+                ),
             )
             .await
     }
 
+    /// GET /api/embed/pivot/dashboard/{token}/dashcard/{dashcard-id}/card/{card-id}
+    ///
+    /// Typed-id variant of [`Self::get_pivot_dashboard_by_token_dashcard_by_dashcard_id_card_by_card_id`].
+    pub async fn get_pivot_dashboard_by_token_dashcard_by_dashcard_id_card_by_card_id_typed(
+        &self,
+        token: impl Into<PathParam>,
+        dashcard_id: DashcardId,
+        card_id: CardId,
+    ) -> Result<Value> {
+        self.get_pivot_dashboard_by_token_dashcard_by_dashcard_id_card_by_card_id(
+            token,
+            dashcard_id,
+            card_id,
+        )
+        .await
+    }
+
     /// GET /api/embed/tiles/card/{token}/{zoom}/{x}/{y}
     pub async fn get_tiles_card_by_token_by_zoom_by_x_by_y(
         &self,
@@ -413,7 +484,7 @@ impl EmbedService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/embed/tiles/card/{token}/{zoom}/{x}/{y}"),
             )
             .await
     }
@@ -455,10 +526,35 @@ impl EmbedService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/embed/tiles/dashboard/{token}/dashcard/{dashcard-id}/card/{card-id}/{zoom}/{x}/{y}"),
             )
             .await
     }
+
+    /// GET /api/embed/tiles/dashboard/{token}/dashcard/{dashcard-id}/card/{card-id}/{zoom}/{x}/{y}
+    ///
+    /// Typed-id variant of [`Self::get_tiles_dashboard_by_token_dashcard_by_dashcard_id_card_by_card_id_by_zoom_by_x_by_y`].
+    pub async fn get_tiles_dashboard_by_token_dashcard_by_dashcard_id_card_by_card_id_by_zoom_by_x_by_y_typed(
+        &self,
+        token: impl Into<PathParam>,
+        dashcard_id: DashcardId,
+        card_id: CardId,
+        zoom: impl Into<PathParam>,
+        x: impl Into<PathParam>,
+        y: impl Into<PathParam>,
+        query: Option<&Value>,
+    ) -> Result<Vec<u8>> {
+        self.get_tiles_dashboard_by_token_dashcard_by_dashcard_id_card_by_card_id_by_zoom_by_x_by_y(
+            token,
+            dashcard_id,
+            card_id,
+            zoom,
+            x,
+            y,
+            query,
+        )
+        .await
+    }
 }
 
 #[cfg(feature = "blocking")]
@@ -482,7 +578,7 @@ impl BlockingEmbedService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/embed/card/{token}"),
         )
     }
 
@@ -509,7 +605,7 @@ impl BlockingEmbedService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/embed/card/{token}/params/{param-key}/remapping"),
         )
     }
 
@@ -538,7 +634,8 @@ impl BlockingEmbedService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default()
+                .route("/api/embed/card/{token}/params/{param-key}/search/{prefix}"),
         )
     }
 
@@ -564,7 +661,7 @@ impl BlockingEmbedService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/embed/card/{token}/params/{param-key}/values"),
         )
     }
 
@@ -577,7 +674,7 @@ impl BlockingEmbedService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/embed/card/{token}/query"),
         )
     }
 
@@ -603,7 +700,7 @@ impl BlockingEmbedService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/embed/card/{token}/query/{export-format}"),
         )
     }
 
@@ -616,7 +713,7 @@ impl BlockingEmbedService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/embed/dashboard/{token}"),
         )
     }
 
@@ -645,7 +742,24 @@ impl BlockingEmbedService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default()
+                .route("/api/embed/dashboard/{token}/dashcard/{dashcard-id}/card/{card-id}"),
+        )
+    }
+
+    /// GET /api/embed/dashboard/{token}/dashcard/{dashcard-id}/card/{card-id}
+    ///
+    /// Typed-id variant of [`Self::get_dashboard_by_token_dashcard_by_dashcard_id_card_by_card_id`].
+    pub fn get_dashboard_by_token_dashcard_by_dashcard_id_card_by_card_id_typed(
+        &self,
+        token: impl Into<PathParam>,
+        dashcard_id: DashcardId,
+        card_id: CardId,
+    ) -> Result<Value> {
+        self.get_dashboard_by_token_dashcard_by_dashcard_id_card_by_card_id(
+            token,
+            dashcard_id,
+            card_id,
         )
     }
 
@@ -678,7 +792,27 @@ impl BlockingEmbedService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/embed/dashboard/{token}/dashcard/{dashcard-id}/card/{card-id}/{export-format}"),
+        )
+    }
+
+    /// GET /api/embed/dashboard/{token}/dashcard/{dashcard-id}/card/{card-id}/{export-format}
+    ///
+    /// Typed-id variant of [`Self::get_dashboard_by_token_dashcard_by_dashcard_id_card_by_card_id_by_export_format`].
+    pub fn get_dashboard_by_token_dashcard_by_dashcard_id_card_by_card_id_by_export_format_typed(
+        &self,
+        token: impl Into<PathParam>,
+        dashcard_id: DashcardId,
+        card_id: CardId,
+        export_format: impl Into<PathParam>,
+        query: Option<&Value>,
+    ) -> Result<Vec<u8>> {
+        self.get_dashboard_by_token_dashcard_by_dashcard_id_card_by_card_id_by_export_format(
+            token,
+            dashcard_id,
+            card_id,
+            export_format,
+            query,
         )
     }
 
@@ -704,7 +838,8 @@ impl BlockingEmbedService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default()
+                .route("/api/embed/dashboard/{token}/params/{param-key}/remapping"),
         )
     }
 
@@ -733,7 +868,8 @@ impl BlockingEmbedService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default()
+                .route("/api/embed/dashboard/{token}/params/{param-key}/search/{prefix}"),
         )
     }
 
@@ -759,7 +895,8 @@ impl BlockingEmbedService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default()
+                .route("/api/embed/dashboard/{token}/params/{param-key}/values"),
         )
     }
 
@@ -772,7 +909,7 @@ impl BlockingEmbedService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/embed/pivot/card/{token}/query"),
         )
     }
 
@@ -802,7 +939,24 @@ impl BlockingEmbedService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default()
+                .route("/api/embed/pivot/dashboard/{token}/dashcard/{dashcard-id}/card/{card-id}"),
+        )
+    }
+
+    /// GET /api/embed/pivot/dashboard/{token}/dashcard/{dashcard-id}/card/{card-id}
+    ///
+    /// Typed-id variant of [`Self::get_pivot_dashboard_by_token_dashcard_by_dashcard_id_card_by_card_id`].
+    pub fn get_pivot_dashboard_by_token_dashcard_by_dashcard_id_card_by_card_id_typed(
+        &self,
+        token: impl Into<PathParam>,
+        dashcard_id: DashcardId,
+        card_id: CardId,
+    ) -> Result<Value> {
+        self.get_pivot_dashboard_by_token_dashcard_by_dashcard_id_card_by_card_id(
+            token,
+            dashcard_id,
+            card_id,
         )
     }
 
@@ -834,7 +988,7 @@ impl BlockingEmbedService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/embed/tiles/card/{token}/{zoom}/{x}/{y}"),
         )
     }
 
@@ -874,7 +1028,31 @@ impl BlockingEmbedService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/embed/tiles/dashboard/{token}/dashcard/{dashcard-id}/card/{card-id}/{zoom}/{x}/{y}"),
+        )
+    }
+
+    /// GET /api/embed/tiles/dashboard/{token}/dashcard/{dashcard-id}/card/{card-id}/{zoom}/{x}/{y}
+    ///
+    /// Typed-id variant of [`Self::get_tiles_dashboard_by_token_dashcard_by_dashcard_id_card_by_card_id_by_zoom_by_x_by_y`].
+    pub fn get_tiles_dashboard_by_token_dashcard_by_dashcard_id_card_by_card_id_by_zoom_by_x_by_y_typed(
+        &self,
+        token: impl Into<PathParam>,
+        dashcard_id: DashcardId,
+        card_id: CardId,
+        zoom: impl Into<PathParam>,
+        x: impl Into<PathParam>,
+        y: impl Into<PathParam>,
+        query: Option<&Value>,
+    ) -> Result<Vec<u8>> {
+        self.get_tiles_dashboard_by_token_dashcard_by_dashcard_id_card_by_card_id_by_zoom_by_x_by_y(
+            token,
+            dashcard_id,
+            card_id,
+            zoom,
+            x,
+            y,
+            query,
         )
     }
 }
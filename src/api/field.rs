@@ -1,7 +1,16 @@
 use http::Method;
 use serde_json::Value;
 
-use crate::{Result, client::RequestOptions, types::path::PathParam};
+use crate::{
+    Result,
+    client::RequestOptions,
+    types::{
+        field::{Field, FieldSummary, FieldValues, UpdateField},
+        ids::FieldId,
+        path::PathParam,
+        table::RelatedEntities,
+    },
+};
 
 #[cfg(feature = "blocking")]
 use crate::client::BlockingClient;
@@ -20,6 +29,81 @@ impl FieldService {
         Self { client }
     }
 
+    /// GET /api/field/{id}
+    pub async fn get_field(&self, id: FieldId) -> Result<Field> {
+        let id = PathParam::from(id);
+        let segments = ["api", "field", id.as_str()];
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/field/{id}"),
+            )
+            .await
+    }
+
+    /// GET /api/field/{id}/values
+    pub async fn field_values(&self, id: FieldId) -> Result<FieldValues> {
+        let id = PathParam::from(id);
+        let segments = ["api", "field", id.as_str(), "values"];
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/field/{id}/values"),
+            )
+            .await
+    }
+
+    /// GET /api/field/{id}/summary
+    pub async fn field_summary(&self, id: FieldId) -> Result<FieldSummary> {
+        let id = PathParam::from(id);
+        let segments = ["api", "field", id.as_str(), "summary"];
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/field/{id}/summary"),
+            )
+            .await
+    }
+
+    /// GET /api/field/{id}/related
+    pub async fn field_related(&self, id: FieldId) -> Result<RelatedEntities> {
+        let id = PathParam::from(id);
+        let segments = ["api", "field", id.as_str(), "related"];
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/field/{id}/related"),
+            )
+            .await
+    }
+
+    /// PUT /api/field/{id}
+    pub async fn update_field(&self, id: FieldId, request: &UpdateField) -> Result<Field> {
+        let id = PathParam::from(id);
+        let segments = ["api", "field", id.as_str()];
+        self.client
+            .request_json(
+                Method::PUT,
+                &segments,
+                Option::<&()>::None,
+                Some(request),
+                RequestOptions::default().route("/api/field/{id}"),
+            )
+            .await
+    }
+
     /// GET /api/field/{id}
     pub async fn get_by_id(
         &self,
@@ -34,11 +118,18 @@ impl FieldService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/field/{id}"),
             )
             .await
     }
 
+    /// GET /api/field/{id}
+    ///
+    /// Typed-id variant of [`Self::get_by_id`].
+    pub async fn get_by_id_typed(&self, id: FieldId, query: Option<&Value>) -> Result<Value> {
+        self.get_by_id(id, query).await
+    }
+
     /// PUT /api/field/{id}
     pub async fn put_by_id(&self, id: impl Into<PathParam>, body: Option<&Value>) -> Result<Value> {
         let id = id.into();
@@ -49,11 +140,18 @@ impl FieldService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/field/{id}"),
             )
             .await
     }
 
+    /// PUT /api/field/{id}
+    ///
+    /// Typed-id variant of [`Self::put_by_id`].
+    pub async fn put_by_id_typed(&self, id: FieldId, body: Option<&Value>) -> Result<Value> {
+        self.put_by_id(id, body).await
+    }
+
     /// DELETE /api/field/{id}/dimension
     pub async fn delete_by_id_dimension(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -64,11 +162,18 @@ impl FieldService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/field/{id}/dimension"),
             )
             .await
     }
 
+    /// DELETE /api/field/{id}/dimension
+    ///
+    /// Typed-id variant of [`Self::delete_by_id_dimension`].
+    pub async fn delete_by_id_dimension_typed(&self, id: FieldId) -> Result<Value> {
+        self.delete_by_id_dimension(id).await
+    }
+
     /// POST /api/field/{id}/dimension
     pub async fn post_by_id_dimension(
         &self,
@@ -83,11 +188,22 @@ impl FieldService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/field/{id}/dimension"),
             )
             .await
     }
 
+    /// POST /api/field/{id}/dimension
+    ///
+    /// Typed-id variant of [`Self::post_by_id_dimension`].
+    pub async fn post_by_id_dimension_typed(
+        &self,
+        id: FieldId,
+        body: Option<&Value>,
+    ) -> Result<Value> {
+        self.post_by_id_dimension(id, body).await
+    }
+
     /// POST /api/field/{id}/discard_values
     pub async fn post_by_id_discard_values(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -98,11 +214,18 @@ impl FieldService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/field/{id}/discard_values"),
             )
             .await
     }
 
+    /// POST /api/field/{id}/discard_values
+    ///
+    /// Typed-id variant of [`Self::post_by_id_discard_values`].
+    pub async fn post_by_id_discard_values_typed(&self, id: FieldId) -> Result<Value> {
+        self.post_by_id_discard_values(id).await
+    }
+
     /// GET /api/field/{id}/related
     pub async fn get_by_id_related(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -113,11 +236,18 @@ impl FieldService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/field/{id}/related"),
             )
             .await
     }
 
+    /// GET /api/field/{id}/related
+    ///
+    /// Typed-id variant of [`Self::get_by_id_related`].
+    pub async fn get_by_id_related_typed(&self, id: FieldId) -> Result<Value> {
+        self.get_by_id_related(id).await
+    }
+
     /// GET /api/field/{id}/remapping/{remapped-id}
     pub async fn get_by_id_remapping_by_remapped_id(
         &self,
@@ -140,11 +270,24 @@ impl FieldService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/field/{id}/remapping/{remapped-id}"),
             )
             .await
     }
 
+    /// GET /api/field/{id}/remapping/{remapped-id}
+    ///
+    /// Typed-id variant of [`Self::get_by_id_remapping_by_remapped_id`].
+    pub async fn get_by_id_remapping_by_remapped_id_typed(
+        &self,
+        id: FieldId,
+        remapped_id: FieldId,
+        query: Option<&Value>,
+    ) -> Result<Value> {
+        self.get_by_id_remapping_by_remapped_id(id, remapped_id, query)
+            .await
+    }
+
     /// POST /api/field/{id}/rescan_values
     pub async fn post_by_id_rescan_values(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -155,11 +298,18 @@ impl FieldService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/field/{id}/rescan_values"),
             )
             .await
     }
 
+    /// POST /api/field/{id}/rescan_values
+    ///
+    /// Typed-id variant of [`Self::post_by_id_rescan_values`].
+    pub async fn post_by_id_rescan_values_typed(&self, id: FieldId) -> Result<Value> {
+        self.post_by_id_rescan_values(id).await
+    }
+
     /// GET /api/field/{id}/search/{search-id}
     pub async fn get_by_id_search_by_search_id(
         &self,
@@ -176,11 +326,24 @@ impl FieldService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/field/{id}/search/{search-id}"),
             )
             .await
     }
 
+    /// GET /api/field/{id}/search/{search-id}
+    ///
+    /// Typed-id variant of [`Self::get_by_id_search_by_search_id`].
+    pub async fn get_by_id_search_by_search_id_typed(
+        &self,
+        id: FieldId,
+        search_id: FieldId,
+        query: Option<&Value>,
+    ) -> Result<Value> {
+        self.get_by_id_search_by_search_id(id, search_id, query)
+            .await
+    }
+
     /// GET /api/field/{id}/summary
     pub async fn get_by_id_summary(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -191,11 +354,18 @@ impl FieldService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/field/{id}/summary"),
             )
             .await
     }
 
+    /// GET /api/field/{id}/summary
+    ///
+    /// Typed-id variant of [`Self::get_by_id_summary`].
+    pub async fn get_by_id_summary_typed(&self, id: FieldId) -> Result<Value> {
+        self.get_by_id_summary(id).await
+    }
+
     /// GET /api/field/{id}/values
     pub async fn get_by_id_values(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -206,11 +376,18 @@ impl FieldService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/field/{id}/values"),
             )
             .await
     }
 
+    /// GET /api/field/{id}/values
+    ///
+    /// Typed-id variant of [`Self::get_by_id_values`].
+    pub async fn get_by_id_values_typed(&self, id: FieldId) -> Result<Value> {
+        self.get_by_id_values(id).await
+    }
+
     /// POST /api/field/{id}/values
     pub async fn post_by_id_values(
         &self,
@@ -225,10 +402,21 @@ impl FieldService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/field/{id}/values"),
             )
             .await
     }
+
+    /// POST /api/field/{id}/values
+    ///
+    /// Typed-id variant of [`Self::post_by_id_values`].
+    pub async fn post_by_id_values_typed(
+        &self,
+        id: FieldId,
+        body: Option<&Value>,
+    ) -> Result<Value> {
+        self.post_by_id_values(id, body).await
+    }
 }
 
 #[cfg(feature = "blocking")]
@@ -243,6 +431,71 @@ impl BlockingFieldService {
         Self { client }
     }
 
+    /// GET /api/field/{id}
+    pub fn get_field(&self, id: FieldId) -> Result<Field> {
+        let id = PathParam::from(id);
+        let segments = ["api", "field", id.as_str()];
+        self.client.request_json(
+            Method::GET,
+            &segments,
+            Option::<&()>::None,
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/field/{id}"),
+        )
+    }
+
+    /// GET /api/field/{id}/values
+    pub fn field_values(&self, id: FieldId) -> Result<FieldValues> {
+        let id = PathParam::from(id);
+        let segments = ["api", "field", id.as_str(), "values"];
+        self.client.request_json(
+            Method::GET,
+            &segments,
+            Option::<&()>::None,
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/field/{id}/values"),
+        )
+    }
+
+    /// GET /api/field/{id}/summary
+    pub fn field_summary(&self, id: FieldId) -> Result<FieldSummary> {
+        let id = PathParam::from(id);
+        let segments = ["api", "field", id.as_str(), "summary"];
+        self.client.request_json(
+            Method::GET,
+            &segments,
+            Option::<&()>::None,
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/field/{id}/summary"),
+        )
+    }
+
+    /// GET /api/field/{id}/related
+    pub fn field_related(&self, id: FieldId) -> Result<RelatedEntities> {
+        let id = PathParam::from(id);
+        let segments = ["api", "field", id.as_str(), "related"];
+        self.client.request_json(
+            Method::GET,
+            &segments,
+            Option::<&()>::None,
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/field/{id}/related"),
+        )
+    }
+
+    /// PUT /api/field/{id}
+    pub fn update_field(&self, id: FieldId, request: &UpdateField) -> Result<Field> {
+        let id = PathParam::from(id);
+        let segments = ["api", "field", id.as_str()];
+        self.client.request_json(
+            Method::PUT,
+            &segments,
+            Option::<&()>::None,
+            Some(request),
+            RequestOptions::default().route("/api/field/{id}"),
+        )
+    }
+
     /// GET /api/field/{id}
     pub fn get_by_id(&self, id: impl Into<PathParam>, query: Option<&Value>) -> Result<Value> {
         let id = id.into();
@@ -252,10 +505,17 @@ impl BlockingFieldService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/field/{id}"),
         )
     }
 
+    /// GET /api/field/{id}
+    ///
+    /// Typed-id variant of [`Self::get_by_id`].
+    pub fn get_by_id_typed(&self, id: FieldId, query: Option<&Value>) -> Result<Value> {
+        self.get_by_id(id, query)
+    }
+
     /// PUT /api/field/{id}
     pub fn put_by_id(&self, id: impl Into<PathParam>, body: Option<&Value>) -> Result<Value> {
         let id = id.into();
@@ -265,10 +525,17 @@ impl BlockingFieldService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/field/{id}"),
         )
     }
 
+    /// PUT /api/field/{id}
+    ///
+    /// Typed-id variant of [`Self::put_by_id`].
+    pub fn put_by_id_typed(&self, id: FieldId, body: Option<&Value>) -> Result<Value> {
+        self.put_by_id(id, body)
+    }
+
     /// DELETE /api/field/{id}/dimension
     pub fn delete_by_id_dimension(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -278,10 +545,17 @@ impl BlockingFieldService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/field/{id}/dimension"),
         )
     }
 
+    /// DELETE /api/field/{id}/dimension
+    ///
+    /// Typed-id variant of [`Self::delete_by_id_dimension`].
+    pub fn delete_by_id_dimension_typed(&self, id: FieldId) -> Result<Value> {
+        self.delete_by_id_dimension(id)
+    }
+
     /// POST /api/field/{id}/dimension
     pub fn post_by_id_dimension(
         &self,
@@ -295,10 +569,17 @@ impl BlockingFieldService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/field/{id}/dimension"),
         )
     }
 
+    /// POST /api/field/{id}/dimension
+    ///
+    /// Typed-id variant of [`Self::post_by_id_dimension`].
+    pub fn post_by_id_dimension_typed(&self, id: FieldId, body: Option<&Value>) -> Result<Value> {
+        self.post_by_id_dimension(id, body)
+    }
+
     /// POST /api/field/{id}/discard_values
     pub fn post_by_id_discard_values(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -308,10 +589,17 @@ impl BlockingFieldService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/field/{id}/discard_values"),
         )
     }
 
+    /// POST /api/field/{id}/discard_values
+    ///
+    /// Typed-id variant of [`Self::post_by_id_discard_values`].
+    pub fn post_by_id_discard_values_typed(&self, id: FieldId) -> Result<Value> {
+        self.post_by_id_discard_values(id)
+    }
+
     /// GET /api/field/{id}/related
     pub fn get_by_id_related(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -321,10 +609,17 @@ impl BlockingFieldService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/field/{id}/related"),
         )
     }
 
+    /// GET /api/field/{id}/related
+    ///
+    /// Typed-id variant of [`Self::get_by_id_related`].
+    pub fn get_by_id_related_typed(&self, id: FieldId) -> Result<Value> {
+        self.get_by_id_related(id)
+    }
+
     /// GET /api/field/{id}/remapping/{remapped-id}
     pub fn get_by_id_remapping_by_remapped_id(
         &self,
@@ -346,10 +641,22 @@ impl BlockingFieldService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/field/{id}/remapping/{remapped-id}"),
         )
     }
 
+    /// GET /api/field/{id}/remapping/{remapped-id}
+    ///
+    /// Typed-id variant of [`Self::get_by_id_remapping_by_remapped_id`].
+    pub fn get_by_id_remapping_by_remapped_id_typed(
+        &self,
+        id: FieldId,
+        remapped_id: FieldId,
+        query: Option<&Value>,
+    ) -> Result<Value> {
+        self.get_by_id_remapping_by_remapped_id(id, remapped_id, query)
+    }
+
     /// POST /api/field/{id}/rescan_values
     pub fn post_by_id_rescan_values(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -359,10 +666,17 @@ impl BlockingFieldService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/field/{id}/rescan_values"),
         )
     }
 
+    /// POST /api/field/{id}/rescan_values
+    ///
+    /// Typed-id variant of [`Self::post_by_id_rescan_values`].
+    pub fn post_by_id_rescan_values_typed(&self, id: FieldId) -> Result<Value> {
+        self.post_by_id_rescan_values(id)
+    }
+
     /// GET /api/field/{id}/search/{search-id}
     pub fn get_by_id_search_by_search_id(
         &self,
@@ -378,10 +692,22 @@ impl BlockingFieldService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/field/{id}/search/{search-id}"),
         )
     }
 
+    /// GET /api/field/{id}/search/{search-id}
+    ///
+    /// Typed-id variant of [`Self::get_by_id_search_by_search_id`].
+    pub fn get_by_id_search_by_search_id_typed(
+        &self,
+        id: FieldId,
+        search_id: FieldId,
+        query: Option<&Value>,
+    ) -> Result<Value> {
+        self.get_by_id_search_by_search_id(id, search_id, query)
+    }
+
     /// GET /api/field/{id}/summary
     pub fn get_by_id_summary(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -391,10 +717,17 @@ impl BlockingFieldService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/field/{id}/summary"),
         )
     }
 
+    /// GET /api/field/{id}/summary
+    ///
+    /// Typed-id variant of [`Self::get_by_id_summary`].
+    pub fn get_by_id_summary_typed(&self, id: FieldId) -> Result<Value> {
+        self.get_by_id_summary(id)
+    }
+
     /// GET /api/field/{id}/values
     pub fn get_by_id_values(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -404,10 +737,17 @@ impl BlockingFieldService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/field/{id}/values"),
         )
     }
 
+    /// GET /api/field/{id}/values
+    ///
+    /// Typed-id variant of [`Self::get_by_id_values`].
+    pub fn get_by_id_values_typed(&self, id: FieldId) -> Result<Value> {
+        self.get_by_id_values(id)
+    }
+
     /// POST /api/field/{id}/values
     pub fn post_by_id_values(
         &self,
@@ -421,7 +761,14 @@ impl BlockingFieldService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/field/{id}/values"),
         )
     }
+
+    /// POST /api/field/{id}/values
+    ///
+    /// Typed-id variant of [`Self::post_by_id_values`].
+    pub fn post_by_id_values_typed(&self, id: FieldId, body: Option<&Value>) -> Result<Value> {
+        self.post_by_id_values(id, body)
+    }
 }
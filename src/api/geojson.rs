@@ -29,7 +29,7 @@ impl GeojsonService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/geojson/"),
             )
             .await
     }
@@ -44,7 +44,7 @@ impl GeojsonService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/geojson/{key}"),
             )
             .await
     }
@@ -70,7 +70,7 @@ impl BlockingGeojsonService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/geojson/"),
         )
     }
 
@@ -83,7 +83,7 @@ impl BlockingGeojsonService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/geojson/{key}"),
         )
     }
 }
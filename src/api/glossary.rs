@@ -1,7 +1,11 @@
 use http::Method;
 use serde_json::Value;
 
-use crate::{Result, client::RequestOptions, types::path::PathParam};
+use crate::{
+    Result,
+    client::RequestOptions,
+    types::{ids::GlossaryId, path::PathParam},
+};
 
 #[cfg(feature = "blocking")]
 use crate::client::BlockingClient;
@@ -29,7 +33,7 @@ impl GlossaryService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/glossary/"),
             )
             .await
     }
@@ -43,7 +47,7 @@ impl GlossaryService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/glossary/"),
             )
             .await
     }
@@ -58,11 +62,18 @@ impl GlossaryService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/glossary/{id}"),
             )
             .await
     }
 
+    /// DELETE /api/glossary/{id}
+    ///
+    /// Typed-id variant of [`Self::delete_by_id`].
+    pub async fn delete_by_id_typed(&self, id: GlossaryId) -> Result<Value> {
+        self.delete_by_id(id).await
+    }
+
     /// PUT /api/glossary/{id}
     pub async fn put_by_id(&self, id: impl Into<PathParam>, body: Option<&Value>) -> Result<Value> {
         let id = id.into();
@@ -73,10 +84,17 @@ impl GlossaryService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/glossary/{id}"),
             )
             .await
     }
+
+    /// PUT /api/glossary/{id}
+    ///
+    /// Typed-id variant of [`Self::put_by_id`].
+    pub async fn put_by_id_typed(&self, id: GlossaryId, body: Option<&Value>) -> Result<Value> {
+        self.put_by_id(id, body).await
+    }
 }
 
 #[cfg(feature = "blocking")]
@@ -99,7 +117,7 @@ impl BlockingGlossaryService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/glossary/"),
         )
     }
 
@@ -111,7 +129,7 @@ impl BlockingGlossaryService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/glossary/"),
         )
     }
 
@@ -124,10 +142,17 @@ impl BlockingGlossaryService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/glossary/{id}"),
         )
     }
 
+    /// DELETE /api/glossary/{id}
+    ///
+    /// Typed-id variant of [`Self::delete_by_id`].
+    pub fn delete_by_id_typed(&self, id: GlossaryId) -> Result<Value> {
+        self.delete_by_id(id)
+    }
+
     /// PUT /api/glossary/{id}
     pub fn put_by_id(&self, id: impl Into<PathParam>, body: Option<&Value>) -> Result<Value> {
         let id = id.into();
@@ -137,7 +162,14 @@ impl BlockingGlossaryService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/glossary/{id}"),
         )
     }
+
+    /// PUT /api/glossary/{id}
+    ///
+    /// Typed-id variant of [`Self::put_by_id`].
+    pub fn put_by_id_typed(&self, id: GlossaryId, body: Option<&Value>) -> Result<Value> {
+        self.put_by_id(id, body)
+    }
 }
@@ -29,7 +29,7 @@ impl GoogleService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/google/settings"),
             )
             .await
     }
@@ -55,7 +55,7 @@ impl BlockingGoogleService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/google/settings"),
         )
     }
 }
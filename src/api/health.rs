@@ -17,9 +17,10 @@ impl HealthService {
         Self { client }
     }
 
+    /// GET /api/health
     pub async fn get(&self) -> Result<HealthResponse> {
         self.client
-            .get_json(&["api", "health"], Option::<&()>::None)
+            .get_json("/api/health", &["api", "health"], Option::<&()>::None)
             .await
     }
 }
@@ -36,8 +37,9 @@ impl BlockingHealthService {
         Self { client }
     }
 
+    /// GET /api/health
     pub fn get(&self) -> Result<HealthResponse> {
         self.client
-            .get_json(&["api", "health"], Option::<&()>::None)
+            .get_json("/api/health", &["api", "health"], Option::<&()>::None)
     }
 }
@@ -29,7 +29,7 @@ impl LdapService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/ldap/settings"),
             )
             .await
     }
@@ -55,7 +55,7 @@ impl BlockingLdapService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/ldap/settings"),
         )
     }
 }
@@ -29,7 +29,7 @@ impl LoggerService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/logger/adjustment"),
             )
             .await
     }
@@ -43,7 +43,7 @@ impl LoggerService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/logger/adjustment"),
             )
             .await
     }
@@ -57,7 +57,7 @@ impl LoggerService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/logger/logs"),
             )
             .await
     }
@@ -71,7 +71,7 @@ impl LoggerService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/logger/presets"),
             )
             .await
     }
@@ -97,7 +97,7 @@ impl BlockingLoggerService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/logger/adjustment"),
         )
     }
 
@@ -109,7 +109,7 @@ impl BlockingLoggerService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/logger/adjustment"),
         )
     }
 
@@ -121,7 +121,7 @@ impl BlockingLoggerService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/logger/logs"),
         )
     }
 
@@ -133,7 +133,7 @@ impl BlockingLoggerService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/logger/presets"),
         )
     }
 }
@@ -29,7 +29,7 @@ impl LoginHistoryService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/login-history/current"),
             )
             .await
     }
@@ -55,7 +55,7 @@ impl BlockingLoginHistoryService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/login-history/current"),
         )
     }
 }
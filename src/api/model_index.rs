@@ -1,7 +1,11 @@
 use http::Method;
 use serde_json::Value;
 
-use crate::{Result, client::RequestOptions, types::path::PathParam};
+use crate::{
+    Result,
+    client::RequestOptions,
+    types::{ids::ModelIndexId, path::PathParam},
+};
 
 #[cfg(feature = "blocking")]
 use crate::client::BlockingClient;
@@ -29,7 +33,7 @@ impl ModelIndexService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/model-index/"),
             )
             .await
     }
@@ -43,7 +47,7 @@ impl ModelIndexService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/model-index/"),
             )
             .await
     }
@@ -58,11 +62,18 @@ impl ModelIndexService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/model-index/{id}"),
             )
             .await
     }
 
+    /// DELETE /api/model-index/{id}
+    ///
+    /// Typed-id variant of [`Self::delete_by_id`].
+    pub async fn delete_by_id_typed(&self, id: ModelIndexId) -> Result<Value> {
+        self.delete_by_id(id).await
+    }
+
     /// GET /api/model-index/{id}
     pub async fn get_by_id(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -73,10 +84,17 @@ impl ModelIndexService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/model-index/{id}"),
             )
             .await
     }
+
+    /// GET /api/model-index/{id}
+    ///
+    /// Typed-id variant of [`Self::get_by_id`].
+    pub async fn get_by_id_typed(&self, id: ModelIndexId) -> Result<Value> {
+        self.get_by_id(id).await
+    }
 }
 
 #[cfg(feature = "blocking")]
@@ -99,7 +117,7 @@ impl BlockingModelIndexService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/model-index/"),
         )
     }
 
@@ -111,7 +129,7 @@ impl BlockingModelIndexService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/model-index/"),
         )
     }
 
@@ -124,10 +142,17 @@ impl BlockingModelIndexService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/model-index/{id}"),
         )
     }
 
+    /// DELETE /api/model-index/{id}
+    ///
+    /// Typed-id variant of [`Self::delete_by_id`].
+    pub fn delete_by_id_typed(&self, id: ModelIndexId) -> Result<Value> {
+        self.delete_by_id(id)
+    }
+
     /// GET /api/model-index/{id}
     pub fn get_by_id(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -137,7 +162,14 @@ impl BlockingModelIndexService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/model-index/{id}"),
         )
     }
+
+    /// GET /api/model-index/{id}
+    ///
+    /// Typed-id variant of [`Self::get_by_id`].
+    pub fn get_by_id_typed(&self, id: ModelIndexId) -> Result<Value> {
+        self.get_by_id(id)
+    }
 }
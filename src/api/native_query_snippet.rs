@@ -1,7 +1,16 @@
 use http::Method;
 use serde_json::Value;
 
-use crate::{Result, client::RequestOptions, types::path::PathParam};
+use crate::{
+    Result,
+    client::RequestOptions,
+    types::{
+        card::Card,
+        ids::SnippetId,
+        path::PathParam,
+        snippet::{CreateSnippet, Snippet, SnippetListQuery, UpdateSnippet},
+    },
+};
 
 #[cfg(feature = "blocking")]
 use crate::client::BlockingClient;
@@ -20,6 +29,86 @@ impl NativeQuerySnippetService {
         Self { client }
     }
 
+    /// GET /api/native-query-snippet/
+    pub async fn list_snippets(&self, query: &SnippetListQuery) -> Result<Vec<Snippet>> {
+        let segments = ["api", "native-query-snippet"];
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                Some(query),
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/native-query-snippet/"),
+            )
+            .await
+    }
+
+    /// GET /api/native-query-snippet/{id}
+    pub async fn get_snippet(&self, id: SnippetId) -> Result<Snippet> {
+        let id = PathParam::from(id);
+        let segments = ["api", "native-query-snippet", id.as_str()];
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/native-query-snippet/{id}"),
+            )
+            .await
+    }
+
+    /// POST /api/native-query-snippet/
+    pub async fn create_snippet(&self, snippet: &CreateSnippet) -> Result<Snippet> {
+        let segments = ["api", "native-query-snippet"];
+        self.client
+            .request_json(
+                Method::POST,
+                &segments,
+                Option::<&()>::None,
+                Some(snippet),
+                RequestOptions::default().route("/api/native-query-snippet/"),
+            )
+            .await
+    }
+
+    /// PUT /api/native-query-snippet/{id}
+    pub async fn update_snippet(&self, id: SnippetId, update: &UpdateSnippet) -> Result<Snippet> {
+        let id = PathParam::from(id);
+        let segments = ["api", "native-query-snippet", id.as_str()];
+        self.client
+            .request_json(
+                Method::PUT,
+                &segments,
+                Option::<&()>::None,
+                Some(update),
+                RequestOptions::default().route("/api/native-query-snippet/{id}"),
+            )
+            .await
+    }
+
+    /// GET /api/card/
+    ///
+    /// Non-archived cards whose native query references `snippet`; see
+    /// [`Snippet::is_referenced_by`]. Run this before renaming or archiving a snippet.
+    pub async fn dependent_cards(&self, snippet: &Snippet) -> Result<Vec<Card>> {
+        let segments = ["api", "card"];
+        let cards: Vec<Card> = self
+            .client
+            .request_json(
+                Method::GET,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/card/"),
+            )
+            .await?;
+        Ok(cards
+            .into_iter()
+            .filter(|card| snippet.is_referenced_by(card))
+            .collect())
+    }
+
     /// GET /api/native-query-snippet/
     pub async fn get(&self, query: Option<&Value>) -> Result<Value> {
         let segments = ["api", "native-query-snippet"];
@@ -29,7 +118,7 @@ impl NativeQuerySnippetService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/native-query-snippet/"),
             )
             .await
     }
@@ -43,7 +132,7 @@ impl NativeQuerySnippetService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/native-query-snippet/"),
             )
             .await
     }
@@ -58,11 +147,18 @@ impl NativeQuerySnippetService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/native-query-snippet/{id}"),
             )
             .await
     }
 
+    /// GET /api/native-query-snippet/{id}
+    ///
+    /// Typed-id variant of [`Self::get_by_id`].
+    pub async fn get_by_id_typed(&self, id: SnippetId) -> Result<Value> {
+        self.get_by_id(id).await
+    }
+
     /// PUT /api/native-query-snippet/{id}
     pub async fn put_by_id(&self, id: impl Into<PathParam>, body: Option<&Value>) -> Result<Value> {
         let id = id.into();
@@ -73,10 +169,17 @@ impl NativeQuerySnippetService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/native-query-snippet/{id}"),
             )
             .await
     }
+
+    /// PUT /api/native-query-snippet/{id}
+    ///
+    /// Typed-id variant of [`Self::put_by_id`].
+    pub async fn put_by_id_typed(&self, id: SnippetId, body: Option<&Value>) -> Result<Value> {
+        self.put_by_id(id, body).await
+    }
 }
 
 #[cfg(feature = "blocking")]
@@ -91,6 +194,75 @@ impl BlockingNativeQuerySnippetService {
         Self { client }
     }
 
+    /// GET /api/native-query-snippet/
+    pub fn list_snippets(&self, query: &SnippetListQuery) -> Result<Vec<Snippet>> {
+        let segments = ["api", "native-query-snippet"];
+        self.client.request_json(
+            Method::GET,
+            &segments,
+            Some(query),
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/native-query-snippet/"),
+        )
+    }
+
+    /// GET /api/native-query-snippet/{id}
+    pub fn get_snippet(&self, id: SnippetId) -> Result<Snippet> {
+        let id = PathParam::from(id);
+        let segments = ["api", "native-query-snippet", id.as_str()];
+        self.client.request_json(
+            Method::GET,
+            &segments,
+            Option::<&()>::None,
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/native-query-snippet/{id}"),
+        )
+    }
+
+    /// POST /api/native-query-snippet/
+    pub fn create_snippet(&self, snippet: &CreateSnippet) -> Result<Snippet> {
+        let segments = ["api", "native-query-snippet"];
+        self.client.request_json(
+            Method::POST,
+            &segments,
+            Option::<&()>::None,
+            Some(snippet),
+            RequestOptions::default().route("/api/native-query-snippet/"),
+        )
+    }
+
+    /// PUT /api/native-query-snippet/{id}
+    pub fn update_snippet(&self, id: SnippetId, update: &UpdateSnippet) -> Result<Snippet> {
+        let id = PathParam::from(id);
+        let segments = ["api", "native-query-snippet", id.as_str()];
+        self.client.request_json(
+            Method::PUT,
+            &segments,
+            Option::<&()>::None,
+            Some(update),
+            RequestOptions::default().route("/api/native-query-snippet/{id}"),
+        )
+    }
+
+    /// GET /api/card/
+    ///
+    /// Non-archived cards whose native query references `snippet`; see
+    /// [`Snippet::is_referenced_by`]. Run this before renaming or archiving a snippet.
+    pub fn dependent_cards(&self, snippet: &Snippet) -> Result<Vec<Card>> {
+        let segments = ["api", "card"];
+        let cards: Vec<Card> = self.client.request_json(
+            Method::GET,
+            &segments,
+            Option::<&()>::None,
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/card/"),
+        )?;
+        Ok(cards
+            .into_iter()
+            .filter(|card| snippet.is_referenced_by(card))
+            .collect())
+    }
+
     /// GET /api/native-query-snippet/
     pub fn get(&self, query: Option<&Value>) -> Result<Value> {
         let segments = ["api", "native-query-snippet"];
@@ -99,7 +271,7 @@ impl BlockingNativeQuerySnippetService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/native-query-snippet/"),
         )
     }
 
@@ -111,7 +283,7 @@ impl BlockingNativeQuerySnippetService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/native-query-snippet/"),
         )
     }
 
@@ -124,10 +296,17 @@ impl BlockingNativeQuerySnippetService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/native-query-snippet/{id}"),
         )
     }
 
+    /// GET /api/native-query-snippet/{id}
+    ///
+    /// Typed-id variant of [`Self::get_by_id`].
+    pub fn get_by_id_typed(&self, id: SnippetId) -> Result<Value> {
+        self.get_by_id(id)
+    }
+
     /// PUT /api/native-query-snippet/{id}
     pub fn put_by_id(&self, id: impl Into<PathParam>, body: Option<&Value>) -> Result<Value> {
         let id = id.into();
@@ -137,7 +316,14 @@ impl BlockingNativeQuerySnippetService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/native-query-snippet/{id}"),
         )
     }
+
+    /// PUT /api/native-query-snippet/{id}
+    ///
+    /// Typed-id variant of [`Self::put_by_id`].
+    pub fn put_by_id_typed(&self, id: SnippetId, body: Option<&Value>) -> Result<Value> {
+        self.put_by_id(id, body)
+    }
 }
@@ -1,7 +1,16 @@
 use http::Method;
+use serde::de::IgnoredAny;
 use serde_json::Value;
 
-use crate::{Result, client::RequestOptions, types::path::PathParam};
+use crate::{
+    Result,
+    client::RequestOptions,
+    types::{
+        ids::NotificationId,
+        notification::{CreateNotification, Notification},
+        path::PathParam,
+    },
+};
 
 #[cfg(feature = "blocking")]
 use crate::client::BlockingClient;
@@ -20,6 +29,104 @@ impl NotificationService {
         Self { client }
     }
 
+    /// GET /api/notification/
+    pub async fn list_notifications(&self) -> Result<Vec<Notification>> {
+        let segments = ["api", "notification"];
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/notification/"),
+            )
+            .await
+    }
+
+    /// GET /api/notification/{id}
+    pub async fn get_notification(&self, id: NotificationId) -> Result<Notification> {
+        let id = PathParam::from(id);
+        let segments = ["api", "notification", id.as_str()];
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/notification/{id}"),
+            )
+            .await
+    }
+
+    /// POST /api/notification/
+    pub async fn create_notification(
+        &self,
+        notification: &CreateNotification,
+    ) -> Result<Notification> {
+        let segments = ["api", "notification"];
+        self.client
+            .request_json(
+                Method::POST,
+                &segments,
+                Option::<&()>::None,
+                Some(notification),
+                RequestOptions::default().route("/api/notification/"),
+            )
+            .await
+    }
+
+    /// PUT /api/notification/{id}
+    pub async fn update_notification(
+        &self,
+        id: NotificationId,
+        notification: &Notification,
+    ) -> Result<Notification> {
+        let id = PathParam::from(id);
+        let segments = ["api", "notification", id.as_str()];
+        self.client
+            .request_json(
+                Method::PUT,
+                &segments,
+                Option::<&()>::None,
+                Some(notification),
+                RequestOptions::default().route("/api/notification/{id}"),
+            )
+            .await
+    }
+
+    /// POST /api/notification/send
+    ///
+    /// Sends `notification` once without saving it.
+    pub async fn send_notification(&self, notification: &CreateNotification) -> Result<()> {
+        let segments = ["api", "notification", "send"];
+        self.client
+            .request_json::<IgnoredAny, _, _>(
+                Method::POST,
+                &segments,
+                Option::<&()>::None,
+                Some(notification),
+                RequestOptions::default().route("/api/notification/send"),
+            )
+            .await
+            .map(|_| ())
+    }
+
+    /// POST /api/notification/{id}/send
+    pub async fn send_saved_notification(&self, id: NotificationId) -> Result<()> {
+        let id = PathParam::from(id);
+        let segments = ["api", "notification", id.as_str(), "send"];
+        self.client
+            .request_json::<IgnoredAny, _, _>(
+                Method::POST,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/notification/{id}/send"),
+            )
+            .await
+            .map(|_| ())
+    }
+
     /// GET /api/notification/
     pub async fn get(&self, query: Option<&Value>) -> Result<Value> {
         let segments = ["api", "notification"];
@@ -29,7 +136,7 @@ impl NotificationService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/notification/"),
             )
             .await
     }
@@ -43,7 +150,7 @@ impl NotificationService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/notification/"),
             )
             .await
     }
@@ -57,7 +164,7 @@ impl NotificationService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/notification/send"),
             )
             .await
     }
@@ -72,11 +179,18 @@ impl NotificationService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/notification/{id}"),
             )
             .await
     }
 
+    /// GET /api/notification/{id}
+    ///
+    /// Typed-id variant of [`Self::get_by_id`].
+    pub async fn get_by_id_typed(&self, id: NotificationId) -> Result<Value> {
+        self.get_by_id(id).await
+    }
+
     /// PUT /api/notification/{id}
     pub async fn put_by_id(&self, id: impl Into<PathParam>, body: Option<&Value>) -> Result<Value> {
         let id = id.into();
@@ -87,11 +201,18 @@ impl NotificationService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/notification/{id}"),
             )
             .await
     }
 
+    /// PUT /api/notification/{id}
+    ///
+    /// Typed-id variant of [`Self::put_by_id`].
+    pub async fn put_by_id_typed(&self, id: NotificationId, body: Option<&Value>) -> Result<Value> {
+        self.put_by_id(id, body).await
+    }
+
     /// POST /api/notification/{id}/send
     pub async fn post_by_id_send(
         &self,
@@ -106,11 +227,22 @@ impl NotificationService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/notification/{id}/send"),
             )
             .await
     }
 
+    /// POST /api/notification/{id}/send
+    ///
+    /// Typed-id variant of [`Self::post_by_id_send`].
+    pub async fn post_by_id_send_typed(
+        &self,
+        id: NotificationId,
+        body: Option<&Value>,
+    ) -> Result<Value> {
+        self.post_by_id_send(id, body).await
+    }
+
     /// POST /api/notification/{id}/unsubscribe
     pub async fn post_by_id_unsubscribe(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -121,10 +253,17 @@ impl NotificationService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/notification/{id}/unsubscribe"),
             )
             .await
     }
+
+    /// POST /api/notification/{id}/unsubscribe
+    ///
+    /// Typed-id variant of [`Self::post_by_id_unsubscribe`].
+    pub async fn post_by_id_unsubscribe_typed(&self, id: NotificationId) -> Result<Value> {
+        self.post_by_id_unsubscribe(id).await
+    }
 }
 
 #[cfg(feature = "blocking")]
@@ -139,6 +278,91 @@ impl BlockingNotificationService {
         Self { client }
     }
 
+    /// GET /api/notification/
+    pub fn list_notifications(&self) -> Result<Vec<Notification>> {
+        let segments = ["api", "notification"];
+        self.client.request_json(
+            Method::GET,
+            &segments,
+            Option::<&()>::None,
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/notification/"),
+        )
+    }
+
+    /// GET /api/notification/{id}
+    pub fn get_notification(&self, id: NotificationId) -> Result<Notification> {
+        let id = PathParam::from(id);
+        let segments = ["api", "notification", id.as_str()];
+        self.client.request_json(
+            Method::GET,
+            &segments,
+            Option::<&()>::None,
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/notification/{id}"),
+        )
+    }
+
+    /// POST /api/notification/
+    pub fn create_notification(&self, notification: &CreateNotification) -> Result<Notification> {
+        let segments = ["api", "notification"];
+        self.client.request_json(
+            Method::POST,
+            &segments,
+            Option::<&()>::None,
+            Some(notification),
+            RequestOptions::default().route("/api/notification/"),
+        )
+    }
+
+    /// PUT /api/notification/{id}
+    pub fn update_notification(
+        &self,
+        id: NotificationId,
+        notification: &Notification,
+    ) -> Result<Notification> {
+        let id = PathParam::from(id);
+        let segments = ["api", "notification", id.as_str()];
+        self.client.request_json(
+            Method::PUT,
+            &segments,
+            Option::<&()>::None,
+            Some(notification),
+            RequestOptions::default().route("/api/notification/{id}"),
+        )
+    }
+
+    /// POST /api/notification/send
+    ///
+    /// Sends `notification` once without saving it.
+    pub fn send_notification(&self, notification: &CreateNotification) -> Result<()> {
+        let segments = ["api", "notification", "send"];
+        self.client
+            .request_json::<IgnoredAny, _, _>(
+                Method::POST,
+                &segments,
+                Option::<&()>::None,
+                Some(notification),
+                RequestOptions::default().route("/api/notification/send"),
+            )
+            .map(|_| ())
+    }
+
+    /// POST /api/notification/{id}/send
+    pub fn send_saved_notification(&self, id: NotificationId) -> Result<()> {
+        let id = PathParam::from(id);
+        let segments = ["api", "notification", id.as_str(), "send"];
+        self.client
+            .request_json::<IgnoredAny, _, _>(
+                Method::POST,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/notification/{id}/send"),
+            )
+            .map(|_| ())
+    }
+
     /// GET /api/notification/
     pub fn get(&self, query: Option<&Value>) -> Result<Value> {
         let segments = ["api", "notification"];
@@ -147,7 +371,7 @@ impl BlockingNotificationService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/notification/"),
         )
     }
 
@@ -159,7 +383,7 @@ impl BlockingNotificationService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/notification/"),
         )
     }
 
@@ -171,7 +395,7 @@ impl BlockingNotificationService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/notification/send"),
         )
     }
 
@@ -184,10 +408,17 @@ impl BlockingNotificationService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/notification/{id}"),
         )
     }
 
+    /// GET /api/notification/{id}
+    ///
+    /// Typed-id variant of [`Self::get_by_id`].
+    pub fn get_by_id_typed(&self, id: NotificationId) -> Result<Value> {
+        self.get_by_id(id)
+    }
+
     /// PUT /api/notification/{id}
     pub fn put_by_id(&self, id: impl Into<PathParam>, body: Option<&Value>) -> Result<Value> {
         let id = id.into();
@@ -197,10 +428,17 @@ impl BlockingNotificationService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/notification/{id}"),
         )
     }
 
+    /// PUT /api/notification/{id}
+    ///
+    /// Typed-id variant of [`Self::put_by_id`].
+    pub fn put_by_id_typed(&self, id: NotificationId, body: Option<&Value>) -> Result<Value> {
+        self.put_by_id(id, body)
+    }
+
     /// POST /api/notification/{id}/send
     pub fn post_by_id_send(&self, id: impl Into<PathParam>, body: Option<&Value>) -> Result<Value> {
         let id = id.into();
@@ -210,10 +448,17 @@ impl BlockingNotificationService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/notification/{id}/send"),
         )
     }
 
+    /// POST /api/notification/{id}/send
+    ///
+    /// Typed-id variant of [`Self::post_by_id_send`].
+    pub fn post_by_id_send_typed(&self, id: NotificationId, body: Option<&Value>) -> Result<Value> {
+        self.post_by_id_send(id, body)
+    }
+
     /// POST /api/notification/{id}/unsubscribe
     pub fn post_by_id_unsubscribe(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -223,7 +468,14 @@ impl BlockingNotificationService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/notification/{id}/unsubscribe"),
         )
     }
+
+    /// POST /api/notification/{id}/unsubscribe
+    ///
+    /// Typed-id variant of [`Self::post_by_id_unsubscribe`].
+    pub fn post_by_id_unsubscribe_typed(&self, id: NotificationId) -> Result<Value> {
+        self.post_by_id_unsubscribe(id)
+    }
 }
@@ -29,7 +29,7 @@ impl NotificationUnsubscribeService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/notification/unsubscribe/"),
             )
             .await
     }
@@ -43,7 +43,7 @@ impl NotificationUnsubscribeService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/notification/unsubscribe/undo"),
             )
             .await
     }
@@ -69,7 +69,7 @@ impl BlockingNotificationUnsubscribeService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/notification/unsubscribe/"),
         )
     }
 
@@ -81,7 +81,7 @@ impl BlockingNotificationUnsubscribeService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/notification/unsubscribe/undo"),
         )
     }
 }
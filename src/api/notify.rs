@@ -1,7 +1,11 @@
 use http::Method;
 use serde_json::Value;
 
-use crate::{Result, client::RequestOptions, types::path::PathParam};
+use crate::{
+    Result,
+    client::RequestOptions,
+    types::{ids::DatabaseId, path::PathParam},
+};
 
 #[cfg(feature = "blocking")]
 use crate::client::BlockingClient;
@@ -29,7 +33,7 @@ impl NotifyService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/notify/db/attached_datawarehouse"),
             )
             .await
     }
@@ -48,11 +52,18 @@ impl NotifyService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/notify/db/{id}"),
             )
             .await
     }
 
+    /// POST /api/notify/db/{id}
+    ///
+    /// Typed-id variant of [`Self::post_db_by_id`].
+    pub async fn post_db_by_id_typed(&self, id: DatabaseId, body: Option<&Value>) -> Result<Value> {
+        self.post_db_by_id(id, body).await
+    }
+
     /// POST /api/notify/db/{id}/new-table
     pub async fn post_db_by_id_new_table(
         &self,
@@ -67,10 +78,21 @@ impl NotifyService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/notify/db/{id}/new-table"),
             )
             .await
     }
+
+    /// POST /api/notify/db/{id}/new-table
+    ///
+    /// Typed-id variant of [`Self::post_db_by_id_new_table`].
+    pub async fn post_db_by_id_new_table_typed(
+        &self,
+        id: DatabaseId,
+        body: Option<&Value>,
+    ) -> Result<Value> {
+        self.post_db_by_id_new_table(id, body).await
+    }
 }
 
 #[cfg(feature = "blocking")]
@@ -93,7 +115,7 @@ impl BlockingNotifyService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/notify/db/attached_datawarehouse"),
         )
     }
 
@@ -106,10 +128,17 @@ impl BlockingNotifyService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/notify/db/{id}"),
         )
     }
 
+    /// POST /api/notify/db/{id}
+    ///
+    /// Typed-id variant of [`Self::post_db_by_id`].
+    pub fn post_db_by_id_typed(&self, id: DatabaseId, body: Option<&Value>) -> Result<Value> {
+        self.post_db_by_id(id, body)
+    }
+
     /// POST /api/notify/db/{id}/new-table
     pub fn post_db_by_id_new_table(
         &self,
@@ -123,7 +152,18 @@ impl BlockingNotifyService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/notify/db/{id}/new-table"),
         )
     }
+
+    /// POST /api/notify/db/{id}/new-table
+    ///
+    /// Typed-id variant of [`Self::post_db_by_id_new_table`].
+    pub fn post_db_by_id_new_table_typed(
+        &self,
+        id: DatabaseId,
+        body: Option<&Value>,
+    ) -> Result<Value> {
+        self.post_db_by_id_new_table(id, body)
+    }
 }
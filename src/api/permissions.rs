@@ -1,13 +1,91 @@
+use std::collections::BTreeMap;
+
 use http::Method;
+use serde::de::IgnoredAny;
 use serde_json::Value;
 
-use crate::{Result, client::RequestOptions, types::path::PathParam};
+use crate::{
+    Error, Result,
+    client::RequestOptions,
+    types::{
+        ids::{DatabaseId, GroupId, MembershipId, UserId},
+        path::PathParam,
+        permissions::{
+            CreateMembership, GraphChanges, GroupMember, GroupName, Membership, MembershipUpdate,
+            PermissionsChanges, PermissionsGraph, PermissionsGraphUpdate, PermissionsGroup,
+        },
+    },
+};
 
 #[cfg(feature = "blocking")]
 use crate::client::BlockingClient;
 #[cfg(feature = "async")]
 use crate::client::Client;
 
+/// How many times the `apply_*_changes` methods send changes before giving up on conflicts.
+const PERMISSIONS_APPLY_ATTEMPTS: u32 = 3;
+
+/// Send `changes` made against graph `revision` with `put`. If someone else edited the graph
+/// in the meantime, refetch it with `get`, drop the changes it already contains and resend
+/// them on top of the new revision. Gives up after three attempts.
+#[cfg(feature = "async")]
+pub(super) async fn apply_graph_changes<C, Put, Get>(
+    revision: i64,
+    changes: &C,
+    put: impl Fn(i64, C) -> Put,
+    get: impl Fn() -> Get,
+) -> Result<C::Graph>
+where
+    C: GraphChanges,
+    Put: Future<Output = Result<C::Graph>>,
+    Get: Future<Output = Result<C::Graph>>,
+{
+    let mut revision = revision;
+    let mut changes = changes.clone();
+    let mut attempt = 1;
+    loop {
+        match put(revision, changes.clone()).await {
+            Err(Error::Conflict(_)) if attempt < PERMISSIONS_APPLY_ATTEMPTS => {
+                let current = get().await?;
+                changes = changes.rebase(&current);
+                if changes.is_empty() {
+                    return Ok(current);
+                }
+                revision = C::revision(&current);
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Blocking counterpart of `apply_graph_changes`.
+#[cfg(feature = "blocking")]
+pub(super) fn apply_graph_changes_blocking<C: GraphChanges>(
+    revision: i64,
+    changes: &C,
+    put: impl Fn(i64, &C) -> Result<C::Graph>,
+    get: impl Fn() -> Result<C::Graph>,
+) -> Result<C::Graph> {
+    let mut revision = revision;
+    let mut changes = changes.clone();
+    let mut attempt = 1;
+    loop {
+        match put(revision, &changes) {
+            Err(Error::Conflict(_)) if attempt < PERMISSIONS_APPLY_ATTEMPTS => {
+                let current = get()?;
+                changes = changes.rebase(&current);
+                if changes.is_empty() {
+                    return Ok(current);
+                }
+                revision = C::revision(&current);
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
 #[cfg(feature = "async")]
 #[derive(Clone)]
 pub struct PermissionsService {
@@ -20,6 +98,236 @@ impl PermissionsService {
         Self { client }
     }
 
+    /// GET /api/permissions/graph
+    pub async fn get_permissions_graph(&self) -> Result<PermissionsGraph> {
+        let segments = ["api", "permissions", "graph"];
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/permissions/graph"),
+            )
+            .await
+    }
+
+    /// PUT /api/permissions/graph
+    ///
+    /// Send `changes` made against graph `revision`. Fails with [`Error::Conflict`] when
+    /// the graph has been edited since; see [`apply_permissions_changes`](Self::apply_permissions_changes).
+    pub async fn put_permissions_changes(
+        &self,
+        revision: i64,
+        changes: &PermissionsChanges,
+    ) -> Result<PermissionsGraph> {
+        let segments = ["api", "permissions", "graph"];
+        self.client
+            .request_json(
+                Method::PUT,
+                &segments,
+                Option::<&()>::None,
+                Some(&PermissionsGraphUpdate::new(revision, changes)),
+                RequestOptions::default().route("/api/permissions/graph"),
+            )
+            .await
+    }
+
+    /// PUT /api/permissions/graph
+    ///
+    /// Send `changes` made against graph `revision`. If someone else edited the graph in
+    /// the meantime, refetch it, drop the changes it already contains and resend it on top
+    /// of the new revision. Gives up after three attempts.
+    pub async fn apply_permissions_changes(
+        &self,
+        revision: i64,
+        changes: &PermissionsChanges,
+    ) -> Result<PermissionsGraph> {
+        apply_graph_changes(
+            revision,
+            changes,
+            |revision, changes| async move {
+                self.put_permissions_changes(revision, &changes).await
+            },
+            || self.get_permissions_graph(),
+        )
+        .await
+    }
+
+    /// GET /api/permissions/group
+    pub async fn list_groups(&self) -> Result<Vec<PermissionsGroup>> {
+        let segments = ["api", "permissions", "group"];
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/permissions/group"),
+            )
+            .await
+    }
+
+    /// GET /api/permissions/group/{id}
+    ///
+    /// Unlike [`Self::list_groups`], includes the group's members.
+    pub async fn get_permissions_group(&self, id: GroupId) -> Result<PermissionsGroup> {
+        let id = PathParam::from(id);
+        let segments = ["api", "permissions", "group", id.as_str()];
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/permissions/group/{id}"),
+            )
+            .await
+    }
+
+    /// POST /api/permissions/group
+    pub async fn create_group(&self, group: &GroupName) -> Result<PermissionsGroup> {
+        let segments = ["api", "permissions", "group"];
+        self.client
+            .request_json(
+                Method::POST,
+                &segments,
+                Option::<&()>::None,
+                Some(group),
+                RequestOptions::default().route("/api/permissions/group"),
+            )
+            .await
+    }
+
+    /// PUT /api/permissions/group/{group-id}
+    pub async fn update_group(
+        &self,
+        group_id: GroupId,
+        group: &GroupName,
+    ) -> Result<PermissionsGroup> {
+        let group_id = PathParam::from(group_id);
+        let segments = ["api", "permissions", "group", group_id.as_str()];
+        self.client
+            .request_json(
+                Method::PUT,
+                &segments,
+                Option::<&()>::None,
+                Some(group),
+                RequestOptions::default().route("/api/permissions/group/{group-id}"),
+            )
+            .await
+    }
+
+    /// DELETE /api/permissions/group/{group-id}
+    pub async fn delete_group(&self, group_id: GroupId) -> Result<()> {
+        let group_id = PathParam::from(group_id);
+        let segments = ["api", "permissions", "group", group_id.as_str()];
+        self.client
+            .request_json::<IgnoredAny, _, _>(
+                Method::DELETE,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/permissions/group/{group-id}"),
+            )
+            .await
+            .map(|_| ())
+    }
+
+    /// GET /api/permissions/membership
+    ///
+    /// Memberships keyed by user.
+    pub async fn list_memberships(&self) -> Result<BTreeMap<UserId, Vec<Membership>>> {
+        let segments = ["api", "permissions", "membership"];
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/permissions/membership"),
+            )
+            .await
+    }
+
+    /// POST /api/permissions/membership
+    ///
+    /// Returns the group's members after the change.
+    pub async fn create_membership(
+        &self,
+        membership: &CreateMembership,
+    ) -> Result<Vec<GroupMember>> {
+        let segments = ["api", "permissions", "membership"];
+        self.client
+            .request_json(
+                Method::POST,
+                &segments,
+                Option::<&()>::None,
+                Some(membership),
+                RequestOptions::default().route("/api/permissions/membership"),
+            )
+            .await
+    }
+
+    /// PUT /api/permissions/membership/{id}
+    pub async fn update_membership(
+        &self,
+        id: MembershipId,
+        is_group_manager: bool,
+    ) -> Result<Membership> {
+        let id = PathParam::from(id);
+        let segments = ["api", "permissions", "membership", id.as_str()];
+        self.client
+            .request_json(
+                Method::PUT,
+                &segments,
+                Option::<&()>::None,
+                Some(&MembershipUpdate { is_group_manager }),
+                RequestOptions::default().route("/api/permissions/membership/{id}"),
+            )
+            .await
+    }
+
+    /// DELETE /api/permissions/membership/{id}
+    pub async fn delete_membership(&self, id: MembershipId) -> Result<()> {
+        let id = PathParam::from(id);
+        let segments = ["api", "permissions", "membership", id.as_str()];
+        self.client
+            .request_json::<IgnoredAny, _, _>(
+                Method::DELETE,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/permissions/membership/{id}"),
+            )
+            .await
+            .map(|_| ())
+    }
+
+    /// PUT /api/permissions/membership/{group-id}/clear
+    ///
+    /// Removes every member from the group.
+    pub async fn clear_group_memberships(&self, group_id: GroupId) -> Result<()> {
+        let group_id = PathParam::from(group_id);
+        let segments = [
+            "api",
+            "permissions",
+            "membership",
+            group_id.as_str(),
+            "clear",
+        ];
+        self.client
+            .request_json::<IgnoredAny, _, _>(
+                Method::PUT,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/permissions/membership/{group-id}/clear"),
+            )
+            .await
+            .map(|_| ())
+    }
+
     /// GET /api/permissions/graph
     pub async fn get_graph(&self) -> Result<Value> {
         let segments = ["api", "permissions", "graph"];
@@ -29,7 +337,7 @@ impl PermissionsService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/permissions/graph"),
             )
             .await
     }
@@ -43,7 +351,7 @@ impl PermissionsService {
                 &segments,
                 query,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/permissions/graph"),
             )
             .await
     }
@@ -58,11 +366,18 @@ impl PermissionsService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/permissions/graph/db/{db-id}"),
             )
             .await
     }
 
+    /// GET /api/permissions/graph/db/{db-id}
+    ///
+    /// Typed-id variant of [`Self::get_graph_db_by_db_id`].
+    pub async fn get_graph_db_by_db_id_typed(&self, db_id: DatabaseId) -> Result<Value> {
+        self.get_graph_db_by_db_id(db_id).await
+    }
+
     /// GET /api/permissions/graph/group/{group-id}
     pub async fn get_graph_group_by_group_id(
         &self,
@@ -76,11 +391,18 @@ impl PermissionsService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/permissions/graph/group/{group-id}"),
             )
             .await
     }
 
+    /// GET /api/permissions/graph/group/{group-id}
+    ///
+    /// Typed-id variant of [`Self::get_graph_group_by_group_id`].
+    pub async fn get_graph_group_by_group_id_typed(&self, group_id: GroupId) -> Result<Value> {
+        self.get_graph_group_by_group_id(group_id).await
+    }
+
     /// GET /api/permissions/group
     pub async fn get_group(&self) -> Result<Value> {
         let segments = ["api", "permissions", "group"];
@@ -90,7 +412,7 @@ impl PermissionsService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/permissions/group"),
             )
             .await
     }
@@ -104,7 +426,7 @@ impl PermissionsService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/permissions/group"),
             )
             .await
     }
@@ -119,11 +441,18 @@ impl PermissionsService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/permissions/group/{group-id}"),
             )
             .await
     }
 
+    /// DELETE /api/permissions/group/{group-id}
+    ///
+    /// Typed-id variant of [`Self::delete_group_by_group_id`].
+    pub async fn delete_group_by_group_id_typed(&self, group_id: GroupId) -> Result<Value> {
+        self.delete_group_by_group_id(group_id).await
+    }
+
     /// PUT /api/permissions/group/{group-id}
     pub async fn put_group_by_group_id(
         &self,
@@ -138,11 +467,22 @@ impl PermissionsService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/permissions/group/{group-id}"),
             )
             .await
     }
 
+    /// PUT /api/permissions/group/{group-id}
+    ///
+    /// Typed-id variant of [`Self::put_group_by_group_id`].
+    pub async fn put_group_by_group_id_typed(
+        &self,
+        group_id: GroupId,
+        body: Option<&Value>,
+    ) -> Result<Value> {
+        self.put_group_by_group_id(group_id, body).await
+    }
+
     /// GET /api/permissions/group/{id}
     pub async fn get_group_by_id(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -153,11 +493,18 @@ impl PermissionsService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/permissions/group/{id}"),
             )
             .await
     }
 
+    /// GET /api/permissions/group/{id}
+    ///
+    /// Typed-id variant of [`Self::get_group_by_id`].
+    pub async fn get_group_by_id_typed(&self, id: GroupId) -> Result<Value> {
+        self.get_group_by_id(id).await
+    }
+
     /// GET /api/permissions/membership
     pub async fn get_membership(&self) -> Result<Value> {
         let segments = ["api", "permissions", "membership"];
@@ -167,7 +514,7 @@ impl PermissionsService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/permissions/membership"),
             )
             .await
     }
@@ -181,7 +528,7 @@ impl PermissionsService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/permissions/membership"),
             )
             .await
     }
@@ -205,11 +552,18 @@ impl PermissionsService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/permissions/membership/{group-id}/clear"),
             )
             .await
     }
 
+    /// PUT /api/permissions/membership/{group-id}/clear
+    ///
+    /// Typed-id variant of [`Self::put_membership_by_group_id_clear`].
+    pub async fn put_membership_by_group_id_clear_typed(&self, group_id: GroupId) -> Result<Value> {
+        self.put_membership_by_group_id_clear(group_id).await
+    }
+
     /// DELETE /api/permissions/membership/{id}
     pub async fn delete_membership_by_id(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -220,11 +574,18 @@ impl PermissionsService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/permissions/membership/{id}"),
             )
             .await
     }
 
+    /// DELETE /api/permissions/membership/{id}
+    ///
+    /// Typed-id variant of [`Self::delete_membership_by_id`].
+    pub async fn delete_membership_by_id_typed(&self, id: MembershipId) -> Result<Value> {
+        self.delete_membership_by_id(id).await
+    }
+
     /// PUT /api/permissions/membership/{id}
     pub async fn put_membership_by_id(
         &self,
@@ -239,10 +600,21 @@ impl PermissionsService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/permissions/membership/{id}"),
             )
             .await
     }
+
+    /// PUT /api/permissions/membership/{id}
+    ///
+    /// Typed-id variant of [`Self::put_membership_by_id`].
+    pub async fn put_membership_by_id_typed(
+        &self,
+        id: MembershipId,
+        body: Option<&Value>,
+    ) -> Result<Value> {
+        self.put_membership_by_id(id, body).await
+    }
 }
 
 #[cfg(feature = "blocking")]
@@ -257,6 +629,205 @@ impl BlockingPermissionsService {
         Self { client }
     }
 
+    /// GET /api/permissions/graph
+    pub fn get_permissions_graph(&self) -> Result<PermissionsGraph> {
+        let segments = ["api", "permissions", "graph"];
+        self.client.request_json(
+            Method::GET,
+            &segments,
+            Option::<&()>::None,
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/permissions/graph"),
+        )
+    }
+
+    /// PUT /api/permissions/graph
+    ///
+    /// Send `changes` made against graph `revision`. Fails with [`Error::Conflict`] when
+    /// the graph has been edited since; see [`apply_permissions_changes`](Self::apply_permissions_changes).
+    pub fn put_permissions_changes(
+        &self,
+        revision: i64,
+        changes: &PermissionsChanges,
+    ) -> Result<PermissionsGraph> {
+        let segments = ["api", "permissions", "graph"];
+        self.client.request_json(
+            Method::PUT,
+            &segments,
+            Option::<&()>::None,
+            Some(&PermissionsGraphUpdate::new(revision, changes)),
+            RequestOptions::default().route("/api/permissions/graph"),
+        )
+    }
+
+    /// PUT /api/permissions/graph
+    ///
+    /// Send `changes` made against graph `revision`. If someone else edited the graph in
+    /// the meantime, refetch it, drop the changes it already contains and resend it on top
+    /// of the new revision. Gives up after three attempts.
+    pub fn apply_permissions_changes(
+        &self,
+        revision: i64,
+        changes: &PermissionsChanges,
+    ) -> Result<PermissionsGraph> {
+        apply_graph_changes_blocking(
+            revision,
+            changes,
+            |revision, changes| self.put_permissions_changes(revision, changes),
+            || self.get_permissions_graph(),
+        )
+    }
+
+    /// GET /api/permissions/group
+    pub fn list_groups(&self) -> Result<Vec<PermissionsGroup>> {
+        let segments = ["api", "permissions", "group"];
+        self.client.request_json(
+            Method::GET,
+            &segments,
+            Option::<&()>::None,
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/permissions/group"),
+        )
+    }
+
+    /// GET /api/permissions/group/{id}
+    ///
+    /// Unlike [`Self::list_groups`], includes the group's members.
+    pub fn get_permissions_group(&self, id: GroupId) -> Result<PermissionsGroup> {
+        let id = PathParam::from(id);
+        let segments = ["api", "permissions", "group", id.as_str()];
+        self.client.request_json(
+            Method::GET,
+            &segments,
+            Option::<&()>::None,
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/permissions/group/{id}"),
+        )
+    }
+
+    /// POST /api/permissions/group
+    pub fn create_group(&self, group: &GroupName) -> Result<PermissionsGroup> {
+        let segments = ["api", "permissions", "group"];
+        self.client.request_json(
+            Method::POST,
+            &segments,
+            Option::<&()>::None,
+            Some(group),
+            RequestOptions::default().route("/api/permissions/group"),
+        )
+    }
+
+    /// PUT /api/permissions/group/{group-id}
+    pub fn update_group(&self, group_id: GroupId, group: &GroupName) -> Result<PermissionsGroup> {
+        let group_id = PathParam::from(group_id);
+        let segments = ["api", "permissions", "group", group_id.as_str()];
+        self.client.request_json(
+            Method::PUT,
+            &segments,
+            Option::<&()>::None,
+            Some(group),
+            RequestOptions::default().route("/api/permissions/group/{group-id}"),
+        )
+    }
+
+    /// DELETE /api/permissions/group/{group-id}
+    pub fn delete_group(&self, group_id: GroupId) -> Result<()> {
+        let group_id = PathParam::from(group_id);
+        let segments = ["api", "permissions", "group", group_id.as_str()];
+        self.client
+            .request_json::<IgnoredAny, _, _>(
+                Method::DELETE,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/permissions/group/{group-id}"),
+            )
+            .map(|_| ())
+    }
+
+    /// GET /api/permissions/membership
+    ///
+    /// Memberships keyed by user.
+    pub fn list_memberships(&self) -> Result<BTreeMap<UserId, Vec<Membership>>> {
+        let segments = ["api", "permissions", "membership"];
+        self.client.request_json(
+            Method::GET,
+            &segments,
+            Option::<&()>::None,
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/permissions/membership"),
+        )
+    }
+
+    /// POST /api/permissions/membership
+    ///
+    /// Returns the group's members after the change.
+    pub fn create_membership(&self, membership: &CreateMembership) -> Result<Vec<GroupMember>> {
+        let segments = ["api", "permissions", "membership"];
+        self.client.request_json(
+            Method::POST,
+            &segments,
+            Option::<&()>::None,
+            Some(membership),
+            RequestOptions::default().route("/api/permissions/membership"),
+        )
+    }
+
+    /// PUT /api/permissions/membership/{id}
+    pub fn update_membership(
+        &self,
+        id: MembershipId,
+        is_group_manager: bool,
+    ) -> Result<Membership> {
+        let id = PathParam::from(id);
+        let segments = ["api", "permissions", "membership", id.as_str()];
+        self.client.request_json(
+            Method::PUT,
+            &segments,
+            Option::<&()>::None,
+            Some(&MembershipUpdate { is_group_manager }),
+            RequestOptions::default().route("/api/permissions/membership/{id}"),
+        )
+    }
+
+    /// DELETE /api/permissions/membership/{id}
+    pub fn delete_membership(&self, id: MembershipId) -> Result<()> {
+        let id = PathParam::from(id);
+        let segments = ["api", "permissions", "membership", id.as_str()];
+        self.client
+            .request_json::<IgnoredAny, _, _>(
+                Method::DELETE,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/permissions/membership/{id}"),
+            )
+            .map(|_| ())
+    }
+
+    /// PUT /api/permissions/membership/{group-id}/clear
+    ///
+    /// Removes every member from the group.
+    pub fn clear_group_memberships(&self, group_id: GroupId) -> Result<()> {
+        let group_id = PathParam::from(group_id);
+        let segments = [
+            "api",
+            "permissions",
+            "membership",
+            group_id.as_str(),
+            "clear",
+        ];
+        self.client
+            .request_json::<IgnoredAny, _, _>(
+                Method::PUT,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/permissions/membership/{group-id}/clear"),
+            )
+            .map(|_| ())
+    }
+
     /// GET /api/permissions/graph
     pub fn get_graph(&self) -> Result<Value> {
         let segments = ["api", "permissions", "graph"];
@@ -265,7 +836,7 @@ impl BlockingPermissionsService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/permissions/graph"),
         )
     }
 
@@ -277,7 +848,7 @@ impl BlockingPermissionsService {
             &segments,
             query,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/permissions/graph"),
         )
     }
 
@@ -290,10 +861,17 @@ impl BlockingPermissionsService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/permissions/graph/db/{db-id}"),
         )
     }
 
+    /// GET /api/permissions/graph/db/{db-id}
+    ///
+    /// Typed-id variant of [`Self::get_graph_db_by_db_id`].
+    pub fn get_graph_db_by_db_id_typed(&self, db_id: DatabaseId) -> Result<Value> {
+        self.get_graph_db_by_db_id(db_id)
+    }
+
     /// GET /api/permissions/graph/group/{group-id}
     pub fn get_graph_group_by_group_id(&self, group_id: impl Into<PathParam>) -> Result<Value> {
         let group_id = group_id.into();
@@ -303,10 +881,17 @@ impl BlockingPermissionsService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/permissions/graph/group/{group-id}"),
         )
     }
 
+    /// GET /api/permissions/graph/group/{group-id}
+    ///
+    /// Typed-id variant of [`Self::get_graph_group_by_group_id`].
+    pub fn get_graph_group_by_group_id_typed(&self, group_id: GroupId) -> Result<Value> {
+        self.get_graph_group_by_group_id(group_id)
+    }
+
     /// GET /api/permissions/group
     pub fn get_group(&self) -> Result<Value> {
         let segments = ["api", "permissions", "group"];
@@ -315,7 +900,7 @@ impl BlockingPermissionsService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/permissions/group"),
         )
     }
 
@@ -327,7 +912,7 @@ impl BlockingPermissionsService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/permissions/group"),
         )
     }
 
@@ -340,10 +925,17 @@ impl BlockingPermissionsService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/permissions/group/{group-id}"),
         )
     }
 
+    /// DELETE /api/permissions/group/{group-id}
+    ///
+    /// Typed-id variant of [`Self::delete_group_by_group_id`].
+    pub fn delete_group_by_group_id_typed(&self, group_id: GroupId) -> Result<Value> {
+        self.delete_group_by_group_id(group_id)
+    }
+
     /// PUT /api/permissions/group/{group-id}
     pub fn put_group_by_group_id(
         &self,
@@ -357,10 +949,21 @@ impl BlockingPermissionsService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/permissions/group/{group-id}"),
         )
     }
 
+    /// PUT /api/permissions/group/{group-id}
+    ///
+    /// Typed-id variant of [`Self::put_group_by_group_id`].
+    pub fn put_group_by_group_id_typed(
+        &self,
+        group_id: GroupId,
+        body: Option<&Value>,
+    ) -> Result<Value> {
+        self.put_group_by_group_id(group_id, body)
+    }
+
     /// GET /api/permissions/group/{id}
     pub fn get_group_by_id(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -370,10 +973,17 @@ impl BlockingPermissionsService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/permissions/group/{id}"),
         )
     }
 
+    /// GET /api/permissions/group/{id}
+    ///
+    /// Typed-id variant of [`Self::get_group_by_id`].
+    pub fn get_group_by_id_typed(&self, id: GroupId) -> Result<Value> {
+        self.get_group_by_id(id)
+    }
+
     /// GET /api/permissions/membership
     pub fn get_membership(&self) -> Result<Value> {
         let segments = ["api", "permissions", "membership"];
@@ -382,7 +992,7 @@ impl BlockingPermissionsService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/permissions/membership"),
         )
     }
 
@@ -394,7 +1004,7 @@ impl BlockingPermissionsService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/permissions/membership"),
         )
     }
 
@@ -416,10 +1026,17 @@ impl BlockingPermissionsService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/permissions/membership/{group-id}/clear"),
         )
     }
 
+    /// PUT /api/permissions/membership/{group-id}/clear
+    ///
+    /// Typed-id variant of [`Self::put_membership_by_group_id_clear`].
+    pub fn put_membership_by_group_id_clear_typed(&self, group_id: GroupId) -> Result<Value> {
+        self.put_membership_by_group_id_clear(group_id)
+    }
+
     /// DELETE /api/permissions/membership/{id}
     pub fn delete_membership_by_id(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -429,10 +1046,17 @@ impl BlockingPermissionsService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/permissions/membership/{id}"),
         )
     }
 
+    /// DELETE /api/permissions/membership/{id}
+    ///
+    /// Typed-id variant of [`Self::delete_membership_by_id`].
+    pub fn delete_membership_by_id_typed(&self, id: MembershipId) -> Result<Value> {
+        self.delete_membership_by_id(id)
+    }
+
     /// PUT /api/permissions/membership/{id}
     pub fn put_membership_by_id(
         &self,
@@ -446,7 +1070,18 @@ impl BlockingPermissionsService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/permissions/membership/{id}"),
         )
     }
+
+    /// PUT /api/permissions/membership/{id}
+    ///
+    /// Typed-id variant of [`Self::put_membership_by_id`].
+    pub fn put_membership_by_id_typed(
+        &self,
+        id: MembershipId,
+        body: Option<&Value>,
+    ) -> Result<Value> {
+        self.put_membership_by_id(id, body)
+    }
 }
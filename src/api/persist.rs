@@ -1,7 +1,16 @@
 use http::Method;
+use serde::de::IgnoredAny;
 use serde_json::Value;
 
-use crate::{Result, client::RequestOptions, types::path::PathParam};
+use crate::{
+    Result,
+    client::RequestOptions,
+    types::{
+        ids::{CardId, DatabaseId, PersistedInfoId},
+        path::PathParam,
+        schedule::{CronSchedule, Schedule},
+    },
+};
 
 #[cfg(feature = "blocking")]
 use crate::client::BlockingClient;
@@ -20,6 +29,26 @@ impl PersistService {
         Self { client }
     }
 
+    /// POST /api/persist/set-refresh-schedule
+    ///
+    /// How often persisted models are refreshed.
+    pub async fn set_refresh_schedule(&self, schedule: &Schedule) -> Result<()> {
+        let body = CronSchedule {
+            cron: schedule.to_cron()?,
+        };
+        let segments = ["api", "persist", "set-refresh-schedule"];
+        self.client
+            .request_json::<IgnoredAny, _, _>(
+                Method::POST,
+                &segments,
+                Option::<&()>::None,
+                Some(&body),
+                RequestOptions::default().route("/api/persist/set-refresh-schedule"),
+            )
+            .await
+            .map(|_| ())
+    }
+
     /// GET /api/persist/
     pub async fn get(&self) -> Result<Value> {
         let segments = ["api", "persist"];
@@ -29,7 +58,7 @@ impl PersistService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/persist/"),
             )
             .await
     }
@@ -44,11 +73,18 @@ impl PersistService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/persist/card/{card-id}"),
             )
             .await
     }
 
+    /// GET /api/persist/card/{card-id}
+    ///
+    /// Typed-id variant of [`Self::get_card_by_card_id`].
+    pub async fn get_card_by_card_id_typed(&self, card_id: CardId) -> Result<Value> {
+        self.get_card_by_card_id(card_id).await
+    }
+
     /// POST /api/persist/card/{card-id}/persist
     pub async fn post_card_by_card_id_persist(
         &self,
@@ -62,11 +98,18 @@ impl PersistService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/persist/card/{card-id}/persist"),
             )
             .await
     }
 
+    /// POST /api/persist/card/{card-id}/persist
+    ///
+    /// Typed-id variant of [`Self::post_card_by_card_id_persist`].
+    pub async fn post_card_by_card_id_persist_typed(&self, card_id: CardId) -> Result<Value> {
+        self.post_card_by_card_id_persist(card_id).await
+    }
+
     /// POST /api/persist/card/{card-id}/refresh
     pub async fn post_card_by_card_id_refresh(
         &self,
@@ -80,11 +123,18 @@ impl PersistService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/persist/card/{card-id}/refresh"),
             )
             .await
     }
 
+    /// POST /api/persist/card/{card-id}/refresh
+    ///
+    /// Typed-id variant of [`Self::post_card_by_card_id_refresh`].
+    pub async fn post_card_by_card_id_refresh_typed(&self, card_id: CardId) -> Result<Value> {
+        self.post_card_by_card_id_refresh(card_id).await
+    }
+
     /// POST /api/persist/card/{card-id}/unpersist
     pub async fn post_card_by_card_id_unpersist(
         &self,
@@ -98,11 +148,18 @@ impl PersistService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/persist/card/{card-id}/unpersist"),
             )
             .await
     }
 
+    /// POST /api/persist/card/{card-id}/unpersist
+    ///
+    /// Typed-id variant of [`Self::post_card_by_card_id_unpersist`].
+    pub async fn post_card_by_card_id_unpersist_typed(&self, card_id: CardId) -> Result<Value> {
+        self.post_card_by_card_id_unpersist(card_id).await
+    }
+
     /// POST /api/persist/database/{id}/persist
     pub async fn post_database_by_id_persist(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -113,11 +170,18 @@ impl PersistService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/persist/database/{id}/persist"),
             )
             .await
     }
 
+    /// POST /api/persist/database/{id}/persist
+    ///
+    /// Typed-id variant of [`Self::post_database_by_id_persist`].
+    pub async fn post_database_by_id_persist_typed(&self, id: DatabaseId) -> Result<Value> {
+        self.post_database_by_id_persist(id).await
+    }
+
     /// POST /api/persist/database/{id}/unpersist
     pub async fn post_database_by_id_unpersist(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -128,11 +192,18 @@ impl PersistService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/persist/database/{id}/unpersist"),
             )
             .await
     }
 
+    /// POST /api/persist/database/{id}/unpersist
+    ///
+    /// Typed-id variant of [`Self::post_database_by_id_unpersist`].
+    pub async fn post_database_by_id_unpersist_typed(&self, id: DatabaseId) -> Result<Value> {
+        self.post_database_by_id_unpersist(id).await
+    }
+
     /// POST /api/persist/disable
     pub async fn post_disable(&self) -> Result<Value> {
         let segments = ["api", "persist", "disable"];
@@ -142,7 +213,7 @@ impl PersistService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/persist/disable"),
             )
             .await
     }
@@ -156,7 +227,7 @@ impl PersistService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/persist/enable"),
             )
             .await
     }
@@ -170,7 +241,7 @@ impl PersistService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/persist/set-refresh-schedule"),
             )
             .await
     }
@@ -188,10 +259,20 @@ impl PersistService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/persist/{persisted-info-id}"),
             )
             .await
     }
+
+    /// GET /api/persist/{persisted-info-id}
+    ///
+    /// Typed-id variant of [`Self::get_by_persisted_info_id`].
+    pub async fn get_by_persisted_info_id_typed(
+        &self,
+        persisted_info_id: PersistedInfoId,
+    ) -> Result<Value> {
+        self.get_by_persisted_info_id(persisted_info_id).await
+    }
 }
 
 #[cfg(feature = "blocking")]
@@ -206,6 +287,25 @@ impl BlockingPersistService {
         Self { client }
     }
 
+    /// POST /api/persist/set-refresh-schedule
+    ///
+    /// How often persisted models are refreshed.
+    pub fn set_refresh_schedule(&self, schedule: &Schedule) -> Result<()> {
+        let body = CronSchedule {
+            cron: schedule.to_cron()?,
+        };
+        let segments = ["api", "persist", "set-refresh-schedule"];
+        self.client
+            .request_json::<IgnoredAny, _, _>(
+                Method::POST,
+                &segments,
+                Option::<&()>::None,
+                Some(&body),
+                RequestOptions::default().route("/api/persist/set-refresh-schedule"),
+            )
+            .map(|_| ())
+    }
+
     /// GET /api/persist/
     pub fn get(&self) -> Result<Value> {
         let segments = ["api", "persist"];
@@ -214,7 +314,7 @@ impl BlockingPersistService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/persist/"),
         )
     }
 
@@ -227,10 +327,17 @@ impl BlockingPersistService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/persist/card/{card-id}"),
         )
     }
 
+    /// GET /api/persist/card/{card-id}
+    ///
+    /// Typed-id variant of [`Self::get_card_by_card_id`].
+    pub fn get_card_by_card_id_typed(&self, card_id: CardId) -> Result<Value> {
+        self.get_card_by_card_id(card_id)
+    }
+
     /// POST /api/persist/card/{card-id}/persist
     pub fn post_card_by_card_id_persist(&self, card_id: impl Into<PathParam>) -> Result<Value> {
         let card_id = card_id.into();
@@ -240,10 +347,17 @@ impl BlockingPersistService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/persist/card/{card-id}/persist"),
         )
     }
 
+    /// POST /api/persist/card/{card-id}/persist
+    ///
+    /// Typed-id variant of [`Self::post_card_by_card_id_persist`].
+    pub fn post_card_by_card_id_persist_typed(&self, card_id: CardId) -> Result<Value> {
+        self.post_card_by_card_id_persist(card_id)
+    }
+
     /// POST /api/persist/card/{card-id}/refresh
     pub fn post_card_by_card_id_refresh(&self, card_id: impl Into<PathParam>) -> Result<Value> {
         let card_id = card_id.into();
@@ -253,10 +367,17 @@ impl BlockingPersistService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/persist/card/{card-id}/refresh"),
         )
     }
 
+    /// POST /api/persist/card/{card-id}/refresh
+    ///
+    /// Typed-id variant of [`Self::post_card_by_card_id_refresh`].
+    pub fn post_card_by_card_id_refresh_typed(&self, card_id: CardId) -> Result<Value> {
+        self.post_card_by_card_id_refresh(card_id)
+    }
+
     /// POST /api/persist/card/{card-id}/unpersist
     pub fn post_card_by_card_id_unpersist(&self, card_id: impl Into<PathParam>) -> Result<Value> {
         let card_id = card_id.into();
@@ -266,10 +387,17 @@ impl BlockingPersistService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/persist/card/{card-id}/unpersist"),
         )
     }
 
+    /// POST /api/persist/card/{card-id}/unpersist
+    ///
+    /// Typed-id variant of [`Self::post_card_by_card_id_unpersist`].
+    pub fn post_card_by_card_id_unpersist_typed(&self, card_id: CardId) -> Result<Value> {
+        self.post_card_by_card_id_unpersist(card_id)
+    }
+
     /// POST /api/persist/database/{id}/persist
     pub fn post_database_by_id_persist(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -279,10 +407,17 @@ impl BlockingPersistService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/persist/database/{id}/persist"),
         )
     }
 
+    /// POST /api/persist/database/{id}/persist
+    ///
+    /// Typed-id variant of [`Self::post_database_by_id_persist`].
+    pub fn post_database_by_id_persist_typed(&self, id: DatabaseId) -> Result<Value> {
+        self.post_database_by_id_persist(id)
+    }
+
     /// POST /api/persist/database/{id}/unpersist
     pub fn post_database_by_id_unpersist(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -292,10 +427,17 @@ impl BlockingPersistService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/persist/database/{id}/unpersist"),
         )
     }
 
+    /// POST /api/persist/database/{id}/unpersist
+    ///
+    /// Typed-id variant of [`Self::post_database_by_id_unpersist`].
+    pub fn post_database_by_id_unpersist_typed(&self, id: DatabaseId) -> Result<Value> {
+        self.post_database_by_id_unpersist(id)
+    }
+
     /// POST /api/persist/disable
     pub fn post_disable(&self) -> Result<Value> {
         let segments = ["api", "persist", "disable"];
@@ -304,7 +446,7 @@ impl BlockingPersistService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/persist/disable"),
         )
     }
 
@@ -316,7 +458,7 @@ impl BlockingPersistService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/persist/enable"),
         )
     }
 
@@ -328,7 +470,7 @@ impl BlockingPersistService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/persist/set-refresh-schedule"),
         )
     }
 
@@ -344,7 +486,17 @@ impl BlockingPersistService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/persist/{persisted-info-id}"),
         )
     }
+
+    /// GET /api/persist/{persisted-info-id}
+    ///
+    /// Typed-id variant of [`Self::get_by_persisted_info_id`].
+    pub fn get_by_persisted_info_id_typed(
+        &self,
+        persisted_info_id: PersistedInfoId,
+    ) -> Result<Value> {
+        self.get_by_persisted_info_id(persisted_info_id)
+    }
 }
@@ -29,7 +29,7 @@ impl PremiumFeaturesService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/premium-features/token/status"),
             )
             .await
     }
@@ -55,7 +55,7 @@ impl BlockingPremiumFeaturesService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/premium-features/token/status"),
         )
     }
 }
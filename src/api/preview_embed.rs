@@ -1,7 +1,14 @@
 use http::Method;
 use serde_json::Value;
 
-use crate::{Result, client::RequestOptions, types::path::PathParam};
+use crate::{
+    Result,
+    client::RequestOptions,
+    types::{
+        ids::{CardId, DashcardId},
+        path::PathParam,
+    },
+};
 
 #[cfg(feature = "blocking")]
 use crate::client::BlockingClient;
@@ -30,7 +37,7 @@ impl PreviewEmbedService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/preview_embed/card/{token}"),
             )
             .await
     }
@@ -59,7 +66,8 @@ impl PreviewEmbedService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default()
+                    .route("/api/preview_embed/card/{token}/params/{param-key}/remapping"),
             )
             .await
     }
@@ -74,7 +82,7 @@ impl PreviewEmbedService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/preview_embed/card/{token}/query"),
             )
             .await
     }
@@ -89,7 +97,7 @@ impl PreviewEmbedService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/preview_embed/dashboard/{token}"),
             )
             .await
     }
@@ -120,11 +128,30 @@ impl PreviewEmbedService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route(
+                    "/api/preview_embed/dashboard/{token}/dashcard/{dashcard-id}/card/{card-id}",
+                ),
             )
             .await
     }
 
+    /// GET /api/preview_embed/dashboard/{token}/dashcard/{dashcard-id}/card/{card-id}
+    ///
+    /// Typed-id variant of [`Self::get_dashboard_by_token_dashcard_by_dashcard_id_card_by_card_id`].
+    pub async fn get_dashboard_by_token_dashcard_by_dashcard_id_card_by_card_id_typed(
+        &self,
+        token: impl Into<PathParam>,
+        dashcard_id: DashcardId,
+        card_id: CardId,
+    ) -> Result<Value> {
+        self.get_dashboard_by_token_dashcard_by_dashcard_id_card_by_card_id(
+            token,
+            dashcard_id,
+            card_id,
+        )
+        .await
+    }
+
     /// GET /api/preview_embed/dashboard/{token}/params/{param-key}/remapping
     pub async fn get_dashboard_by_token_params_by_param_key_remapping(
         &self,
@@ -148,7 +175,8 @@ impl PreviewEmbedService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default()
+                    .route("/api/preview_embed/dashboard/{token}/params/{param-key}/remapping"),
             )
             .await
     }
@@ -179,7 +207,9 @@ impl PreviewEmbedService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route(
+                    "/api/preview_embed/dashboard/{token}/params/{param-key}/search/{prefix}",
+                ),
             )
             .await
     }
@@ -207,7 +237,8 @@ impl PreviewEmbedService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default()
+                    .route("/api/preview_embed/dashboard/{token}/params/{param-key}/values"),
             )
             .await
     }
@@ -232,7 +263,7 @@ impl PreviewEmbedService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/preview_embed/pivot/card/{token}/query"),
             )
             .await
     }
@@ -264,11 +295,28 @@ impl PreviewEmbedService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/preview_embed/pivot/dashboard/{token}/dashcard/{dashcard-id}/card/{card-id}"),
             )
             .await
     }
 
+    /// GET /api/preview_embed/pivot/dashboard/{token}/dashcard/{dashcard-id}/card/{card-id}
+    ///
+    /// Typed-id variant of [`Self::get_pivot_dashboard_by_token_dashcard_by_dashcard_id_card_by_card_id`].
+    pub async fn get_pivot_dashboard_by_token_dashcard_by_dashcard_id_card_by_card_id_typed(
+        &self,
+        token: impl Into<PathParam>,
+        dashcard_id: DashcardId,
+        card_id: CardId,
+    ) -> Result<Value> {
+        self.get_pivot_dashboard_by_token_dashcard_by_dashcard_id_card_by_card_id(
+            token,
+            dashcard_id,
+            card_id,
+        )
+        .await
+    }
+
     /// GET /api/preview_embed/tiles/card/{token}/{zoom}/{x}/{y}
     pub async fn get_tiles_card_by_token_by_zoom_by_x_by_y(
         &self,
@@ -298,7 +346,8 @@ impl PreviewEmbedService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default()
+                    .route("/api/preview_embed/tiles/card/{token}/{zoom}/{x}/{y}"),
             )
             .await
     }
@@ -340,10 +389,35 @@ impl PreviewEmbedService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/preview_embed/tiles/dashboard/{token}/dashcard/{dashcard-id}/card/{card-id}/{zoom}/{x}/{y}"),
             )
             .await
     }
+
+    /// GET /api/preview_embed/tiles/dashboard/{token}/dashcard/{dashcard-id}/card/{card-id}/{zoom}/{x}/{y}
+    ///
+    /// Typed-id variant of [`Self::get_tiles_dashboard_by_token_dashcard_by_dashcard_id_card_by_card_id_by_zoom_by_x_by_y`].
+    pub async fn get_tiles_dashboard_by_token_dashcard_by_dashcard_id_card_by_card_id_by_zoom_by_x_by_y_typed(
+        &self,
+        token: impl Into<PathParam>,
+        dashcard_id: DashcardId,
+        card_id: CardId,
+        zoom: impl Into<PathParam>,
+        x: impl Into<PathParam>,
+        y: impl Into<PathParam>,
+        query: Option<&Value>,
+    ) -> Result<Vec<u8>> {
+        self.get_tiles_dashboard_by_token_dashcard_by_dashcard_id_card_by_card_id_by_zoom_by_x_by_y(
+            token,
+            dashcard_id,
+            card_id,
+            zoom,
+            x,
+            y,
+            query,
+        )
+        .await
+    }
 }
 
 #[cfg(feature = "blocking")]
@@ -367,7 +441,7 @@ impl BlockingPreviewEmbedService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/preview_embed/card/{token}"),
         )
     }
 
@@ -394,7 +468,8 @@ impl BlockingPreviewEmbedService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default()
+                .route("/api/preview_embed/card/{token}/params/{param-key}/remapping"),
         )
     }
 
@@ -407,7 +482,7 @@ impl BlockingPreviewEmbedService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/preview_embed/card/{token}/query"),
         )
     }
 
@@ -420,7 +495,7 @@ impl BlockingPreviewEmbedService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/preview_embed/dashboard/{token}"),
         )
     }
 
@@ -449,7 +524,25 @@ impl BlockingPreviewEmbedService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route(
+                "/api/preview_embed/dashboard/{token}/dashcard/{dashcard-id}/card/{card-id}",
+            ),
+        )
+    }
+
+    /// GET /api/preview_embed/dashboard/{token}/dashcard/{dashcard-id}/card/{card-id}
+    ///
+    /// Typed-id variant of [`Self::get_dashboard_by_token_dashcard_by_dashcard_id_card_by_card_id`].
+    pub fn get_dashboard_by_token_dashcard_by_dashcard_id_card_by_card_id_typed(
+        &self,
+        token: impl Into<PathParam>,
+        dashcard_id: DashcardId,
+        card_id: CardId,
+    ) -> Result<Value> {
+        self.get_dashboard_by_token_dashcard_by_dashcard_id_card_by_card_id(
+            token,
+            dashcard_id,
+            card_id,
         )
     }
 
@@ -475,7 +568,8 @@ impl BlockingPreviewEmbedService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default()
+                .route("/api/preview_embed/dashboard/{token}/params/{param-key}/remapping"),
         )
     }
 
@@ -504,7 +598,8 @@ impl BlockingPreviewEmbedService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default()
+                .route("/api/preview_embed/dashboard/{token}/params/{param-key}/search/{prefix}"),
         )
     }
 
@@ -530,7 +625,8 @@ impl BlockingPreviewEmbedService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default()
+                .route("/api/preview_embed/dashboard/{token}/params/{param-key}/values"),
         )
     }
 
@@ -550,7 +646,7 @@ impl BlockingPreviewEmbedService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/preview_embed/pivot/card/{token}/query"),
         )
     }
 
@@ -580,7 +676,25 @@ impl BlockingPreviewEmbedService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route(
+                "/api/preview_embed/pivot/dashboard/{token}/dashcard/{dashcard-id}/card/{card-id}",
+            ),
+        )
+    }
+
+    /// GET /api/preview_embed/pivot/dashboard/{token}/dashcard/{dashcard-id}/card/{card-id}
+    ///
+    /// Typed-id variant of [`Self::get_pivot_dashboard_by_token_dashcard_by_dashcard_id_card_by_card_id`].
+    pub fn get_pivot_dashboard_by_token_dashcard_by_dashcard_id_card_by_card_id_typed(
+        &self,
+        token: impl Into<PathParam>,
+        dashcard_id: DashcardId,
+        card_id: CardId,
+    ) -> Result<Value> {
+        self.get_pivot_dashboard_by_token_dashcard_by_dashcard_id_card_by_card_id(
+            token,
+            dashcard_id,
+            card_id,
         )
     }
 
@@ -612,7 +726,7 @@ impl BlockingPreviewEmbedService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/preview_embed/tiles/card/{token}/{zoom}/{x}/{y}"),
         )
     }
 
@@ -652,7 +766,31 @@ impl BlockingPreviewEmbedService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/preview_embed/tiles/dashboard/{token}/dashcard/{dashcard-id}/card/{card-id}/{zoom}/{x}/{y}"),
+        )
+    }
+
+    /// GET /api/preview_embed/tiles/dashboard/{token}/dashcard/{dashcard-id}/card/{card-id}/{zoom}/{x}/{y}
+    ///
+    /// Typed-id variant of [`Self::get_tiles_dashboard_by_token_dashcard_by_dashcard_id_card_by_card_id_by_zoom_by_x_by_y`].
+    pub fn get_tiles_dashboard_by_token_dashcard_by_dashcard_id_card_by_card_id_by_zoom_by_x_by_y_typed(
+        &self,
+        token: impl Into<PathParam>,
+        dashcard_id: DashcardId,
+        card_id: CardId,
+        zoom: impl Into<PathParam>,
+        x: impl Into<PathParam>,
+        y: impl Into<PathParam>,
+        query: Option<&Value>,
+    ) -> Result<Vec<u8>> {
+        self.get_tiles_dashboard_by_token_dashcard_by_dashcard_id_card_by_card_id_by_zoom_by_x_by_y(
+            token,
+            dashcard_id,
+            card_id,
+            zoom,
+            x,
+            y,
+            query,
         )
     }
 }
@@ -29,7 +29,7 @@ impl ProductFeedbackService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/product-feedback/"),
             )
             .await
     }
@@ -55,7 +55,7 @@ impl BlockingProductFeedbackService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/product-feedback/"),
         )
     }
 }
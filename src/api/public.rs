@@ -1,7 +1,15 @@
 use http::Method;
 use serde_json::Value;
 
-use crate::{Result, client::RequestOptions, types::path::PathParam};
+use crate::{
+    Result,
+    client::RequestOptions,
+    types::{
+        action::{ActionParameters, ActionResult, ExecuteAction, PublicAction},
+        ids::{CardId, DashcardId},
+        path::PathParam,
+    },
+};
 
 #[cfg(feature = "blocking")]
 use crate::client::BlockingClient;
@@ -20,6 +28,45 @@ impl PublicService {
         Self { client }
     }
 
+    /// GET /api/public/action/{uuid}
+    pub async fn get_public_action(&self, uuid: impl Into<PathParam>) -> Result<PublicAction> {
+        let uuid = uuid.into();
+        let segments = ["api", "public", "action", uuid.as_str()];
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/public/action/{uuid}"),
+            )
+            .await
+    }
+
+    /// POST /api/public/action/{uuid}/execute
+    ///
+    /// `action` is the one fetched with [`Self::get_public_action`]; `parameters` are
+    /// validated against it before sending.
+    pub async fn execute_public_action(
+        &self,
+        uuid: impl Into<PathParam>,
+        action: &PublicAction,
+        parameters: &ActionParameters,
+    ) -> Result<ActionResult> {
+        action.validate(parameters)?;
+        let uuid = uuid.into();
+        let segments = ["api", "public", "action", uuid.as_str(), "execute"];
+        self.client
+            .request_json(
+                Method::POST,
+                &segments,
+                Option::<&()>::None,
+                Some(&ExecuteAction { parameters }),
+                RequestOptions::default().route("/api/public/action/{uuid}/execute"),
+            )
+            .await
+    }
+
     /// GET /api/public/action/{uuid}
     pub async fn get_action_by_uuid(&self, uuid: impl Into<PathParam>) -> Result<Value> {
         let uuid = uuid.into();
@@ -30,7 +77,7 @@ impl PublicService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/public/action/{uuid}"),
             )
             .await
     }
@@ -49,7 +96,7 @@ impl PublicService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/public/action/{uuid}/execute"),
             )
             .await
     }
@@ -64,7 +111,7 @@ impl PublicService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/public/card/{uuid}"),
             )
             .await
     }
@@ -93,7 +140,8 @@ impl PublicService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default()
+                    .route("/api/public/card/{uuid}/params/{param-key}/remapping"),
             )
             .await
     }
@@ -124,7 +172,8 @@ impl PublicService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default()
+                    .route("/api/public/card/{uuid}/params/{param-key}/search/{query}"),
             )
             .await
     }
@@ -152,7 +201,8 @@ impl PublicService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default()
+                    .route("/api/public/card/{uuid}/params/{param-key}/values"),
             )
             .await
     }
@@ -171,7 +221,7 @@ impl PublicService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/public/card/{uuid}/query"),
             )
             .await
     }
@@ -199,7 +249,7 @@ impl PublicService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/public/card/{uuid}/query/{export-format}"),
             )
             .await
     }
@@ -214,7 +264,7 @@ impl PublicService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/public/dashboard/{uuid}"),
             )
             .await
     }
@@ -246,11 +296,31 @@ impl PublicService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default()
+                    .route("/api/public/dashboard/{uuid}/dashcard/{dashcard-id}/card/{card-id}"),
             )
             .await
     }
 
+    /// GET /api/public/dashboard/{uuid}/dashcard/{dashcard-id}/card/{card-id}
+    ///
+    /// Typed-id variant of [`Self::get_dashboard_by_uuid_dashcard_by_dashcard_id_card_by_card_id`].
+    pub async fn get_dashboard_by_uuid_dashcard_by_dashcard_id_card_by_card_id_typed(
+        &self,
+        uuid: impl Into<PathParam>,
+        dashcard_id: DashcardId,
+        card_id: CardId,
+        query: Option<&Value>,
+    ) -> Result<Value> {
+        self.get_dashboard_by_uuid_dashcard_by_dashcard_id_card_by_card_id(
+            uuid,
+            dashcard_id,
+            card_id,
+            query,
+        )
+        .await
+    }
+
     /// POST /api/public/dashboard/{uuid}/dashcard/{dashcard-id}/card/{card-id}/{export-format}
     pub async fn post_dashboard_by_uuid_dashcard_by_dashcard_id_card_by_card_id_by_export_format(
         &self,
@@ -281,11 +351,32 @@ impl PublicService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/public/dashboard/{uuid}/dashcard/{dashcard-id}/card/{card-id}/{export-format}"),
             )
             .await
     }
 
+    /// POST /api/public/dashboard/{uuid}/dashcard/{dashcard-id}/card/{card-id}/{export-format}
+    ///
+    /// Typed-id variant of [`Self::post_dashboard_by_uuid_dashcard_by_dashcard_id_card_by_card_id_by_export_format`].
+    pub async fn post_dashboard_by_uuid_dashcard_by_dashcard_id_card_by_card_id_by_export_format_typed(
+        &self,
+        uuid: impl Into<PathParam>,
+        dashcard_id: DashcardId,
+        card_id: CardId,
+        export_format: impl Into<PathParam>,
+        body: Option<&Value>,
+    ) -> Result<Vec<u8>> {
+        self.post_dashboard_by_uuid_dashcard_by_dashcard_id_card_by_card_id_by_export_format(
+            uuid,
+            dashcard_id,
+            card_id,
+            export_format,
+            body,
+        )
+        .await
+    }
+
     /// GET /api/public/dashboard/{uuid}/dashcard/{dashcard-id}/execute
     pub async fn get_dashboard_by_uuid_dashcard_by_dashcard_id_execute(
         &self,
@@ -310,11 +401,25 @@ impl PublicService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default()
+                    .route("/api/public/dashboard/{uuid}/dashcard/{dashcard-id}/execute"),
             )
             .await
     }
 
+    /// GET /api/public/dashboard/{uuid}/dashcard/{dashcard-id}/execute
+    ///
+    /// Typed-id variant of [`Self::get_dashboard_by_uuid_dashcard_by_dashcard_id_execute`].
+    pub async fn get_dashboard_by_uuid_dashcard_by_dashcard_id_execute_typed(
+        &self,
+        uuid: impl Into<PathParam>,
+        dashcard_id: DashcardId,
+        query: Option<&Value>,
+    ) -> Result<Value> {
+        self.get_dashboard_by_uuid_dashcard_by_dashcard_id_execute(uuid, dashcard_id, query)
+            .await
+    }
+
     /// POST /api/public/dashboard/{uuid}/dashcard/{dashcard-id}/execute
     pub async fn post_dashboard_by_uuid_dashcard_by_dashcard_id_execute(
         &self,
@@ -339,11 +444,25 @@ impl PublicService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default()
+                    .route("/api/public/dashboard/{uuid}/dashcard/{dashcard-id}/execute"),
             )
             .await
     }
 
+    /// POST /api/public/dashboard/{uuid}/dashcard/{dashcard-id}/execute
+    ///
+    /// Typed-id variant of [`Self::post_dashboard_by_uuid_dashcard_by_dashcard_id_execute`].
+    pub async fn post_dashboard_by_uuid_dashcard_by_dashcard_id_execute_typed(
+        &self,
+        uuid: impl Into<PathParam>,
+        dashcard_id: DashcardId,
+        body: Option<&Value>,
+    ) -> Result<Value> {
+        self.post_dashboard_by_uuid_dashcard_by_dashcard_id_execute(uuid, dashcard_id, body)
+            .await
+    }
+
     /// GET /api/public/dashboard/{uuid}/params/{param-key}/remapping
     pub async fn get_dashboard_by_uuid_params_by_param_key_remapping(
         &self,
@@ -368,7 +487,8 @@ impl PublicService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default()
+                    .route("/api/public/dashboard/{uuid}/params/{param-key}/remapping"),
             )
             .await
     }
@@ -399,7 +519,8 @@ impl PublicService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default()
+                    .route("/api/public/dashboard/{uuid}/params/{param-key}/search/{query}"),
             )
             .await
     }
@@ -427,7 +548,8 @@ impl PublicService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default()
+                    .route("/api/public/dashboard/{uuid}/params/{param-key}/values"),
             )
             .await
     }
@@ -441,7 +563,7 @@ impl PublicService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/public/oembed"),
             )
             .await
     }
@@ -460,7 +582,7 @@ impl PublicService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/public/pivot/card/{uuid}/query"),
             )
             .await
     }
@@ -493,11 +615,32 @@ impl PublicService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route(
+                    "/api/public/pivot/dashboard/{uuid}/dashcard/{dashcard-id}/card/{card-id}",
+                ),
             )
             .await
     }
 
+    /// GET /api/public/pivot/dashboard/{uuid}/dashcard/{dashcard-id}/card/{card-id}
+    ///
+    /// Typed-id variant of [`Self::get_pivot_dashboard_by_uuid_dashcard_by_dashcard_id_card_by_card_id`].
+    pub async fn get_pivot_dashboard_by_uuid_dashcard_by_dashcard_id_card_by_card_id_typed(
+        &self,
+        uuid: impl Into<PathParam>,
+        dashcard_id: DashcardId,
+        card_id: CardId,
+        query: Option<&Value>,
+    ) -> Result<Value> {
+        self.get_pivot_dashboard_by_uuid_dashcard_by_dashcard_id_card_by_card_id(
+            uuid,
+            dashcard_id,
+            card_id,
+            query,
+        )
+        .await
+    }
+
     /// GET /api/public/tiles/card/{uuid}/{zoom}/{x}/{y}
     pub async fn get_tiles_card_by_uuid_by_zoom_by_x_by_y(
         &self,
@@ -527,7 +670,7 @@ impl PublicService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/public/tiles/card/{uuid}/{zoom}/{x}/{y}"),
             )
             .await
     }
@@ -569,10 +712,35 @@ impl PublicService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/public/tiles/dashboard/{uuid}/dashcard/{dashcard-id}/card/{card-id}/{zoom}/{x}/{y}"),
             )
             .await
     }
+
+    /// GET /api/public/tiles/dashboard/{uuid}/dashcard/{dashcard-id}/card/{card-id}/{zoom}/{x}/{y}
+    ///
+    /// Typed-id variant of [`Self::get_tiles_dashboard_by_uuid_dashcard_by_dashcard_id_card_by_card_id_by_zoom_by_x_by_y`].
+    pub async fn get_tiles_dashboard_by_uuid_dashcard_by_dashcard_id_card_by_card_id_by_zoom_by_x_by_y_typed(
+        &self,
+        uuid: impl Into<PathParam>,
+        dashcard_id: DashcardId,
+        card_id: CardId,
+        zoom: impl Into<PathParam>,
+        x: impl Into<PathParam>,
+        y: impl Into<PathParam>,
+        query: Option<&Value>,
+    ) -> Result<Vec<u8>> {
+        self.get_tiles_dashboard_by_uuid_dashcard_by_dashcard_id_card_by_card_id_by_zoom_by_x_by_y(
+            uuid,
+            dashcard_id,
+            card_id,
+            zoom,
+            x,
+            y,
+            query,
+        )
+        .await
+    }
 }
 
 #[cfg(feature = "blocking")]
@@ -587,6 +755,41 @@ impl BlockingPublicService {
         Self { client }
     }
 
+    /// GET /api/public/action/{uuid}
+    pub fn get_public_action(&self, uuid: impl Into<PathParam>) -> Result<PublicAction> {
+        let uuid = uuid.into();
+        let segments = ["api", "public", "action", uuid.as_str()];
+        self.client.request_json(
+            Method::GET,
+            &segments,
+            Option::<&()>::None,
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/public/action/{uuid}"),
+        )
+    }
+
+    /// POST /api/public/action/{uuid}/execute
+    ///
+    /// `action` is the one fetched with [`Self::get_public_action`]; `parameters` are
+    /// validated against it before sending.
+    pub fn execute_public_action(
+        &self,
+        uuid: impl Into<PathParam>,
+        action: &PublicAction,
+        parameters: &ActionParameters,
+    ) -> Result<ActionResult> {
+        action.validate(parameters)?;
+        let uuid = uuid.into();
+        let segments = ["api", "public", "action", uuid.as_str(), "execute"];
+        self.client.request_json(
+            Method::POST,
+            &segments,
+            Option::<&()>::None,
+            Some(&ExecuteAction { parameters }),
+            RequestOptions::default().route("/api/public/action/{uuid}/execute"),
+        )
+    }
+
     /// GET /api/public/action/{uuid}
     pub fn get_action_by_uuid(&self, uuid: impl Into<PathParam>) -> Result<Value> {
         let uuid = uuid.into();
@@ -596,7 +799,7 @@ impl BlockingPublicService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/public/action/{uuid}"),
         )
     }
 
@@ -613,7 +816,7 @@ impl BlockingPublicService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/public/action/{uuid}/execute"),
         )
     }
 
@@ -626,7 +829,7 @@ impl BlockingPublicService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/public/card/{uuid}"),
         )
     }
 
@@ -653,7 +856,7 @@ impl BlockingPublicService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/public/card/{uuid}/params/{param-key}/remapping"),
         )
     }
 
@@ -682,7 +885,8 @@ impl BlockingPublicService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default()
+                .route("/api/public/card/{uuid}/params/{param-key}/search/{query}"),
         )
     }
 
@@ -708,7 +912,7 @@ impl BlockingPublicService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/public/card/{uuid}/params/{param-key}/values"),
         )
     }
 
@@ -725,7 +929,7 @@ impl BlockingPublicService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/public/card/{uuid}/query"),
         )
     }
 
@@ -751,7 +955,7 @@ impl BlockingPublicService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/public/card/{uuid}/query/{export-format}"),
         )
     }
 
@@ -764,7 +968,7 @@ impl BlockingPublicService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/public/dashboard/{uuid}"),
         )
     }
 
@@ -794,7 +998,26 @@ impl BlockingPublicService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default()
+                .route("/api/public/dashboard/{uuid}/dashcard/{dashcard-id}/card/{card-id}"),
+        )
+    }
+
+    /// GET /api/public/dashboard/{uuid}/dashcard/{dashcard-id}/card/{card-id}
+    ///
+    /// Typed-id variant of [`Self::get_dashboard_by_uuid_dashcard_by_dashcard_id_card_by_card_id`].
+    pub fn get_dashboard_by_uuid_dashcard_by_dashcard_id_card_by_card_id_typed(
+        &self,
+        uuid: impl Into<PathParam>,
+        dashcard_id: DashcardId,
+        card_id: CardId,
+        query: Option<&Value>,
+    ) -> Result<Value> {
+        self.get_dashboard_by_uuid_dashcard_by_dashcard_id_card_by_card_id(
+            uuid,
+            dashcard_id,
+            card_id,
+            query,
         )
     }
 
@@ -827,7 +1050,27 @@ impl BlockingPublicService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/public/dashboard/{uuid}/dashcard/{dashcard-id}/card/{card-id}/{export-format}"),
+        )
+    }
+
+    /// POST /api/public/dashboard/{uuid}/dashcard/{dashcard-id}/card/{card-id}/{export-format}
+    ///
+    /// Typed-id variant of [`Self::post_dashboard_by_uuid_dashcard_by_dashcard_id_card_by_card_id_by_export_format`].
+    pub fn post_dashboard_by_uuid_dashcard_by_dashcard_id_card_by_card_id_by_export_format_typed(
+        &self,
+        uuid: impl Into<PathParam>,
+        dashcard_id: DashcardId,
+        card_id: CardId,
+        export_format: impl Into<PathParam>,
+        body: Option<&Value>,
+    ) -> Result<Vec<u8>> {
+        self.post_dashboard_by_uuid_dashcard_by_dashcard_id_card_by_card_id_by_export_format(
+            uuid,
+            dashcard_id,
+            card_id,
+            export_format,
+            body,
         )
     }
 
@@ -854,10 +1097,23 @@ impl BlockingPublicService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default()
+                .route("/api/public/dashboard/{uuid}/dashcard/{dashcard-id}/execute"),
         )
     }
 
+    /// GET /api/public/dashboard/{uuid}/dashcard/{dashcard-id}/execute
+    ///
+    /// Typed-id variant of [`Self::get_dashboard_by_uuid_dashcard_by_dashcard_id_execute`].
+    pub fn get_dashboard_by_uuid_dashcard_by_dashcard_id_execute_typed(
+        &self,
+        uuid: impl Into<PathParam>,
+        dashcard_id: DashcardId,
+        query: Option<&Value>,
+    ) -> Result<Value> {
+        self.get_dashboard_by_uuid_dashcard_by_dashcard_id_execute(uuid, dashcard_id, query)
+    }
+
     /// POST /api/public/dashboard/{uuid}/dashcard/{dashcard-id}/execute
     pub fn post_dashboard_by_uuid_dashcard_by_dashcard_id_execute(
         &self,
@@ -881,10 +1137,23 @@ impl BlockingPublicService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default()
+                .route("/api/public/dashboard/{uuid}/dashcard/{dashcard-id}/execute"),
         )
     }
 
+    /// POST /api/public/dashboard/{uuid}/dashcard/{dashcard-id}/execute
+    ///
+    /// Typed-id variant of [`Self::post_dashboard_by_uuid_dashcard_by_dashcard_id_execute`].
+    pub fn post_dashboard_by_uuid_dashcard_by_dashcard_id_execute_typed(
+        &self,
+        uuid: impl Into<PathParam>,
+        dashcard_id: DashcardId,
+        body: Option<&Value>,
+    ) -> Result<Value> {
+        self.post_dashboard_by_uuid_dashcard_by_dashcard_id_execute(uuid, dashcard_id, body)
+    }
+
     /// GET /api/public/dashboard/{uuid}/params/{param-key}/remapping
     pub fn get_dashboard_by_uuid_params_by_param_key_remapping(
         &self,
@@ -908,7 +1177,8 @@ impl BlockingPublicService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default()
+                .route("/api/public/dashboard/{uuid}/params/{param-key}/remapping"),
         )
     }
 
@@ -937,7 +1207,8 @@ impl BlockingPublicService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default()
+                .route("/api/public/dashboard/{uuid}/params/{param-key}/search/{query}"),
         )
     }
 
@@ -963,7 +1234,8 @@ impl BlockingPublicService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default()
+                .route("/api/public/dashboard/{uuid}/params/{param-key}/values"),
         )
     }
 
@@ -975,7 +1247,7 @@ impl BlockingPublicService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/public/oembed"),
         )
     }
 
@@ -992,7 +1264,7 @@ impl BlockingPublicService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/public/pivot/card/{uuid}/query"),
         )
     }
 
@@ -1023,7 +1295,26 @@ impl BlockingPublicService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default()
+                .route("/api/public/pivot/dashboard/{uuid}/dashcard/{dashcard-id}/card/{card-id}"),
+        )
+    }
+
+    /// GET /api/public/pivot/dashboard/{uuid}/dashcard/{dashcard-id}/card/{card-id}
+    ///
+    /// Typed-id variant of [`Self::get_pivot_dashboard_by_uuid_dashcard_by_dashcard_id_card_by_card_id`].
+    pub fn get_pivot_dashboard_by_uuid_dashcard_by_dashcard_id_card_by_card_id_typed(
+        &self,
+        uuid: impl Into<PathParam>,
+        dashcard_id: DashcardId,
+        card_id: CardId,
+        query: Option<&Value>,
+    ) -> Result<Value> {
+        self.get_pivot_dashboard_by_uuid_dashcard_by_dashcard_id_card_by_card_id(
+            uuid,
+            dashcard_id,
+            card_id,
+            query,
         )
     }
 
@@ -1055,7 +1346,7 @@ impl BlockingPublicService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/public/tiles/card/{uuid}/{zoom}/{x}/{y}"),
         )
     }
 
@@ -1095,7 +1386,31 @@ impl BlockingPublicService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/public/tiles/dashboard/{uuid}/dashcard/{dashcard-id}/card/{card-id}/{zoom}/{x}/{y}"),
+        )
+    }
+
+    /// GET /api/public/tiles/dashboard/{uuid}/dashcard/{dashcard-id}/card/{card-id}/{zoom}/{x}/{y}
+    ///
+    /// Typed-id variant of [`Self::get_tiles_dashboard_by_uuid_dashcard_by_dashcard_id_card_by_card_id_by_zoom_by_x_by_y`].
+    pub fn get_tiles_dashboard_by_uuid_dashcard_by_dashcard_id_card_by_card_id_by_zoom_by_x_by_y_typed(
+        &self,
+        uuid: impl Into<PathParam>,
+        dashcard_id: DashcardId,
+        card_id: CardId,
+        zoom: impl Into<PathParam>,
+        x: impl Into<PathParam>,
+        y: impl Into<PathParam>,
+        query: Option<&Value>,
+    ) -> Result<Vec<u8>> {
+        self.get_tiles_dashboard_by_uuid_dashcard_by_dashcard_id_card_by_card_id_by_zoom_by_x_by_y(
+            uuid,
+            dashcard_id,
+            card_id,
+            zoom,
+            x,
+            y,
+            query,
         )
     }
 }
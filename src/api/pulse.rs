@@ -1,7 +1,16 @@
 use http::Method;
+use serde::de::IgnoredAny;
 use serde_json::Value;
 
-use crate::{Result, client::RequestOptions, types::path::PathParam};
+use crate::{
+    Result,
+    client::RequestOptions,
+    types::{
+        ids::{CardId, DashboardId, PulseId},
+        path::PathParam,
+        pulse::{CreatePulse, Pulse, PulseListQuery, UpdatePulse},
+    },
+};
 
 #[cfg(feature = "blocking")]
 use crate::client::BlockingClient;
@@ -20,6 +29,81 @@ impl PulseService {
         Self { client }
     }
 
+    /// GET /api/pulse/
+    pub async fn list_pulses(&self, query: &PulseListQuery) -> Result<Vec<Pulse>> {
+        let segments = ["api", "pulse"];
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                Some(query),
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/pulse/"),
+            )
+            .await
+    }
+
+    /// GET /api/pulse/{id}
+    pub async fn get_pulse(&self, id: PulseId) -> Result<Pulse> {
+        let id = PathParam::from(id);
+        let segments = ["api", "pulse", id.as_str()];
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/pulse/{id}"),
+            )
+            .await
+    }
+
+    /// POST /api/pulse/
+    pub async fn create_pulse(&self, pulse: &CreatePulse) -> Result<Pulse> {
+        let segments = ["api", "pulse"];
+        self.client
+            .request_json(
+                Method::POST,
+                &segments,
+                Option::<&()>::None,
+                Some(pulse),
+                RequestOptions::default().route("/api/pulse/"),
+            )
+            .await
+    }
+
+    /// PUT /api/pulse/{id}
+    pub async fn update_pulse(&self, id: PulseId, update: &UpdatePulse) -> Result<Pulse> {
+        let id = PathParam::from(id);
+        let segments = ["api", "pulse", id.as_str()];
+        self.client
+            .request_json(
+                Method::PUT,
+                &segments,
+                Option::<&()>::None,
+                Some(update),
+                RequestOptions::default().route("/api/pulse/{id}"),
+            )
+            .await
+    }
+
+    /// POST /api/pulse/test
+    ///
+    /// Sends `pulse` once to its channels without saving it.
+    pub async fn test_pulse(&self, pulse: &CreatePulse) -> Result<()> {
+        let segments = ["api", "pulse", "test"];
+        self.client
+            .request_json::<IgnoredAny, _, _>(
+                Method::POST,
+                &segments,
+                Option::<&()>::None,
+                Some(pulse),
+                RequestOptions::default().route("/api/pulse/test"),
+            )
+            .await
+            .map(|_| ())
+    }
+
     /// GET /api/pulse/
     pub async fn get(&self, query: Option<&Value>) -> Result<Value> {
         let segments = ["api", "pulse"];
@@ -29,7 +113,7 @@ impl PulseService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/pulse/"),
             )
             .await
     }
@@ -43,7 +127,7 @@ impl PulseService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/pulse/"),
             )
             .await
     }
@@ -57,7 +141,7 @@ impl PulseService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/pulse/form_input"),
             )
             .await
     }
@@ -72,11 +156,18 @@ impl PulseService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/pulse/preview_card/{id}"),
             )
             .await
     }
 
+    /// GET /api/pulse/preview_card/{id}
+    ///
+    /// Typed-id variant of [`Self::get_preview_card_by_id`].
+    pub async fn get_preview_card_by_id_typed(&self, id: CardId) -> Result<Value> {
+        self.get_preview_card_by_id(id).await
+    }
+
     /// GET /api/pulse/preview_card_info/{id}
     pub async fn get_preview_card_info_by_id(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -87,11 +178,18 @@ impl PulseService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/pulse/preview_card_info/{id}"),
             )
             .await
     }
 
+    /// GET /api/pulse/preview_card_info/{id}
+    ///
+    /// Typed-id variant of [`Self::get_preview_card_info_by_id`].
+    pub async fn get_preview_card_info_by_id_typed(&self, id: CardId) -> Result<Value> {
+        self.get_preview_card_info_by_id(id).await
+    }
+
     /// GET /api/pulse/preview_card_png/{id}
     pub async fn get_preview_card_png_by_id(
         &self,
@@ -106,11 +204,22 @@ impl PulseService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/pulse/preview_card_png/{id}"),
             )
             .await
     }
 
+    /// GET /api/pulse/preview_card_png/{id}
+    ///
+    /// Typed-id variant of [`Self::get_preview_card_png_by_id`].
+    pub async fn get_preview_card_png_by_id_typed(
+        &self,
+        id: CardId,
+        query: Option<&Value>,
+    ) -> Result<Value> {
+        self.get_preview_card_png_by_id(id, query).await
+    }
+
     /// GET /api/pulse/preview_dashboard/{id}
     pub async fn get_preview_dashboard_by_id(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -121,11 +230,18 @@ impl PulseService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/pulse/preview_dashboard/{id}"),
             )
             .await
     }
 
+    /// GET /api/pulse/preview_dashboard/{id}
+    ///
+    /// Typed-id variant of [`Self::get_preview_dashboard_by_id`].
+    pub async fn get_preview_dashboard_by_id_typed(&self, id: DashboardId) -> Result<Value> {
+        self.get_preview_dashboard_by_id(id).await
+    }
+
     /// POST /api/pulse/test
     pub async fn post_test(&self, body: Option<&Value>) -> Result<Value> {
         let segments = ["api", "pulse", "test"];
@@ -135,7 +251,7 @@ impl PulseService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/pulse/test"),
             )
             .await
     }
@@ -150,11 +266,18 @@ impl PulseService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/pulse/{id}"),
             )
             .await
     }
 
+    /// GET /api/pulse/{id}
+    ///
+    /// Typed-id variant of [`Self::get_by_id`].
+    pub async fn get_by_id_typed(&self, id: PulseId) -> Result<Value> {
+        self.get_by_id(id).await
+    }
+
     /// PUT /api/pulse/{id}
     pub async fn put_by_id(&self, id: impl Into<PathParam>, body: Option<&Value>) -> Result<Value> {
         let id = id.into();
@@ -165,11 +288,18 @@ impl PulseService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/pulse/{id}"),
             )
             .await
     }
 
+    /// PUT /api/pulse/{id}
+    ///
+    /// Typed-id variant of [`Self::put_by_id`].
+    pub async fn put_by_id_typed(&self, id: PulseId, body: Option<&Value>) -> Result<Value> {
+        self.put_by_id(id, body).await
+    }
+
     /// DELETE /api/pulse/{id}/subscription
     pub async fn delete_by_id_subscription(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -180,10 +310,17 @@ impl PulseService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/pulse/{id}/subscription"),
             )
             .await
     }
+
+    /// DELETE /api/pulse/{id}/subscription
+    ///
+    /// Typed-id variant of [`Self::delete_by_id_subscription`].
+    pub async fn delete_by_id_subscription_typed(&self, id: PulseId) -> Result<Value> {
+        self.delete_by_id_subscription(id).await
+    }
 }
 
 #[cfg(feature = "blocking")]
@@ -198,6 +335,72 @@ impl BlockingPulseService {
         Self { client }
     }
 
+    /// GET /api/pulse/
+    pub fn list_pulses(&self, query: &PulseListQuery) -> Result<Vec<Pulse>> {
+        let segments = ["api", "pulse"];
+        self.client.request_json(
+            Method::GET,
+            &segments,
+            Some(query),
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/pulse/"),
+        )
+    }
+
+    /// GET /api/pulse/{id}
+    pub fn get_pulse(&self, id: PulseId) -> Result<Pulse> {
+        let id = PathParam::from(id);
+        let segments = ["api", "pulse", id.as_str()];
+        self.client.request_json(
+            Method::GET,
+            &segments,
+            Option::<&()>::None,
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/pulse/{id}"),
+        )
+    }
+
+    /// POST /api/pulse/
+    pub fn create_pulse(&self, pulse: &CreatePulse) -> Result<Pulse> {
+        let segments = ["api", "pulse"];
+        self.client.request_json(
+            Method::POST,
+            &segments,
+            Option::<&()>::None,
+            Some(pulse),
+            RequestOptions::default().route("/api/pulse/"),
+        )
+    }
+
+    /// PUT /api/pulse/{id}
+    pub fn update_pulse(&self, id: PulseId, update: &UpdatePulse) -> Result<Pulse> {
+        let id = PathParam::from(id);
+        let segments = ["api", "pulse", id.as_str()];
+        self.client.request_json(
+            Method::PUT,
+            &segments,
+            Option::<&()>::None,
+            Some(update),
+            RequestOptions::default().route("/api/pulse/{id}"),
+        )
+    }
+
+    /// POST /api/pulse/test
+    ///
+    /// Sends `pulse` once to its channels without saving it.
+    pub fn test_pulse(&self, pulse: &CreatePulse) -> Result<()> {
+        let segments = ["api", "pulse", "test"];
+        self.client
+            .request_json::<IgnoredAny, _, _>(
+                Method::POST,
+                &segments,
+                Option::<&()>::None,
+                Some(pulse),
+                RequestOptions::default().route("/api/pulse/test"),
+            )
+            .map(|_| ())
+    }
+
     /// GET /api/pulse/
     pub fn get(&self, query: Option<&Value>) -> Result<Value> {
         let segments = ["api", "pulse"];
@@ -206,7 +409,7 @@ impl BlockingPulseService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/pulse/"),
         )
     }
 
@@ -218,7 +421,7 @@ impl BlockingPulseService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/pulse/"),
         )
     }
 
@@ -230,7 +433,7 @@ impl BlockingPulseService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/pulse/form_input"),
         )
     }
 
@@ -243,10 +446,17 @@ impl BlockingPulseService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/pulse/preview_card/{id}"),
         )
     }
 
+    /// GET /api/pulse/preview_card/{id}
+    ///
+    /// Typed-id variant of [`Self::get_preview_card_by_id`].
+    pub fn get_preview_card_by_id_typed(&self, id: CardId) -> Result<Value> {
+        self.get_preview_card_by_id(id)
+    }
+
     /// GET /api/pulse/preview_card_info/{id}
     pub fn get_preview_card_info_by_id(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -256,10 +466,17 @@ impl BlockingPulseService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/pulse/preview_card_info/{id}"),
         )
     }
 
+    /// GET /api/pulse/preview_card_info/{id}
+    ///
+    /// Typed-id variant of [`Self::get_preview_card_info_by_id`].
+    pub fn get_preview_card_info_by_id_typed(&self, id: CardId) -> Result<Value> {
+        self.get_preview_card_info_by_id(id)
+    }
+
     /// GET /api/pulse/preview_card_png/{id}
     pub fn get_preview_card_png_by_id(
         &self,
@@ -273,10 +490,21 @@ impl BlockingPulseService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/pulse/preview_card_png/{id}"),
         )
     }
 
+    /// GET /api/pulse/preview_card_png/{id}
+    ///
+    /// Typed-id variant of [`Self::get_preview_card_png_by_id`].
+    pub fn get_preview_card_png_by_id_typed(
+        &self,
+        id: CardId,
+        query: Option<&Value>,
+    ) -> Result<Value> {
+        self.get_preview_card_png_by_id(id, query)
+    }
+
     /// GET /api/pulse/preview_dashboard/{id}
     pub fn get_preview_dashboard_by_id(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -286,10 +514,17 @@ impl BlockingPulseService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/pulse/preview_dashboard/{id}"),
         )
     }
 
+    /// GET /api/pulse/preview_dashboard/{id}
+    ///
+    /// Typed-id variant of [`Self::get_preview_dashboard_by_id`].
+    pub fn get_preview_dashboard_by_id_typed(&self, id: DashboardId) -> Result<Value> {
+        self.get_preview_dashboard_by_id(id)
+    }
+
     /// POST /api/pulse/test
     pub fn post_test(&self, body: Option<&Value>) -> Result<Value> {
         let segments = ["api", "pulse", "test"];
@@ -298,7 +533,7 @@ impl BlockingPulseService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/pulse/test"),
         )
     }
 
@@ -311,10 +546,17 @@ impl BlockingPulseService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/pulse/{id}"),
         )
     }
 
+    /// GET /api/pulse/{id}
+    ///
+    /// Typed-id variant of [`Self::get_by_id`].
+    pub fn get_by_id_typed(&self, id: PulseId) -> Result<Value> {
+        self.get_by_id(id)
+    }
+
     /// PUT /api/pulse/{id}
     pub fn put_by_id(&self, id: impl Into<PathParam>, body: Option<&Value>) -> Result<Value> {
         let id = id.into();
@@ -324,10 +566,17 @@ impl BlockingPulseService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/pulse/{id}"),
         )
     }
 
+    /// PUT /api/pulse/{id}
+    ///
+    /// Typed-id variant of [`Self::put_by_id`].
+    pub fn put_by_id_typed(&self, id: PulseId, body: Option<&Value>) -> Result<Value> {
+        self.put_by_id(id, body)
+    }
+
     /// DELETE /api/pulse/{id}/subscription
     pub fn delete_by_id_subscription(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -337,7 +586,14 @@ impl BlockingPulseService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/pulse/{id}/subscription"),
         )
     }
+
+    /// DELETE /api/pulse/{id}/subscription
+    ///
+    /// Typed-id variant of [`Self::delete_by_id_subscription`].
+    pub fn delete_by_id_subscription_typed(&self, id: PulseId) -> Result<Value> {
+        self.delete_by_id_subscription(id)
+    }
 }
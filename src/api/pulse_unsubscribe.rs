@@ -29,7 +29,7 @@ impl PulseUnsubscribeService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/pulse/unsubscribe/"),
             )
             .await
     }
@@ -43,7 +43,7 @@ impl PulseUnsubscribeService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/pulse/unsubscribe/undo"),
             )
             .await
     }
@@ -69,7 +69,7 @@ impl BlockingPulseUnsubscribeService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/pulse/unsubscribe/"),
         )
     }
 
@@ -81,7 +81,7 @@ impl BlockingPulseUnsubscribeService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/pulse/unsubscribe/undo"),
         )
     }
 }
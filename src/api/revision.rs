@@ -1,7 +1,16 @@
 use http::Method;
+use serde::de::{DeserializeOwned, IgnoredAny};
 use serde_json::Value;
 
-use crate::{Result, client::RequestOptions, types::path::PathParam};
+use crate::{
+    Result,
+    client::RequestOptions,
+    types::{
+        ids::RevisionId,
+        path::PathParam,
+        revision::{RevertRevision, RevertedEntity, Revision, RevisionEntity},
+    },
+};
 
 #[cfg(feature = "blocking")]
 use crate::client::BlockingClient;
@@ -20,6 +29,73 @@ impl RevisionService {
         Self { client }
     }
 
+    /// GET /api/revision/{entity}/{id}
+    pub async fn list_revisions(&self, entity: impl Into<RevisionEntity>) -> Result<Vec<Revision>> {
+        let entity = entity.into();
+        let id = PathParam::from(entity.id());
+        let segments = ["api", "revision", entity.kind(), id.as_str()];
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/revision/{entity}/{id}"),
+            )
+            .await
+    }
+
+    /// POST /api/revision/revert
+    ///
+    /// Reverts `entity` to `revision`, then fetches and returns the restored entity. The
+    /// revert itself is recorded as a new revision with `is_reversion` set.
+    pub async fn revert_to(
+        &self,
+        entity: impl Into<RevisionEntity>,
+        revision: RevisionId,
+    ) -> Result<RevertedEntity> {
+        let entity = entity.into();
+        let segments = ["api", "revision", "revert"];
+        let body = RevertRevision {
+            entity: entity.kind(),
+            id: entity.id(),
+            revision_id: revision,
+        };
+        self.client
+            .request_json::<IgnoredAny, _, _>(
+                Method::POST,
+                &segments,
+                Option::<&()>::None,
+                Some(&body),
+                RequestOptions::default().route("/api/revision/revert"),
+            )
+            .await?;
+        Ok(match entity {
+            RevisionEntity::Card(_) => RevertedEntity::Card(self.get_entity(entity).await?),
+            RevisionEntity::Dashboard(_) => {
+                RevertedEntity::Dashboard(self.get_entity(entity).await?)
+            }
+            RevisionEntity::Segment(_) => RevertedEntity::Segment(self.get_entity(entity).await?),
+            RevisionEntity::Document(_) => RevertedEntity::Document(self.get_entity(entity).await?),
+        })
+    }
+
+    async fn get_entity<T: DeserializeOwned>(&self, entity: RevisionEntity) -> Result<T> {
+        let (prefix, route) = entity.endpoint();
+        let id = PathParam::from(entity.id());
+        let mut segments = prefix.to_vec();
+        segments.push(id.as_str());
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route(route),
+            )
+            .await
+    }
+
     /// GET /api/revision/
     pub async fn get(&self, query: Option<&Value>) -> Result<Value> {
         let segments = ["api", "revision"];
@@ -29,7 +105,7 @@ impl RevisionService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/revision/"),
             )
             .await
     }
@@ -43,7 +119,7 @@ impl RevisionService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/revision/revert"),
             )
             .await
     }
@@ -63,7 +139,7 @@ impl RevisionService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/revision/{entity}/{id}"),
             )
             .await
     }
@@ -81,6 +157,65 @@ impl BlockingRevisionService {
         Self { client }
     }
 
+    /// GET /api/revision/{entity}/{id}
+    pub fn list_revisions(&self, entity: impl Into<RevisionEntity>) -> Result<Vec<Revision>> {
+        let entity = entity.into();
+        let id = PathParam::from(entity.id());
+        let segments = ["api", "revision", entity.kind(), id.as_str()];
+        self.client.request_json(
+            Method::GET,
+            &segments,
+            Option::<&()>::None,
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/revision/{entity}/{id}"),
+        )
+    }
+
+    /// POST /api/revision/revert
+    ///
+    /// Reverts `entity` to `revision`, then fetches and returns the restored entity. The
+    /// revert itself is recorded as a new revision with `is_reversion` set.
+    pub fn revert_to(
+        &self,
+        entity: impl Into<RevisionEntity>,
+        revision: RevisionId,
+    ) -> Result<RevertedEntity> {
+        let entity = entity.into();
+        let segments = ["api", "revision", "revert"];
+        let body = RevertRevision {
+            entity: entity.kind(),
+            id: entity.id(),
+            revision_id: revision,
+        };
+        self.client.request_json::<IgnoredAny, _, _>(
+            Method::POST,
+            &segments,
+            Option::<&()>::None,
+            Some(&body),
+            RequestOptions::default().route("/api/revision/revert"),
+        )?;
+        Ok(match entity {
+            RevisionEntity::Card(_) => RevertedEntity::Card(self.get_entity(entity)?),
+            RevisionEntity::Dashboard(_) => RevertedEntity::Dashboard(self.get_entity(entity)?),
+            RevisionEntity::Segment(_) => RevertedEntity::Segment(self.get_entity(entity)?),
+            RevisionEntity::Document(_) => RevertedEntity::Document(self.get_entity(entity)?),
+        })
+    }
+
+    fn get_entity<T: DeserializeOwned>(&self, entity: RevisionEntity) -> Result<T> {
+        let (prefix, route) = entity.endpoint();
+        let id = PathParam::from(entity.id());
+        let mut segments = prefix.to_vec();
+        segments.push(id.as_str());
+        self.client.request_json(
+            Method::GET,
+            &segments,
+            Option::<&()>::None,
+            Option::<&()>::None,
+            RequestOptions::default().route(route),
+        )
+    }
+
     /// GET /api/revision/
     pub fn get(&self, query: Option<&Value>) -> Result<Value> {
         let segments = ["api", "revision"];
@@ -89,7 +224,7 @@ impl BlockingRevisionService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/revision/"),
         )
     }
 
@@ -101,7 +236,7 @@ impl BlockingRevisionService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/revision/revert"),
         )
     }
 
@@ -119,7 +254,7 @@ impl BlockingRevisionService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/revision/{entity}/{id}"),
         )
     }
 }
@@ -1,7 +1,11 @@
 use http::Method;
 use serde_json::Value;
 
-use crate::{Result, client::RequestOptions};
+use crate::{
+    Result,
+    client::RequestOptions,
+    types::search::{SearchQuery, SearchResults},
+};
 
 #[cfg(feature = "blocking")]
 use crate::client::BlockingClient;
@@ -20,6 +24,20 @@ impl SearchService {
         Self { client }
     }
 
+    /// GET /api/search/
+    pub async fn search(&self, query: &SearchQuery) -> Result<SearchResults> {
+        let segments = ["api", "search"];
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                Some(query),
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/search/"),
+            )
+            .await
+    }
+
     /// GET /api/search/
     pub async fn get(&self, query: Option<&Value>) -> Result<Value> {
         let segments = ["api", "search"];
@@ -29,7 +47,7 @@ impl SearchService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/search/"),
             )
             .await
     }
@@ -43,7 +61,7 @@ impl SearchService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/search/force-reindex"),
             )
             .await
     }
@@ -57,7 +75,7 @@ impl SearchService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/search/re-init"),
             )
             .await
     }
@@ -71,7 +89,7 @@ impl SearchService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/search/weights"),
             )
             .await
     }
@@ -85,7 +103,7 @@ impl SearchService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/search/weights"),
             )
             .await
     }
@@ -103,6 +121,18 @@ impl BlockingSearchService {
         Self { client }
     }
 
+    /// GET /api/search/
+    pub fn search(&self, query: &SearchQuery) -> Result<SearchResults> {
+        let segments = ["api", "search"];
+        self.client.request_json(
+            Method::GET,
+            &segments,
+            Some(query),
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/search/"),
+        )
+    }
+
     /// GET /api/search/
     pub fn get(&self, query: Option<&Value>) -> Result<Value> {
         let segments = ["api", "search"];
@@ -111,7 +141,7 @@ impl BlockingSearchService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/search/"),
         )
     }
 
@@ -123,7 +153,7 @@ impl BlockingSearchService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/search/force-reindex"),
         )
     }
 
@@ -135,7 +165,7 @@ impl BlockingSearchService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/search/re-init"),
         )
     }
 
@@ -147,7 +177,7 @@ impl BlockingSearchService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/search/weights"),
         )
     }
 
@@ -159,7 +189,7 @@ impl BlockingSearchService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/search/weights"),
         )
     }
 }
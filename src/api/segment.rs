@@ -1,7 +1,18 @@
 use http::Method;
+use serde::de::IgnoredAny;
 use serde_json::Value;
 
-use crate::{Result, client::RequestOptions, types::path::PathParam};
+use crate::{
+    Result,
+    client::RequestOptions,
+    types::{
+        card::Card,
+        ids::SegmentId,
+        mbql::references_segment,
+        path::PathParam,
+        segment::{ArchiveSegment, CreateSegment, Segment, UpdateSegment},
+    },
+};
 
 #[cfg(feature = "blocking")]
 use crate::client::BlockingClient;
@@ -20,6 +31,102 @@ impl SegmentService {
         Self { client }
     }
 
+    /// GET /api/segment/
+    pub async fn list_segments(&self) -> Result<Vec<Segment>> {
+        let segments = ["api", "segment"];
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/segment/"),
+            )
+            .await
+    }
+
+    /// GET /api/segment/{id}
+    pub async fn get_segment(&self, id: SegmentId) -> Result<Segment> {
+        let id = PathParam::from(id);
+        let segments = ["api", "segment", id.as_str()];
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/segment/{id}"),
+            )
+            .await
+    }
+
+    /// POST /api/segment/
+    pub async fn create_segment(&self, segment: &CreateSegment) -> Result<Segment> {
+        let segments = ["api", "segment"];
+        self.client
+            .request_json(
+                Method::POST,
+                &segments,
+                Option::<&()>::None,
+                Some(segment),
+                RequestOptions::default().route("/api/segment/"),
+            )
+            .await
+    }
+
+    /// PUT /api/segment/{id}
+    pub async fn update_segment(&self, id: SegmentId, update: &UpdateSegment) -> Result<Segment> {
+        let id = PathParam::from(id);
+        let segments = ["api", "segment", id.as_str()];
+        self.client
+            .request_json(
+                Method::PUT,
+                &segments,
+                Option::<&()>::None,
+                Some(update),
+                RequestOptions::default().route("/api/segment/{id}"),
+            )
+            .await
+    }
+
+    /// DELETE /api/segment/{id}
+    pub async fn archive_segment(&self, id: SegmentId, revision_message: &str) -> Result<()> {
+        let id = PathParam::from(id);
+        let segments = ["api", "segment", id.as_str()];
+        self.client
+            .request_json::<IgnoredAny, _, _>(
+                Method::DELETE,
+                &segments,
+                Some(&ArchiveSegment { revision_message }),
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/segment/{id}"),
+            )
+            .await
+            .map(|_| ())
+    }
+
+    /// GET /api/card/
+    ///
+    /// Non-archived cards whose query filters on segment `id`; see
+    /// [`Segment::is_referenced_by`].
+    pub async fn dependent_cards(&self, id: SegmentId) -> Result<Vec<Card>> {
+        let segments = ["api", "card"];
+        let cards: Vec<Card> = self
+            .client
+            .request_json(
+                Method::GET,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/card/"),
+            )
+            .await?;
+        Ok(cards
+            .into_iter()
+            .filter(|card| references_segment(&card.dataset_query, id))
+            .collect())
+    }
+
     /// GET /api/segment/
     pub async fn get(&self) -> Result<Value> {
         let segments = ["api", "segment"];
@@ -29,7 +136,7 @@ impl SegmentService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/segment/"),
             )
             .await
     }
@@ -43,7 +150,7 @@ impl SegmentService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/segment/"),
             )
             .await
     }
@@ -62,11 +169,18 @@ impl SegmentService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/segment/{id}"),
             )
             .await
     }
 
+    /// DELETE /api/segment/{id}
+    ///
+    /// Typed-id variant of [`Self::delete_by_id`].
+    pub async fn delete_by_id_typed(&self, id: SegmentId, query: Option<&Value>) -> Result<Value> {
+        self.delete_by_id(id, query).await
+    }
+
     /// GET /api/segment/{id}
     pub async fn get_by_id(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -77,11 +191,18 @@ impl SegmentService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/segment/{id}"),
             )
             .await
     }
 
+    /// GET /api/segment/{id}
+    ///
+    /// Typed-id variant of [`Self::get_by_id`].
+    pub async fn get_by_id_typed(&self, id: SegmentId) -> Result<Value> {
+        self.get_by_id(id).await
+    }
+
     /// PUT /api/segment/{id}
     pub async fn put_by_id(&self, id: impl Into<PathParam>, body: Option<&Value>) -> Result<Value> {
         let id = id.into();
@@ -92,11 +213,18 @@ impl SegmentService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/segment/{id}"),
             )
             .await
     }
 
+    /// PUT /api/segment/{id}
+    ///
+    /// Typed-id variant of [`Self::put_by_id`].
+    pub async fn put_by_id_typed(&self, id: SegmentId, body: Option<&Value>) -> Result<Value> {
+        self.put_by_id(id, body).await
+    }
+
     /// GET /api/segment/{id}/related
     pub async fn get_by_id_related(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -107,10 +235,17 @@ impl SegmentService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/segment/{id}/related"),
             )
             .await
     }
+
+    /// GET /api/segment/{id}/related
+    ///
+    /// Typed-id variant of [`Self::get_by_id_related`].
+    pub async fn get_by_id_related_typed(&self, id: SegmentId) -> Result<Value> {
+        self.get_by_id_related(id).await
+    }
 }
 
 #[cfg(feature = "blocking")]
@@ -125,6 +260,90 @@ impl BlockingSegmentService {
         Self { client }
     }
 
+    /// GET /api/segment/
+    pub fn list_segments(&self) -> Result<Vec<Segment>> {
+        let segments = ["api", "segment"];
+        self.client.request_json(
+            Method::GET,
+            &segments,
+            Option::<&()>::None,
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/segment/"),
+        )
+    }
+
+    /// GET /api/segment/{id}
+    pub fn get_segment(&self, id: SegmentId) -> Result<Segment> {
+        let id = PathParam::from(id);
+        let segments = ["api", "segment", id.as_str()];
+        self.client.request_json(
+            Method::GET,
+            &segments,
+            Option::<&()>::None,
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/segment/{id}"),
+        )
+    }
+
+    /// POST /api/segment/
+    pub fn create_segment(&self, segment: &CreateSegment) -> Result<Segment> {
+        let segments = ["api", "segment"];
+        self.client.request_json(
+            Method::POST,
+            &segments,
+            Option::<&()>::None,
+            Some(segment),
+            RequestOptions::default().route("/api/segment/"),
+        )
+    }
+
+    /// PUT /api/segment/{id}
+    pub fn update_segment(&self, id: SegmentId, update: &UpdateSegment) -> Result<Segment> {
+        let id = PathParam::from(id);
+        let segments = ["api", "segment", id.as_str()];
+        self.client.request_json(
+            Method::PUT,
+            &segments,
+            Option::<&()>::None,
+            Some(update),
+            RequestOptions::default().route("/api/segment/{id}"),
+        )
+    }
+
+    /// DELETE /api/segment/{id}
+    pub fn archive_segment(&self, id: SegmentId, revision_message: &str) -> Result<()> {
+        let id = PathParam::from(id);
+        let segments = ["api", "segment", id.as_str()];
+        self.client
+            .request_json::<IgnoredAny, _, _>(
+                Method::DELETE,
+                &segments,
+                Some(&ArchiveSegment { revision_message }),
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/segment/{id}"),
+            )
+            .map(|_| ())
+    }
+
+    /// GET /api/card/
+    ///
+    /// Non-archived cards whose query filters on segment `id`; see
+    /// [`Segment::is_referenced_by`].
+    pub fn dependent_cards(&self, id: SegmentId) -> Result<Vec<Card>> {
+        let segments = ["api", "card"];
+        let cards: Vec<Card> = self.client.request_json(
+            Method::GET,
+            &segments,
+            Option::<&()>::None,
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/card/"),
+        )?;
+        Ok(cards
+            .into_iter()
+            .filter(|card| references_segment(&card.dataset_query, id))
+            .collect())
+    }
+
     /// GET /api/segment/
     pub fn get(&self) -> Result<Value> {
         let segments = ["api", "segment"];
@@ -133,7 +352,7 @@ impl BlockingSegmentService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/segment/"),
         )
     }
 
@@ -145,7 +364,7 @@ impl BlockingSegmentService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/segment/"),
         )
     }
 
@@ -158,10 +377,17 @@ impl BlockingSegmentService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/segment/{id}"),
         )
     }
 
+    /// DELETE /api/segment/{id}
+    ///
+    /// Typed-id variant of [`Self::delete_by_id`].
+    pub fn delete_by_id_typed(&self, id: SegmentId, query: Option<&Value>) -> Result<Value> {
+        self.delete_by_id(id, query)
+    }
+
     /// GET /api/segment/{id}
     pub fn get_by_id(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -171,10 +397,17 @@ impl BlockingSegmentService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/segment/{id}"),
         )
     }
 
+    /// GET /api/segment/{id}
+    ///
+    /// Typed-id variant of [`Self::get_by_id`].
+    pub fn get_by_id_typed(&self, id: SegmentId) -> Result<Value> {
+        self.get_by_id(id)
+    }
+
     /// PUT /api/segment/{id}
     pub fn put_by_id(&self, id: impl Into<PathParam>, body: Option<&Value>) -> Result<Value> {
         let id = id.into();
@@ -184,10 +417,17 @@ impl BlockingSegmentService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/segment/{id}"),
         )
     }
 
+    /// PUT /api/segment/{id}
+    ///
+    /// Typed-id variant of [`Self::put_by_id`].
+    pub fn put_by_id_typed(&self, id: SegmentId, body: Option<&Value>) -> Result<Value> {
+        self.put_by_id(id, body)
+    }
+
     /// GET /api/segment/{id}/related
     pub fn get_by_id_related(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -197,7 +437,14 @@ impl BlockingSegmentService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/segment/{id}/related"),
         )
     }
+
+    /// GET /api/segment/{id}/related
+    ///
+    /// Typed-id variant of [`Self::get_by_id_related`].
+    pub fn get_by_id_related_typed(&self, id: SegmentId) -> Result<Value> {
+        self.get_by_id_related(id)
+    }
 }
@@ -1,7 +1,7 @@
 use http::Method;
 use serde_json::Value;
 
-use crate::types::session::{CreateSessionRequest, CreateSessionResponse};
+use crate::types::session::{CreateSessionRequest, CreateSessionResponse, SessionProperties};
 use crate::{Result, client::RequestOptions};
 
 #[cfg(feature = "blocking")]
@@ -23,7 +23,26 @@ impl SessionService {
 
     pub async fn create(&self, request: &CreateSessionRequest) -> Result<CreateSessionResponse> {
         let segments = ["api", "session"];
-        self.client.post_json(&segments, request).await
+        self.client
+            .post_json("/api/session/", &segments, request)
+            .await
+    }
+
+    /// GET /api/session/properties
+    ///
+    /// Typed variant of [`Self::get_properties`]. See also `server_info` on the client, which
+    /// caches this response.
+    pub async fn properties(&self) -> Result<SessionProperties> {
+        let segments = ["api", "session", "properties"];
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/session/properties"),
+            )
+            .await
     }
 
     pub async fn create_with_options(
@@ -33,7 +52,7 @@ impl SessionService {
     ) -> Result<CreateSessionResponse> {
         let segments = ["api", "session"];
         self.client
-            .post_json_with_options(&segments, request, options)
+            .post_json_with_options("/api/session/", &segments, request, options)
             .await
     }
 
@@ -46,7 +65,7 @@ impl SessionService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/session/"),
             )
             .await
     }
@@ -60,7 +79,7 @@ impl SessionService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/session/"),
             )
             .await
     }
@@ -74,7 +93,7 @@ impl SessionService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/session/forgot_password"),
             )
             .await
     }
@@ -88,7 +107,7 @@ impl SessionService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/session/google_auth"),
             )
             .await
     }
@@ -102,7 +121,7 @@ impl SessionService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/session/password-check"),
             )
             .await
     }
@@ -116,7 +135,7 @@ impl SessionService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/session/password_reset_token_valid"),
             )
             .await
     }
@@ -130,7 +149,7 @@ impl SessionService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/session/properties"),
             )
             .await
     }
@@ -144,7 +163,7 @@ impl SessionService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/session/reset_password"),
             )
             .await
     }
@@ -164,7 +183,22 @@ impl BlockingSessionService {
 
     pub fn create(&self, request: &CreateSessionRequest) -> Result<CreateSessionResponse> {
         let segments = ["api", "session"];
-        self.client.post_json(&segments, request)
+        self.client.post_json("/api/session/", &segments, request)
+    }
+
+    /// GET /api/session/properties
+    ///
+    /// Typed variant of [`Self::get_properties`]. See also `server_info` on the client, which
+    /// caches this response.
+    pub fn properties(&self) -> Result<SessionProperties> {
+        let segments = ["api", "session", "properties"];
+        self.client.request_json(
+            Method::GET,
+            &segments,
+            Option::<&()>::None,
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/session/properties"),
+        )
     }
 
     pub fn create_with_options(
@@ -174,7 +208,7 @@ impl BlockingSessionService {
     ) -> Result<CreateSessionResponse> {
         let segments = ["api", "session"];
         self.client
-            .post_json_with_options(&segments, request, options)
+            .post_json_with_options("/api/session/", &segments, request, options)
     }
 
     /// DELETE /api/session/
@@ -185,7 +219,7 @@ impl BlockingSessionService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/session/"),
         )
     }
 
@@ -197,7 +231,7 @@ impl BlockingSessionService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/session/"),
         )
     }
 
@@ -209,7 +243,7 @@ impl BlockingSessionService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/session/forgot_password"),
         )
     }
 
@@ -221,7 +255,7 @@ impl BlockingSessionService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/session/google_auth"),
         )
     }
 
@@ -233,7 +267,7 @@ impl BlockingSessionService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/session/password-check"),
         )
     }
 
@@ -245,7 +279,7 @@ impl BlockingSessionService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/session/password_reset_token_valid"),
         )
     }
 
@@ -257,7 +291,7 @@ impl BlockingSessionService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/session/properties"),
         )
     }
 
@@ -269,7 +303,7 @@ impl BlockingSessionService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/session/reset_password"),
         )
     }
 }
@@ -1,7 +1,15 @@
 use http::Method;
+use serde::de::IgnoredAny;
 use serde_json::Value;
 
-use crate::{Result, client::RequestOptions, types::path::PathParam};
+use crate::{
+    Result,
+    client::RequestOptions,
+    types::{
+        path::PathParam,
+        setting::{Setting, SettingValue, SettingsUpdate, decode_setting, setting_value},
+    },
+};
 
 #[cfg(feature = "blocking")]
 use crate::client::BlockingClient;
@@ -20,6 +28,80 @@ impl SettingService {
         Self { client }
     }
 
+    /// GET /api/setting/{key}
+    ///
+    /// `None` when the setting is unset.
+    pub async fn get_setting<S: Setting>(&self) -> Result<Option<S::Value>> {
+        let segments = ["api", "setting", S::KEY];
+        let body = self
+            .client
+            .request_bytes(
+                Method::GET,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/setting/{key}"),
+            )
+            .await?;
+        decode_setting::<S>(&body)
+    }
+
+    /// PUT /api/setting/{key}
+    ///
+    /// Fails with [`Error::InvalidSetting`](crate::Error::InvalidSetting) before sending
+    /// anything if `value` would be sent as `null`, which resets the setting.
+    pub async fn set_setting<S: Setting>(&self, value: &S::Value) -> Result<()> {
+        let segments = ["api", "setting", S::KEY];
+        self.client
+            .request_json::<IgnoredAny, _, _>(
+                Method::PUT,
+                &segments,
+                Option::<&()>::None,
+                Some(&SettingValue {
+                    value: setting_value::<S>(value)?,
+                }),
+                RequestOptions::default().route("/api/setting/{key}"),
+            )
+            .await
+            .map(|_| ())
+    }
+
+    /// PUT /api/setting/{key}
+    ///
+    /// Resets the setting to its default.
+    pub async fn reset_setting<S: Setting>(&self) -> Result<()> {
+        let segments = ["api", "setting", S::KEY];
+        self.client
+            .request_json::<IgnoredAny, _, _>(
+                Method::PUT,
+                &segments,
+                Option::<&()>::None,
+                Some(&SettingValue { value: Value::Null }),
+                RequestOptions::default().route("/api/setting/{key}"),
+            )
+            .await
+            .map(|_| ())
+    }
+
+    /// PUT /api/setting/
+    ///
+    /// Fails with [`Error::InvalidSetting`](crate::Error::InvalidSetting) before sending
+    /// anything if a known setting has a value of the wrong type.
+    pub async fn put_settings(&self, update: &SettingsUpdate) -> Result<()> {
+        update.validate()?;
+        let segments = ["api", "setting"];
+        self.client
+            .request_json::<IgnoredAny, _, _>(
+                Method::PUT,
+                &segments,
+                Option::<&()>::None,
+                Some(update),
+                RequestOptions::default().route("/api/setting/"),
+            )
+            .await
+            .map(|_| ())
+    }
+
     /// GET /api/setting/
     pub async fn get(&self) -> Result<Value> {
         let segments = ["api", "setting"];
@@ -29,7 +111,7 @@ impl SettingService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/setting/"),
             )
             .await
     }
@@ -43,7 +125,7 @@ impl SettingService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/setting/"),
             )
             .await
     }
@@ -58,7 +140,7 @@ impl SettingService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/setting/{key}"),
             )
             .await
     }
@@ -77,7 +159,7 @@ impl SettingService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/setting/{key}"),
             )
             .await
     }
@@ -95,6 +177,74 @@ impl BlockingSettingService {
         Self { client }
     }
 
+    /// GET /api/setting/{key}
+    ///
+    /// `None` when the setting is unset.
+    pub fn get_setting<S: Setting>(&self) -> Result<Option<S::Value>> {
+        let segments = ["api", "setting", S::KEY];
+        let body = self.client.request_bytes(
+            Method::GET,
+            &segments,
+            Option::<&()>::None,
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/setting/{key}"),
+        )?;
+        decode_setting::<S>(&body)
+    }
+
+    /// PUT /api/setting/{key}
+    ///
+    /// Fails with [`Error::InvalidSetting`](crate::Error::InvalidSetting) before sending
+    /// anything if `value` would be sent as `null`, which resets the setting.
+    pub fn set_setting<S: Setting>(&self, value: &S::Value) -> Result<()> {
+        let segments = ["api", "setting", S::KEY];
+        self.client
+            .request_json::<IgnoredAny, _, _>(
+                Method::PUT,
+                &segments,
+                Option::<&()>::None,
+                Some(&SettingValue {
+                    value: setting_value::<S>(value)?,
+                }),
+                RequestOptions::default().route("/api/setting/{key}"),
+            )
+            .map(|_| ())
+    }
+
+    /// PUT /api/setting/{key}
+    ///
+    /// Resets the setting to its default.
+    pub fn reset_setting<S: Setting>(&self) -> Result<()> {
+        let segments = ["api", "setting", S::KEY];
+        self.client
+            .request_json::<IgnoredAny, _, _>(
+                Method::PUT,
+                &segments,
+                Option::<&()>::None,
+                Some(&SettingValue { value: Value::Null }),
+                RequestOptions::default().route("/api/setting/{key}"),
+            )
+            .map(|_| ())
+    }
+
+    /// PUT /api/setting/
+    ///
+    /// Fails with [`Error::InvalidSetting`](crate::Error::InvalidSetting) before sending
+    /// anything if a known setting has a value of the wrong type.
+    pub fn put_settings(&self, update: &SettingsUpdate) -> Result<()> {
+        update.validate()?;
+        let segments = ["api", "setting"];
+        self.client
+            .request_json::<IgnoredAny, _, _>(
+                Method::PUT,
+                &segments,
+                Option::<&()>::None,
+                Some(update),
+                RequestOptions::default().route("/api/setting/"),
+            )
+            .map(|_| ())
+    }
+
     /// GET /api/setting/
     pub fn get(&self) -> Result<Value> {
         let segments = ["api", "setting"];
@@ -103,7 +253,7 @@ impl BlockingSettingService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/setting/"),
         )
     }
 
@@ -115,7 +265,7 @@ impl BlockingSettingService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/setting/"),
         )
     }
 
@@ -128,7 +278,7 @@ impl BlockingSettingService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/setting/{key}"),
         )
     }
 
@@ -141,7 +291,7 @@ impl BlockingSettingService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/setting/{key}"),
         )
     }
 }
@@ -29,7 +29,7 @@ impl SetupService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/setup/"),
             )
             .await
     }
@@ -55,7 +55,7 @@ impl BlockingSetupService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/setup/"),
         )
     }
 }
@@ -29,7 +29,7 @@ impl SlackService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/slack/bug-report"),
             )
             .await
     }
@@ -43,7 +43,7 @@ impl SlackService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/slack/manifest"),
             )
             .await
     }
@@ -57,7 +57,7 @@ impl SlackService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/slack/settings"),
             )
             .await
     }
@@ -83,7 +83,7 @@ impl BlockingSlackService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/slack/bug-report"),
         )
     }
 
@@ -95,7 +95,7 @@ impl BlockingSlackService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/slack/manifest"),
         )
     }
 
@@ -107,7 +107,7 @@ impl BlockingSlackService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/slack/settings"),
         )
     }
 }
@@ -1,7 +1,15 @@
 use http::Method;
 use serde_json::Value;
 
-use crate::{Result, client::RequestOptions, types::path::PathParam};
+use crate::{
+    Result,
+    client::RequestOptions,
+    types::{
+        ids::TableId,
+        path::PathParam,
+        table::{ForeignKey, RelatedEntities, Table, UpdateTable},
+    },
+};
 
 #[cfg(feature = "blocking")]
 use crate::client::BlockingClient;
@@ -20,6 +28,81 @@ impl TableService {
         Self { client }
     }
 
+    /// GET /api/table/{id}
+    pub async fn get_table(&self, id: TableId) -> Result<Table> {
+        let id = PathParam::from(id);
+        let segments = ["api", "table", id.as_str()];
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/table/{id}"),
+            )
+            .await
+    }
+
+    /// GET /api/table/{id}/query_metadata
+    pub async fn table_query_metadata(&self, id: TableId, query: Option<&Value>) -> Result<Table> {
+        let id = PathParam::from(id);
+        let segments = ["api", "table", id.as_str(), "query_metadata"];
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                query,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/table/{id}/query_metadata"),
+            )
+            .await
+    }
+
+    /// GET /api/table/{id}/fks
+    pub async fn table_fks(&self, id: TableId) -> Result<Vec<ForeignKey>> {
+        let id = PathParam::from(id);
+        let segments = ["api", "table", id.as_str(), "fks"];
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/table/{id}/fks"),
+            )
+            .await
+    }
+
+    /// GET /api/table/{id}/related
+    pub async fn table_related(&self, id: TableId) -> Result<RelatedEntities> {
+        let id = PathParam::from(id);
+        let segments = ["api", "table", id.as_str(), "related"];
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/table/{id}/related"),
+            )
+            .await
+    }
+
+    /// PUT /api/table/{id}
+    pub async fn update_table(&self, id: TableId, request: &UpdateTable) -> Result<Table> {
+        let id = PathParam::from(id);
+        let segments = ["api", "table", id.as_str()];
+        self.client
+            .request_json(
+                Method::PUT,
+                &segments,
+                Option::<&()>::None,
+                Some(request),
+                RequestOptions::default().route("/api/table/{id}"),
+            )
+            .await
+    }
+
     /// GET /api/table/
     pub async fn get(&self) -> Result<Value> {
         let segments = ["api", "table"];
@@ -29,7 +112,7 @@ impl TableService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/table/"),
             )
             .await
     }
@@ -43,7 +126,7 @@ impl TableService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/table/"),
             )
             .await
     }
@@ -59,7 +142,7 @@ impl TableService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/table/card__:id/fks"),
             )
             .await
     }
@@ -75,7 +158,7 @@ impl TableService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/table/card__:id/query_metadata"),
             )
             .await
     }
@@ -94,11 +177,18 @@ impl TableService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/table/{id}"),
             )
             .await
     }
 
+    /// GET /api/table/{id}
+    ///
+    /// Typed-id variant of [`Self::get_by_id`].
+    pub async fn get_by_id_typed(&self, id: TableId, query: Option<&Value>) -> Result<Value> {
+        self.get_by_id(id, query).await
+    }
+
     /// PUT /api/table/{id}
     pub async fn put_by_id(&self, id: impl Into<PathParam>, body: Option<&Value>) -> Result<Value> {
         let id = id.into();
@@ -109,11 +199,18 @@ impl TableService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/table/{id}"),
             )
             .await
     }
 
+    /// PUT /api/table/{id}
+    ///
+    /// Typed-id variant of [`Self::put_by_id`].
+    pub async fn put_by_id_typed(&self, id: TableId, body: Option<&Value>) -> Result<Value> {
+        self.put_by_id(id, body).await
+    }
+
     /// POST /api/table/{id}/append-csv
     pub async fn post_by_id_append_csv(
         &self,
@@ -128,11 +225,22 @@ impl TableService {
                 &segments,
                 Option::<&()>::None,
                 form,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/table/{id}/append-csv"),
             )
             .await
     }
 
+    /// POST /api/table/{id}/append-csv
+    ///
+    /// Typed-id variant of [`Self::post_by_id_append_csv`].
+    pub async fn post_by_id_append_csv_typed(
+        &self,
+        id: TableId,
+        form: &crate::types::multipart::MultipartForm,
+    ) -> Result<Value> {
+        self.post_by_id_append_csv(id, form).await
+    }
+
     /// POST /api/table/{id}/discard_values
     pub async fn post_by_id_discard_values(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -143,11 +251,18 @@ impl TableService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/table/{id}/discard_values"),
             )
             .await
     }
 
+    /// POST /api/table/{id}/discard_values
+    ///
+    /// Typed-id variant of [`Self::post_by_id_discard_values`].
+    pub async fn post_by_id_discard_values_typed(&self, id: TableId) -> Result<Value> {
+        self.post_by_id_discard_values(id).await
+    }
+
     /// PUT /api/table/{id}/fields/order
     pub async fn put_by_id_fields_order(
         &self,
@@ -162,11 +277,22 @@ impl TableService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/table/{id}/fields/order"),
             )
             .await
     }
 
+    /// PUT /api/table/{id}/fields/order
+    ///
+    /// Typed-id variant of [`Self::put_by_id_fields_order`].
+    pub async fn put_by_id_fields_order_typed(
+        &self,
+        id: TableId,
+        body: Option<&Value>,
+    ) -> Result<Value> {
+        self.put_by_id_fields_order(id, body).await
+    }
+
     /// GET /api/table/{id}/fks
     pub async fn get_by_id_fks(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -177,11 +303,18 @@ impl TableService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/table/{id}/fks"),
             )
             .await
     }
 
+    /// GET /api/table/{id}/fks
+    ///
+    /// Typed-id variant of [`Self::get_by_id_fks`].
+    pub async fn get_by_id_fks_typed(&self, id: TableId) -> Result<Value> {
+        self.get_by_id_fks(id).await
+    }
+
     /// GET /api/table/{id}/query_metadata
     pub async fn get_by_id_query_metadata(
         &self,
@@ -196,11 +329,22 @@ impl TableService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/table/{id}/query_metadata"),
             )
             .await
     }
 
+    /// GET /api/table/{id}/query_metadata
+    ///
+    /// Typed-id variant of [`Self::get_by_id_query_metadata`].
+    pub async fn get_by_id_query_metadata_typed(
+        &self,
+        id: TableId,
+        query: Option<&Value>,
+    ) -> Result<Value> {
+        self.get_by_id_query_metadata(id, query).await
+    }
+
     /// GET /api/table/{id}/related
     pub async fn get_by_id_related(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -211,11 +355,18 @@ impl TableService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/table/{id}/related"),
             )
             .await
     }
 
+    /// GET /api/table/{id}/related
+    ///
+    /// Typed-id variant of [`Self::get_by_id_related`].
+    pub async fn get_by_id_related_typed(&self, id: TableId) -> Result<Value> {
+        self.get_by_id_related(id).await
+    }
+
     /// POST /api/table/{id}/replace-csv
     pub async fn post_by_id_replace_csv(
         &self,
@@ -230,11 +381,22 @@ impl TableService {
                 &segments,
                 Option::<&()>::None,
                 form,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/table/{id}/replace-csv"),
             )
             .await
     }
 
+    /// POST /api/table/{id}/replace-csv
+    ///
+    /// Typed-id variant of [`Self::post_by_id_replace_csv`].
+    pub async fn post_by_id_replace_csv_typed(
+        &self,
+        id: TableId,
+        form: &crate::types::multipart::MultipartForm,
+    ) -> Result<Value> {
+        self.post_by_id_replace_csv(id, form).await
+    }
+
     /// POST /api/table/{id}/rescan_values
     pub async fn post_by_id_rescan_values(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -245,11 +407,18 @@ impl TableService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/table/{id}/rescan_values"),
             )
             .await
     }
 
+    /// POST /api/table/{id}/rescan_values
+    ///
+    /// Typed-id variant of [`Self::post_by_id_rescan_values`].
+    pub async fn post_by_id_rescan_values_typed(&self, id: TableId) -> Result<Value> {
+        self.post_by_id_rescan_values(id).await
+    }
+
     /// POST /api/table/{id}/sync_schema
     pub async fn post_by_id_sync_schema(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -260,11 +429,18 @@ impl TableService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/table/{id}/sync_schema"),
             )
             .await
     }
 
+    /// POST /api/table/{id}/sync_schema
+    ///
+    /// Typed-id variant of [`Self::post_by_id_sync_schema`].
+    pub async fn post_by_id_sync_schema_typed(&self, id: TableId) -> Result<Value> {
+        self.post_by_id_sync_schema(id).await
+    }
+
     /// GET /api/table/{table-id}/data
     pub async fn get_by_table_id_data(&self, table_id: impl Into<PathParam>) -> Result<Value> {
         let table_id = table_id.into();
@@ -275,10 +451,17 @@ impl TableService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/table/{table-id}/data"),
             )
             .await
     }
+
+    /// GET /api/table/{table-id}/data
+    ///
+    /// Typed-id variant of [`Self::get_by_table_id_data`].
+    pub async fn get_by_table_id_data_typed(&self, table_id: TableId) -> Result<Value> {
+        self.get_by_table_id_data(table_id).await
+    }
 }
 
 #[cfg(feature = "blocking")]
@@ -293,6 +476,71 @@ impl BlockingTableService {
         Self { client }
     }
 
+    /// GET /api/table/{id}
+    pub fn get_table(&self, id: TableId) -> Result<Table> {
+        let id = PathParam::from(id);
+        let segments = ["api", "table", id.as_str()];
+        self.client.request_json(
+            Method::GET,
+            &segments,
+            Option::<&()>::None,
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/table/{id}"),
+        )
+    }
+
+    /// GET /api/table/{id}/query_metadata
+    pub fn table_query_metadata(&self, id: TableId, query: Option<&Value>) -> Result<Table> {
+        let id = PathParam::from(id);
+        let segments = ["api", "table", id.as_str(), "query_metadata"];
+        self.client.request_json(
+            Method::GET,
+            &segments,
+            query,
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/table/{id}/query_metadata"),
+        )
+    }
+
+    /// GET /api/table/{id}/fks
+    pub fn table_fks(&self, id: TableId) -> Result<Vec<ForeignKey>> {
+        let id = PathParam::from(id);
+        let segments = ["api", "table", id.as_str(), "fks"];
+        self.client.request_json(
+            Method::GET,
+            &segments,
+            Option::<&()>::None,
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/table/{id}/fks"),
+        )
+    }
+
+    /// GET /api/table/{id}/related
+    pub fn table_related(&self, id: TableId) -> Result<RelatedEntities> {
+        let id = PathParam::from(id);
+        let segments = ["api", "table", id.as_str(), "related"];
+        self.client.request_json(
+            Method::GET,
+            &segments,
+            Option::<&()>::None,
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/table/{id}/related"),
+        )
+    }
+
+    /// PUT /api/table/{id}
+    pub fn update_table(&self, id: TableId, request: &UpdateTable) -> Result<Table> {
+        let id = PathParam::from(id);
+        let segments = ["api", "table", id.as_str()];
+        self.client.request_json(
+            Method::PUT,
+            &segments,
+            Option::<&()>::None,
+            Some(request),
+            RequestOptions::default().route("/api/table/{id}"),
+        )
+    }
+
     /// GET /api/table/
     pub fn get(&self) -> Result<Value> {
         let segments = ["api", "table"];
@@ -301,7 +549,7 @@ impl BlockingTableService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/table/"),
         )
     }
 
@@ -313,7 +561,7 @@ impl BlockingTableService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/table/"),
         )
     }
 
@@ -327,7 +575,7 @@ impl BlockingTableService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/table/card__:id/fks"),
         )
     }
 
@@ -341,7 +589,7 @@ impl BlockingTableService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/table/card__:id/query_metadata"),
         )
     }
 
@@ -354,10 +602,17 @@ impl BlockingTableService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/table/{id}"),
         )
     }
 
+    /// GET /api/table/{id}
+    ///
+    /// Typed-id variant of [`Self::get_by_id`].
+    pub fn get_by_id_typed(&self, id: TableId, query: Option<&Value>) -> Result<Value> {
+        self.get_by_id(id, query)
+    }
+
     /// PUT /api/table/{id}
     pub fn put_by_id(&self, id: impl Into<PathParam>, body: Option<&Value>) -> Result<Value> {
         let id = id.into();
@@ -367,10 +622,17 @@ impl BlockingTableService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/table/{id}"),
         )
     }
 
+    /// PUT /api/table/{id}
+    ///
+    /// Typed-id variant of [`Self::put_by_id`].
+    pub fn put_by_id_typed(&self, id: TableId, body: Option<&Value>) -> Result<Value> {
+        self.put_by_id(id, body)
+    }
+
     /// POST /api/table/{id}/append-csv
     pub fn post_by_id_append_csv(
         &self,
@@ -384,10 +646,21 @@ impl BlockingTableService {
             &segments,
             Option::<&()>::None,
             form,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/table/{id}/append-csv"),
         )
     }
 
+    /// POST /api/table/{id}/append-csv
+    ///
+    /// Typed-id variant of [`Self::post_by_id_append_csv`].
+    pub fn post_by_id_append_csv_typed(
+        &self,
+        id: TableId,
+        form: &crate::types::multipart::MultipartForm,
+    ) -> Result<Value> {
+        self.post_by_id_append_csv(id, form)
+    }
+
     /// POST /api/table/{id}/discard_values
     pub fn post_by_id_discard_values(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -397,10 +670,17 @@ impl BlockingTableService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/table/{id}/discard_values"),
         )
     }
 
+    /// POST /api/table/{id}/discard_values
+    ///
+    /// Typed-id variant of [`Self::post_by_id_discard_values`].
+    pub fn post_by_id_discard_values_typed(&self, id: TableId) -> Result<Value> {
+        self.post_by_id_discard_values(id)
+    }
+
     /// PUT /api/table/{id}/fields/order
     pub fn put_by_id_fields_order(
         &self,
@@ -414,10 +694,17 @@ impl BlockingTableService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/table/{id}/fields/order"),
         )
     }
 
+    /// PUT /api/table/{id}/fields/order
+    ///
+    /// Typed-id variant of [`Self::put_by_id_fields_order`].
+    pub fn put_by_id_fields_order_typed(&self, id: TableId, body: Option<&Value>) -> Result<Value> {
+        self.put_by_id_fields_order(id, body)
+    }
+
     /// GET /api/table/{id}/fks
     pub fn get_by_id_fks(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -427,10 +714,17 @@ impl BlockingTableService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/table/{id}/fks"),
         )
     }
 
+    /// GET /api/table/{id}/fks
+    ///
+    /// Typed-id variant of [`Self::get_by_id_fks`].
+    pub fn get_by_id_fks_typed(&self, id: TableId) -> Result<Value> {
+        self.get_by_id_fks(id)
+    }
+
     /// GET /api/table/{id}/query_metadata
     pub fn get_by_id_query_metadata(
         &self,
@@ -444,10 +738,21 @@ impl BlockingTableService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/table/{id}/query_metadata"),
         )
     }
 
+    /// GET /api/table/{id}/query_metadata
+    ///
+    /// Typed-id variant of [`Self::get_by_id_query_metadata`].
+    pub fn get_by_id_query_metadata_typed(
+        &self,
+        id: TableId,
+        query: Option<&Value>,
+    ) -> Result<Value> {
+        self.get_by_id_query_metadata(id, query)
+    }
+
     /// GET /api/table/{id}/related
     pub fn get_by_id_related(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -457,10 +762,17 @@ impl BlockingTableService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/table/{id}/related"),
         )
     }
 
+    /// GET /api/table/{id}/related
+    ///
+    /// Typed-id variant of [`Self::get_by_id_related`].
+    pub fn get_by_id_related_typed(&self, id: TableId) -> Result<Value> {
+        self.get_by_id_related(id)
+    }
+
     /// POST /api/table/{id}/replace-csv
     pub fn post_by_id_replace_csv(
         &self,
@@ -474,10 +786,21 @@ impl BlockingTableService {
             &segments,
             Option::<&()>::None,
             form,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/table/{id}/replace-csv"),
         )
     }
 
+    /// POST /api/table/{id}/replace-csv
+    ///
+    /// Typed-id variant of [`Self::post_by_id_replace_csv`].
+    pub fn post_by_id_replace_csv_typed(
+        &self,
+        id: TableId,
+        form: &crate::types::multipart::MultipartForm,
+    ) -> Result<Value> {
+        self.post_by_id_replace_csv(id, form)
+    }
+
     /// POST /api/table/{id}/rescan_values
     pub fn post_by_id_rescan_values(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -487,10 +810,17 @@ impl BlockingTableService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/table/{id}/rescan_values"),
         )
     }
 
+    /// POST /api/table/{id}/rescan_values
+    ///
+    /// Typed-id variant of [`Self::post_by_id_rescan_values`].
+    pub fn post_by_id_rescan_values_typed(&self, id: TableId) -> Result<Value> {
+        self.post_by_id_rescan_values(id)
+    }
+
     /// POST /api/table/{id}/sync_schema
     pub fn post_by_id_sync_schema(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -500,10 +830,17 @@ impl BlockingTableService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/table/{id}/sync_schema"),
         )
     }
 
+    /// POST /api/table/{id}/sync_schema
+    ///
+    /// Typed-id variant of [`Self::post_by_id_sync_schema`].
+    pub fn post_by_id_sync_schema_typed(&self, id: TableId) -> Result<Value> {
+        self.post_by_id_sync_schema(id)
+    }
+
     /// GET /api/table/{table-id}/data
     pub fn get_by_table_id_data(&self, table_id: impl Into<PathParam>) -> Result<Value> {
         let table_id = table_id.into();
@@ -513,7 +850,14 @@ impl BlockingTableService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/table/{table-id}/data"),
         )
     }
+
+    /// GET /api/table/{table-id}/data
+    ///
+    /// Typed-id variant of [`Self::get_by_table_id_data`].
+    pub fn get_by_table_id_data_typed(&self, table_id: TableId) -> Result<Value> {
+        self.get_by_table_id_data(table_id)
+    }
 }
@@ -1,7 +1,11 @@
 use http::Method;
 use serde_json::Value;
 
-use crate::{Result, client::RequestOptions, types::path::PathParam};
+use crate::{
+    Result,
+    client::RequestOptions,
+    types::{ids::TaskId, path::PathParam},
+};
 
 #[cfg(feature = "blocking")]
 use crate::client::BlockingClient;
@@ -29,7 +33,7 @@ impl TaskService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/task/"),
             )
             .await
     }
@@ -43,7 +47,7 @@ impl TaskService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/task/info"),
             )
             .await
     }
@@ -57,7 +61,7 @@ impl TaskService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/task/unique-tasks"),
             )
             .await
     }
@@ -72,10 +76,17 @@ impl TaskService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/task/{id}"),
             )
             .await
     }
+
+    /// GET /api/task/{id}
+    ///
+    /// Typed-id variant of [`Self::get_by_id`].
+    pub async fn get_by_id_typed(&self, id: TaskId) -> Result<Value> {
+        self.get_by_id(id).await
+    }
 }
 
 #[cfg(feature = "blocking")]
@@ -98,7 +109,7 @@ impl BlockingTaskService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/task/"),
         )
     }
 
@@ -110,7 +121,7 @@ impl BlockingTaskService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/task/info"),
         )
     }
 
@@ -122,7 +133,7 @@ impl BlockingTaskService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/task/unique-tasks"),
         )
     }
 
@@ -135,7 +146,14 @@ impl BlockingTaskService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/task/{id}"),
         )
     }
+
+    /// GET /api/task/{id}
+    ///
+    /// Typed-id variant of [`Self::get_by_id`].
+    pub fn get_by_id_typed(&self, id: TaskId) -> Result<Value> {
+        self.get_by_id(id)
+    }
 }
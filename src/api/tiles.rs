@@ -1,7 +1,14 @@
 use http::Method;
 use serde_json::Value;
 
-use crate::{Result, client::RequestOptions, types::path::PathParam};
+use crate::{
+    Result,
+    client::RequestOptions,
+    types::{
+        ids::{CardId, DashboardId, DashcardId},
+        path::PathParam,
+    },
+};
 
 #[cfg(feature = "blocking")]
 use crate::client::BlockingClient;
@@ -47,11 +54,26 @@ impl TilesService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/tiles/{card-id}/{zoom}/{x}/{y}"),
             )
             .await
     }
 
+    /// GET /api/tiles/{card-id}/{zoom}/{x}/{y}
+    ///
+    /// Typed-id variant of [`Self::get_by_card_id_by_zoom_by_x_by_y`].
+    pub async fn get_by_card_id_by_zoom_by_x_by_y_typed(
+        &self,
+        card_id: CardId,
+        zoom: impl Into<PathParam>,
+        x: impl Into<PathParam>,
+        y: impl Into<PathParam>,
+        query: Option<&Value>,
+    ) -> Result<Vec<u8>> {
+        self.get_by_card_id_by_zoom_by_x_by_y(card_id, zoom, x, y, query)
+            .await
+    }
+
     /// GET /api/tiles/{dashboard-id}/dashcard/{dashcard-id}/card/{card-id}/{zoom}/{x}/{y}
     pub async fn get_by_dashboard_id_dashcard_by_dashcard_id_card_by_card_id_by_zoom_by_x_by_y(
         &self,
@@ -87,11 +109,36 @@ impl TilesService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/tiles/{dashboard-id}/dashcard/{dashcard-id}/card/{card-id}/{zoom}/{x}/{y}"),
             )
             .await
     }
 
+    /// GET /api/tiles/{dashboard-id}/dashcard/{dashcard-id}/card/{card-id}/{zoom}/{x}/{y}
+    ///
+    /// Typed-id variant of [`Self::get_by_dashboard_id_dashcard_by_dashcard_id_card_by_card_id_by_zoom_by_x_by_y`].
+    pub async fn get_by_dashboard_id_dashcard_by_dashcard_id_card_by_card_id_by_zoom_by_x_by_y_typed(
+        &self,
+        dashboard_id: DashboardId,
+        dashcard_id: DashcardId,
+        card_id: CardId,
+        zoom: impl Into<PathParam>,
+        x: impl Into<PathParam>,
+        y: impl Into<PathParam>,
+        query: Option<&Value>,
+    ) -> Result<Vec<u8>> {
+        self.get_by_dashboard_id_dashcard_by_dashcard_id_card_by_card_id_by_zoom_by_x_by_y(
+            dashboard_id,
+            dashcard_id,
+            card_id,
+            zoom,
+            x,
+            y,
+            query,
+        )
+        .await
+    }
+
     /// GET /api/tiles/{zoom}/{x}/{y}
     pub async fn get_by_zoom_by_x_by_y(
         &self,
@@ -110,7 +157,7 @@ impl TilesService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/tiles/{zoom}/{x}/{y}"),
             )
             .await
     }
@@ -154,10 +201,24 @@ impl BlockingTilesService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/tiles/{card-id}/{zoom}/{x}/{y}"),
         )
     }
 
+    /// GET /api/tiles/{card-id}/{zoom}/{x}/{y}
+    ///
+    /// Typed-id variant of [`Self::get_by_card_id_by_zoom_by_x_by_y`].
+    pub fn get_by_card_id_by_zoom_by_x_by_y_typed(
+        &self,
+        card_id: CardId,
+        zoom: impl Into<PathParam>,
+        x: impl Into<PathParam>,
+        y: impl Into<PathParam>,
+        query: Option<&Value>,
+    ) -> Result<Vec<u8>> {
+        self.get_by_card_id_by_zoom_by_x_by_y(card_id, zoom, x, y, query)
+    }
+
     /// GET /api/tiles/{dashboard-id}/dashcard/{dashcard-id}/card/{card-id}/{zoom}/{x}/{y}
     pub fn get_by_dashboard_id_dashcard_by_dashcard_id_card_by_card_id_by_zoom_by_x_by_y(
         &self,
@@ -192,7 +253,33 @@ impl BlockingTilesService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route(
+                "/api/tiles/{dashboard-id}/dashcard/{dashcard-id}/card/{card-id}/{zoom}/{x}/{y}",
+            ),
+        )
+    }
+
+    /// GET /api/tiles/{dashboard-id}/dashcard/{dashcard-id}/card/{card-id}/{zoom}/{x}/{y}
+    ///
+    /// Typed-id variant of [`Self::get_by_dashboard_id_dashcard_by_dashcard_id_card_by_card_id_by_zoom_by_x_by_y`].
+    pub fn get_by_dashboard_id_dashcard_by_dashcard_id_card_by_card_id_by_zoom_by_x_by_y_typed(
+        &self,
+        dashboard_id: DashboardId,
+        dashcard_id: DashcardId,
+        card_id: CardId,
+        zoom: impl Into<PathParam>,
+        x: impl Into<PathParam>,
+        y: impl Into<PathParam>,
+        query: Option<&Value>,
+    ) -> Result<Vec<u8>> {
+        self.get_by_dashboard_id_dashcard_by_dashcard_id_card_by_card_id_by_zoom_by_x_by_y(
+            dashboard_id,
+            dashcard_id,
+            card_id,
+            zoom,
+            x,
+            y,
+            query,
         )
     }
 
@@ -213,7 +300,7 @@ impl BlockingTilesService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/tiles/{zoom}/{x}/{y}"),
         )
     }
 }
@@ -1,7 +1,17 @@
 use http::Method;
+use serde::de::IgnoredAny;
 use serde_json::Value;
 
-use crate::{Result, client::RequestOptions, types::path::PathParam};
+use crate::{
+    Result,
+    client::RequestOptions,
+    types::{
+        collection::CollectionRef,
+        ids::{CollectionId, TimelineId},
+        path::PathParam,
+        timeline::{CreateTimeline, Timeline, TimelineQuery, UpdateTimeline},
+    },
+};
 
 #[cfg(feature = "blocking")]
 use crate::client::BlockingClient;
@@ -20,6 +30,110 @@ impl TimelineService {
         Self { client }
     }
 
+    /// GET /api/timeline/
+    pub async fn list_timelines(&self, query: &TimelineQuery) -> Result<Vec<Timeline>> {
+        let segments = ["api", "timeline"];
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                Some(query),
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/timeline/"),
+            )
+            .await
+    }
+
+    /// GET /api/timeline/{id}
+    pub async fn get_timeline(&self, id: TimelineId, query: &TimelineQuery) -> Result<Timeline> {
+        let id = PathParam::from(id);
+        let segments = ["api", "timeline", id.as_str()];
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                Some(query),
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/timeline/{id}"),
+            )
+            .await
+    }
+
+    /// GET /api/timeline/collection/{id}
+    ///
+    /// Timelines in a collection, or in the root collection for [`CollectionRef::Root`].
+    pub async fn collection_timelines(
+        &self,
+        collection: impl Into<CollectionRef>,
+        query: &TimelineQuery,
+    ) -> Result<Vec<Timeline>> {
+        let collection = collection.into();
+        let route = match collection {
+            CollectionRef::Root => "/api/timeline/collection/root",
+            CollectionRef::Id(_) => "/api/timeline/collection/{id}",
+        };
+        let id = PathParam::from(collection);
+        let segments = ["api", "timeline", "collection", id.as_str()];
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                Some(query),
+                Option::<&()>::None,
+                RequestOptions::default().route(route),
+            )
+            .await
+    }
+
+    /// POST /api/timeline/
+    pub async fn create_timeline(&self, timeline: &CreateTimeline) -> Result<Timeline> {
+        let segments = ["api", "timeline"];
+        self.client
+            .request_json(
+                Method::POST,
+                &segments,
+                Option::<&()>::None,
+                Some(timeline),
+                RequestOptions::default().route("/api/timeline/"),
+            )
+            .await
+    }
+
+    /// PUT /api/timeline/{id}
+    pub async fn update_timeline(
+        &self,
+        id: TimelineId,
+        update: &UpdateTimeline,
+    ) -> Result<Timeline> {
+        let id = PathParam::from(id);
+        let segments = ["api", "timeline", id.as_str()];
+        self.client
+            .request_json(
+                Method::PUT,
+                &segments,
+                Option::<&()>::None,
+                Some(update),
+                RequestOptions::default().route("/api/timeline/{id}"),
+            )
+            .await
+    }
+
+    /// DELETE /api/timeline/{id}
+    pub async fn delete_timeline(&self, id: TimelineId) -> Result<()> {
+        let id = PathParam::from(id);
+        let segments = ["api", "timeline", id.as_str()];
+        self.client
+            .request_json::<IgnoredAny, _, _>(
+                Method::DELETE,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/timeline/{id}"),
+            )
+            .await
+            .map(|_| ())
+    }
+
     /// GET /api/timeline/
     pub async fn get(&self, query: Option<&Value>) -> Result<Value> {
         let segments = ["api", "timeline"];
@@ -29,7 +143,7 @@ impl TimelineService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/timeline/"),
             )
             .await
     }
@@ -43,7 +157,7 @@ impl TimelineService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/timeline/"),
             )
             .await
     }
@@ -57,7 +171,7 @@ impl TimelineService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/timeline/collection/root"),
             )
             .await
     }
@@ -76,11 +190,22 @@ impl TimelineService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/timeline/collection/{id}"),
             )
             .await
     }
 
+    /// GET /api/timeline/collection/{id}
+    ///
+    /// Typed-id variant of [`Self::get_collection_by_id`].
+    pub async fn get_collection_by_id_typed(
+        &self,
+        id: CollectionId,
+        query: Option<&Value>,
+    ) -> Result<Value> {
+        self.get_collection_by_id(id, query).await
+    }
+
     /// DELETE /api/timeline/{id}
     pub async fn delete_by_id(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -91,11 +216,18 @@ impl TimelineService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/timeline/{id}"),
             )
             .await
     }
 
+    /// DELETE /api/timeline/{id}
+    ///
+    /// Typed-id variant of [`Self::delete_by_id`].
+    pub async fn delete_by_id_typed(&self, id: TimelineId) -> Result<Value> {
+        self.delete_by_id(id).await
+    }
+
     /// GET /api/timeline/{id}
     pub async fn get_by_id(
         &self,
@@ -110,11 +242,18 @@ impl TimelineService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/timeline/{id}"),
             )
             .await
     }
 
+    /// GET /api/timeline/{id}
+    ///
+    /// Typed-id variant of [`Self::get_by_id`].
+    pub async fn get_by_id_typed(&self, id: TimelineId, query: Option<&Value>) -> Result<Value> {
+        self.get_by_id(id, query).await
+    }
+
     /// PUT /api/timeline/{id}
     pub async fn put_by_id(&self, id: impl Into<PathParam>, body: Option<&Value>) -> Result<Value> {
         let id = id.into();
@@ -125,10 +264,17 @@ impl TimelineService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/timeline/{id}"),
             )
             .await
     }
+
+    /// PUT /api/timeline/{id}
+    ///
+    /// Typed-id variant of [`Self::put_by_id`].
+    pub async fn put_by_id_typed(&self, id: TimelineId, body: Option<&Value>) -> Result<Value> {
+        self.put_by_id(id, body).await
+    }
 }
 
 #[cfg(feature = "blocking")]
@@ -143,6 +289,95 @@ impl BlockingTimelineService {
         Self { client }
     }
 
+    /// GET /api/timeline/
+    pub fn list_timelines(&self, query: &TimelineQuery) -> Result<Vec<Timeline>> {
+        let segments = ["api", "timeline"];
+        self.client.request_json(
+            Method::GET,
+            &segments,
+            Some(query),
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/timeline/"),
+        )
+    }
+
+    /// GET /api/timeline/{id}
+    pub fn get_timeline(&self, id: TimelineId, query: &TimelineQuery) -> Result<Timeline> {
+        let id = PathParam::from(id);
+        let segments = ["api", "timeline", id.as_str()];
+        self.client.request_json(
+            Method::GET,
+            &segments,
+            Some(query),
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/timeline/{id}"),
+        )
+    }
+
+    /// GET /api/timeline/collection/{id}
+    ///
+    /// Timelines in a collection, or in the root collection for [`CollectionRef::Root`].
+    pub fn collection_timelines(
+        &self,
+        collection: impl Into<CollectionRef>,
+        query: &TimelineQuery,
+    ) -> Result<Vec<Timeline>> {
+        let collection = collection.into();
+        let route = match collection {
+            CollectionRef::Root => "/api/timeline/collection/root",
+            CollectionRef::Id(_) => "/api/timeline/collection/{id}",
+        };
+        let id = PathParam::from(collection);
+        let segments = ["api", "timeline", "collection", id.as_str()];
+        self.client.request_json(
+            Method::GET,
+            &segments,
+            Some(query),
+            Option::<&()>::None,
+            RequestOptions::default().route(route),
+        )
+    }
+
+    /// POST /api/timeline/
+    pub fn create_timeline(&self, timeline: &CreateTimeline) -> Result<Timeline> {
+        let segments = ["api", "timeline"];
+        self.client.request_json(
+            Method::POST,
+            &segments,
+            Option::<&()>::None,
+            Some(timeline),
+            RequestOptions::default().route("/api/timeline/"),
+        )
+    }
+
+    /// PUT /api/timeline/{id}
+    pub fn update_timeline(&self, id: TimelineId, update: &UpdateTimeline) -> Result<Timeline> {
+        let id = PathParam::from(id);
+        let segments = ["api", "timeline", id.as_str()];
+        self.client.request_json(
+            Method::PUT,
+            &segments,
+            Option::<&()>::None,
+            Some(update),
+            RequestOptions::default().route("/api/timeline/{id}"),
+        )
+    }
+
+    /// DELETE /api/timeline/{id}
+    pub fn delete_timeline(&self, id: TimelineId) -> Result<()> {
+        let id = PathParam::from(id);
+        let segments = ["api", "timeline", id.as_str()];
+        self.client
+            .request_json::<IgnoredAny, _, _>(
+                Method::DELETE,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/timeline/{id}"),
+            )
+            .map(|_| ())
+    }
+
     /// GET /api/timeline/
     pub fn get(&self, query: Option<&Value>) -> Result<Value> {
         let segments = ["api", "timeline"];
@@ -151,7 +386,7 @@ impl BlockingTimelineService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/timeline/"),
         )
     }
 
@@ -163,7 +398,7 @@ impl BlockingTimelineService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/timeline/"),
         )
     }
 
@@ -175,7 +410,7 @@ impl BlockingTimelineService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/timeline/collection/root"),
         )
     }
 
@@ -192,10 +427,21 @@ impl BlockingTimelineService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/timeline/collection/{id}"),
         )
     }
 
+    /// GET /api/timeline/collection/{id}
+    ///
+    /// Typed-id variant of [`Self::get_collection_by_id`].
+    pub fn get_collection_by_id_typed(
+        &self,
+        id: CollectionId,
+        query: Option<&Value>,
+    ) -> Result<Value> {
+        self.get_collection_by_id(id, query)
+    }
+
     /// DELETE /api/timeline/{id}
     pub fn delete_by_id(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -205,10 +451,17 @@ impl BlockingTimelineService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/timeline/{id}"),
         )
     }
 
+    /// DELETE /api/timeline/{id}
+    ///
+    /// Typed-id variant of [`Self::delete_by_id`].
+    pub fn delete_by_id_typed(&self, id: TimelineId) -> Result<Value> {
+        self.delete_by_id(id)
+    }
+
     /// GET /api/timeline/{id}
     pub fn get_by_id(&self, id: impl Into<PathParam>, query: Option<&Value>) -> Result<Value> {
         let id = id.into();
@@ -218,10 +471,17 @@ impl BlockingTimelineService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/timeline/{id}"),
         )
     }
 
+    /// GET /api/timeline/{id}
+    ///
+    /// Typed-id variant of [`Self::get_by_id`].
+    pub fn get_by_id_typed(&self, id: TimelineId, query: Option<&Value>) -> Result<Value> {
+        self.get_by_id(id, query)
+    }
+
     /// PUT /api/timeline/{id}
     pub fn put_by_id(&self, id: impl Into<PathParam>, body: Option<&Value>) -> Result<Value> {
         let id = id.into();
@@ -231,7 +491,14 @@ impl BlockingTimelineService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/timeline/{id}"),
         )
     }
+
+    /// PUT /api/timeline/{id}
+    ///
+    /// Typed-id variant of [`Self::put_by_id`].
+    pub fn put_by_id_typed(&self, id: TimelineId, body: Option<&Value>) -> Result<Value> {
+        self.put_by_id(id, body)
+    }
 }
@@ -1,7 +1,19 @@
 use http::Method;
+use serde::de::IgnoredAny;
 use serde_json::Value;
 
-use crate::{Result, client::RequestOptions, types::path::PathParam};
+use crate::{
+    Result,
+    client::RequestOptions,
+    types::{
+        ids::{TimelineEventId, TimelineId},
+        path::PathParam,
+        timeline::{
+            CreateTimelineEvent, EventUpsert, Timeline, TimelineEvent, TimelineQuery,
+            UpdateTimelineEvent, event_timelines, plan_event_upserts,
+        },
+    },
+};
 
 #[cfg(feature = "blocking")]
 use crate::client::BlockingClient;
@@ -20,6 +32,117 @@ impl TimelineEventService {
         Self { client }
     }
 
+    /// GET /api/timeline-event/{id}
+    pub async fn get_timeline_event(&self, id: TimelineEventId) -> Result<TimelineEvent> {
+        let id = PathParam::from(id);
+        let segments = ["api", "timeline-event", id.as_str()];
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/timeline-event/{id}"),
+            )
+            .await
+    }
+
+    /// POST /api/timeline-event/
+    pub async fn create_timeline_event(
+        &self,
+        event: &CreateTimelineEvent,
+    ) -> Result<TimelineEvent> {
+        let segments = ["api", "timeline-event"];
+        self.client
+            .request_json(
+                Method::POST,
+                &segments,
+                Option::<&()>::None,
+                Some(event),
+                RequestOptions::default().route("/api/timeline-event/"),
+            )
+            .await
+    }
+
+    /// PUT /api/timeline-event/{id}
+    pub async fn update_timeline_event(
+        &self,
+        id: TimelineEventId,
+        update: &UpdateTimelineEvent,
+    ) -> Result<TimelineEvent> {
+        let id = PathParam::from(id);
+        let segments = ["api", "timeline-event", id.as_str()];
+        self.client
+            .request_json(
+                Method::PUT,
+                &segments,
+                Option::<&()>::None,
+                Some(update),
+                RequestOptions::default().route("/api/timeline-event/{id}"),
+            )
+            .await
+    }
+
+    /// DELETE /api/timeline-event/{id}
+    pub async fn delete_timeline_event(&self, id: TimelineEventId) -> Result<()> {
+        let id = PathParam::from(id);
+        let segments = ["api", "timeline-event", id.as_str()];
+        self.client
+            .request_json::<IgnoredAny, _, _>(
+                Method::DELETE,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/timeline-event/{id}"),
+            )
+            .await
+            .map(|_| ())
+    }
+
+    /// Create or update `events`, matching existing events by timeline, name and timestamp.
+    ///
+    /// Each timeline is fetched once with its events. Events that already match are
+    /// returned without a write, so repeating a call is a no-op. An unset description or
+    /// icon leaves the existing one in place. Results follow the order of `events`.
+    pub async fn upsert_timeline_events(
+        &self,
+        events: &[CreateTimelineEvent],
+    ) -> Result<Vec<TimelineEvent>> {
+        let mut existing = Vec::new();
+        for timeline in event_timelines(events) {
+            existing.extend(self.timeline_events(timeline).await?);
+        }
+        let mut upserted = Vec::with_capacity(events.len());
+        for step in plan_event_upserts(&existing, events) {
+            upserted.push(match step {
+                EventUpsert::Unchanged(event) => event.clone(),
+                EventUpsert::Update(id, event) => {
+                    self.update_timeline_event(id, &UpdateTimelineEvent::from(event))
+                        .await?
+                }
+                EventUpsert::Create(event) => self.create_timeline_event(event).await?,
+            });
+        }
+        Ok(upserted)
+    }
+
+    /// GET /api/timeline/{id}
+    async fn timeline_events(&self, timeline: TimelineId) -> Result<Vec<TimelineEvent>> {
+        let id = PathParam::from(timeline);
+        let segments = ["api", "timeline", id.as_str()];
+        let timeline: Timeline = self
+            .client
+            .request_json(
+                Method::GET,
+                &segments,
+                Some(&TimelineQuery::new().events()),
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/timeline/{id}"),
+            )
+            .await?;
+        Ok(timeline.events)
+    }
+
     /// POST /api/timeline-event/
     pub async fn post(&self, body: Option<&Value>) -> Result<Value> {
         let segments = ["api", "timeline-event"];
@@ -29,7 +152,7 @@ impl TimelineEventService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/timeline-event/"),
             )
             .await
     }
@@ -44,11 +167,18 @@ impl TimelineEventService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/timeline-event/{id}"),
             )
             .await
     }
 
+    /// DELETE /api/timeline-event/{id}
+    ///
+    /// Typed-id variant of [`Self::delete_by_id`].
+    pub async fn delete_by_id_typed(&self, id: TimelineEventId) -> Result<Value> {
+        self.delete_by_id(id).await
+    }
+
     /// GET /api/timeline-event/{id}
     pub async fn get_by_id(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -59,11 +189,18 @@ impl TimelineEventService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/timeline-event/{id}"),
             )
             .await
     }
 
+    /// GET /api/timeline-event/{id}
+    ///
+    /// Typed-id variant of [`Self::get_by_id`].
+    pub async fn get_by_id_typed(&self, id: TimelineEventId) -> Result<Value> {
+        self.get_by_id(id).await
+    }
+
     /// PUT /api/timeline-event/{id}
     pub async fn put_by_id(&self, id: impl Into<PathParam>, body: Option<&Value>) -> Result<Value> {
         let id = id.into();
@@ -74,10 +211,21 @@ impl TimelineEventService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/timeline-event/{id}"),
             )
             .await
     }
+
+    /// PUT /api/timeline-event/{id}
+    ///
+    /// Typed-id variant of [`Self::put_by_id`].
+    pub async fn put_by_id_typed(
+        &self,
+        id: TimelineEventId,
+        body: Option<&Value>,
+    ) -> Result<Value> {
+        self.put_by_id(id, body).await
+    }
 }
 
 #[cfg(feature = "blocking")]
@@ -92,6 +240,103 @@ impl BlockingTimelineEventService {
         Self { client }
     }
 
+    /// GET /api/timeline-event/{id}
+    pub fn get_timeline_event(&self, id: TimelineEventId) -> Result<TimelineEvent> {
+        let id = PathParam::from(id);
+        let segments = ["api", "timeline-event", id.as_str()];
+        self.client.request_json(
+            Method::GET,
+            &segments,
+            Option::<&()>::None,
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/timeline-event/{id}"),
+        )
+    }
+
+    /// POST /api/timeline-event/
+    pub fn create_timeline_event(&self, event: &CreateTimelineEvent) -> Result<TimelineEvent> {
+        let segments = ["api", "timeline-event"];
+        self.client.request_json(
+            Method::POST,
+            &segments,
+            Option::<&()>::None,
+            Some(event),
+            RequestOptions::default().route("/api/timeline-event/"),
+        )
+    }
+
+    /// PUT /api/timeline-event/{id}
+    pub fn update_timeline_event(
+        &self,
+        id: TimelineEventId,
+        update: &UpdateTimelineEvent,
+    ) -> Result<TimelineEvent> {
+        let id = PathParam::from(id);
+        let segments = ["api", "timeline-event", id.as_str()];
+        self.client.request_json(
+            Method::PUT,
+            &segments,
+            Option::<&()>::None,
+            Some(update),
+            RequestOptions::default().route("/api/timeline-event/{id}"),
+        )
+    }
+
+    /// DELETE /api/timeline-event/{id}
+    pub fn delete_timeline_event(&self, id: TimelineEventId) -> Result<()> {
+        let id = PathParam::from(id);
+        let segments = ["api", "timeline-event", id.as_str()];
+        self.client
+            .request_json::<IgnoredAny, _, _>(
+                Method::DELETE,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/timeline-event/{id}"),
+            )
+            .map(|_| ())
+    }
+
+    /// Create or update `events`, matching existing events by timeline, name and timestamp.
+    ///
+    /// Each timeline is fetched once with its events. Events that already match are
+    /// returned without a write, so repeating a call is a no-op. An unset description or
+    /// icon leaves the existing one in place. Results follow the order of `events`.
+    pub fn upsert_timeline_events(
+        &self,
+        events: &[CreateTimelineEvent],
+    ) -> Result<Vec<TimelineEvent>> {
+        let mut existing = Vec::new();
+        for timeline in event_timelines(events) {
+            existing.extend(self.timeline_events(timeline)?);
+        }
+        let mut upserted = Vec::with_capacity(events.len());
+        for step in plan_event_upserts(&existing, events) {
+            upserted.push(match step {
+                EventUpsert::Unchanged(event) => event.clone(),
+                EventUpsert::Update(id, event) => {
+                    self.update_timeline_event(id, &UpdateTimelineEvent::from(event))?
+                }
+                EventUpsert::Create(event) => self.create_timeline_event(event)?,
+            });
+        }
+        Ok(upserted)
+    }
+
+    /// GET /api/timeline/{id}
+    fn timeline_events(&self, timeline: TimelineId) -> Result<Vec<TimelineEvent>> {
+        let id = PathParam::from(timeline);
+        let segments = ["api", "timeline", id.as_str()];
+        let timeline: Timeline = self.client.request_json(
+            Method::GET,
+            &segments,
+            Some(&TimelineQuery::new().events()),
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/timeline/{id}"),
+        )?;
+        Ok(timeline.events)
+    }
+
     /// POST /api/timeline-event/
     pub fn post(&self, body: Option<&Value>) -> Result<Value> {
         let segments = ["api", "timeline-event"];
@@ -100,7 +345,7 @@ impl BlockingTimelineEventService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/timeline-event/"),
         )
     }
 
@@ -113,10 +358,17 @@ impl BlockingTimelineEventService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/timeline-event/{id}"),
         )
     }
 
+    /// DELETE /api/timeline-event/{id}
+    ///
+    /// Typed-id variant of [`Self::delete_by_id`].
+    pub fn delete_by_id_typed(&self, id: TimelineEventId) -> Result<Value> {
+        self.delete_by_id(id)
+    }
+
     /// GET /api/timeline-event/{id}
     pub fn get_by_id(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -126,10 +378,17 @@ impl BlockingTimelineEventService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/timeline-event/{id}"),
         )
     }
 
+    /// GET /api/timeline-event/{id}
+    ///
+    /// Typed-id variant of [`Self::get_by_id`].
+    pub fn get_by_id_typed(&self, id: TimelineEventId) -> Result<Value> {
+        self.get_by_id(id)
+    }
+
     /// PUT /api/timeline-event/{id}
     pub fn put_by_id(&self, id: impl Into<PathParam>, body: Option<&Value>) -> Result<Value> {
         let id = id.into();
@@ -139,7 +398,14 @@ impl BlockingTimelineEventService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/timeline-event/{id}"),
         )
     }
+
+    /// PUT /api/timeline-event/{id}
+    ///
+    /// Typed-id variant of [`Self::put_by_id`].
+    pub fn put_by_id_typed(&self, id: TimelineEventId, body: Option<&Value>) -> Result<Value> {
+        self.put_by_id(id, body)
+    }
 }
@@ -29,7 +29,7 @@ impl UploadService {
                 &segments,
                 Option::<&()>::None,
                 form,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/upload/csv"),
             )
             .await
     }
@@ -55,7 +55,7 @@ impl BlockingUploadService {
             &segments,
             Option::<&()>::None,
             form,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/upload/csv"),
         )
     }
 }
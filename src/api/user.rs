@@ -1,7 +1,16 @@
 use http::Method;
+use serde::de::IgnoredAny;
 use serde_json::Value;
 
-use crate::{Result, client::RequestOptions, types::path::PathParam};
+use crate::{
+    Result,
+    client::RequestOptions,
+    types::{
+        ids::UserId,
+        path::PathParam,
+        user::{ChangePassword, CreateUser, UpdateUser, User, UserList, UserListQuery},
+    },
+};
 
 #[cfg(feature = "blocking")]
 use crate::client::BlockingClient;
@@ -20,6 +29,125 @@ impl UserService {
         Self { client }
     }
 
+    /// GET /api/user/
+    pub async fn list_users(&self, query: &UserListQuery) -> Result<UserList> {
+        let segments = ["api", "user"];
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                Some(query),
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/user/"),
+            )
+            .await
+    }
+
+    /// GET /api/user/current
+    pub async fn current_user(&self) -> Result<User> {
+        let segments = ["api", "user", "current"];
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/user/current"),
+            )
+            .await
+    }
+
+    /// GET /api/user/{id}
+    pub async fn get_user(&self, id: UserId) -> Result<User> {
+        let id = PathParam::from(id);
+        let segments = ["api", "user", id.as_str()];
+        self.client
+            .request_json(
+                Method::GET,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/user/{id}"),
+            )
+            .await
+    }
+
+    /// POST /api/user/
+    pub async fn create_user(&self, user: &CreateUser) -> Result<User> {
+        let segments = ["api", "user"];
+        self.client
+            .request_json(
+                Method::POST,
+                &segments,
+                Option::<&()>::None,
+                Some(user),
+                RequestOptions::default().route("/api/user/"),
+            )
+            .await
+    }
+
+    /// PUT /api/user/{id}
+    pub async fn update_user(&self, id: UserId, update: &UpdateUser) -> Result<User> {
+        let id = PathParam::from(id);
+        let segments = ["api", "user", id.as_str()];
+        self.client
+            .request_json(
+                Method::PUT,
+                &segments,
+                Option::<&()>::None,
+                Some(update),
+                RequestOptions::default().route("/api/user/{id}"),
+            )
+            .await
+    }
+
+    /// DELETE /api/user/{id}
+    pub async fn deactivate_user(&self, id: UserId) -> Result<()> {
+        let id = PathParam::from(id);
+        let segments = ["api", "user", id.as_str()];
+        self.client
+            .request_json::<IgnoredAny, _, _>(
+                Method::DELETE,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/user/{id}"),
+            )
+            .await
+            .map(|_| ())
+    }
+
+    /// PUT /api/user/{id}/reactivate
+    pub async fn reactivate_user(&self, id: UserId) -> Result<User> {
+        let id = PathParam::from(id);
+        let segments = ["api", "user", id.as_str(), "reactivate"];
+        self.client
+            .request_json(
+                Method::PUT,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/user/{id}/reactivate"),
+            )
+            .await
+    }
+
+    /// PUT /api/user/{id}/password
+    pub async fn change_password(&self, id: UserId, change: &ChangePassword) -> Result<()> {
+        let id = PathParam::from(id);
+        let segments = ["api", "user", id.as_str(), "password"];
+        self.client
+            .request_json::<IgnoredAny, _, _>(
+                Method::PUT,
+                &segments,
+                Option::<&()>::None,
+                Some(change),
+                RequestOptions::default().route("/api/user/{id}/password"),
+            )
+            .await
+            .map(|_| ())
+    }
+
     /// GET /api/user/
     pub async fn get(&self, query: Option<&Value>) -> Result<Value> {
         let segments = ["api", "user"];
@@ -29,7 +157,7 @@ impl UserService {
                 &segments,
                 query,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/user/"),
             )
             .await
     }
@@ -43,7 +171,7 @@ impl UserService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/user/"),
             )
             .await
     }
@@ -57,7 +185,7 @@ impl UserService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/user/current"),
             )
             .await
     }
@@ -71,7 +199,7 @@ impl UserService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/user/recipients"),
             )
             .await
     }
@@ -86,11 +214,18 @@ impl UserService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/user/{id}"),
             )
             .await
     }
 
+    /// DELETE /api/user/{id}
+    ///
+    /// Typed-id variant of [`Self::delete_by_id`].
+    pub async fn delete_by_id_typed(&self, id: UserId) -> Result<Value> {
+        self.delete_by_id(id).await
+    }
+
     /// GET /api/user/{id}
     pub async fn get_by_id(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -101,11 +236,18 @@ impl UserService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/user/{id}"),
             )
             .await
     }
 
+    /// GET /api/user/{id}
+    ///
+    /// Typed-id variant of [`Self::get_by_id`].
+    pub async fn get_by_id_typed(&self, id: UserId) -> Result<Value> {
+        self.get_by_id(id).await
+    }
+
     /// PUT /api/user/{id}
     pub async fn put_by_id(&self, id: impl Into<PathParam>, body: Option<&Value>) -> Result<Value> {
         let id = id.into();
@@ -116,11 +258,18 @@ impl UserService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/user/{id}"),
             )
             .await
     }
 
+    /// PUT /api/user/{id}
+    ///
+    /// Typed-id variant of [`Self::put_by_id`].
+    pub async fn put_by_id_typed(&self, id: UserId, body: Option<&Value>) -> Result<Value> {
+        self.put_by_id(id, body).await
+    }
+
     /// PUT /api/user/{id}/modal/{modal}
     pub async fn put_by_id_modal_by_modal(
         &self,
@@ -136,11 +285,22 @@ impl UserService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/user/{id}/modal/{modal}"),
             )
             .await
     }
 
+    /// PUT /api/user/{id}/modal/{modal}
+    ///
+    /// Typed-id variant of [`Self::put_by_id_modal_by_modal`].
+    pub async fn put_by_id_modal_by_modal_typed(
+        &self,
+        id: UserId,
+        modal: impl Into<PathParam>,
+    ) -> Result<Value> {
+        self.put_by_id_modal_by_modal(id, modal).await
+    }
+
     /// PUT /api/user/{id}/password
     pub async fn put_by_id_password(
         &self,
@@ -155,11 +315,22 @@ impl UserService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/user/{id}/password"),
             )
             .await
     }
 
+    /// PUT /api/user/{id}/password
+    ///
+    /// Typed-id variant of [`Self::put_by_id_password`].
+    pub async fn put_by_id_password_typed(
+        &self,
+        id: UserId,
+        body: Option<&Value>,
+    ) -> Result<Value> {
+        self.put_by_id_password(id, body).await
+    }
+
     /// PUT /api/user/{id}/reactivate
     pub async fn put_by_id_reactivate(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -170,10 +341,17 @@ impl UserService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/user/{id}/reactivate"),
             )
             .await
     }
+
+    /// PUT /api/user/{id}/reactivate
+    ///
+    /// Typed-id variant of [`Self::put_by_id_reactivate`].
+    pub async fn put_by_id_reactivate_typed(&self, id: UserId) -> Result<Value> {
+        self.put_by_id_reactivate(id).await
+    }
 }
 
 #[cfg(feature = "blocking")]
@@ -188,6 +366,111 @@ impl BlockingUserService {
         Self { client }
     }
 
+    /// GET /api/user/
+    pub fn list_users(&self, query: &UserListQuery) -> Result<UserList> {
+        let segments = ["api", "user"];
+        self.client.request_json(
+            Method::GET,
+            &segments,
+            Some(query),
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/user/"),
+        )
+    }
+
+    /// GET /api/user/current
+    pub fn current_user(&self) -> Result<User> {
+        let segments = ["api", "user", "current"];
+        self.client.request_json(
+            Method::GET,
+            &segments,
+            Option::<&()>::None,
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/user/current"),
+        )
+    }
+
+    /// GET /api/user/{id}
+    pub fn get_user(&self, id: UserId) -> Result<User> {
+        let id = PathParam::from(id);
+        let segments = ["api", "user", id.as_str()];
+        self.client.request_json(
+            Method::GET,
+            &segments,
+            Option::<&()>::None,
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/user/{id}"),
+        )
+    }
+
+    /// POST /api/user/
+    pub fn create_user(&self, user: &CreateUser) -> Result<User> {
+        let segments = ["api", "user"];
+        self.client.request_json(
+            Method::POST,
+            &segments,
+            Option::<&()>::None,
+            Some(user),
+            RequestOptions::default().route("/api/user/"),
+        )
+    }
+
+    /// PUT /api/user/{id}
+    pub fn update_user(&self, id: UserId, update: &UpdateUser) -> Result<User> {
+        let id = PathParam::from(id);
+        let segments = ["api", "user", id.as_str()];
+        self.client.request_json(
+            Method::PUT,
+            &segments,
+            Option::<&()>::None,
+            Some(update),
+            RequestOptions::default().route("/api/user/{id}"),
+        )
+    }
+
+    /// DELETE /api/user/{id}
+    pub fn deactivate_user(&self, id: UserId) -> Result<()> {
+        let id = PathParam::from(id);
+        let segments = ["api", "user", id.as_str()];
+        self.client
+            .request_json::<IgnoredAny, _, _>(
+                Method::DELETE,
+                &segments,
+                Option::<&()>::None,
+                Option::<&()>::None,
+                RequestOptions::default().route("/api/user/{id}"),
+            )
+            .map(|_| ())
+    }
+
+    /// PUT /api/user/{id}/reactivate
+    pub fn reactivate_user(&self, id: UserId) -> Result<User> {
+        let id = PathParam::from(id);
+        let segments = ["api", "user", id.as_str(), "reactivate"];
+        self.client.request_json(
+            Method::PUT,
+            &segments,
+            Option::<&()>::None,
+            Option::<&()>::None,
+            RequestOptions::default().route("/api/user/{id}/reactivate"),
+        )
+    }
+
+    /// PUT /api/user/{id}/password
+    pub fn change_password(&self, id: UserId, change: &ChangePassword) -> Result<()> {
+        let id = PathParam::from(id);
+        let segments = ["api", "user", id.as_str(), "password"];
+        self.client
+            .request_json::<IgnoredAny, _, _>(
+                Method::PUT,
+                &segments,
+                Option::<&()>::None,
+                Some(change),
+                RequestOptions::default().route("/api/user/{id}/password"),
+            )
+            .map(|_| ())
+    }
+
     /// GET /api/user/
     pub fn get(&self, query: Option<&Value>) -> Result<Value> {
         let segments = ["api", "user"];
@@ -196,7 +479,7 @@ impl BlockingUserService {
             &segments,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/user/"),
         )
     }
 
@@ -208,7 +491,7 @@ impl BlockingUserService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/user/"),
         )
     }
 
@@ -220,7 +503,7 @@ impl BlockingUserService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/user/current"),
         )
     }
 
@@ -232,7 +515,7 @@ impl BlockingUserService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/user/recipients"),
         )
     }
 
@@ -245,10 +528,17 @@ impl BlockingUserService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/user/{id}"),
         )
     }
 
+    /// DELETE /api/user/{id}
+    ///
+    /// Typed-id variant of [`Self::delete_by_id`].
+    pub fn delete_by_id_typed(&self, id: UserId) -> Result<Value> {
+        self.delete_by_id(id)
+    }
+
     /// GET /api/user/{id}
     pub fn get_by_id(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -258,10 +548,17 @@ impl BlockingUserService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/user/{id}"),
         )
     }
 
+    /// GET /api/user/{id}
+    ///
+    /// Typed-id variant of [`Self::get_by_id`].
+    pub fn get_by_id_typed(&self, id: UserId) -> Result<Value> {
+        self.get_by_id(id)
+    }
+
     /// PUT /api/user/{id}
     pub fn put_by_id(&self, id: impl Into<PathParam>, body: Option<&Value>) -> Result<Value> {
         let id = id.into();
@@ -271,10 +568,17 @@ impl BlockingUserService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/user/{id}"),
         )
     }
 
+    /// PUT /api/user/{id}
+    ///
+    /// Typed-id variant of [`Self::put_by_id`].
+    pub fn put_by_id_typed(&self, id: UserId, body: Option<&Value>) -> Result<Value> {
+        self.put_by_id(id, body)
+    }
+
     /// PUT /api/user/{id}/modal/{modal}
     pub fn put_by_id_modal_by_modal(
         &self,
@@ -289,10 +593,21 @@ impl BlockingUserService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/user/{id}/modal/{modal}"),
         )
     }
 
+    /// PUT /api/user/{id}/modal/{modal}
+    ///
+    /// Typed-id variant of [`Self::put_by_id_modal_by_modal`].
+    pub fn put_by_id_modal_by_modal_typed(
+        &self,
+        id: UserId,
+        modal: impl Into<PathParam>,
+    ) -> Result<Value> {
+        self.put_by_id_modal_by_modal(id, modal)
+    }
+
     /// PUT /api/user/{id}/password
     pub fn put_by_id_password(
         &self,
@@ -306,10 +621,17 @@ impl BlockingUserService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/user/{id}/password"),
         )
     }
 
+    /// PUT /api/user/{id}/password
+    ///
+    /// Typed-id variant of [`Self::put_by_id_password`].
+    pub fn put_by_id_password_typed(&self, id: UserId, body: Option<&Value>) -> Result<Value> {
+        self.put_by_id_password(id, body)
+    }
+
     /// PUT /api/user/{id}/reactivate
     pub fn put_by_id_reactivate(&self, id: impl Into<PathParam>) -> Result<Value> {
         let id = id.into();
@@ -319,7 +641,14 @@ impl BlockingUserService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/user/{id}/reactivate"),
         )
     }
+
+    /// PUT /api/user/{id}/reactivate
+    ///
+    /// Typed-id variant of [`Self::put_by_id_reactivate`].
+    pub fn put_by_id_reactivate_typed(&self, id: UserId) -> Result<Value> {
+        self.put_by_id_reactivate(id)
+    }
 }
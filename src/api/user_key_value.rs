@@ -33,7 +33,7 @@ impl UserKeyValueService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/user-key-value/namespace/{namespace}"),
             )
             .await
     }
@@ -60,7 +60,8 @@ impl UserKeyValueService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default()
+                    .route("/api/user-key-value/namespace/{namespace}/key/{key}"),
             )
             .await
     }
@@ -87,7 +88,8 @@ impl UserKeyValueService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default()
+                    .route("/api/user-key-value/namespace/{namespace}/key/{key}"),
             )
             .await
     }
@@ -115,7 +117,8 @@ impl UserKeyValueService {
                 &segments,
                 Option::<&()>::None,
                 body,
-                RequestOptions::default(),
+                RequestOptions::default()
+                    .route("/api/user-key-value/namespace/{namespace}/key/{key}"),
             )
             .await
     }
@@ -142,7 +145,7 @@ impl BlockingUserKeyValueService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/user-key-value/namespace/{namespace}"),
         )
     }
 
@@ -167,7 +170,7 @@ impl BlockingUserKeyValueService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/user-key-value/namespace/{namespace}/key/{key}"),
         )
     }
 
@@ -192,7 +195,7 @@ impl BlockingUserKeyValueService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/user-key-value/namespace/{namespace}/key/{key}"),
         )
     }
 
@@ -218,7 +221,7 @@ impl BlockingUserKeyValueService {
             &segments,
             Option::<&()>::None,
             body,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/user-key-value/namespace/{namespace}/key/{key}"),
         )
     }
 }
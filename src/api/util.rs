@@ -29,7 +29,7 @@ impl UtilService {
                 &segments,
                 Option::<&()>::None,
                 Option::<&()>::None,
-                RequestOptions::default(),
+                RequestOptions::default().route("/api/util/random_token"),
             )
             .await
     }
@@ -55,7 +55,7 @@ impl BlockingUtilService {
             &segments,
             Option::<&()>::None,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route("/api/util/random_token"),
         )
     }
 }
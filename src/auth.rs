@@ -31,9 +31,13 @@ impl Auth {
 
     /// Create an auth config from a Metabase API key.
     pub fn api_key(key: impl Into<String>) -> Self {
-        Self::ApiKey {
-            key: SecretString::from(key.into()),
-        }
+        Self::api_key_secret(SecretString::from(key.into()))
+    }
+
+    /// Create an auth config from an API key already held as a [`SecretString`], such as
+    /// the one returned by key creation.
+    pub fn api_key_secret(key: SecretString) -> Self {
+        Self::ApiKey { key }
     }
 
     pub(crate) fn apply(&self, headers: &mut HeaderMap) -> Result<(), Error> {
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 
 use http::Method;
 use serde::{Serialize, de::DeserializeOwned};
@@ -7,10 +7,10 @@ use url::Url;
 use crate::{
     Result,
     auth::Auth,
-    client::{ClientConfig, RequestOptions},
+    client::{ClientConfig, EndpointHealth, EndpointPool, EndpointStrategy, RequestOptions},
     error::Error,
     transport::AsyncTransport,
-    types::multipart::MultipartForm,
+    types::{health::HealthResponse, multipart::MultipartForm, session::SessionProperties},
     util,
 };
 
@@ -22,42 +22,44 @@ pub struct Client {
 struct Inner {
     base_url: Url,
     auth: Auth,
+    server_info: OnceLock<Arc<SessionProperties>>,
     transport: AsyncTransport,
 }
 
 pub struct ClientBuilder {
-    base_url: Url,
+    base_urls: Vec<Url>,
     auth: Auth,
     config: ClientConfig,
 }
 
 impl Client {
     pub fn builder(base_url: impl AsRef<str>) -> Result<ClientBuilder> {
-        let base_url_str = base_url.as_ref().to_owned();
-        let base_url = Url::parse(&base_url_str)
-            .map_err(|source| Error::invalid_base_url(base_url_str.clone(), source))?;
-        if base_url.cannot_be_a_base() {
-            return Err(Error::invalid_base_url_config(
-                base_url_str.clone(),
-                "base_url must be hierarchical",
-            ));
-        }
-        if base_url.query().is_some() {
-            return Err(Error::invalid_base_url_config(
-                base_url_str.clone(),
-                "base_url must not include a query string",
-            ));
-        }
-        if base_url.fragment().is_some() {
+        Self::builder_with_base_urls([base_url])
+    }
+
+    /// Create a builder that routes across several Metabase replicas.
+    ///
+    /// The first url is the primary; see [`ClientBuilder::endpoint_strategy`] for how the others
+    /// are used. Idempotent requests that fail with a transport error are retried on
+    /// another replica.
+    pub fn builder_with_base_urls<I, S>(base_urls: I) -> Result<ClientBuilder>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let base_urls = base_urls
+            .into_iter()
+            .map(|base_url| util::parse_base_url(base_url.as_ref()))
+            .collect::<Result<Vec<_>>>()?;
+        if base_urls.is_empty() {
             return Err(Error::invalid_base_url_config(
-                base_url_str.clone(),
-                "base_url must not include a fragment",
+                "",
+                "at least one base_url is required",
             ));
         }
-        let base_url = util::normalize_base_url(base_url);
 
         Ok(ClientBuilder {
-            base_url,
+            base_urls,
             auth: Auth::none(),
             config: ClientConfig::default(),
         })
@@ -68,15 +70,82 @@ impl Client {
             inner: Arc::new(Inner {
                 base_url: self.inner.base_url.clone(),
                 auth,
+                server_info: OnceLock::new(),
                 transport: self.inner.transport.clone(),
             }),
         }
     }
 
+    /// The primary base url.
     pub fn base_url(&self) -> &Url {
         &self.inner.base_url
     }
 
+    /// Last observed health of every configured base url, primary first.
+    pub fn endpoints(&self) -> Vec<EndpointHealth> {
+        self.inner.transport.endpoints().health()
+    }
+
+    /// Probe every base url with `GET /api/health` and update its health.
+    ///
+    /// Endpoints that fail the probe are skipped by request routing until they recover
+    /// or their cooldown expires. Call this periodically to detect recovered replicas early.
+    pub async fn probe_endpoints(&self) -> Vec<EndpointHealth> {
+        let endpoints = self.inner.transport.endpoints();
+        for index in 0..endpoints.len() {
+            let healthy = match self.probe_endpoint(index).await {
+                Ok(health) => health.status == "ok",
+                Err(_) => false,
+            };
+            if healthy {
+                endpoints.mark_healthy(index);
+            } else {
+                endpoints.mark_unhealthy(index);
+            }
+        }
+        endpoints.health()
+    }
+
+    async fn probe_endpoint(&self, index: usize) -> Result<HealthResponse> {
+        let url = util::build_url(&self.inner.base_url, &["api", "health"])?;
+        self.inner
+            .transport
+            .execute_json(
+                Method::GET,
+                url,
+                &self.inner.auth,
+                Option::<&()>::None,
+                RequestOptions::default()
+                    .route("/api/health")
+                    .endpoint(index),
+            )
+            .await
+    }
+
+    /// Snapshot of request, error, retry and latency stats.
+    ///
+    /// Returns `None` unless stats collection was enabled with `collect_stats(true)`.
+    /// Clients derived through [`with_auth`](Self::with_auth) share the same counters.
+    pub fn stats(&self) -> Option<crate::client::ClientStats> {
+        self.inner.transport.stats()
+    }
+
+    /// Server version, licensed features and public settings, from
+    /// `GET /api/session/properties`.
+    ///
+    /// Fetched on first use and cached for the lifetime of this client; failures are not
+    /// cached. Clients derived through [`with_auth`](Self::with_auth) fetch their own, since
+    /// visible settings depend on the caller.
+    pub async fn server_info(&self) -> Result<Arc<SessionProperties>> {
+        if let Some(info) = self.inner.server_info.get() {
+            return Ok(Arc::clone(info));
+        }
+        let info = self.session().properties().await?;
+        Ok(Arc::clone(
+            self.inner.server_info.get_or_init(|| Arc::new(info)),
+        ))
+    }
+
     pub(crate) async fn request_json<T, Q, B>(
         &self,
         method: Method,
@@ -138,7 +207,12 @@ impl Client {
             .await
     }
 
-    pub(crate) async fn get_json<T, Q>(&self, path: &[&str], query: Option<&Q>) -> Result<T>
+    pub(crate) async fn get_json<T, Q>(
+        &self,
+        route: &'static str,
+        path: &[&str],
+        query: Option<&Q>,
+    ) -> Result<T>
     where
         T: DeserializeOwned,
         Q: Serialize + ?Sized,
@@ -148,12 +222,17 @@ impl Client {
             path,
             query,
             Option::<&()>::None,
-            RequestOptions::default(),
+            RequestOptions::default().route(route),
         )
         .await
     }
 
-    pub(crate) async fn post_json<T, B>(&self, path: &[&str], body: &B) -> Result<T>
+    pub(crate) async fn post_json<T, B>(
+        &self,
+        route: &'static str,
+        path: &[&str],
+        body: &B,
+    ) -> Result<T>
     where
         T: DeserializeOwned,
         B: Serialize + ?Sized,
@@ -163,13 +242,14 @@ impl Client {
             path,
             Option::<&()>::None,
             Some(body),
-            RequestOptions::default(),
+            RequestOptions::default().route(route),
         )
         .await
     }
 
     pub(crate) async fn post_json_with_options<T, B>(
         &self,
+        route: &'static str,
         path: &[&str],
         body: &B,
         options: RequestOptions,
@@ -178,8 +258,14 @@ impl Client {
         T: DeserializeOwned,
         B: Serialize + ?Sized,
     {
-        self.request_json(Method::POST, path, Option::<&()>::None, Some(body), options)
-            .await
+        self.request_json(
+            Method::POST,
+            path,
+            Option::<&()>::None,
+            Some(body),
+            options.route(route),
+        )
+        .await
     }
 }
 
@@ -220,12 +306,65 @@ impl ClientBuilder {
         self
     }
 
+    /// Record the concrete request path (e.g. `/api/card/42/query`) on tracing spans.
+    ///
+    /// Disabled by default: spans and metrics are labelled with the endpoint's route
+    /// template only, since concrete paths carry ids, UUIDs and embed tokens.
+    pub fn record_request_path(mut self, enabled: bool) -> Self {
+        self.config.telemetry.record_path = enabled;
+        self
+    }
+
+    /// Configure how secrets in request paths and query strings are masked.
+    ///
+    /// Defaults to [`RedactionPolicy::standard`](crate::RedactionPolicy::standard).
+    pub fn redaction_policy(mut self, policy: crate::client::RedactionPolicy) -> Self {
+        self.config.redaction = policy;
+        self
+    }
+
+    /// Keep in-process request stats, readable through `stats()`.
+    ///
+    /// Disabled by default, in which case no bookkeeping happens on the request path.
+    pub fn collect_stats(mut self, enabled: bool) -> Self {
+        self.config.collect_stats = enabled;
+        self
+    }
+
+    /// Capture every request/response exchange into `recorder`.
+    ///
+    /// Intended for debugging; captured data is redacted with the configured
+    /// [`RedactionPolicy`](crate::RedactionPolicy) but bodies are kept in memory.
+    pub fn debug_recorder(mut self, recorder: crate::client::DebugRecorder) -> Self {
+        self.config.debug = Some(recorder);
+        self
+    }
+
+    /// Choose how requests are spread across base urls (default: primary with failover).
+    pub fn endpoint_strategy(mut self, strategy: EndpointStrategy) -> Self {
+        self.config.endpoints.strategy = strategy;
+        self
+    }
+
+    /// How long a base url is skipped after a transport error or failed probe (default: 30s).
+    pub fn unhealthy_cooldown(mut self, cooldown: std::time::Duration) -> Self {
+        self.config.endpoints.unhealthy_cooldown = cooldown;
+        self
+    }
+
     pub fn build(self) -> Result<Client> {
-        let transport = AsyncTransport::new(&self.config)?;
+        let base_url = self.base_urls[0].clone();
+        let endpoints = EndpointPool::new(
+            self.base_urls,
+            self.config.endpoints.strategy,
+            self.config.endpoints.unhealthy_cooldown,
+        );
+        let transport = AsyncTransport::new(&self.config, Arc::new(endpoints))?;
         Ok(Client {
             inner: Arc::new(Inner {
-                base_url: self.base_url,
+                base_url,
                 auth: self.auth,
+                server_info: OnceLock::new(),
                 transport,
             }),
         })
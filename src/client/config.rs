@@ -2,7 +2,7 @@ use std::time::Duration;
 
 use crate::util;
 
-use super::RetryPolicy;
+use super::{DebugRecorder, EndpointStrategy, RedactionPolicy, RetryPolicy, endpoints};
 
 #[derive(Clone)]
 pub(crate) struct TimeoutConfig {
@@ -38,9 +38,34 @@ impl Default for BodySnippetConfig {
     }
 }
 
+#[derive(Clone)]
+pub(crate) struct EndpointConfig {
+    pub(crate) strategy: EndpointStrategy,
+    pub(crate) unhealthy_cooldown: Duration,
+}
+
+impl Default for EndpointConfig {
+    fn default() -> Self {
+        Self {
+            strategy: EndpointStrategy::default(),
+            unhealthy_cooldown: endpoints::DEFAULT_UNHEALTHY_COOLDOWN,
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+pub(crate) struct TelemetryConfig {
+    pub(crate) record_path: bool,
+}
+
 #[derive(Clone, Default)]
 pub(crate) struct ClientConfig {
     pub(crate) timeouts: TimeoutConfig,
     pub(crate) body_snippet: BodySnippetConfig,
     pub(crate) retry: RetryPolicy,
+    pub(crate) telemetry: TelemetryConfig,
+    pub(crate) redaction: RedactionPolicy,
+    pub(crate) collect_stats: bool,
+    pub(crate) debug: Option<DebugRecorder>,
+    pub(crate) endpoints: EndpointConfig,
 }
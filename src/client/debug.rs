@@ -0,0 +1,550 @@
+use std::{
+    fmt::Write as _,
+    sync::{Arc, Mutex, MutexGuard},
+    time::{Duration, Instant, SystemTime},
+};
+
+use http::{HeaderMap, Method, StatusCode};
+use serde_json::{Value, json};
+use time::OffsetDateTime;
+use url::Url;
+
+use crate::{client::RedactionPolicy, types::multipart::MultipartForm};
+
+const DEFAULT_MAX_EXCHANGES: usize = 256;
+const DEFAULT_MAX_BODY_BYTES: usize = 64 * 1024;
+
+/// Opt-in recorder for the HTTP exchanges a client makes.
+///
+/// Attach a recorder with `debug_recorder` on the client builder and keep a clone to read
+/// it back. Every attempt (including retries) is captured with the client's
+/// [`RedactionPolicy`] applied to urls, credentials headers and JSON bodies. Captured
+/// requests can be replayed with [`Exchange::to_curl`] and a session exported with
+/// [`DebugRecorder::to_har`].
+#[derive(Clone)]
+pub struct DebugRecorder {
+    inner: Arc<Mutex<RecorderState>>,
+}
+
+struct RecorderState {
+    exchanges: Vec<Exchange>,
+    max_exchanges: usize,
+    max_body_bytes: usize,
+}
+
+/// One captured request/response pair.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct Exchange {
+    pub started_at: SystemTime,
+    pub duration: Duration,
+    /// Route template of the endpoint, e.g. `/api/card/{id}`.
+    pub route: &'static str,
+    pub request: CapturedRequest,
+    /// `None` when the request failed before a response was received.
+    pub response: Option<CapturedResponse>,
+    /// Transport error message, when the request failed.
+    pub error: Option<String>,
+}
+
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct CapturedRequest {
+    pub method: Method,
+    /// Absolute url with secrets masked.
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Option<CapturedBody>,
+}
+
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum CapturedBody {
+    Json(String),
+    Multipart {
+        fields: Vec<(String, String)>,
+        /// `(field name, file name)` pairs; file contents are not captured.
+        files: Vec<(String, String)>,
+    },
+}
+
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct CapturedResponse {
+    pub status: StatusCode,
+    pub headers: Vec<(String, String)>,
+    /// Response body as text, truncated to the recorder's body limit.
+    pub body: String,
+    /// Size of the body as received, before truncation.
+    pub body_size: usize,
+}
+
+impl DebugRecorder {
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(RecorderState {
+                exchanges: Vec::new(),
+                max_exchanges: DEFAULT_MAX_EXCHANGES,
+                max_body_bytes: DEFAULT_MAX_BODY_BYTES,
+            })),
+        }
+    }
+
+    /// Keep at most `max` exchanges, dropping the oldest first (default: 256).
+    pub fn max_exchanges(self, max: usize) -> Self {
+        self.lock().max_exchanges = max;
+        self
+    }
+
+    /// Truncate captured bodies to `max` bytes (default: 64 KiB).
+    pub fn max_body_bytes(self, max: usize) -> Self {
+        self.lock().max_body_bytes = max;
+        self
+    }
+
+    /// All exchanges captured so far, oldest first.
+    pub fn exchanges(&self) -> Vec<Exchange> {
+        self.lock().exchanges.clone()
+    }
+
+    /// The most recently captured exchange.
+    pub fn last(&self) -> Option<Exchange> {
+        self.lock().exchanges.last().cloned()
+    }
+
+    pub fn clear(&self) {
+        self.lock().exchanges.clear();
+    }
+
+    /// Export the captured session as a HAR 1.2 document.
+    pub fn to_har(&self) -> Value {
+        let entries: Vec<Value> = self.lock().exchanges.iter().map(har_entry).collect();
+        json!({
+            "log": {
+                "version": "1.2",
+                "creator": {
+                    "name": env!("CARGO_PKG_NAME"),
+                    "version": env!("CARGO_PKG_VERSION"),
+                },
+                "entries": entries,
+            }
+        })
+    }
+
+    pub(crate) fn begin(
+        &self,
+        method: &Method,
+        url: &Url,
+        route: Option<&'static str>,
+        headers: &HeaderMap,
+        body: Option<CapturedBodySource<'_>>,
+        redaction: &RedactionPolicy,
+    ) -> PendingExchange {
+        let max_body_bytes = self.lock().max_body_bytes;
+
+        let mut captured_headers = capture_headers(headers, redaction);
+        let body = body.map(|body| match body {
+            CapturedBodySource::Json(bytes) => {
+                captured_headers.push(("Content-Type".to_owned(), "application/json".to_owned()));
+                CapturedBody::Json(redaction.redact_body(bytes, max_body_bytes, route))
+            }
+            CapturedBodySource::Multipart(form) => CapturedBody::Multipart {
+                fields: form
+                    .fields()
+                    .iter()
+                    .map(|(name, value)| {
+                        let value = if redaction.enabled && redaction.is_sensitive(name) {
+                            "<redacted>".to_owned()
+                        } else {
+                            value.clone()
+                        };
+                        (name.clone(), value)
+                    })
+                    .collect(),
+                files: form
+                    .files()
+                    .iter()
+                    .map(|file| (file.name().to_owned(), file.filename().to_owned()))
+                    .collect(),
+            },
+        });
+
+        PendingExchange {
+            recorder: self.clone(),
+            started_at: SystemTime::now(),
+            clock: Instant::now(),
+            route,
+            request: CapturedRequest {
+                method: method.clone(),
+                url: format!(
+                    "{}{}",
+                    url.origin().ascii_serialization(),
+                    redaction.redact_url(url, route)
+                ),
+                headers: captured_headers,
+                body,
+            },
+            redaction: redaction.clone(),
+            max_body_bytes,
+        }
+    }
+
+    fn push(&self, exchange: Exchange) {
+        let mut state = self.lock();
+        if state.max_exchanges == 0 {
+            return;
+        }
+        if state.exchanges.len() >= state.max_exchanges {
+            let overflow = state.exchanges.len() + 1 - state.max_exchanges;
+            state.exchanges.drain(..overflow);
+        }
+        state.exchanges.push(exchange);
+    }
+
+    fn lock(&self) -> MutexGuard<'_, RecorderState> {
+        self.inner
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Default for DebugRecorder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for DebugRecorder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DebugRecorder")
+            .field("exchanges", &self.lock().exchanges.len())
+            .finish()
+    }
+}
+
+impl Exchange {
+    /// Render the request as a copy-pasteable `curl` command.
+    ///
+    /// Redacted values stay redacted; substitute real credentials before running it.
+    pub fn to_curl(&self) -> String {
+        let request = &self.request;
+        let mut out = format!("curl -X {} {}", request.method, shell_quote(&request.url));
+        for (name, value) in &request.headers {
+            if matches!(&request.body, Some(CapturedBody::Multipart { .. }))
+                && name.eq_ignore_ascii_case("content-type")
+            {
+                continue;
+            }
+            let _ = write!(
+                out,
+                " \\\n  -H {}",
+                shell_quote(&format!("{name}: {value}"))
+            );
+        }
+        match &request.body {
+            None => {}
+            Some(CapturedBody::Json(text)) => {
+                let _ = write!(out, " \\\n  --data-raw {}", shell_quote(text));
+            }
+            Some(CapturedBody::Multipart { fields, files }) => {
+                for (name, value) in fields {
+                    let _ = write!(out, " \\\n  -F {}", shell_quote(&format!("{name}={value}")));
+                }
+                for (name, filename) in files {
+                    let _ = write!(
+                        out,
+                        " \\\n  -F {}",
+                        shell_quote(&format!("{name}=@{filename}"))
+                    );
+                }
+            }
+        }
+        out
+    }
+}
+
+pub(crate) enum CapturedBodySource<'a> {
+    Json(&'a [u8]),
+    Multipart(&'a MultipartForm),
+}
+
+/// An in-flight capture; finished exactly once per attempt.
+pub(crate) struct PendingExchange {
+    recorder: DebugRecorder,
+    started_at: SystemTime,
+    clock: Instant,
+    route: Option<&'static str>,
+    request: CapturedRequest,
+    redaction: RedactionPolicy,
+    max_body_bytes: usize,
+}
+
+impl PendingExchange {
+    pub(crate) fn response(self, status: StatusCode, headers: &HeaderMap, body: &[u8]) {
+        let response = CapturedResponse {
+            status,
+            headers: capture_headers(headers, &self.redaction),
+            body: self
+                .redaction
+                .redact_body(body, self.max_body_bytes, self.route),
+            body_size: body.len(),
+        };
+        self.finish(Some(response), None);
+    }
+
+    pub(crate) fn error(self, err: &dyn std::error::Error) {
+        self.finish(None, Some(err.to_string()));
+    }
+
+    fn finish(self, response: Option<CapturedResponse>, error: Option<String>) {
+        self.recorder.push(Exchange {
+            started_at: self.started_at,
+            duration: self.clock.elapsed(),
+            route: self.route.unwrap_or(crate::transport::UNKNOWN_ROUTE),
+            request: self.request,
+            response,
+            error,
+        });
+    }
+}
+
+fn capture_headers(headers: &HeaderMap, redaction: &RedactionPolicy) -> Vec<(String, String)> {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            let value = String::from_utf8_lossy(value.as_bytes());
+            (
+                name.as_str().to_owned(),
+                redaction.redact_header(name.as_str(), &value),
+            )
+        })
+        .collect()
+}
+
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
+
+fn har_entry(exchange: &Exchange) -> Value {
+    let request = &exchange.request;
+    let query_string: Vec<Value> = Url::parse(&request.url)
+        .map(|url| {
+            url.query_pairs()
+                .map(|(name, value)| json!({ "name": name, "value": value }))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut har_request = json!({
+        "method": request.method.as_str(),
+        "url": request.url,
+        "httpVersion": "HTTP/1.1",
+        "cookies": [],
+        "headers": har_headers(&request.headers),
+        "queryString": query_string,
+        "headersSize": -1,
+        "bodySize": -1,
+    });
+    match &request.body {
+        None => {}
+        Some(CapturedBody::Json(text)) => {
+            har_request["bodySize"] = json!(text.len());
+            har_request["postData"] = json!({ "mimeType": "application/json", "text": text });
+        }
+        Some(CapturedBody::Multipart { fields, files }) => {
+            let mut params: Vec<Value> = fields
+                .iter()
+                .map(|(name, value)| json!({ "name": name, "value": value }))
+                .collect();
+            params.extend(
+                files
+                    .iter()
+                    .map(|(name, filename)| json!({ "name": name, "fileName": filename })),
+            );
+            har_request["postData"] =
+                json!({ "mimeType": "multipart/form-data", "params": params });
+        }
+    }
+
+    let har_response = match &exchange.response {
+        Some(response) => {
+            let mime_type = response
+                .headers
+                .iter()
+                .find(|(name, _)| name.eq_ignore_ascii_case("content-type"))
+                .map(|(_, value)| value.as_str())
+                .unwrap_or("");
+            json!({
+                "status": response.status.as_u16(),
+                "statusText": response.status.canonical_reason().unwrap_or(""),
+                "httpVersion": "HTTP/1.1",
+                "cookies": [],
+                "headers": har_headers(&response.headers),
+                "content": {
+                    "size": response.body_size,
+                    "mimeType": mime_type,
+                    "text": response.body,
+                },
+                "redirectURL": "",
+                "headersSize": -1,
+                "bodySize": response.body_size,
+            })
+        }
+        None => json!({
+            "status": 0,
+            "statusText": "",
+            "httpVersion": "HTTP/1.1",
+            "cookies": [],
+            "headers": [],
+            "content": { "size": 0, "mimeType": "" },
+            "redirectURL": "",
+            "headersSize": -1,
+            "bodySize": -1,
+            "_error": exchange.error,
+        }),
+    };
+
+    let time = exchange.duration.as_secs_f64() * 1000.0;
+    json!({
+        "startedDateTime": format_started_at(exchange.started_at),
+        "time": time,
+        "request": har_request,
+        "response": har_response,
+        "cache": {},
+        "timings": { "send": 0, "wait": time, "receive": 0 },
+        "_route": exchange.route,
+    })
+}
+
+/// Format a capture time as RFC 3339 in UTC with millisecond precision.
+///
+/// Written out field by field, since `time`'s formatter can fail and HAR requires a value.
+fn format_started_at(time: SystemTime) -> String {
+    let time = OffsetDateTime::from(time);
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+        time.year(),
+        u8::from(time.month()),
+        time.day(),
+        time.hour(),
+        time.minute(),
+        time.second(),
+        time.millisecond()
+    )
+}
+
+fn har_headers(headers: &[(String, String)]) -> Vec<Value> {
+    headers
+        .iter()
+        .map(|(name, value)| json!({ "name": name, "value": value }))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error as StdError;
+
+    #[test]
+    fn har_started_date_time_is_rfc3339_utc() {
+        assert_eq!(
+            format_started_at(SystemTime::UNIX_EPOCH),
+            "1970-01-01T00:00:00.000Z"
+        );
+        let time = SystemTime::UNIX_EPOCH + Duration::from_millis(1_709_210_096_789);
+        assert_eq!(format_started_at(time), "2024-02-29T12:34:56.789Z");
+    }
+
+    #[test]
+    fn to_curl_quotes_and_redacts() -> Result<(), Box<dyn StdError + Send + Sync>> {
+        let recorder = DebugRecorder::new();
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "X-Metabase-Session",
+            http::HeaderValue::from_static("s3cr3t"),
+        );
+        let url = Url::parse("https://mb.example.com/api/session")?;
+        recorder
+            .begin(
+                &Method::POST,
+                &url,
+                Some("/api/session/"),
+                &headers,
+                Some(CapturedBodySource::Json(
+                    br#"{"username":"o'neil","password":"pw"}"#,
+                )),
+                &RedactionPolicy::standard(),
+            )
+            .error(&std::io::Error::other("connection refused"));
+
+        let exchange = recorder
+            .last()
+            .ok_or_else(|| std::io::Error::other("nothing captured"))?;
+        assert_eq!(
+            exchange.to_curl(),
+            "curl -X POST 'https://mb.example.com/api/session' \\\n  \
+             -H 'x-metabase-session: <redacted>' \\\n  \
+             -H 'Content-Type: application/json' \\\n  \
+             --data-raw '{\"password\":\"<redacted>\",\"username\":\"o'\\''neil\"}'"
+        );
+
+        let har = recorder.to_har();
+        assert_eq!(har["log"]["entries"][0]["response"]["status"], 0);
+        assert_eq!(
+            har["log"]["entries"][0]["response"]["_error"],
+            "connection refused"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn session_token_and_untemplated_paths_are_redacted()
+    -> Result<(), Box<dyn StdError + Send + Sync>> {
+        let recorder = DebugRecorder::new();
+        let url = Url::parse("https://mb.example.com/api/session/")?;
+        recorder
+            .begin(
+                &Method::POST,
+                &url,
+                Some("/api/session/"),
+                &HeaderMap::new(),
+                None,
+                &RedactionPolicy::standard(),
+            )
+            .response(
+                StatusCode::OK,
+                &HeaderMap::new(),
+                br#"{"id":"2d5e7c1b-8f3a-4e6d-9b0c-1a2b3c4d5e6f"}"#,
+            );
+        let exchange = recorder
+            .last()
+            .ok_or_else(|| std::io::Error::other("nothing captured"))?;
+        let response = exchange
+            .response
+            .ok_or_else(|| std::io::Error::other("no response captured"))?;
+        assert_eq!(response.body, r#"{"id":"<redacted>"}"#);
+
+        let url = Url::parse(
+            "https://mb.example.com/api/public/card/0b5a7e4c-1d2f-4a3b-9c8d-7e6f5a4b3c2d",
+        )?;
+        recorder
+            .begin(
+                &Method::GET,
+                &url,
+                None,
+                &HeaderMap::new(),
+                None,
+                &RedactionPolicy::standard(),
+            )
+            .error(&std::io::Error::other("connection refused"));
+        let exchange = recorder
+            .last()
+            .ok_or_else(|| std::io::Error::other("nothing captured"))?;
+        assert_eq!(exchange.route, "unknown");
+        assert_eq!(
+            exchange.request.url,
+            "https://mb.example.com/api/public/card/<redacted>"
+        );
+        Ok(())
+    }
+}
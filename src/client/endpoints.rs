@@ -0,0 +1,237 @@
+use std::{
+    sync::{
+        Mutex,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::{Duration, Instant},
+};
+
+use url::Url;
+
+/// How requests are spread across multiple base urls.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EndpointStrategy {
+    /// Send everything to the first healthy base url, in the order they were given.
+    #[default]
+    PrimaryWithFailover,
+    /// Rotate through healthy base urls.
+    RoundRobin,
+}
+
+/// Health of one configured base url, as last observed by the client.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct EndpointHealth {
+    pub base_url: Url,
+    pub healthy: bool,
+}
+
+pub(crate) const DEFAULT_UNHEALTHY_COOLDOWN: Duration = Duration::from_secs(30);
+
+/// The set of base urls a client routes to.
+///
+/// Request urls are always built against the primary (first) base url and rebased onto
+/// the selected endpoint per attempt, so a single-endpoint pool is a no-op.
+pub(crate) struct EndpointPool {
+    endpoints: Vec<Endpoint>,
+    strategy: EndpointStrategy,
+    cooldown: Duration,
+    next: AtomicUsize,
+}
+
+struct Endpoint {
+    base_url: Url,
+    unhealthy_until: Mutex<Option<Instant>>,
+}
+
+impl EndpointPool {
+    pub(crate) fn new(base_urls: Vec<Url>, strategy: EndpointStrategy, cooldown: Duration) -> Self {
+        Self {
+            endpoints: base_urls
+                .into_iter()
+                .map(|base_url| Endpoint {
+                    base_url,
+                    unhealthy_until: Mutex::new(None),
+                })
+                .collect(),
+            strategy,
+            cooldown,
+            next: AtomicUsize::new(0),
+        }
+    }
+
+    pub(crate) fn primary(&self) -> &Url {
+        &self.endpoints[0].base_url
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.endpoints.len()
+    }
+
+    /// Pick the endpoint for the next attempt.
+    ///
+    /// `pinned` forces a specific endpoint; `previous` is the endpoint the last attempt
+    /// of the same request went to, which is avoided when another healthy one exists.
+    pub(crate) fn select(&self, pinned: Option<usize>, previous: Option<usize>) -> usize {
+        if let Some(index) = pinned {
+            return index.min(self.endpoints.len() - 1);
+        }
+        if self.endpoints.len() == 1 {
+            return 0;
+        }
+
+        let now = Instant::now();
+        let start = match self.strategy {
+            EndpointStrategy::PrimaryWithFailover => 0,
+            EndpointStrategy::RoundRobin => {
+                self.next.fetch_add(1, Ordering::Relaxed) % self.endpoints.len()
+            }
+        };
+        let order = (0..self.endpoints.len()).map(|offset| (start + offset) % self.endpoints.len());
+
+        let mut fallback = None;
+        for index in order {
+            if !self.is_healthy_at(index, now) {
+                continue;
+            }
+            if Some(index) != previous {
+                return index;
+            }
+            fallback.get_or_insert(index);
+        }
+
+        // Nothing else is healthy: reuse the previous endpoint, or the one that has been
+        // unhealthy the longest.
+        fallback.unwrap_or_else(|| self.least_recently_failed())
+    }
+
+    /// Rebase a url built against the primary base url onto endpoint `index`.
+    pub(crate) fn rebase(&self, url: &Url, index: usize) -> Url {
+        if index == 0 {
+            return url.clone();
+        }
+        let primary = self.primary();
+        let Some(rest) = url.path().strip_prefix(primary.path()) else {
+            return url.clone();
+        };
+        let mut rebased = self.endpoints[index].base_url.clone();
+        let path = format!("{}{rest}", rebased.path());
+        rebased.set_path(&path);
+        rebased.set_query(url.query());
+        rebased
+    }
+
+    pub(crate) fn mark_unhealthy(&self, index: usize) {
+        if self.endpoints.len() == 1 {
+            return;
+        }
+        if let Some(endpoint) = self.endpoints.get(index) {
+            *lock(&endpoint.unhealthy_until) = Some(Instant::now() + self.cooldown);
+        }
+    }
+
+    pub(crate) fn mark_healthy(&self, index: usize) {
+        if self.endpoints.len() == 1 {
+            return;
+        }
+        if let Some(endpoint) = self.endpoints.get(index) {
+            *lock(&endpoint.unhealthy_until) = None;
+        }
+    }
+
+    pub(crate) fn health(&self) -> Vec<EndpointHealth> {
+        let now = Instant::now();
+        self.endpoints
+            .iter()
+            .enumerate()
+            .map(|(index, endpoint)| EndpointHealth {
+                base_url: endpoint.base_url.clone(),
+                healthy: self.is_healthy_at(index, now),
+            })
+            .collect()
+    }
+
+    fn is_healthy_at(&self, index: usize, now: Instant) -> bool {
+        match *lock(&self.endpoints[index].unhealthy_until) {
+            Some(until) => until <= now,
+            None => true,
+        }
+    }
+
+    fn least_recently_failed(&self) -> usize {
+        self.endpoints
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, endpoint)| *lock(&endpoint.unhealthy_until))
+            .map(|(index, _)| index)
+            .unwrap_or(0)
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error as StdError;
+
+    fn pool(strategy: EndpointStrategy) -> Result<EndpointPool, Box<dyn StdError + Send + Sync>> {
+        Ok(EndpointPool::new(
+            vec![
+                Url::parse("https://a.example.com/")?,
+                Url::parse("https://b.example.com/metabase/")?,
+                Url::parse("https://c.example.com/")?,
+            ],
+            strategy,
+            DEFAULT_UNHEALTHY_COOLDOWN,
+        ))
+    }
+
+    #[test]
+    fn primary_with_failover_skips_unhealthy() -> Result<(), Box<dyn StdError + Send + Sync>> {
+        let pool = pool(EndpointStrategy::PrimaryWithFailover)?;
+        assert_eq!(pool.select(None, None), 0);
+        pool.mark_unhealthy(0);
+        assert_eq!(pool.select(None, None), 1);
+        assert_eq!(pool.select(None, Some(1)), 2);
+        pool.mark_healthy(0);
+        assert_eq!(pool.select(None, None), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn round_robin_rotates() -> Result<(), Box<dyn StdError + Send + Sync>> {
+        let pool = pool(EndpointStrategy::RoundRobin)?;
+        let picks: Vec<usize> = (0..4).map(|_| pool.select(None, None)).collect();
+        assert_eq!(picks, [0, 1, 2, 0]);
+        Ok(())
+    }
+
+    #[test]
+    fn all_unhealthy_falls_back() -> Result<(), Box<dyn StdError + Send + Sync>> {
+        let pool = pool(EndpointStrategy::PrimaryWithFailover)?;
+        pool.mark_unhealthy(1);
+        pool.mark_unhealthy(2);
+        pool.mark_unhealthy(0);
+        assert_eq!(pool.select(None, None), 1);
+        assert!(pool.health().iter().all(|endpoint| !endpoint.healthy));
+        Ok(())
+    }
+
+    #[test]
+    fn rebase_keeps_path_and_query() -> Result<(), Box<dyn StdError + Send + Sync>> {
+        let pool = pool(EndpointStrategy::PrimaryWithFailover)?;
+        let url = Url::parse("https://a.example.com/api/card/1?ignore_view=true")?;
+        assert_eq!(
+            pool.rebase(&url, 1).as_str(),
+            "https://b.example.com/metabase/api/card/1?ignore_view=true"
+        );
+        assert_eq!(pool.rebase(&url, 0), url);
+        Ok(())
+    }
+}
@@ -1,6 +1,10 @@
 mod config;
+mod debug;
+mod endpoints;
 mod options;
+mod redaction;
 mod retry;
+mod stats;
 
 #[cfg(feature = "async")]
 mod async_client;
@@ -12,8 +16,15 @@ mod services_async;
 mod services_blocking;
 
 pub(crate) use config::ClientConfig;
+pub(crate) use debug::CapturedBodySource;
+pub use debug::{CapturedBody, CapturedRequest, CapturedResponse, DebugRecorder, Exchange};
+pub(crate) use endpoints::EndpointPool;
+pub use endpoints::{EndpointHealth, EndpointStrategy};
 pub use options::{IdempotencyKey, RequestOptions};
+pub use redaction::RedactionPolicy;
 pub use retry::{Jitter, RetryPolicy};
+pub(crate) use stats::StatsCollector;
+pub use stats::{ClientStats, LatencySummary, RouteStats};
 
 #[cfg(feature = "async")]
 pub use async_client::{Client, ClientBuilder};
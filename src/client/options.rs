@@ -5,6 +5,8 @@ use std::time::Duration;
 pub struct RequestOptions {
     pub(crate) timeout: Option<Duration>,
     pub(crate) idempotency_key: Option<IdempotencyKey>,
+    pub(crate) route: Option<&'static str>,
+    pub(crate) endpoint: Option<usize>,
 }
 
 impl RequestOptions {
@@ -21,6 +23,20 @@ impl RequestOptions {
         self.idempotency_key = Some(key);
         self
     }
+
+    /// Attach the endpoint's route template (e.g. `/api/card/{card-id}/query`).
+    ///
+    /// The template is used as a low-cardinality label for metrics and tracing.
+    pub(crate) fn route(mut self, route: &'static str) -> Self {
+        self.route = Some(route);
+        self
+    }
+
+    /// Pin the request to one base url, bypassing endpoint selection and retries.
+    pub(crate) fn endpoint(mut self, index: usize) -> Self {
+        self.endpoint = Some(index);
+        self
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -0,0 +1,398 @@
+const REDACTED: &str = "<redacted>";
+
+const SENSITIVE_HEADERS: [&str; 5] = [
+    "x-metabase-session",
+    "x-api-key",
+    "authorization",
+    "cookie",
+    "set-cookie",
+];
+
+/// Credentials header names that also mark a `name: value` pair in free-form text.
+const SENSITIVE_TEXT_MARKERS: [&str; 2] = ["authorization", "cookie"];
+
+/// Top-level body keys that hold a credential only on a given route, e.g. the session
+/// token returned as `id` by `POST /api/session/`.
+const SENSITIVE_ROUTE_KEYS: [(&str, &str); 1] = [("/api/session/", "id")];
+
+const DEFAULT_SENSITIVE_KEYS: [&str; 9] = [
+    "token",
+    "uuid",
+    "password",
+    "secret",
+    "secret_key",
+    "api_key",
+    "unmasked_key",
+    "session",
+    "jwt",
+];
+
+/// Controls how secrets embedded in request paths and query strings are masked.
+///
+/// Applied to [`Error::path`](crate::Error::path), `Debug`/`Display` output of errors, and
+/// tracing spans. Path segments are masked when the matching route template placeholder
+/// (e.g. `{token}` in `/api/embed/card/{token}/query`) names a sensitive key; query values
+/// are masked when their parameter name does.
+#[derive(Clone, Debug)]
+pub struct RedactionPolicy {
+    pub(crate) enabled: bool,
+    pub(crate) sensitive_keys: Vec<String>,
+}
+
+impl RedactionPolicy {
+    /// Mask embed tokens, public UUIDs, passwords and other well-known secrets.
+    pub fn standard() -> Self {
+        Self {
+            enabled: true,
+            sensitive_keys: DEFAULT_SENSITIVE_KEYS
+                .iter()
+                .map(|key| (*key).to_owned())
+                .collect(),
+        }
+    }
+
+    /// Report paths and query strings verbatim.
+    pub fn disabled() -> Self {
+        Self {
+            enabled: false,
+            sensitive_keys: Vec::new(),
+        }
+    }
+
+    /// Treat placeholders, query parameters and body keys named `key` as sensitive.
+    ///
+    /// Names are compared by their `_`, `-` or camelCase separated words, and match when
+    /// they end with the words of `key`: `signature` matches `x-signature` but not
+    /// `signature_version`. Matching is case-insensitive.
+    pub fn sensitive_key(mut self, key: impl Into<String>) -> Self {
+        self.sensitive_keys.push(key.into().to_ascii_lowercase());
+        self
+    }
+
+    pub(crate) fn redact_path(&self, path: &str, route: Option<&str>) -> String {
+        if !self.enabled {
+            return path.to_owned();
+        }
+
+        let mut segments: Vec<&str> = path.split('/').collect();
+        match route {
+            Some(route) => {
+                // Align from the end so a base url prefix (e.g. `/metabase`) is ignored.
+                let template: Vec<&str> = route.trim_end_matches('/').split('/').collect();
+                let concrete = path.trim_end_matches('/').split('/').count();
+                let offset = concrete.saturating_sub(template.len());
+                let mut masked = Vec::new();
+                for (index, placeholder) in template.iter().enumerate() {
+                    let Some(name) = placeholder
+                        .strip_prefix('{')
+                        .and_then(|p| p.strip_suffix('}'))
+                    else {
+                        continue;
+                    };
+                    if self.is_sensitive(name) {
+                        masked.push(offset + index);
+                    }
+                }
+                for index in masked {
+                    if let Some(segment) = segments.get_mut(index) {
+                        *segment = REDACTED;
+                    }
+                }
+            }
+            None => {
+                for segment in segments.iter_mut() {
+                    if looks_like_token(segment) {
+                        *segment = REDACTED;
+                    }
+                }
+            }
+        }
+        segments.join("/")
+    }
+
+    pub(crate) fn redact_query(&self, query: &str) -> String {
+        if !self.enabled {
+            return query.to_owned();
+        }
+
+        query
+            .split('&')
+            .map(|pair| match pair.split_once('=') {
+                Some((key, _)) if self.is_sensitive(key) => format!("{key}={REDACTED}"),
+                _ => pair.to_owned(),
+            })
+            .collect::<Vec<_>>()
+            .join("&")
+    }
+
+    pub(crate) fn redact_url(&self, url: &url::Url, route: Option<&str>) -> String {
+        let path = self.redact_path(url.path(), route);
+        match url.query() {
+            Some(query) => format!("{path}?{}", self.redact_query(query)),
+            None => path,
+        }
+    }
+
+    pub(crate) fn redact_header(&self, name: &str, value: &str) -> String {
+        if self.enabled && SENSITIVE_HEADERS.contains(&name.to_ascii_lowercase().as_str()) {
+            return REDACTED.to_owned();
+        }
+        value.to_owned()
+    }
+
+    /// Render a captured body as text, masking sensitive keys of JSON objects.
+    ///
+    /// `route` is the endpoint's template; routes that return a credential under a
+    /// generic key (see `SENSITIVE_ROUTE_KEYS`) have that key masked as well.
+    pub(crate) fn redact_body(&self, bytes: &[u8], limit: usize, route: Option<&str>) -> String {
+        let text = crate::util::body_snippet(bytes, limit);
+        if !self.enabled {
+            return text;
+        }
+        match serde_json::from_slice::<serde_json::Value>(bytes) {
+            Ok(mut value) => {
+                self.redact_json(&mut value);
+                if let serde_json::Value::Object(map) = &mut value {
+                    for (_, key) in SENSITIVE_ROUTE_KEYS
+                        .iter()
+                        .filter(|(template, _)| Some(*template) == route)
+                    {
+                        if let Some(value) = map.get_mut(*key) {
+                            *value = serde_json::Value::String(REDACTED.to_owned());
+                        }
+                    }
+                }
+                let text = value.to_string();
+                crate::util::body_snippet(text.as_bytes(), limit)
+            }
+            Err(_) => text,
+        }
+    }
+
+    /// Mask an error body snippet that may carry secrets.
+    ///
+    /// Applied whenever body snippet redaction is on, even with a disabled policy: the
+    /// standard keys are always masked, on top of any added with
+    /// [`Self::sensitive_key`]. JSON snippets are re-rendered with those keys masked; other
+    /// text is replaced whole only when it has a `key=value` or `key: value` pair for one.
+    pub(crate) fn redact_snippet(&self, snippet: &str) -> String {
+        let is_sensitive =
+            |name: &str| self.is_sensitive(name) || ends_with_any(name, DEFAULT_SENSITIVE_KEYS);
+        match serde_json::from_str::<serde_json::Value>(snippet) {
+            Ok(mut value) => {
+                let original = value.clone();
+                redact_json(&mut value, &is_sensitive);
+                if value == original {
+                    snippet.to_owned()
+                } else {
+                    value.to_string()
+                }
+            }
+            Err(_) if has_sensitive_pair(snippet, &is_sensitive) => REDACTED.to_owned(),
+            Err(_) => snippet.to_owned(),
+        }
+    }
+
+    fn redact_json(&self, value: &mut serde_json::Value) {
+        redact_json(value, &|name| self.is_sensitive(name));
+    }
+
+    pub(crate) fn is_sensitive(&self, name: &str) -> bool {
+        ends_with_any(name, self.sensitive_keys.iter().map(String::as_str))
+    }
+}
+
+fn redact_json(value: &mut serde_json::Value, is_sensitive: &dyn Fn(&str) -> bool) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if is_sensitive(key) {
+                    *value = serde_json::Value::String(REDACTED.to_owned());
+                } else {
+                    redact_json(value, is_sensitive);
+                }
+            }
+        }
+        serde_json::Value::Array(items) => {
+            for item in items {
+                redact_json(item, is_sensitive);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Lower-cased words of a name, split on `_`, `-`, `.` and camelCase boundaries.
+fn words(name: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut previous_lower = false;
+    for c in name.chars() {
+        if matches!(c, '_' | '-' | '.' | ' ') {
+            words.extend((!word.is_empty()).then(|| std::mem::take(&mut word)));
+            previous_lower = false;
+            continue;
+        }
+        if c.is_ascii_uppercase() && previous_lower {
+            words.push(std::mem::take(&mut word));
+        }
+        previous_lower = c.is_ascii_lowercase() || c.is_ascii_digit();
+        word.push(c.to_ascii_lowercase());
+    }
+    words.extend((!word.is_empty()).then_some(word));
+    words
+}
+
+/// Whether `name` ends with the words of one of `keys`.
+fn ends_with_any<'a>(name: &str, keys: impl IntoIterator<Item = &'a str>) -> bool {
+    let name = words(name);
+    keys.into_iter().any(|key| {
+        let key = words(key);
+        !key.is_empty() && name.ends_with(&key)
+    })
+}
+
+/// Whether `text` has a `key=value` or `key: value` pair whose key is sensitive, or names
+/// a credentials header.
+fn has_sensitive_pair(text: &str, is_sensitive: &dyn Fn(&str) -> bool) -> bool {
+    let is_word = |c: char| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.');
+    let mut rest = text;
+    while let Some(start) = rest.find(is_word) {
+        let tail = &rest[start..];
+        let end = tail.find(|c: char| !is_word(c)).unwrap_or(tail.len());
+        let (word, after) = tail.split_at(end);
+        let separated = after
+            .trim_start_matches(['"', '\'', ' '])
+            .starts_with(['=', ':']);
+        if separated && (is_sensitive(word) || ends_with_any(word, SENSITIVE_TEXT_MARKERS)) {
+            return true;
+        }
+        rest = after;
+    }
+    false
+}
+
+impl Default for RedactionPolicy {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+/// Heuristic used when no route template is known: JWTs and UUIDs.
+fn looks_like_token(segment: &str) -> bool {
+    if segment.starts_with("eyJ") && segment.matches('.').count() == 2 {
+        return true;
+    }
+    let bytes = segment.as_bytes();
+    bytes.len() == 36
+        && bytes.iter().enumerate().all(|(index, b)| match index {
+            8 | 13 | 18 | 23 => *b == b'-',
+            _ => b.is_ascii_hexdigit(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn redact_path_masks_sensitive_placeholders() {
+        let policy = RedactionPolicy::standard();
+        let path = policy.redact_path(
+            "/metabase/api/embed/card/eyJhbGciOiJIUzI1NiJ9.e30.sig/query/csv",
+            Some("/api/embed/card/{token}/query/{export-format}"),
+        );
+        assert_eq!(path, "/metabase/api/embed/card/<redacted>/query/csv");
+
+        let path = policy.redact_path("/api/card/42/query", Some("/api/card/{card-id}/query"));
+        assert_eq!(path, "/api/card/42/query");
+    }
+
+    #[test]
+    fn redact_path_falls_back_to_heuristics_without_route() {
+        let policy = RedactionPolicy::standard();
+        let path = policy.redact_path(
+            "/api/public/card/0b5a7e4c-1d2f-4a3b-9c8d-7e6f5a4b3c2d",
+            None,
+        );
+        assert_eq!(path, "/api/public/card/<redacted>");
+    }
+
+    #[test]
+    fn redact_query_masks_configured_keys() {
+        let policy = RedactionPolicy::standard().sensitive_key("Signature");
+        let query = policy.redact_query("token=abc&limit=10&x-signature=def");
+        assert_eq!(query, "token=<redacted>&limit=10&x-signature=<redacted>");
+    }
+
+    #[test]
+    fn disabled_policy_is_verbatim() {
+        let policy = RedactionPolicy::disabled();
+        assert_eq!(
+            policy.redact_path("/api/embed/card/abc", Some("/api/embed/card/{token}")),
+            "/api/embed/card/abc"
+        );
+        assert_eq!(policy.redact_query("token=abc"), "token=abc");
+    }
+
+    #[test]
+    fn redact_body_masks_nested_json_keys() {
+        let policy = RedactionPolicy::standard();
+        let body = policy.redact_body(
+            br#"{"username":"a","password":"pw","x":[{"token":"t"}]}"#,
+            1024,
+            None,
+        );
+        assert_eq!(
+            body,
+            r#"{"password":"<redacted>","username":"a","x":[{"token":"<redacted>"}]}"#
+        );
+        assert_eq!(policy.redact_header("X-API-KEY", "mb_key"), "<redacted>");
+        assert_eq!(policy.redact_header("Accept", "*/*"), "*/*");
+    }
+
+    #[test]
+    fn keys_match_by_trailing_words() {
+        let policy = RedactionPolicy::standard();
+        assert!(policy.is_sensitive("embedding-secret-key"));
+        assert!(policy.is_sensitive("sessionToken"));
+        assert!(policy.is_sensitive("X-API-KEY"));
+        assert!(policy.is_sensitive("public_uuid"));
+        assert!(!policy.is_sensitive("token-features"));
+        assert!(!policy.is_sensitive("has_session_expired"));
+        assert!(!policy.is_sensitive("signature"));
+
+        let body = policy.redact_body(
+            br#"{"token-features":{"sso":true},"premium-embedding-token":"t"}"#,
+            1024,
+            None,
+        );
+        assert_eq!(
+            body,
+            r#"{"premium-embedding-token":"<redacted>","token-features":{"sso":true}}"#
+        );
+    }
+
+    #[test]
+    fn snippets_drop_text_only_for_key_value_pairs() {
+        let policy = RedactionPolicy::disabled();
+        assert_eq!(policy.redact_snippet("Invalid UUID"), "Invalid UUID");
+        assert_eq!(
+            policy.redact_snippet("Session expired, log in again"),
+            "Session expired, log in again"
+        );
+        assert_eq!(policy.redact_snippet("bad request: token=abcd"), REDACTED);
+        assert_eq!(policy.redact_snippet("Authorization: Bearer x"), REDACTED);
+        assert_eq!(
+            policy.redact_snippet(r#"{"message":"no","password":"pw"}"#),
+            r#"{"message":"no","password":"<redacted>"}"#
+        );
+        assert_eq!(
+            policy
+                .clone()
+                .sensitive_key("signature")
+                .redact_snippet("x-signature: abc"),
+            REDACTED
+        );
+    }
+}
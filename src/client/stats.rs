@@ -0,0 +1,196 @@
+use std::{
+    collections::{BTreeMap, HashMap, VecDeque},
+    sync::{Mutex, MutexGuard},
+    time::Duration,
+};
+
+use http::Method;
+
+use crate::error::{Error, ErrorKind};
+
+/// Number of latency samples kept per route for percentile estimates.
+const LATENCY_WINDOW: usize = 1024;
+
+/// Point-in-time view of the requests a client has made.
+///
+/// Returned by `Client::stats` / `BlockingClient::stats` when stats collection is enabled
+/// on the builder. Counts are per logical call: retries are reported separately and are
+/// not counted as extra requests.
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct ClientStats {
+    pub requests: u64,
+    pub retries: u64,
+    pub errors: BTreeMap<ErrorKind, u64>,
+    /// Per-route breakdown, sorted by route template then method.
+    pub routes: Vec<RouteStats>,
+}
+
+/// Stats for a single endpoint, keyed by method and route template.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct RouteStats {
+    pub method: Method,
+    pub route: &'static str,
+    pub requests: u64,
+    pub errors: u64,
+    pub retries: u64,
+    pub latency: LatencySummary,
+}
+
+/// Latency percentiles over the most recent calls to a route.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct LatencySummary {
+    pub samples: usize,
+    pub p50: Duration,
+    pub p90: Duration,
+    pub p99: Duration,
+    pub max: Duration,
+}
+
+#[derive(Default)]
+pub(crate) struct StatsCollector {
+    state: Mutex<State>,
+}
+
+#[derive(Default)]
+struct State {
+    retries: u64,
+    errors: BTreeMap<ErrorKind, u64>,
+    routes: HashMap<(Method, &'static str), RouteState>,
+}
+
+#[derive(Default)]
+struct RouteState {
+    requests: u64,
+    errors: u64,
+    retries: u64,
+    latencies: VecDeque<Duration>,
+}
+
+impl StatsCollector {
+    pub(crate) fn record(
+        &self,
+        method: &Method,
+        route: &'static str,
+        error: Option<&Error>,
+        elapsed: Duration,
+    ) {
+        let mut state = self.lock();
+        if let Some(error) = error {
+            *state.errors.entry(error.kind()).or_default() += 1;
+        }
+
+        let entry = state.routes.entry((method.clone(), route)).or_default();
+        entry.requests += 1;
+        if error.is_some() {
+            entry.errors += 1;
+        }
+        if entry.latencies.len() == LATENCY_WINDOW {
+            entry.latencies.pop_front();
+        }
+        entry.latencies.push_back(elapsed);
+    }
+
+    pub(crate) fn record_retry(&self, method: &Method, route: &'static str) {
+        let mut state = self.lock();
+        state.retries += 1;
+        state
+            .routes
+            .entry((method.clone(), route))
+            .or_default()
+            .retries += 1;
+    }
+
+    pub(crate) fn snapshot(&self) -> ClientStats {
+        let state = self.lock();
+
+        let mut routes: Vec<RouteStats> = state
+            .routes
+            .iter()
+            .map(|((method, route), entry)| RouteStats {
+                method: method.clone(),
+                route,
+                requests: entry.requests,
+                errors: entry.errors,
+                retries: entry.retries,
+                latency: summarize(&entry.latencies),
+            })
+            .collect();
+        routes.sort_by(|a, b| {
+            a.route
+                .cmp(b.route)
+                .then_with(|| a.method.as_str().cmp(b.method.as_str()))
+        });
+
+        ClientStats {
+            requests: routes.iter().map(|route| route.requests).sum(),
+            retries: state.retries,
+            errors: state.errors.clone(),
+            routes,
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+fn summarize(latencies: &VecDeque<Duration>) -> LatencySummary {
+    let mut sorted: Vec<Duration> = latencies.iter().copied().collect();
+    sorted.sort_unstable();
+
+    let percentile = |p: usize| -> Duration {
+        if sorted.is_empty() {
+            return Duration::ZERO;
+        }
+        let rank = (p * sorted.len()).div_ceil(100).max(1);
+        sorted[rank.min(sorted.len()) - 1]
+    };
+
+    LatencySummary {
+        samples: sorted.len(),
+        p50: percentile(50),
+        p90: percentile(90),
+        p99: percentile(99),
+        max: sorted.last().copied().unwrap_or_default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snapshot_aggregates_per_route() {
+        let collector = StatsCollector::default();
+        for ms in 1..=100 {
+            collector.record(
+                &Method::GET,
+                "/api/card/{id}",
+                None,
+                Duration::from_millis(ms),
+            );
+        }
+        collector.record_retry(&Method::GET, "/api/card/{id}");
+        let err = Error::build_url(url::ParseError::EmptyHost);
+        collector.record(&Method::POST, "/api/card/", Some(&err), Duration::ZERO);
+
+        let stats = collector.snapshot();
+        assert_eq!(stats.requests, 101);
+        assert_eq!(stats.retries, 1);
+        assert_eq!(stats.errors.get(&ErrorKind::BuildUrl), Some(&1));
+
+        assert_eq!(stats.routes.len(), 2);
+        let card = &stats.routes[1];
+        assert_eq!(card.route, "/api/card/{id}");
+        assert_eq!(card.requests, 100);
+        assert_eq!(card.retries, 1);
+        assert_eq!(card.latency.p50, Duration::from_millis(50));
+        assert_eq!(card.latency.p99, Duration::from_millis(99));
+        assert_eq!(card.latency.max, Duration::from_millis(100));
+    }
+}
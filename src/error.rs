@@ -59,9 +59,70 @@ pub enum Error {
 
     #[error(transparent)]
     Decode(DecodeError),
+
+    #[error("invalid schedule ({schedule}): {reason}")]
+    InvalidSchedule {
+        schedule: String,
+        reason: &'static str,
+    },
+
+    #[error("invalid value for setting {key}")]
+    InvalidSetting {
+        key: String,
+        #[source]
+        source: serde_json::Error,
+    },
+
+    #[error("invalid parameter {parameter} for action {action}: {reason}")]
+    InvalidActionParameter {
+        action: crate::types::ids::ActionId,
+        parameter: String,
+        reason: &'static str,
+    },
+}
+
+/// Coarse classification of an [`Error`], one per variant.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    InvalidBaseUrl,
+    BuildUrl,
+    SerializeQuery,
+    InvalidHeaderValue,
+    Transport,
+    Auth,
+    NotFound,
+    Conflict,
+    RateLimited,
+    Api,
+    Decode,
+    InvalidSchedule,
+    InvalidSetting,
+    InvalidActionParameter,
 }
 
 impl Error {
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Error::InvalidBaseUrl { .. } | Error::InvalidBaseUrlConfig { .. } => {
+                ErrorKind::InvalidBaseUrl
+            }
+            Error::BuildUrl { .. } => ErrorKind::BuildUrl,
+            Error::SerializeQuery { .. } => ErrorKind::SerializeQuery,
+            Error::InvalidHeaderValue { .. } => ErrorKind::InvalidHeaderValue,
+            Error::Transport(_) => ErrorKind::Transport,
+            Error::Auth(_) => ErrorKind::Auth,
+            Error::NotFound(_) => ErrorKind::NotFound,
+            Error::Conflict(_) => ErrorKind::Conflict,
+            Error::RateLimited(_) => ErrorKind::RateLimited,
+            Error::Api(_) => ErrorKind::Api,
+            Error::Decode(_) => ErrorKind::Decode,
+            Error::InvalidSchedule { .. } => ErrorKind::InvalidSchedule,
+            Error::InvalidSetting { .. } => ErrorKind::InvalidSetting,
+            Error::InvalidActionParameter { .. } => ErrorKind::InvalidActionParameter,
+        }
+    }
+
     pub fn status(&self) -> Option<StatusCode> {
         match self {
             Error::Auth(err) | Error::NotFound(err) | Error::Conflict(err) | Error::Api(err) => {
@@ -166,6 +227,35 @@ impl Error {
         }
     }
 
+    pub(crate) fn invalid_schedule(
+        schedule: &crate::types::schedule::Schedule,
+        reason: &'static str,
+    ) -> Self {
+        Self::InvalidSchedule {
+            schedule: schedule.to_string(),
+            reason,
+        }
+    }
+
+    pub(crate) fn invalid_setting(key: impl Into<String>, source: serde_json::Error) -> Self {
+        Self::InvalidSetting {
+            key: key.into(),
+            source,
+        }
+    }
+
+    pub(crate) fn invalid_action_parameter(
+        action: crate::types::ids::ActionId,
+        parameter: impl Into<String>,
+        reason: &'static str,
+    ) -> Self {
+        Self::InvalidActionParameter {
+            action,
+            parameter: parameter.into(),
+            reason,
+        }
+    }
+
     pub(crate) fn invalid_header_value(
         header: &'static str,
         source: http::header::InvalidHeaderValue,
@@ -49,7 +49,10 @@ pub use auth::Auth;
 pub use client::BlockingClient;
 #[cfg(feature = "async")]
 pub use client::Client;
-pub use client::{IdempotencyKey, Jitter, RequestOptions, RetryPolicy};
-pub use error::Error;
+pub use client::{
+    ClientStats, DebugRecorder, EndpointStrategy, IdempotencyKey, Jitter, RedactionPolicy,
+    RequestOptions, RetryPolicy,
+};
+pub use error::{Error, ErrorKind};
 
 pub type Result<T> = std::result::Result<T, Error>;
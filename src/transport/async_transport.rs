@@ -3,12 +3,14 @@ use serde::{Serialize, de::DeserializeOwned};
 use serde_json::Value;
 use url::Url;
 
-#[cfg(feature = "metrics")]
-use std::time::Instant;
+use std::{sync::Arc, time::Instant};
 
 use crate::{
     auth::Auth,
-    client::{ClientConfig, RequestOptions},
+    client::{
+        CapturedBodySource, ClientConfig, ClientStats, DebugRecorder, EndpointPool,
+        RedactionPolicy, RequestOptions, StatsCollector,
+    },
     error::{ApiError, DecodeError, Error},
     types::multipart::MultipartForm,
     util,
@@ -21,10 +23,16 @@ pub(crate) struct AsyncTransport {
     body_snippet_limit: usize,
     redact_body_snippet: bool,
     retry: crate::client::RetryPolicy,
+    #[cfg_attr(not(feature = "tracing"), allow(dead_code))]
+    record_path: bool,
+    redaction: RedactionPolicy,
+    stats: Option<Arc<StatsCollector>>,
+    debug: Option<DebugRecorder>,
+    endpoints: Arc<EndpointPool>,
 }
 
 impl AsyncTransport {
-    pub(crate) fn new(config: &ClientConfig) -> Result<Self, Error> {
+    pub(crate) fn new(config: &ClientConfig, endpoints: Arc<EndpointPool>) -> Result<Self, Error> {
         let _ = rustls::crypto::ring::default_provider().install_default();
 
         let user_agent = format!("{}/{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
@@ -42,9 +50,24 @@ impl AsyncTransport {
             body_snippet_limit: config.body_snippet.limit,
             redact_body_snippet: config.body_snippet.redact,
             retry: config.retry.clone(),
+            record_path: config.telemetry.record_path,
+            redaction: config.redaction.clone(),
+            stats: config
+                .collect_stats
+                .then(|| Arc::new(StatsCollector::default())),
+            debug: config.debug.clone(),
+            endpoints,
         })
     }
 
+    pub(crate) fn endpoints(&self) -> &EndpointPool {
+        &self.endpoints
+    }
+
+    pub(crate) fn stats(&self) -> Option<ClientStats> {
+        self.stats.as_ref().map(|stats| stats.snapshot())
+    }
+
     pub(crate) async fn execute_json<T, B>(
         &self,
         method: Method,
@@ -57,9 +80,83 @@ impl AsyncTransport {
         T: DeserializeOwned,
         B: Serialize + ?Sized,
     {
-        let path = url.path().to_owned();
-        let can_retry = super::retry::is_idempotent_method(&method)
-            || (method == Method::POST && options.idempotency_key.is_some());
+        let Some(stats) = &self.stats else {
+            return self.send_json(method, url, auth, body, options).await;
+        };
+        let route = options.route.unwrap_or(super::UNKNOWN_ROUTE);
+        let started_at = Instant::now();
+        let result = self
+            .send_json(method.clone(), url, auth, body, options)
+            .await;
+        stats.record(&method, route, result.as_ref().err(), started_at.elapsed());
+        result
+    }
+
+    pub(crate) async fn execute_bytes<B>(
+        &self,
+        method: Method,
+        url: Url,
+        auth: &Auth,
+        body: Option<&B>,
+        options: RequestOptions,
+    ) -> Result<Vec<u8>, Error>
+    where
+        B: Serialize + ?Sized,
+    {
+        let Some(stats) = &self.stats else {
+            return self.send_bytes(method, url, auth, body, options).await;
+        };
+        let route = options.route.unwrap_or(super::UNKNOWN_ROUTE);
+        let started_at = Instant::now();
+        let result = self
+            .send_bytes(method.clone(), url, auth, body, options)
+            .await;
+        stats.record(&method, route, result.as_ref().err(), started_at.elapsed());
+        result
+    }
+
+    pub(crate) async fn execute_multipart_json<T>(
+        &self,
+        method: Method,
+        url: Url,
+        auth: &Auth,
+        form: &MultipartForm,
+        options: RequestOptions,
+    ) -> Result<T, Error>
+    where
+        T: DeserializeOwned,
+    {
+        let Some(stats) = &self.stats else {
+            return self
+                .send_multipart_json(method, url, auth, form, options)
+                .await;
+        };
+        let route = options.route.unwrap_or(super::UNKNOWN_ROUTE);
+        let started_at = Instant::now();
+        let result = self
+            .send_multipart_json(method.clone(), url, auth, form, options)
+            .await;
+        stats.record(&method, route, result.as_ref().err(), started_at.elapsed());
+        result
+    }
+
+    async fn send_json<T, B>(
+        &self,
+        method: Method,
+        url: Url,
+        auth: &Auth,
+        body: Option<&B>,
+        options: RequestOptions,
+    ) -> Result<T, Error>
+    where
+        T: DeserializeOwned,
+        B: Serialize + ?Sized,
+    {
+        let path = self.redaction.redact_path(url.path(), options.route);
+        let route = options.route.unwrap_or(super::UNKNOWN_ROUTE);
+        let can_retry = options.endpoint.is_none()
+            && (super::retry::is_idempotent_method(&method)
+                || (method == Method::POST && options.idempotency_key.is_some()));
 
         let mut headers = HeaderMap::new();
         auth.apply(&mut headers)?;
@@ -70,16 +167,35 @@ impl AsyncTransport {
             headers.insert("Idempotency-Key", value);
         }
 
+        let debug_body = match (&self.debug, body) {
+            (Some(_), Some(body)) => serde_json::to_vec(body).ok(),
+            _ => None,
+        };
+
         let mut retry_count = 0usize;
+        let mut previous_endpoint = None;
         loop {
+            let endpoint = self.endpoints.select(options.endpoint, previous_endpoint);
+            previous_endpoint = Some(endpoint);
+            let url = self.endpoints.rebase(&url, endpoint);
+
             #[cfg(feature = "tracing")]
-            let _span = tracing::info_span!(
-                "metabase.request",
-                method = %method,
-                path = %path,
-                retry_count = retry_count
-            )
-            .entered();
+            let _span = {
+                let span = tracing::info_span!(
+                    "metabase.request",
+                    method = %method,
+                    route = route,
+                    path = tracing::field::Empty,
+                    retry_count = retry_count
+                );
+                if self.record_path {
+                    span.record(
+                        "path",
+                        self.redaction.redact_url(&url, options.route).as_str(),
+                    );
+                }
+                span.entered()
+            };
 
             #[cfg(feature = "metrics")]
             metrics::counter!(
@@ -91,6 +207,17 @@ impl AsyncTransport {
             #[cfg(feature = "metrics")]
             let started_at = Instant::now();
 
+            let capture = self.debug.as_ref().map(|recorder| {
+                recorder.begin(
+                    &method,
+                    &url,
+                    options.route,
+                    &headers,
+                    debug_body.as_deref().map(CapturedBodySource::Json),
+                    &self.redaction,
+                )
+            });
+
             let mut request = self
                 .client
                 .request(method.clone(), url.clone())
@@ -107,12 +234,22 @@ impl AsyncTransport {
             let response = match request.send().await {
                 Ok(response) => response,
                 Err(err) => {
+                    let err = self.scrub(err);
+                    if let Some(capture) = capture {
+                        capture.error(&err);
+                    }
+                    if is_retryable_reqwest_error(&err) {
+                        self.endpoints.mark_unhealthy(endpoint);
+                    }
                     if can_retry
                         && retry_count < self.retry.max_retries
                         && is_retryable_reqwest_error(&err)
                     {
                         let delay = super::retry::next_delay(&self.retry, retry_count);
                         retry_count += 1;
+                        if let Some(stats) = &self.stats {
+                            stats.record_retry(&method, route);
+                        }
                         if !delay.is_zero() {
                             tokio::time::sleep(delay).await;
                         }
@@ -122,6 +259,7 @@ impl AsyncTransport {
                     metrics::counter!(
                         "metabase_requests_total",
                         "method" => method_label(&method),
+                        "route" => route,
                         "outcome" => "transport_error"
                     )
                     .increment(1);
@@ -129,6 +267,7 @@ impl AsyncTransport {
                     metrics::histogram!(
                         "metabase_request_duration_seconds",
                         "method" => method_label(&method),
+                        "route" => route,
                         "outcome" => "transport_error"
                     )
                     .record(started_at.elapsed().as_secs_f64());
@@ -138,6 +277,11 @@ impl AsyncTransport {
 
             let status: StatusCode = response.status();
             let response_headers = response.headers().clone();
+            if super::retry::is_unavailable_status(status) {
+                self.endpoints.mark_unhealthy(endpoint);
+            } else {
+                self.endpoints.mark_healthy(endpoint);
+            }
 
             if can_retry
                 && retry_count < self.retry.max_retries
@@ -146,7 +290,13 @@ impl AsyncTransport {
                 let delay = super::retry::retry_after(&response_headers)
                     .unwrap_or_else(|| super::retry::next_delay(&self.retry, retry_count));
                 retry_count += 1;
-                let _ = response.bytes().await;
+                if let Some(stats) = &self.stats {
+                    stats.record_retry(&method, route);
+                }
+                let body = response.bytes().await.unwrap_or_default();
+                if let Some(capture) = capture {
+                    capture.response(status, &response_headers, &body);
+                }
                 if !delay.is_zero() {
                     tokio::time::sleep(delay).await;
                 }
@@ -155,10 +305,23 @@ impl AsyncTransport {
 
             let request_id = util::extract_request_id(&response_headers);
 
-            let body_bytes = response
-                .bytes()
-                .await
-                .map_err(|err| Error::transport_with_context(method.clone(), path.clone(), err))?;
+            let body_bytes = match response.bytes().await {
+                Ok(body_bytes) => body_bytes,
+                Err(err) => {
+                    let err = self.scrub(err);
+                    if let Some(capture) = capture {
+                        capture.error(&err);
+                    }
+                    return Err(Error::transport_with_context(
+                        method.clone(),
+                        path.clone(),
+                        err,
+                    ));
+                }
+            };
+            if let Some(capture) = capture {
+                capture.response(status, &response_headers, &body_bytes);
+            }
 
             if status.is_success() {
                 let body_slice = if body_bytes.iter().all(|b| b.is_ascii_whitespace()) {
@@ -175,7 +338,7 @@ impl AsyncTransport {
                         util::capture_body_snippet(
                             &body_bytes,
                             self.body_snippet_limit,
-                            self.redact_body_snippet,
+                            self.redact_body_snippet.then_some(&self.redaction),
                         )
                     });
                     Error::Decode(DecodeError::new(
@@ -193,6 +356,7 @@ impl AsyncTransport {
                 metrics::counter!(
                     "metabase_requests_total",
                     "method" => method_label(&method),
+                    "route" => route,
                     "outcome" => if result.is_ok() { "ok" } else { "decode_error" }
                 )
                 .increment(1);
@@ -200,6 +364,7 @@ impl AsyncTransport {
                 metrics::histogram!(
                     "metabase_request_duration_seconds",
                     "method" => method_label(&method),
+                    "route" => route,
                     "outcome" => if result.is_ok() { "ok" } else { "decode_error" }
                 )
                 .record(started_at.elapsed().as_secs_f64());
@@ -219,7 +384,7 @@ impl AsyncTransport {
                 util::capture_body_snippet(
                     &body_bytes,
                     self.body_snippet_limit,
-                    self.redact_body_snippet,
+                    self.redact_body_snippet.then_some(&self.redaction),
                 )
             });
 
@@ -240,6 +405,7 @@ impl AsyncTransport {
             metrics::counter!(
                 "metabase_requests_total",
                 "method" => method_label(&method),
+                "route" => route,
                 "outcome" => status_class(status)
             )
             .increment(1);
@@ -247,6 +413,7 @@ impl AsyncTransport {
             metrics::histogram!(
                 "metabase_request_duration_seconds",
                 "method" => method_label(&method),
+                "route" => route,
                 "outcome" => status_class(status)
             )
             .record(started_at.elapsed().as_secs_f64());
@@ -255,7 +422,7 @@ impl AsyncTransport {
         }
     }
 
-    pub(crate) async fn execute_bytes<B>(
+    async fn send_bytes<B>(
         &self,
         method: Method,
         url: Url,
@@ -266,9 +433,11 @@ impl AsyncTransport {
     where
         B: Serialize + ?Sized,
     {
-        let path = url.path().to_owned();
-        let can_retry = super::retry::is_idempotent_method(&method)
-            || (method == Method::POST && options.idempotency_key.is_some());
+        let path = self.redaction.redact_path(url.path(), options.route);
+        let route = options.route.unwrap_or(super::UNKNOWN_ROUTE);
+        let can_retry = options.endpoint.is_none()
+            && (super::retry::is_idempotent_method(&method)
+                || (method == Method::POST && options.idempotency_key.is_some()));
 
         let mut headers = HeaderMap::new();
         auth.apply(&mut headers)?;
@@ -279,16 +448,35 @@ impl AsyncTransport {
             headers.insert("Idempotency-Key", value);
         }
 
+        let debug_body = match (&self.debug, body) {
+            (Some(_), Some(body)) => serde_json::to_vec(body).ok(),
+            _ => None,
+        };
+
         let mut retry_count = 0usize;
+        let mut previous_endpoint = None;
         loop {
+            let endpoint = self.endpoints.select(options.endpoint, previous_endpoint);
+            previous_endpoint = Some(endpoint);
+            let url = self.endpoints.rebase(&url, endpoint);
+
             #[cfg(feature = "tracing")]
-            let _span = tracing::info_span!(
-                "metabase.request",
-                method = %method,
-                path = %path,
-                retry_count = retry_count
-            )
-            .entered();
+            let _span = {
+                let span = tracing::info_span!(
+                    "metabase.request",
+                    method = %method,
+                    route = route,
+                    path = tracing::field::Empty,
+                    retry_count = retry_count
+                );
+                if self.record_path {
+                    span.record(
+                        "path",
+                        self.redaction.redact_url(&url, options.route).as_str(),
+                    );
+                }
+                span.entered()
+            };
 
             #[cfg(feature = "metrics")]
             metrics::counter!(
@@ -300,6 +488,17 @@ impl AsyncTransport {
             #[cfg(feature = "metrics")]
             let started_at = Instant::now();
 
+            let capture = self.debug.as_ref().map(|recorder| {
+                recorder.begin(
+                    &method,
+                    &url,
+                    options.route,
+                    &headers,
+                    debug_body.as_deref().map(CapturedBodySource::Json),
+                    &self.redaction,
+                )
+            });
+
             let mut request = self
                 .client
                 .request(method.clone(), url.clone())
@@ -316,12 +515,22 @@ impl AsyncTransport {
             let response = match request.send().await {
                 Ok(response) => response,
                 Err(err) => {
+                    let err = self.scrub(err);
+                    if let Some(capture) = capture {
+                        capture.error(&err);
+                    }
+                    if is_retryable_reqwest_error(&err) {
+                        self.endpoints.mark_unhealthy(endpoint);
+                    }
                     if can_retry
                         && retry_count < self.retry.max_retries
                         && is_retryable_reqwest_error(&err)
                     {
                         let delay = super::retry::next_delay(&self.retry, retry_count);
                         retry_count += 1;
+                        if let Some(stats) = &self.stats {
+                            stats.record_retry(&method, route);
+                        }
                         if !delay.is_zero() {
                             tokio::time::sleep(delay).await;
                         }
@@ -331,6 +540,7 @@ impl AsyncTransport {
                     metrics::counter!(
                         "metabase_requests_total",
                         "method" => method_label(&method),
+                        "route" => route,
                         "outcome" => "transport_error"
                     )
                     .increment(1);
@@ -338,6 +548,7 @@ impl AsyncTransport {
                     metrics::histogram!(
                         "metabase_request_duration_seconds",
                         "method" => method_label(&method),
+                        "route" => route,
                         "outcome" => "transport_error"
                     )
                     .record(started_at.elapsed().as_secs_f64());
@@ -347,6 +558,11 @@ impl AsyncTransport {
 
             let status: StatusCode = response.status();
             let response_headers = response.headers().clone();
+            if super::retry::is_unavailable_status(status) {
+                self.endpoints.mark_unhealthy(endpoint);
+            } else {
+                self.endpoints.mark_healthy(endpoint);
+            }
 
             if can_retry
                 && retry_count < self.retry.max_retries
@@ -355,7 +571,13 @@ impl AsyncTransport {
                 let delay = super::retry::retry_after(&response_headers)
                     .unwrap_or_else(|| super::retry::next_delay(&self.retry, retry_count));
                 retry_count += 1;
-                let _ = response.bytes().await;
+                if let Some(stats) = &self.stats {
+                    stats.record_retry(&method, route);
+                }
+                let body = response.bytes().await.unwrap_or_default();
+                if let Some(capture) = capture {
+                    capture.response(status, &response_headers, &body);
+                }
                 if !delay.is_zero() {
                     tokio::time::sleep(delay).await;
                 }
@@ -364,16 +586,30 @@ impl AsyncTransport {
 
             let request_id = util::extract_request_id(&response_headers);
 
-            let body_bytes = response
-                .bytes()
-                .await
-                .map_err(|err| Error::transport_with_context(method.clone(), path.clone(), err))?;
+            let body_bytes = match response.bytes().await {
+                Ok(body_bytes) => body_bytes,
+                Err(err) => {
+                    let err = self.scrub(err);
+                    if let Some(capture) = capture {
+                        capture.error(&err);
+                    }
+                    return Err(Error::transport_with_context(
+                        method.clone(),
+                        path.clone(),
+                        err,
+                    ));
+                }
+            };
+            if let Some(capture) = capture {
+                capture.response(status, &response_headers, &body_bytes);
+            }
 
             if status.is_success() {
                 #[cfg(feature = "metrics")]
                 metrics::counter!(
                     "metabase_requests_total",
                     "method" => method_label(&method),
+                    "route" => route,
                     "outcome" => "ok"
                 )
                 .increment(1);
@@ -381,6 +617,7 @@ impl AsyncTransport {
                 metrics::histogram!(
                     "metabase_request_duration_seconds",
                     "method" => method_label(&method),
+                    "route" => route,
                     "outcome" => "ok"
                 )
                 .record(started_at.elapsed().as_secs_f64());
@@ -400,7 +637,7 @@ impl AsyncTransport {
                 util::capture_body_snippet(
                     &body_bytes,
                     self.body_snippet_limit,
-                    self.redact_body_snippet,
+                    self.redact_body_snippet.then_some(&self.redaction),
                 )
             });
 
@@ -421,6 +658,7 @@ impl AsyncTransport {
             metrics::counter!(
                 "metabase_requests_total",
                 "method" => method_label(&method),
+                "route" => route,
                 "outcome" => status_class(status)
             )
             .increment(1);
@@ -428,6 +666,7 @@ impl AsyncTransport {
             metrics::histogram!(
                 "metabase_request_duration_seconds",
                 "method" => method_label(&method),
+                "route" => route,
                 "outcome" => status_class(status)
             )
             .record(started_at.elapsed().as_secs_f64());
@@ -436,7 +675,7 @@ impl AsyncTransport {
         }
     }
 
-    pub(crate) async fn execute_multipart_json<T>(
+    async fn send_multipart_json<T>(
         &self,
         method: Method,
         url: Url,
@@ -447,9 +686,11 @@ impl AsyncTransport {
     where
         T: DeserializeOwned,
     {
-        let path = url.path().to_owned();
-        let can_retry = super::retry::is_idempotent_method(&method)
-            || (method == Method::POST && options.idempotency_key.is_some());
+        let path = self.redaction.redact_path(url.path(), options.route);
+        let route = options.route.unwrap_or(super::UNKNOWN_ROUTE);
+        let can_retry = options.endpoint.is_none()
+            && (super::retry::is_idempotent_method(&method)
+                || (method == Method::POST && options.idempotency_key.is_some()));
 
         let mut headers = HeaderMap::new();
         auth.apply(&mut headers)?;
@@ -461,15 +702,29 @@ impl AsyncTransport {
         }
 
         let mut retry_count = 0usize;
+        let mut previous_endpoint = None;
         loop {
+            let endpoint = self.endpoints.select(options.endpoint, previous_endpoint);
+            previous_endpoint = Some(endpoint);
+            let url = self.endpoints.rebase(&url, endpoint);
+
             #[cfg(feature = "tracing")]
-            let _span = tracing::info_span!(
-                "metabase.request",
-                method = %method,
-                path = %path,
-                retry_count = retry_count
-            )
-            .entered();
+            let _span = {
+                let span = tracing::info_span!(
+                    "metabase.request",
+                    method = %method,
+                    route = route,
+                    path = tracing::field::Empty,
+                    retry_count = retry_count
+                );
+                if self.record_path {
+                    span.record(
+                        "path",
+                        self.redaction.redact_url(&url, options.route).as_str(),
+                    );
+                }
+                span.entered()
+            };
 
             #[cfg(feature = "metrics")]
             metrics::counter!(
@@ -481,6 +736,17 @@ impl AsyncTransport {
             #[cfg(feature = "metrics")]
             let started_at = Instant::now();
 
+            let capture = self.debug.as_ref().map(|recorder| {
+                recorder.begin(
+                    &method,
+                    &url,
+                    options.route,
+                    &headers,
+                    Some(CapturedBodySource::Multipart(form)),
+                    &self.redaction,
+                )
+            });
+
             let mut request = self
                 .client
                 .request(method.clone(), url.clone())
@@ -499,7 +765,7 @@ impl AsyncTransport {
                     .file_name(file.filename().to_owned());
                 if let Some(content_type) = file.content_type() {
                     part = part.mime_str(content_type).map_err(|err| {
-                        Error::transport_with_context(method.clone(), path.clone(), err)
+                        Error::transport_with_context(method.clone(), path.clone(), self.scrub(err))
                     })?;
                 }
                 reqwest_form = reqwest_form.part(file.name().to_owned(), part);
@@ -509,12 +775,22 @@ impl AsyncTransport {
             let response = match request.send().await {
                 Ok(response) => response,
                 Err(err) => {
+                    let err = self.scrub(err);
+                    if let Some(capture) = capture {
+                        capture.error(&err);
+                    }
+                    if is_retryable_reqwest_error(&err) {
+                        self.endpoints.mark_unhealthy(endpoint);
+                    }
                     if can_retry
                         && retry_count < self.retry.max_retries
                         && is_retryable_reqwest_error(&err)
                     {
                         let delay = super::retry::next_delay(&self.retry, retry_count);
                         retry_count += 1;
+                        if let Some(stats) = &self.stats {
+                            stats.record_retry(&method, route);
+                        }
                         if !delay.is_zero() {
                             tokio::time::sleep(delay).await;
                         }
@@ -524,6 +800,7 @@ impl AsyncTransport {
                     metrics::counter!(
                         "metabase_requests_total",
                         "method" => method_label(&method),
+                        "route" => route,
                         "outcome" => "transport_error"
                     )
                     .increment(1);
@@ -531,6 +808,7 @@ impl AsyncTransport {
                     metrics::histogram!(
                         "metabase_request_duration_seconds",
                         "method" => method_label(&method),
+                        "route" => route,
                         "outcome" => "transport_error"
                     )
                     .record(started_at.elapsed().as_secs_f64());
@@ -540,6 +818,11 @@ impl AsyncTransport {
 
             let status: StatusCode = response.status();
             let response_headers = response.headers().clone();
+            if super::retry::is_unavailable_status(status) {
+                self.endpoints.mark_unhealthy(endpoint);
+            } else {
+                self.endpoints.mark_healthy(endpoint);
+            }
 
             if can_retry
                 && retry_count < self.retry.max_retries
@@ -548,7 +831,13 @@ impl AsyncTransport {
                 let delay = super::retry::retry_after(&response_headers)
                     .unwrap_or_else(|| super::retry::next_delay(&self.retry, retry_count));
                 retry_count += 1;
-                let _ = response.bytes().await;
+                if let Some(stats) = &self.stats {
+                    stats.record_retry(&method, route);
+                }
+                let body = response.bytes().await.unwrap_or_default();
+                if let Some(capture) = capture {
+                    capture.response(status, &response_headers, &body);
+                }
                 if !delay.is_zero() {
                     tokio::time::sleep(delay).await;
                 }
@@ -557,10 +846,23 @@ impl AsyncTransport {
 
             let request_id = util::extract_request_id(&response_headers);
 
-            let body_bytes = response
-                .bytes()
-                .await
-                .map_err(|err| Error::transport_with_context(method.clone(), path.clone(), err))?;
+            let body_bytes = match response.bytes().await {
+                Ok(body_bytes) => body_bytes,
+                Err(err) => {
+                    let err = self.scrub(err);
+                    if let Some(capture) = capture {
+                        capture.error(&err);
+                    }
+                    return Err(Error::transport_with_context(
+                        method.clone(),
+                        path.clone(),
+                        err,
+                    ));
+                }
+            };
+            if let Some(capture) = capture {
+                capture.response(status, &response_headers, &body_bytes);
+            }
 
             if status.is_success() {
                 let body_slice = if body_bytes.iter().all(|b| b.is_ascii_whitespace()) {
@@ -577,7 +879,7 @@ impl AsyncTransport {
                         util::capture_body_snippet(
                             &body_bytes,
                             self.body_snippet_limit,
-                            self.redact_body_snippet,
+                            self.redact_body_snippet.then_some(&self.redaction),
                         )
                     });
                     Error::Decode(DecodeError::new(
@@ -595,6 +897,7 @@ impl AsyncTransport {
                 metrics::counter!(
                     "metabase_requests_total",
                     "method" => method_label(&method),
+                    "route" => route,
                     "outcome" => if result.is_ok() { "ok" } else { "decode_error" }
                 )
                 .increment(1);
@@ -602,6 +905,7 @@ impl AsyncTransport {
                 metrics::histogram!(
                     "metabase_request_duration_seconds",
                     "method" => method_label(&method),
+                    "route" => route,
                     "outcome" => if result.is_ok() { "ok" } else { "decode_error" }
                 )
                 .record(started_at.elapsed().as_secs_f64());
@@ -621,7 +925,7 @@ impl AsyncTransport {
                 util::capture_body_snippet(
                     &body_bytes,
                     self.body_snippet_limit,
-                    self.redact_body_snippet,
+                    self.redact_body_snippet.then_some(&self.redaction),
                 )
             });
 
@@ -642,6 +946,7 @@ impl AsyncTransport {
             metrics::counter!(
                 "metabase_requests_total",
                 "method" => method_label(&method),
+                "route" => route,
                 "outcome" => status_class(status)
             )
             .increment(1);
@@ -649,6 +954,7 @@ impl AsyncTransport {
             metrics::histogram!(
                 "metabase_request_duration_seconds",
                 "method" => method_label(&method),
+                "route" => route,
                 "outcome" => status_class(status)
             )
             .record(started_at.elapsed().as_secs_f64());
@@ -658,6 +964,17 @@ impl AsyncTransport {
     }
 }
 
+impl AsyncTransport {
+    /// Drop the request url from `reqwest` errors so embedded tokens don't leak via `source()`.
+    fn scrub(&self, err: reqwest::Error) -> reqwest::Error {
+        if self.redaction.enabled {
+            err.without_url()
+        } else {
+            err
+        }
+    }
+}
+
 fn is_retryable_reqwest_error(err: &reqwest::Error) -> bool {
     err.is_connect() || err.is_timeout()
 }
@@ -3,12 +3,14 @@ use serde::{Serialize, de::DeserializeOwned};
 use serde_json::Value;
 use url::Url;
 
-#[cfg(feature = "metrics")]
-use std::time::Instant;
+use std::{sync::Arc, time::Instant};
 
 use crate::{
     auth::Auth,
-    client::{ClientConfig, RequestOptions},
+    client::{
+        CapturedBodySource, ClientConfig, ClientStats, DebugRecorder, EndpointPool,
+        RedactionPolicy, RequestOptions, StatsCollector,
+    },
     error::{ApiError, DecodeError, Error},
     types::multipart::MultipartForm,
     util,
@@ -21,10 +23,16 @@ pub(crate) struct BlockingTransport {
     body_snippet_limit: usize,
     redact_body_snippet: bool,
     retry: crate::client::RetryPolicy,
+    #[cfg_attr(not(feature = "tracing"), allow(dead_code))]
+    record_path: bool,
+    redaction: RedactionPolicy,
+    stats: Option<Arc<StatsCollector>>,
+    debug: Option<DebugRecorder>,
+    endpoints: Arc<EndpointPool>,
 }
 
 impl BlockingTransport {
-    pub(crate) fn new(config: &ClientConfig) -> Result<Self, Error> {
+    pub(crate) fn new(config: &ClientConfig, endpoints: Arc<EndpointPool>) -> Result<Self, Error> {
         let _ = rustls::crypto::ring::default_provider().install_default();
 
         let user_agent = format!("{}/{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
@@ -46,9 +54,24 @@ impl BlockingTransport {
             body_snippet_limit,
             redact_body_snippet: config.body_snippet.redact,
             retry: config.retry.clone(),
+            record_path: config.telemetry.record_path,
+            redaction: config.redaction.clone(),
+            stats: config
+                .collect_stats
+                .then(|| Arc::new(StatsCollector::default())),
+            debug: config.debug.clone(),
+            endpoints,
         })
     }
 
+    pub(crate) fn endpoints(&self) -> &EndpointPool {
+        &self.endpoints
+    }
+
+    pub(crate) fn stats(&self) -> Option<ClientStats> {
+        self.stats.as_ref().map(|stats| stats.snapshot())
+    }
+
     pub(crate) fn execute_json<T, B>(
         &self,
         method: Method,
@@ -61,9 +84,75 @@ impl BlockingTransport {
         T: DeserializeOwned,
         B: Serialize,
     {
-        let path = url.path().to_owned();
-        let can_retry = super::retry::is_idempotent_method(&method)
-            || (method == Method::POST && options.idempotency_key.is_some());
+        let Some(stats) = &self.stats else {
+            return self.send_json(method, url, auth, body, options);
+        };
+        let route = options.route.unwrap_or(super::UNKNOWN_ROUTE);
+        let started_at = Instant::now();
+        let result = self.send_json(method.clone(), url, auth, body, options);
+        stats.record(&method, route, result.as_ref().err(), started_at.elapsed());
+        result
+    }
+
+    pub(crate) fn execute_bytes<B>(
+        &self,
+        method: Method,
+        url: Url,
+        auth: &Auth,
+        body: Option<&B>,
+        options: RequestOptions,
+    ) -> Result<Vec<u8>, Error>
+    where
+        B: Serialize,
+    {
+        let Some(stats) = &self.stats else {
+            return self.send_bytes(method, url, auth, body, options);
+        };
+        let route = options.route.unwrap_or(super::UNKNOWN_ROUTE);
+        let started_at = Instant::now();
+        let result = self.send_bytes(method.clone(), url, auth, body, options);
+        stats.record(&method, route, result.as_ref().err(), started_at.elapsed());
+        result
+    }
+
+    pub(crate) fn execute_multipart_json<T>(
+        &self,
+        method: Method,
+        url: Url,
+        auth: &Auth,
+        form: &MultipartForm,
+        options: RequestOptions,
+    ) -> Result<T, Error>
+    where
+        T: DeserializeOwned,
+    {
+        let Some(stats) = &self.stats else {
+            return self.send_multipart_json(method, url, auth, form, options);
+        };
+        let route = options.route.unwrap_or(super::UNKNOWN_ROUTE);
+        let started_at = Instant::now();
+        let result = self.send_multipart_json(method.clone(), url, auth, form, options);
+        stats.record(&method, route, result.as_ref().err(), started_at.elapsed());
+        result
+    }
+
+    fn send_json<T, B>(
+        &self,
+        method: Method,
+        url: Url,
+        auth: &Auth,
+        body: Option<&B>,
+        options: RequestOptions,
+    ) -> Result<T, Error>
+    where
+        T: DeserializeOwned,
+        B: Serialize,
+    {
+        let path = self.redaction.redact_path(url.path(), options.route);
+        let route = options.route.unwrap_or(super::UNKNOWN_ROUTE);
+        let can_retry = options.endpoint.is_none()
+            && (super::retry::is_idempotent_method(&method)
+                || (method == Method::POST && options.idempotency_key.is_some()));
 
         let mut headers = HeaderMap::new();
         auth.apply(&mut headers)?;
@@ -74,16 +163,35 @@ impl BlockingTransport {
             headers.insert("Idempotency-Key", value);
         }
 
+        let debug_body = match (&self.debug, body) {
+            (Some(_), Some(body)) => serde_json::to_vec(body).ok(),
+            _ => None,
+        };
+
         let mut retry_count = 0usize;
+        let mut previous_endpoint = None;
         loop {
+            let endpoint = self.endpoints.select(options.endpoint, previous_endpoint);
+            previous_endpoint = Some(endpoint);
+            let url = self.endpoints.rebase(&url, endpoint);
+
             #[cfg(feature = "tracing")]
-            let _span = tracing::info_span!(
-                "metabase.request",
-                method = %method,
-                path = %path,
-                retry_count = retry_count
-            )
-            .entered();
+            let _span = {
+                let span = tracing::info_span!(
+                    "metabase.request",
+                    method = %method,
+                    route = route,
+                    path = tracing::field::Empty,
+                    retry_count = retry_count
+                );
+                if self.record_path {
+                    span.record(
+                        "path",
+                        self.redaction.redact_url(&url, options.route).as_str(),
+                    );
+                }
+                span.entered()
+            };
 
             #[cfg(feature = "metrics")]
             metrics::counter!(
@@ -95,6 +203,17 @@ impl BlockingTransport {
             #[cfg(feature = "metrics")]
             let started_at = Instant::now();
 
+            let capture = self.debug.as_ref().map(|recorder| {
+                recorder.begin(
+                    &method,
+                    &url,
+                    options.route,
+                    &headers,
+                    debug_body.as_deref().map(CapturedBodySource::Json),
+                    &self.redaction,
+                )
+            });
+
             let response = match self.execute(
                 method.clone(),
                 url.clone(),
@@ -104,12 +223,21 @@ impl BlockingTransport {
             ) {
                 Ok(response) => response,
                 Err(err) => {
+                    if let Some(capture) = capture {
+                        capture.error(&err);
+                    }
+                    if is_retryable_ureq_error(&err) {
+                        self.endpoints.mark_unhealthy(endpoint);
+                    }
                     if can_retry
                         && retry_count < self.retry.max_retries
                         && is_retryable_ureq_error(&err)
                     {
                         let delay = super::retry::next_delay(&self.retry, retry_count);
                         retry_count += 1;
+                        if let Some(stats) = &self.stats {
+                            stats.record_retry(&method, route);
+                        }
                         if !delay.is_zero() {
                             std::thread::sleep(delay);
                         }
@@ -119,6 +247,7 @@ impl BlockingTransport {
                     metrics::counter!(
                         "metabase_requests_total",
                         "method" => method_label(&method),
+                        "route" => route,
                         "outcome" => "transport_error"
                     )
                     .increment(1);
@@ -126,6 +255,7 @@ impl BlockingTransport {
                     metrics::histogram!(
                         "metabase_request_duration_seconds",
                         "method" => method_label(&method),
+                        "route" => route,
                         "outcome" => "transport_error"
                     )
                     .record(started_at.elapsed().as_secs_f64());
@@ -139,6 +269,11 @@ impl BlockingTransport {
 
             let status: StatusCode = response.status();
             let response_headers = response.headers().clone();
+            if super::retry::is_unavailable_status(status) {
+                self.endpoints.mark_unhealthy(endpoint);
+            } else {
+                self.endpoints.mark_healthy(endpoint);
+            }
 
             if can_retry
                 && retry_count < self.retry.max_retries
@@ -147,7 +282,13 @@ impl BlockingTransport {
                 let delay = super::retry::retry_after(&response_headers)
                     .unwrap_or_else(|| super::retry::next_delay(&self.retry, retry_count));
                 retry_count += 1;
-                let _ = response.into_body().read_to_vec();
+                if let Some(stats) = &self.stats {
+                    stats.record_retry(&method, route);
+                }
+                let body = response.into_body().read_to_vec().unwrap_or_default();
+                if let Some(capture) = capture {
+                    capture.response(status, &response_headers, &body);
+                }
                 if !delay.is_zero() {
                     std::thread::sleep(delay);
                 }
@@ -156,10 +297,22 @@ impl BlockingTransport {
 
             let request_id = util::extract_request_id(&response_headers);
 
-            let body_bytes = response
-                .into_body()
-                .read_to_vec()
-                .map_err(|err| Error::transport_with_context(method.clone(), path.clone(), err))?;
+            let body_bytes = match response.into_body().read_to_vec() {
+                Ok(body_bytes) => body_bytes,
+                Err(err) => {
+                    if let Some(capture) = capture {
+                        capture.error(&err);
+                    }
+                    return Err(Error::transport_with_context(
+                        method.clone(),
+                        path.clone(),
+                        err,
+                    ));
+                }
+            };
+            if let Some(capture) = capture {
+                capture.response(status, &response_headers, &body_bytes);
+            }
 
             if status.is_success() {
                 let body_slice = if body_bytes.iter().all(|b| b.is_ascii_whitespace()) {
@@ -176,7 +329,7 @@ impl BlockingTransport {
                         util::capture_body_snippet(
                             &body_bytes,
                             self.body_snippet_limit,
-                            self.redact_body_snippet,
+                            self.redact_body_snippet.then_some(&self.redaction),
                         )
                     });
                     Error::Decode(DecodeError::new(
@@ -194,6 +347,7 @@ impl BlockingTransport {
                 metrics::counter!(
                     "metabase_requests_total",
                     "method" => method_label(&method),
+                    "route" => route,
                     "outcome" => if result.is_ok() { "ok" } else { "decode_error" }
                 )
                 .increment(1);
@@ -201,6 +355,7 @@ impl BlockingTransport {
                 metrics::histogram!(
                     "metabase_request_duration_seconds",
                     "method" => method_label(&method),
+                    "route" => route,
                     "outcome" => if result.is_ok() { "ok" } else { "decode_error" }
                 )
                 .record(started_at.elapsed().as_secs_f64());
@@ -220,7 +375,7 @@ impl BlockingTransport {
                 util::capture_body_snippet(
                     &body_bytes,
                     self.body_snippet_limit,
-                    self.redact_body_snippet,
+                    self.redact_body_snippet.then_some(&self.redaction),
                 )
             });
 
@@ -241,6 +396,7 @@ impl BlockingTransport {
             metrics::counter!(
                 "metabase_requests_total",
                 "method" => method_label(&method),
+                "route" => route,
                 "outcome" => status_class(status)
             )
             .increment(1);
@@ -248,6 +404,7 @@ impl BlockingTransport {
             metrics::histogram!(
                 "metabase_request_duration_seconds",
                 "method" => method_label(&method),
+                "route" => route,
                 "outcome" => status_class(status)
             )
             .record(started_at.elapsed().as_secs_f64());
@@ -256,7 +413,7 @@ impl BlockingTransport {
         }
     }
 
-    pub(crate) fn execute_bytes<B>(
+    fn send_bytes<B>(
         &self,
         method: Method,
         url: Url,
@@ -267,9 +424,11 @@ impl BlockingTransport {
     where
         B: Serialize,
     {
-        let path = url.path().to_owned();
-        let can_retry = super::retry::is_idempotent_method(&method)
-            || (method == Method::POST && options.idempotency_key.is_some());
+        let path = self.redaction.redact_path(url.path(), options.route);
+        let route = options.route.unwrap_or(super::UNKNOWN_ROUTE);
+        let can_retry = options.endpoint.is_none()
+            && (super::retry::is_idempotent_method(&method)
+                || (method == Method::POST && options.idempotency_key.is_some()));
 
         let mut headers = HeaderMap::new();
         auth.apply(&mut headers)?;
@@ -280,16 +439,35 @@ impl BlockingTransport {
             headers.insert("Idempotency-Key", value);
         }
 
+        let debug_body = match (&self.debug, body) {
+            (Some(_), Some(body)) => serde_json::to_vec(body).ok(),
+            _ => None,
+        };
+
         let mut retry_count = 0usize;
+        let mut previous_endpoint = None;
         loop {
+            let endpoint = self.endpoints.select(options.endpoint, previous_endpoint);
+            previous_endpoint = Some(endpoint);
+            let url = self.endpoints.rebase(&url, endpoint);
+
             #[cfg(feature = "tracing")]
-            let _span = tracing::info_span!(
-                "metabase.request",
-                method = %method,
-                path = %path,
-                retry_count = retry_count
-            )
-            .entered();
+            let _span = {
+                let span = tracing::info_span!(
+                    "metabase.request",
+                    method = %method,
+                    route = route,
+                    path = tracing::field::Empty,
+                    retry_count = retry_count
+                );
+                if self.record_path {
+                    span.record(
+                        "path",
+                        self.redaction.redact_url(&url, options.route).as_str(),
+                    );
+                }
+                span.entered()
+            };
 
             #[cfg(feature = "metrics")]
             metrics::counter!(
@@ -301,6 +479,17 @@ impl BlockingTransport {
             #[cfg(feature = "metrics")]
             let started_at = Instant::now();
 
+            let capture = self.debug.as_ref().map(|recorder| {
+                recorder.begin(
+                    &method,
+                    &url,
+                    options.route,
+                    &headers,
+                    debug_body.as_deref().map(CapturedBodySource::Json),
+                    &self.redaction,
+                )
+            });
+
             let response = match self.execute(
                 method.clone(),
                 url.clone(),
@@ -310,12 +499,21 @@ impl BlockingTransport {
             ) {
                 Ok(response) => response,
                 Err(err) => {
+                    if let Some(capture) = capture {
+                        capture.error(&err);
+                    }
+                    if is_retryable_ureq_error(&err) {
+                        self.endpoints.mark_unhealthy(endpoint);
+                    }
                     if can_retry
                         && retry_count < self.retry.max_retries
                         && is_retryable_ureq_error(&err)
                     {
                         let delay = super::retry::next_delay(&self.retry, retry_count);
                         retry_count += 1;
+                        if let Some(stats) = &self.stats {
+                            stats.record_retry(&method, route);
+                        }
                         if !delay.is_zero() {
                             std::thread::sleep(delay);
                         }
@@ -325,6 +523,7 @@ impl BlockingTransport {
                     metrics::counter!(
                         "metabase_requests_total",
                         "method" => method_label(&method),
+                        "route" => route,
                         "outcome" => "transport_error"
                     )
                     .increment(1);
@@ -332,6 +531,7 @@ impl BlockingTransport {
                     metrics::histogram!(
                         "metabase_request_duration_seconds",
                         "method" => method_label(&method),
+                        "route" => route,
                         "outcome" => "transport_error"
                     )
                     .record(started_at.elapsed().as_secs_f64());
@@ -345,6 +545,11 @@ impl BlockingTransport {
 
             let status: StatusCode = response.status();
             let response_headers = response.headers().clone();
+            if super::retry::is_unavailable_status(status) {
+                self.endpoints.mark_unhealthy(endpoint);
+            } else {
+                self.endpoints.mark_healthy(endpoint);
+            }
 
             if can_retry
                 && retry_count < self.retry.max_retries
@@ -353,7 +558,13 @@ impl BlockingTransport {
                 let delay = super::retry::retry_after(&response_headers)
                     .unwrap_or_else(|| super::retry::next_delay(&self.retry, retry_count));
                 retry_count += 1;
-                let _ = response.into_body().read_to_vec();
+                if let Some(stats) = &self.stats {
+                    stats.record_retry(&method, route);
+                }
+                let body = response.into_body().read_to_vec().unwrap_or_default();
+                if let Some(capture) = capture {
+                    capture.response(status, &response_headers, &body);
+                }
                 if !delay.is_zero() {
                     std::thread::sleep(delay);
                 }
@@ -362,16 +573,29 @@ impl BlockingTransport {
 
             let request_id = util::extract_request_id(&response_headers);
 
-            let body_bytes = response
-                .into_body()
-                .read_to_vec()
-                .map_err(|err| Error::transport_with_context(method.clone(), path.clone(), err))?;
+            let body_bytes = match response.into_body().read_to_vec() {
+                Ok(body_bytes) => body_bytes,
+                Err(err) => {
+                    if let Some(capture) = capture {
+                        capture.error(&err);
+                    }
+                    return Err(Error::transport_with_context(
+                        method.clone(),
+                        path.clone(),
+                        err,
+                    ));
+                }
+            };
+            if let Some(capture) = capture {
+                capture.response(status, &response_headers, &body_bytes);
+            }
 
             if status.is_success() {
                 #[cfg(feature = "metrics")]
                 metrics::counter!(
                     "metabase_requests_total",
                     "method" => method_label(&method),
+                    "route" => route,
                     "outcome" => "ok"
                 )
                 .increment(1);
@@ -379,6 +603,7 @@ impl BlockingTransport {
                 metrics::histogram!(
                     "metabase_request_duration_seconds",
                     "method" => method_label(&method),
+                    "route" => route,
                     "outcome" => "ok"
                 )
                 .record(started_at.elapsed().as_secs_f64());
@@ -398,7 +623,7 @@ impl BlockingTransport {
                 util::capture_body_snippet(
                     &body_bytes,
                     self.body_snippet_limit,
-                    self.redact_body_snippet,
+                    self.redact_body_snippet.then_some(&self.redaction),
                 )
             });
 
@@ -419,6 +644,7 @@ impl BlockingTransport {
             metrics::counter!(
                 "metabase_requests_total",
                 "method" => method_label(&method),
+                "route" => route,
                 "outcome" => status_class(status)
             )
             .increment(1);
@@ -426,6 +652,7 @@ impl BlockingTransport {
             metrics::histogram!(
                 "metabase_request_duration_seconds",
                 "method" => method_label(&method),
+                "route" => route,
                 "outcome" => status_class(status)
             )
             .record(started_at.elapsed().as_secs_f64());
@@ -434,7 +661,7 @@ impl BlockingTransport {
         }
     }
 
-    pub(crate) fn execute_multipart_json<T>(
+    fn send_multipart_json<T>(
         &self,
         method: Method,
         url: Url,
@@ -445,9 +672,11 @@ impl BlockingTransport {
     where
         T: DeserializeOwned,
     {
-        let path = url.path().to_owned();
-        let can_retry = super::retry::is_idempotent_method(&method)
-            || (method == Method::POST && options.idempotency_key.is_some());
+        let path = self.redaction.redact_path(url.path(), options.route);
+        let route = options.route.unwrap_or(super::UNKNOWN_ROUTE);
+        let can_retry = options.endpoint.is_none()
+            && (super::retry::is_idempotent_method(&method)
+                || (method == Method::POST && options.idempotency_key.is_some()));
 
         let mut headers = HeaderMap::new();
         auth.apply(&mut headers)?;
@@ -459,15 +688,29 @@ impl BlockingTransport {
         }
 
         let mut retry_count = 0usize;
+        let mut previous_endpoint = None;
         loop {
+            let endpoint = self.endpoints.select(options.endpoint, previous_endpoint);
+            previous_endpoint = Some(endpoint);
+            let url = self.endpoints.rebase(&url, endpoint);
+
             #[cfg(feature = "tracing")]
-            let _span = tracing::info_span!(
-                "metabase.request",
-                method = %method,
-                path = %path,
-                retry_count = retry_count
-            )
-            .entered();
+            let _span = {
+                let span = tracing::info_span!(
+                    "metabase.request",
+                    method = %method,
+                    route = route,
+                    path = tracing::field::Empty,
+                    retry_count = retry_count
+                );
+                if self.record_path {
+                    span.record(
+                        "path",
+                        self.redaction.redact_url(&url, options.route).as_str(),
+                    );
+                }
+                span.entered()
+            };
 
             #[cfg(feature = "metrics")]
             metrics::counter!(
@@ -479,6 +722,17 @@ impl BlockingTransport {
             #[cfg(feature = "metrics")]
             let started_at = Instant::now();
 
+            let capture = self.debug.as_ref().map(|recorder| {
+                recorder.begin(
+                    &method,
+                    &url,
+                    options.route,
+                    &headers,
+                    Some(CapturedBodySource::Multipart(form)),
+                    &self.redaction,
+                )
+            });
+
             let response = match self.execute_multipart(
                 method.clone(),
                 url.clone(),
@@ -488,12 +742,21 @@ impl BlockingTransport {
             ) {
                 Ok(response) => response,
                 Err(err) => {
+                    if let Some(capture) = capture {
+                        capture.error(&err);
+                    }
+                    if is_retryable_ureq_error(&err) {
+                        self.endpoints.mark_unhealthy(endpoint);
+                    }
                     if can_retry
                         && retry_count < self.retry.max_retries
                         && is_retryable_ureq_error(&err)
                     {
                         let delay = super::retry::next_delay(&self.retry, retry_count);
                         retry_count += 1;
+                        if let Some(stats) = &self.stats {
+                            stats.record_retry(&method, route);
+                        }
                         if !delay.is_zero() {
                             std::thread::sleep(delay);
                         }
@@ -503,6 +766,7 @@ impl BlockingTransport {
                     metrics::counter!(
                         "metabase_requests_total",
                         "method" => method_label(&method),
+                        "route" => route,
                         "outcome" => "transport_error"
                     )
                     .increment(1);
@@ -510,6 +774,7 @@ impl BlockingTransport {
                     metrics::histogram!(
                         "metabase_request_duration_seconds",
                         "method" => method_label(&method),
+                        "route" => route,
                         "outcome" => "transport_error"
                     )
                     .record(started_at.elapsed().as_secs_f64());
@@ -523,6 +788,11 @@ impl BlockingTransport {
 
             let status: StatusCode = response.status();
             let response_headers = response.headers().clone();
+            if super::retry::is_unavailable_status(status) {
+                self.endpoints.mark_unhealthy(endpoint);
+            } else {
+                self.endpoints.mark_healthy(endpoint);
+            }
 
             if can_retry
                 && retry_count < self.retry.max_retries
@@ -531,7 +801,13 @@ impl BlockingTransport {
                 let delay = super::retry::retry_after(&response_headers)
                     .unwrap_or_else(|| super::retry::next_delay(&self.retry, retry_count));
                 retry_count += 1;
-                let _ = response.into_body().read_to_vec();
+                if let Some(stats) = &self.stats {
+                    stats.record_retry(&method, route);
+                }
+                let body = response.into_body().read_to_vec().unwrap_or_default();
+                if let Some(capture) = capture {
+                    capture.response(status, &response_headers, &body);
+                }
                 if !delay.is_zero() {
                     std::thread::sleep(delay);
                 }
@@ -540,10 +816,22 @@ impl BlockingTransport {
 
             let request_id = util::extract_request_id(&response_headers);
 
-            let body_bytes = response
-                .into_body()
-                .read_to_vec()
-                .map_err(|err| Error::transport_with_context(method.clone(), path.clone(), err))?;
+            let body_bytes = match response.into_body().read_to_vec() {
+                Ok(body_bytes) => body_bytes,
+                Err(err) => {
+                    if let Some(capture) = capture {
+                        capture.error(&err);
+                    }
+                    return Err(Error::transport_with_context(
+                        method.clone(),
+                        path.clone(),
+                        err,
+                    ));
+                }
+            };
+            if let Some(capture) = capture {
+                capture.response(status, &response_headers, &body_bytes);
+            }
 
             if status.is_success() {
                 let body_slice = if body_bytes.iter().all(|b| b.is_ascii_whitespace()) {
@@ -560,7 +848,7 @@ impl BlockingTransport {
                         util::capture_body_snippet(
                             &body_bytes,
                             self.body_snippet_limit,
-                            self.redact_body_snippet,
+                            self.redact_body_snippet.then_some(&self.redaction),
                         )
                     });
                     Error::Decode(DecodeError::new(
@@ -578,6 +866,7 @@ impl BlockingTransport {
                 metrics::counter!(
                     "metabase_requests_total",
                     "method" => method_label(&method),
+                    "route" => route,
                     "outcome" => if result.is_ok() { "ok" } else { "decode_error" }
                 )
                 .increment(1);
@@ -585,6 +874,7 @@ impl BlockingTransport {
                 metrics::histogram!(
                     "metabase_request_duration_seconds",
                     "method" => method_label(&method),
+                    "route" => route,
                     "outcome" => if result.is_ok() { "ok" } else { "decode_error" }
                 )
                 .record(started_at.elapsed().as_secs_f64());
@@ -604,7 +894,7 @@ impl BlockingTransport {
                 util::capture_body_snippet(
                     &body_bytes,
                     self.body_snippet_limit,
-                    self.redact_body_snippet,
+                    self.redact_body_snippet.then_some(&self.redaction),
                 )
             });
 
@@ -625,6 +915,7 @@ impl BlockingTransport {
             metrics::counter!(
                 "metabase_requests_total",
                 "method" => method_label(&method),
+                "route" => route,
                 "outcome" => status_class(status)
             )
             .increment(1);
@@ -632,6 +923,7 @@ impl BlockingTransport {
             metrics::histogram!(
                 "metabase_request_duration_seconds",
                 "method" => method_label(&method),
+                "route" => route,
                 "outcome" => status_class(status)
             )
             .record(started_at.elapsed().as_secs_f64());
@@ -659,9 +951,21 @@ impl BlockingTransport {
                     Method::OPTIONS => self.agent.options(url),
                     Method::TRACE => self.agent.trace(url),
                     Method::POST | Method::PUT | Method::PATCH => {
-                        return Err(ureq::Error::Other(Box::new(std::io::Error::other(
-                            "request body required",
-                        ))));
+                        let mut request = match method {
+                            Method::POST => self.agent.post(url),
+                            Method::PUT => self.agent.put(url),
+                            _ => self.agent.patch(url),
+                        };
+
+                        if let Some(timeout) = timeout_override {
+                            request = request.config().timeout_global(Some(timeout)).build();
+                        }
+
+                        for (name, value) in headers.iter() {
+                            request = request.header(name, value);
+                        }
+
+                        return request.send_empty();
                     }
                     _ => {
                         let mut builder = http::Request::builder().method(method).uri(url);
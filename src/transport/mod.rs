@@ -4,6 +4,9 @@ mod async_transport;
 mod blocking_transport;
 mod retry;
 
+/// Route label used when a request was issued without a route template.
+pub(crate) const UNKNOWN_ROUTE: &str = "unknown";
+
 #[cfg(feature = "async")]
 pub(crate) use async_transport::AsyncTransport;
 #[cfg(feature = "blocking")]
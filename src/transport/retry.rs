@@ -17,6 +17,17 @@ pub(crate) fn is_retryable_status(status: StatusCode) -> bool {
     )
 }
 
+/// Statuses that indicate the endpoint itself (or its gateway) is down.
+///
+/// A 504 is left out: it usually means a slow query behind a healthy replica, and taking
+/// replicas out of rotation under load would only shift that load onto the others.
+pub(crate) fn is_unavailable_status(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::BAD_GATEWAY | StatusCode::SERVICE_UNAVAILABLE
+    )
+}
+
 pub(crate) fn is_idempotent_method(method: &Method) -> bool {
     matches!(
         *method,
@@ -112,6 +123,10 @@ mod tests {
         assert!(is_retryable_status(StatusCode::TOO_MANY_REQUESTS));
         assert!(is_retryable_status(StatusCode::SERVICE_UNAVAILABLE));
         assert!(!is_retryable_status(StatusCode::BAD_REQUEST));
+
+        assert!(is_unavailable_status(StatusCode::BAD_GATEWAY));
+        assert!(is_unavailable_status(StatusCode::SERVICE_UNAVAILABLE));
+        assert!(!is_unavailable_status(StatusCode::GATEWAY_TIMEOUT));
     }
 
     #[test]
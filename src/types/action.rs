@@ -0,0 +1,673 @@
+use std::collections::BTreeMap;
+
+use serde_json::{Map, Value};
+use time::format_description::well_known::{Iso8601, Rfc3339};
+use time::{Date, OffsetDateTime, PrimitiveDateTime};
+
+use crate::types::field::BaseType;
+use crate::types::ids::{ActionId, CardId, DatabaseId};
+use crate::{Error, Result};
+
+string_enum! {
+    /// What an implicit action does to a row of its model.
+    pub enum ImplicitActionKind {
+        Create => "row/create",
+        Update => "row/update",
+        Delete => "row/delete",
+    }
+}
+
+string_enum! {
+    /// How a parameter is entered in the action form.
+    pub enum ActionInputType {
+        String => "string",
+        Text => "text",
+        Number => "number",
+        Date => "date",
+        DateTime => "datetime",
+        Time => "time",
+        Boolean => "boolean",
+        Select => "select",
+        Radio => "radio",
+        Category => "category",
+    }
+}
+
+/// An action, as returned by `GET /api/action/{action-id}`.
+///
+/// Fields not modelled here are kept in [`extra`](Self::extra).
+#[derive(Clone, Debug, serde::Deserialize)]
+#[serde(try_from = "RawAction")]
+#[non_exhaustive]
+pub struct Action {
+    pub id: ActionId,
+    pub name: String,
+    pub description: Option<String>,
+    /// The model the action belongs to.
+    pub model_id: Option<CardId>,
+    pub database_id: Option<DatabaseId>,
+    /// What the action runs, from its `type`.
+    pub kind: ActionKind,
+    pub parameters: Vec<ActionParameter>,
+    pub visualization_settings: ActionVisualizationSettings,
+    pub public_uuid: Option<String>,
+    pub archived: bool,
+    pub extra: Map<String, Value>,
+}
+
+/// Wire shape of [`Action`]; the [`ActionKind`] fields are split out of `rest`.
+#[derive(serde::Deserialize)]
+struct RawAction {
+    id: ActionId,
+    name: String,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    model_id: Option<CardId>,
+    #[serde(default)]
+    database_id: Option<DatabaseId>,
+    #[serde(default)]
+    parameters: Vec<ActionParameter>,
+    #[serde(default)]
+    visualization_settings: ActionVisualizationSettings,
+    #[serde(default)]
+    public_uuid: Option<String>,
+    #[serde(default)]
+    archived: bool,
+    #[serde(flatten)]
+    rest: Map<String, Value>,
+}
+
+impl TryFrom<RawAction> for Action {
+    type Error = serde_json::Error;
+
+    fn try_from(raw: RawAction) -> std::result::Result<Self, Self::Error> {
+        let mut extra = raw.rest;
+        let keys: &[&str] = match extra.get("type").and_then(Value::as_str) {
+            Some("query") => &["type", "dataset_query"],
+            Some("http") => &["type", "template", "response_handle", "error_handle"],
+            Some("implicit") => &["type", "kind"],
+            _ => &[],
+        };
+        let kind = if keys.is_empty() {
+            match extra.remove("type") {
+                Some(Value::String(tag)) => ActionKind::Unknown(tag),
+                other => {
+                    if let Some(other) = other {
+                        extra.insert("type".to_owned(), other);
+                    }
+                    ActionKind::Unknown(String::new())
+                }
+            }
+        } else {
+            let fields: Map<String, Value> = keys
+                .iter()
+                .filter_map(|key| extra.remove_entry(*key))
+                .collect();
+            serde_json::from_value(Value::Object(fields))?
+        };
+        Ok(Self {
+            id: raw.id,
+            name: raw.name,
+            description: raw.description,
+            model_id: raw.model_id,
+            database_id: raw.database_id,
+            kind,
+            parameters: raw.parameters,
+            visualization_settings: raw.visualization_settings,
+            public_uuid: raw.public_uuid,
+            archived: raw.archived,
+            extra,
+        })
+    }
+}
+
+impl Action {
+    /// Check `parameters` against the declared parameters; see [`ActionParameters`].
+    pub fn validate(&self, parameters: &ActionParameters) -> Result<()> {
+        parameters.validate(self.id, &self.parameters, &self.visualization_settings)
+    }
+}
+
+/// What an action runs.
+#[derive(Clone, Debug, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ActionKind {
+    /// A native query with `{{parameter}}` template tags.
+    Query {
+        #[serde(default)]
+        dataset_query: Value,
+    },
+    /// A request to an external endpoint, built from `template`.
+    Http {
+        #[serde(default)]
+        template: Value,
+        /// A jq expression applied to the response.
+        #[serde(default)]
+        response_handle: Option<String>,
+        #[serde(default)]
+        error_handle: Option<String>,
+    },
+    /// A create, update or delete on the model's table.
+    Implicit { kind: ImplicitActionKind },
+    /// An action type this SDK does not know about yet, with its `type` tag (empty if the
+    /// action had no string `type`). Its other fields are left in [`Action::extra`].
+    #[serde(skip)]
+    Unknown(String),
+}
+
+/// A parameter an action declares.
+#[derive(Clone, Debug, serde::Deserialize)]
+#[non_exhaustive]
+pub struct ActionParameter {
+    /// The key the value is passed under in [`ActionParameters`].
+    pub id: String,
+    #[serde(default)]
+    pub slug: Option<String>,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default, rename = "display-name")]
+    pub display_name: Option<String>,
+    /// The value type, e.g. `number/=` on query actions or `type/Integer` on implicit ones.
+    #[serde(rename = "type", default)]
+    pub parameter_type: Option<ActionParameterType>,
+    #[serde(default)]
+    pub required: bool,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// The declared type of an action parameter.
+///
+/// Query and HTTP actions use the parameter vocabulary (`number/=`, `string/=`,
+/// `date/single`, ...), kept here as the part after the slash; implicit actions use the
+/// column's base type.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ActionParameterType {
+    /// A `number/*` parameter, e.g. `Number("=")`.
+    Number(String),
+    /// A `string/*` parameter, e.g. `String("=")`.
+    String(String),
+    /// A `date/*` parameter, e.g. `Date("single")`.
+    Date(String),
+    /// A column base type, e.g. `type/Integer`.
+    Base(BaseType),
+    /// A parameter type this SDK does not know about yet.
+    Unknown(String),
+}
+
+impl ActionParameterType {
+    /// Why `value` does not fit this type, if it does not. Types without a check pass.
+    fn mismatch(&self, value: &Value) -> Option<&'static str> {
+        match self {
+            Self::Number(operator) if operator == "=" => {
+                (!value.is_number()).then_some("expected a number")
+            }
+            Self::String(operator) if operator == "=" => {
+                (!value.is_string()).then_some("expected a string")
+            }
+            Self::Date(operator) if operator == "single" => {
+                let text = value.as_str();
+                let ok = text.is_some_and(|text| {
+                    Date::parse(text, &Iso8601::DATE).is_ok() || is_date_time(text)
+                });
+                (!ok).then_some("expected a date (YYYY-MM-DD) or date-time (ISO 8601)")
+            }
+            Self::Base(base_type) => base_type_mismatch(base_type, value),
+            _ => None,
+        }
+    }
+}
+
+impl From<String> for ActionParameterType {
+    fn from(value: String) -> Self {
+        let Some((prefix, rest)) = value.split_once('/') else {
+            return Self::Unknown(value);
+        };
+        match prefix {
+            "number" => Self::Number(rest.to_owned()),
+            "string" => Self::String(rest.to_owned()),
+            "date" => Self::Date(rest.to_owned()),
+            "type" => Self::Base(BaseType::from(value.as_str())),
+            _ => Self::Unknown(value),
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ActionParameterType {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Self::from)
+    }
+}
+
+/// How an action's form is presented.
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct ActionVisualizationSettings {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub submit_button_label: Option<String>,
+    #[serde(default)]
+    pub confirm_message: Option<String>,
+    #[serde(default)]
+    pub success_message: Option<String>,
+    /// Form settings per parameter id.
+    #[serde(default)]
+    pub fields: BTreeMap<String, ActionFieldSettings>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Form settings of one action parameter.
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct ActionFieldSettings {
+    #[serde(default)]
+    pub input_type: Option<ActionInputType>,
+    #[serde(default)]
+    pub required: bool,
+    #[serde(default)]
+    pub hidden: bool,
+    #[serde(default)]
+    pub default_value: Option<Value>,
+    #[serde(default)]
+    pub placeholder: Option<String>,
+    #[serde(default)]
+    pub value_options: Vec<Value>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// A publicly shared action, as returned by `GET /api/public/action/{uuid}`.
+#[derive(Clone, Debug, serde::Deserialize)]
+#[non_exhaustive]
+pub struct PublicAction {
+    pub id: ActionId,
+    pub name: String,
+    #[serde(default)]
+    pub parameters: Vec<ActionParameter>,
+    #[serde(default)]
+    pub visualization_settings: ActionVisualizationSettings,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl PublicAction {
+    /// Check `parameters` against the declared parameters; see [`ActionParameters`].
+    pub fn validate(&self, parameters: &ActionParameters) -> Result<()> {
+        parameters.validate(self.id, &self.parameters, &self.visualization_settings)
+    }
+}
+
+/// Query parameters for `GET /api/action/`.
+#[derive(Clone, Debug, Default, serde::Serialize)]
+#[non_exhaustive]
+pub struct ActionListQuery {
+    /// Only actions of this model.
+    #[serde(rename = "model-id", skip_serializing_if = "Option::is_none")]
+    pub model_id: Option<CardId>,
+}
+
+impl ActionListQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn model(mut self, model: CardId) -> Self {
+        self.model_id = Some(model);
+        self
+    }
+}
+
+/// Parameter values for an action execution, keyed by parameter id.
+///
+/// Before sending, values are checked against the action: every key must be a declared
+/// parameter, required parameters without a default must be present, and non-null values
+/// must match the parameter type. Dates are `YYYY-MM-DD`; date-times are ISO 8601 with or
+/// without an offset; `date/single` accepts either.
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize)]
+#[serde(transparent)]
+pub struct ActionParameters(BTreeMap<String, Value>);
+
+impl ActionParameters {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set(mut self, id: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.0.insert(id.into(), value.into());
+        self
+    }
+
+    pub fn get(&self, id: &str) -> Option<&Value> {
+        self.0.get(id)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn validate(
+        &self,
+        action: ActionId,
+        declared: &[ActionParameter],
+        settings: &ActionVisualizationSettings,
+    ) -> Result<()> {
+        if let Some(id) = self
+            .0
+            .keys()
+            .find(|id| !declared.iter().any(|parameter| &parameter.id == *id))
+        {
+            return Err(Error::invalid_action_parameter(
+                action,
+                id.as_str(),
+                "not a parameter of this action",
+            ));
+        }
+        for parameter in declared {
+            let field = settings.fields.get(&parameter.id);
+            match self.0.get(&parameter.id) {
+                None | Some(Value::Null) => {
+                    let required = parameter.required || field.is_some_and(|field| field.required);
+                    let defaulted = field.is_some_and(|field| field.default_value.is_some());
+                    if required && !defaulted {
+                        return Err(Error::invalid_action_parameter(
+                            action,
+                            parameter.id.as_str(),
+                            "required parameter is missing",
+                        ));
+                    }
+                }
+                Some(value) => {
+                    if let Some(reason) = parameter
+                        .parameter_type
+                        .as_ref()
+                        .and_then(|parameter_type| parameter_type.mismatch(value))
+                    {
+                        return Err(Error::invalid_action_parameter(
+                            action,
+                            parameter.id.as_str(),
+                            reason,
+                        ));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Why `value` does not fit the column base type `parameter_type`, if it does not.
+fn base_type_mismatch(parameter_type: &BaseType, value: &Value) -> Option<&'static str> {
+    let text = value.as_str();
+    let ok = match parameter_type {
+        BaseType::Text | BaseType::Uuid | BaseType::IpAddress => value.is_string(),
+        BaseType::Integer => value.is_i64() || value.is_u64(),
+        // Values past `i64` are sent as decimal strings.
+        BaseType::BigInteger => {
+            value.is_i64()
+                || value.is_u64()
+                || text.is_some_and(|text| {
+                    let digits = text.strip_prefix('-').unwrap_or(text);
+                    !digits.is_empty() && digits.bytes().all(|byte| byte.is_ascii_digit())
+                })
+        }
+        BaseType::Float | BaseType::Decimal | BaseType::Number => value.is_number(),
+        BaseType::Boolean => value.is_boolean(),
+        BaseType::Date => text.is_some_and(|text| Date::parse(text, &Iso8601::DATE).is_ok()),
+        BaseType::DateTime
+        | BaseType::DateTimeWithLocalTz
+        | BaseType::DateTimeWithTz
+        | BaseType::DateTimeWithZoneId
+        | BaseType::DateTimeWithZoneOffset => text.is_some_and(is_date_time),
+        _ => true,
+    };
+    if ok {
+        return None;
+    }
+    Some(match parameter_type {
+        BaseType::Integer | BaseType::BigInteger => "expected an integer",
+        BaseType::Float | BaseType::Decimal | BaseType::Number => "expected a number",
+        BaseType::Boolean => "expected a boolean",
+        BaseType::Date => "expected a date (YYYY-MM-DD)",
+        BaseType::Text | BaseType::Uuid | BaseType::IpAddress => "expected a string",
+        _ => "expected a date-time (ISO 8601)",
+    })
+}
+
+/// Whether `text` is an ISO 8601 date-time, with or without an offset.
+fn is_date_time(text: &str) -> bool {
+    OffsetDateTime::parse(text, &Rfc3339).is_ok()
+        || PrimitiveDateTime::parse(text, &Iso8601::DEFAULT).is_ok()
+}
+
+/// Request body for `POST /api/action/{id}/execute`.
+#[derive(serde::Serialize)]
+pub(crate) struct ExecuteAction<'a> {
+    pub(crate) parameters: &'a ActionParameters,
+}
+
+/// Response from executing an action.
+///
+/// Which field is set depends on the action: query actions report `rows-affected`,
+/// implicit actions `rows-updated`, `rows-deleted` or `created-row`. HTTP action
+/// responses are kept in [`extra`](Self::extra).
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+#[non_exhaustive]
+pub struct ActionResult {
+    #[serde(default, rename = "rows-affected")]
+    pub rows_affected: Option<u64>,
+    #[serde(default, rename = "rows-updated")]
+    pub rows_updated: Option<Vec<u64>>,
+    #[serde(default, rename = "rows-deleted")]
+    pub rows_deleted: Option<Vec<u64>>,
+    #[serde(default, rename = "created-row")]
+    pub created_row: Option<Map<String, Value>>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl ActionResult {
+    /// The number of rows changed, whichever way the action reported it.
+    pub fn row_count(&self) -> Option<u64> {
+        self.rows_affected
+            .or_else(|| self.rows_updated.as_ref().map(|rows| rows.iter().sum()))
+            .or_else(|| self.rows_deleted.as_ref().map(|rows| rows.iter().sum()))
+            .or_else(|| self.created_row.as_ref().map(|_| 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error as StdError;
+
+    #[test]
+    fn parameters_are_checked_against_the_action()
+    -> std::result::Result<(), Box<dyn StdError + Send + Sync>> {
+        let action: Action = serde_json::from_value(serde_json::json!({
+            "id": 9,
+            "name": "Refund",
+            "type": "query",
+            "model_id": 3,
+            "database_id": 1,
+            "dataset_query": {
+                "type": "native",
+                "database": 1,
+                "native": {
+                    "query": "update orders set refunded = {{amount}} where id = {{order_id}}",
+                    "template-tags": {}
+                }
+            },
+            "parameters": [
+                {
+                    "id": "order_id",
+                    "type": "number/=",
+                    "slug": "order_id",
+                    "target": ["variable", ["template-tag", "order_id"]],
+                    "required": true
+                },
+                {
+                    "id": "amount",
+                    "type": "number/=",
+                    "slug": "amount",
+                    "target": ["variable", ["template-tag", "amount"]]
+                },
+                {
+                    "id": "refunded_on",
+                    "type": "date/single",
+                    "slug": "refunded_on",
+                    "target": ["variable", ["template-tag", "refunded_on"]]
+                },
+                {
+                    "id": "reason",
+                    "type": "string/=",
+                    "slug": "reason",
+                    "target": ["variable", ["template-tag", "reason"]]
+                }
+            ],
+            "visualization_settings": {
+                "confirmMessage": "Refund?",
+                "fields": {
+                    "reason": {"inputType": "text", "required": true, "defaultValue": "n/a"},
+                    "amount": {"inputType": "number", "required": true}
+                }
+            }
+        }))?;
+        assert!(matches!(action.kind, ActionKind::Query { .. }));
+        assert!(action.extra.is_empty());
+        assert_eq!(
+            action.parameters[0].parameter_type,
+            Some(ActionParameterType::Number("=".to_owned()))
+        );
+
+        let valid = ActionParameters::new()
+            .set("order_id", 42)
+            .set("amount", 9.5)
+            .set("refunded_on", "2024-05-01");
+        action.validate(&valid)?;
+        action.validate(
+            &valid
+                .clone()
+                .set("refunded_on", "2024-05-01T10:00:00+02:00"),
+        )?;
+
+        let reason = |parameters: ActionParameters| match action.validate(&parameters) {
+            Err(Error::InvalidActionParameter {
+                parameter, reason, ..
+            }) => Some((parameter, reason)),
+            _ => None,
+        };
+        assert_eq!(
+            reason(valid.clone().set("order_id", "42")),
+            Some(("order_id".to_owned(), "expected a number"))
+        );
+        assert_eq!(
+            reason(valid.clone().set("reason", 7)),
+            Some(("reason".to_owned(), "expected a string"))
+        );
+        assert_eq!(
+            reason(valid.clone().set("refunded_on", "01/05/2024")),
+            Some((
+                "refunded_on".to_owned(),
+                "expected a date (YYYY-MM-DD) or date-time (ISO 8601)"
+            ))
+        );
+        assert_eq!(
+            reason(valid.clone().set("amount", Value::Null)),
+            Some(("amount".to_owned(), "required parameter is missing"))
+        );
+        assert_eq!(
+            reason(valid.set("note", "x")),
+            Some(("note".to_owned(), "not a parameter of this action"))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn implicit_action_parameters_are_checked_against_base_types()
+    -> std::result::Result<(), Box<dyn StdError + Send + Sync>> {
+        let action: Action = serde_json::from_value(serde_json::json!({
+            "id": 10,
+            "name": "Update order",
+            "type": "implicit",
+            "kind": "row/update",
+            "model_id": 3,
+            "database_id": 1,
+            "parameters": [
+                {"id": "id", "type": "type/Integer", "required": true},
+                {"id": "total", "type": "type/Float"},
+                {"id": "shipped_on", "type": "type/Date"},
+                {"id": "updated_at", "type": "type/DateTime"}
+            ]
+        }))?;
+        assert!(matches!(
+            action.kind,
+            ActionKind::Implicit {
+                kind: ImplicitActionKind::Update
+            }
+        ));
+
+        let valid = ActionParameters::new()
+            .set("id", 42)
+            .set("total", 9.5)
+            .set("shipped_on", "2024-05-01")
+            .set("updated_at", "2024-05-01T10:00:00");
+        action.validate(&valid)?;
+
+        let reason = |parameters: ActionParameters| match action.validate(&parameters) {
+            Err(Error::InvalidActionParameter { reason, .. }) => Some(reason),
+            _ => None,
+        };
+        assert_eq!(
+            reason(valid.clone().set("id", 4.2)),
+            Some("expected an integer")
+        );
+        assert_eq!(
+            reason(valid.set("shipped_on", "May 1st")),
+            Some("expected a date (YYYY-MM-DD)")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_action_types_keep_their_tag()
+    -> std::result::Result<(), Box<dyn StdError + Send + Sync>> {
+        let action: Action = serde_json::from_value(serde_json::json!({
+            "id": 11,
+            "name": "Notify",
+            "type": "webhook",
+            "url": "https://example.com/hook"
+        }))?;
+        assert!(matches!(&action.kind, ActionKind::Unknown(tag) if tag == "webhook"));
+        assert_eq!(action.extra.get("type"), None);
+        assert_eq!(action.extra["url"], "https://example.com/hook");
+        Ok(())
+    }
+
+    #[test]
+    fn results_count_rows_for_each_action_type()
+    -> std::result::Result<(), Box<dyn StdError + Send + Sync>> {
+        let counts = [
+            serde_json::json!({"rows-affected": 3}),
+            serde_json::json!({"rows-updated": [2]}),
+            serde_json::json!({"rows-deleted": [1]}),
+            serde_json::json!({"created-row": {"id": 7}}),
+            serde_json::json!({"status": "ok"}),
+        ]
+        .into_iter()
+        .map(|body| serde_json::from_value::<ActionResult>(body).map(|result| result.row_count()))
+        .collect::<serde_json::Result<Vec<_>>>()?;
+        assert_eq!(counts, [Some(3), Some(2), Some(1), Some(1), None]);
+        Ok(())
+    }
+}
@@ -0,0 +1,112 @@
+use secrecy::SecretString;
+use serde_json::{Map, Value};
+
+use crate::types::ids::{ApiKeyId, GroupId, UserId};
+use crate::types::user::User;
+
+/// An API key, as returned by `GET /api/api-key/`.
+///
+/// Fields not modelled here are kept in [`extra`](Self::extra).
+#[derive(Clone, Debug, serde::Deserialize)]
+#[non_exhaustive]
+pub struct ApiKey {
+    pub id: ApiKeyId,
+    pub name: String,
+    /// The group whose permissions requests with this key get.
+    #[serde(default)]
+    pub group: Option<ApiKeyGroup>,
+    /// e.g. `mb_AbCd…`; safe to display.
+    #[serde(default)]
+    pub masked_key: Option<String>,
+    #[serde(default)]
+    pub key_prefix: Option<String>,
+    /// The full key. Only set in the response to `POST /api/api-key/`; the server never
+    /// returns it again. Pass it to [`Auth::api_key_secret`](crate::Auth::api_key_secret).
+    #[serde(default)]
+    pub unmasked_key: Option<SecretString>,
+    /// The user the key acts as.
+    #[serde(default)]
+    pub user_id: Option<UserId>,
+    #[serde(default)]
+    pub creator_id: Option<UserId>,
+    #[serde(default)]
+    pub updated_by: Option<User>,
+    #[serde(default)]
+    pub created_at: Option<String>,
+    #[serde(default)]
+    pub updated_at: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize)]
+#[non_exhaustive]
+pub struct ApiKeyGroup {
+    pub id: GroupId,
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+/// Response from `PUT /api/api-key/{id}/regenerate`.
+///
+/// The previous key stops working immediately.
+#[derive(Clone, Debug, serde::Deserialize)]
+#[non_exhaustive]
+pub struct RegeneratedApiKey {
+    pub id: ApiKeyId,
+    /// The new full key; the server never returns it again.
+    pub unmasked_key: SecretString,
+    #[serde(default)]
+    pub masked_key: Option<String>,
+    #[serde(default)]
+    pub prefix: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Request body for `POST /api/api-key/`.
+#[derive(Clone, Debug, serde::Serialize)]
+#[non_exhaustive]
+pub struct CreateApiKey {
+    pub name: String,
+    pub group_id: GroupId,
+}
+
+impl CreateApiKey {
+    /// A key named `name` with the permissions of `group`.
+    pub fn new(name: impl Into<String>, group: GroupId) -> Self {
+        Self {
+            name: name.into(),
+            group_id: group,
+        }
+    }
+}
+
+/// Request body for `PUT /api/api-key/{id}`.
+///
+/// Only fields that were set are sent.
+#[derive(Clone, Debug, Default, serde::Serialize)]
+#[non_exhaustive]
+pub struct UpdateApiKey {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_id: Option<GroupId>,
+}
+
+impl UpdateApiKey {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Move the key to another group.
+    pub fn group(mut self, group: GroupId) -> Self {
+        self.group_id = Some(group);
+        self
+    }
+}
@@ -0,0 +1,128 @@
+use serde_json::{Map, Value};
+
+use crate::Result;
+use crate::types::schedule::Schedule;
+
+string_enum! {
+    /// What a cache configuration applies to.
+    pub enum CacheModel {
+        /// The instance-wide default; its `model_id` is 0.
+        Root => "root",
+        Database => "database",
+        Dashboard => "dashboard",
+        Question => "question",
+    }
+}
+
+/// How results are cached, tagged by `type`.
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum CacheStrategy {
+    Nocache,
+    /// Cache for `multiplier` times the query's average run time, once it takes at least
+    /// `min_duration_ms`.
+    Ttl {
+        multiplier: u64,
+        min_duration_ms: u64,
+    },
+    /// Cache for a fixed time; `unit` is e.g. `hours`.
+    Duration {
+        duration: u64,
+        unit: String,
+        #[serde(default)]
+        refresh_automatically: bool,
+    },
+    /// Invalidate on a Quartz cron schedule; see [`CacheStrategy::schedule`].
+    Schedule {
+        schedule: String,
+        #[serde(default)]
+        refresh_automatically: bool,
+    },
+    /// Use the parent's configuration.
+    Inherit,
+    /// A strategy this SDK does not know about yet, as received; sent back unchanged.
+    #[serde(untagged)]
+    Unknown(Map<String, Value>),
+}
+
+impl CacheStrategy {
+    /// Invalidate the cache on `schedule`.
+    pub fn schedule(schedule: &Schedule) -> Result<Self> {
+        Ok(Self::Schedule {
+            schedule: schedule.to_cron()?,
+            refresh_automatically: false,
+        })
+    }
+
+    /// The invalidation schedule of a schedule strategy.
+    pub fn invalidation_schedule(&self) -> Option<Schedule> {
+        match self {
+            Self::Schedule { schedule, .. } => Some(Schedule::cron(schedule.as_str())),
+            _ => None,
+        }
+    }
+}
+
+/// One entry of `GET /api/cache/`; also the request body of `PUT /api/cache/`.
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+#[non_exhaustive]
+pub struct CacheConfig {
+    pub model: CacheModel,
+    pub model_id: i64,
+    pub strategy: CacheStrategy,
+}
+
+impl CacheConfig {
+    pub fn new(model: CacheModel, model_id: impl Into<i64>, strategy: CacheStrategy) -> Self {
+        Self {
+            model,
+            model_id: model_id.into(),
+            strategy,
+        }
+    }
+}
+
+/// Response from `GET /api/cache/`.
+#[derive(serde::Deserialize)]
+pub(crate) struct CacheConfigs {
+    pub(crate) data: Vec<CacheConfig>,
+}
+
+/// Query parameters for `GET /api/cache/`.
+#[derive(serde::Serialize)]
+pub(crate) struct CacheConfigQuery<'a> {
+    pub(crate) model: &'a [CacheModel],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error as StdError;
+
+    #[test]
+    fn unknown_strategies_round_trip() -> std::result::Result<(), Box<dyn StdError + Send + Sync>> {
+        let json = serde_json::json!({
+            "model": "question",
+            "model_id": 3,
+            "strategy": {"type": "query", "field_id": 9, "aggregation": "max"}
+        });
+        let config: CacheConfig = serde_json::from_value(json.clone())?;
+        assert!(matches!(config.strategy, CacheStrategy::Unknown(_)));
+        assert_eq!(serde_json::to_value(&config)?, json);
+
+        let config: CacheConfig = serde_json::from_value(serde_json::json!({
+            "model": "root",
+            "model_id": 0,
+            "strategy": {"type": "ttl", "multiplier": 10, "min_duration_ms": 1000}
+        }))?;
+        assert_eq!(
+            config.strategy,
+            CacheStrategy::Ttl {
+                multiplier: 10,
+                min_duration_ms: 1000
+            }
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,253 @@
+use std::collections::BTreeMap;
+
+use serde_json::{Map, Value};
+
+use crate::types::ids::{CardId, CollectionId, DatabaseId, TableId};
+
+string_enum! {
+    /// What a card is used as.
+    pub enum CardType {
+        Question => "question",
+        Model => "model",
+        Metric => "metric",
+    }
+}
+
+/// A saved question, model or metric, as returned by `GET /api/card/{id}`.
+///
+/// Fields not modelled here are kept in [`extra`](Self::extra).
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[non_exhaustive]
+pub struct Card {
+    pub id: CardId,
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(rename = "type", default)]
+    pub card_type: Option<CardType>,
+    #[serde(default)]
+    pub dataset_query: Value,
+    #[serde(default)]
+    pub display: String,
+    #[serde(default)]
+    pub visualization_settings: Map<String, Value>,
+    #[serde(default)]
+    pub collection_id: Option<CollectionId>,
+    #[serde(default)]
+    pub database_id: Option<DatabaseId>,
+    #[serde(default)]
+    pub table_id: Option<TableId>,
+    #[serde(default)]
+    pub archived: bool,
+    #[serde(default)]
+    pub enable_embedding: bool,
+    #[serde(default)]
+    pub result_metadata: Option<Vec<Value>>,
+    #[serde(default)]
+    pub embedding_params: Option<BTreeMap<String, String>>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Request body for `POST /api/card/`.
+#[derive(Clone, Debug, serde::Serialize)]
+#[non_exhaustive]
+pub struct CreateCard {
+    pub name: String,
+    pub dataset_query: Value,
+    pub display: String,
+    pub visualization_settings: Map<String, Value>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub card_type: Option<CardType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collection_id: Option<CollectionId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result_metadata: Option<Vec<Value>>,
+}
+
+impl CreateCard {
+    /// A table question over `dataset_query` with default visualization settings.
+    pub fn new(name: impl Into<String>, dataset_query: Value) -> Self {
+        Self {
+            name: name.into(),
+            dataset_query,
+            display: "table".to_owned(),
+            visualization_settings: Map::new(),
+            card_type: None,
+            description: None,
+            collection_id: None,
+            result_metadata: None,
+        }
+    }
+
+    pub fn card_type(mut self, card_type: CardType) -> Self {
+        self.card_type = Some(card_type);
+        self
+    }
+
+    pub fn display(mut self, display: impl Into<String>) -> Self {
+        self.display = display.into();
+        self
+    }
+
+    pub fn visualization_settings(mut self, settings: Map<String, Value>) -> Self {
+        self.visualization_settings = settings;
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn collection_id(mut self, collection_id: CollectionId) -> Self {
+        self.collection_id = Some(collection_id);
+        self
+    }
+
+    pub fn result_metadata(mut self, result_metadata: Vec<Value>) -> Self {
+        self.result_metadata = Some(result_metadata);
+        self
+    }
+}
+
+/// Request body for `PUT /api/card/{id}`.
+///
+/// Only fields that were set are sent.
+#[derive(Clone, Debug, Default, serde::Serialize)]
+#[non_exhaustive]
+pub struct UpdateCard {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub card_type: Option<CardType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dataset_query: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visualization_settings: Option<Map<String, Value>>,
+    /// `Some(None)` moves the card to the root collection.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collection_id: Option<Option<CollectionId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archived: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_embedding: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result_metadata: Option<Vec<Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub embedding_params: Option<BTreeMap<String, String>>,
+}
+
+impl UpdateCard {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn card_type(mut self, card_type: CardType) -> Self {
+        self.card_type = Some(card_type);
+        self
+    }
+
+    pub fn dataset_query(mut self, dataset_query: Value) -> Self {
+        self.dataset_query = Some(dataset_query);
+        self
+    }
+
+    pub fn display(mut self, display: impl Into<String>) -> Self {
+        self.display = Some(display.into());
+        self
+    }
+
+    pub fn visualization_settings(mut self, settings: Map<String, Value>) -> Self {
+        self.visualization_settings = Some(settings);
+        self
+    }
+
+    pub fn collection_id(mut self, collection_id: Option<CollectionId>) -> Self {
+        self.collection_id = Some(collection_id);
+        self
+    }
+
+    pub fn archived(mut self, archived: bool) -> Self {
+        self.archived = Some(archived);
+        self
+    }
+
+    pub fn enable_embedding(mut self, enable_embedding: bool) -> Self {
+        self.enable_embedding = Some(enable_embedding);
+        self
+    }
+
+    pub fn result_metadata(mut self, result_metadata: Vec<Value>) -> Self {
+        self.result_metadata = Some(result_metadata);
+        self
+    }
+
+    pub fn embedding_params(mut self, embedding_params: BTreeMap<String, String>) -> Self {
+        self.embedding_params = Some(embedding_params);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error as StdError;
+
+    #[test]
+    fn card_keeps_unknown_fields() -> Result<(), Box<dyn StdError + Send + Sync>> {
+        let card: Card = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "name": "Orders",
+            "type": "model",
+            "display": "table",
+            "collection_id": null,
+            "cache_ttl": 60,
+        }))?;
+        assert_eq!(card.id, CardId(1));
+        assert_eq!(card.card_type, Some(CardType::Model));
+        assert_eq!(card.collection_id, None);
+        assert_eq!(card.extra.get("cache_ttl"), Some(&Value::from(60)));
+
+        let future: Card = serde_json::from_value(serde_json::json!({
+            "id": 2,
+            "name": "x",
+            "type": "something-new",
+        }))?;
+        assert_eq!(
+            future.card_type,
+            Some(CardType::Unknown("something-new".to_owned()))
+        );
+        assert_eq!(
+            serde_json::to_value(&future)?["type"],
+            Value::from("something-new")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn update_card_sends_only_set_fields() -> Result<(), Box<dyn StdError + Send + Sync>> {
+        let body = serde_json::to_value(UpdateCard::new().name("Renamed").collection_id(None))?;
+        assert_eq!(
+            body,
+            serde_json::json!({"name": "Renamed", "collection_id": null})
+        );
+        Ok(())
+    }
+}
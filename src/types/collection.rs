@@ -0,0 +1,535 @@
+use std::{collections::BTreeMap, fmt};
+
+use serde_json::{Map, Value};
+
+use crate::types::ids::{CardId, CollectionId, DashboardId, GroupId, PulseId, SnippetId, UserId};
+use crate::types::path::PathParam;
+use crate::types::permissions::GraphChanges;
+
+/// Either the root collection ("Our analytics") or a numbered collection.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CollectionRef {
+    Root,
+    Id(CollectionId),
+}
+
+impl fmt::Display for CollectionRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Root => f.write_str("root"),
+            Self::Id(id) => write!(f, "{id}"),
+        }
+    }
+}
+
+impl From<CollectionId> for CollectionRef {
+    fn from(value: CollectionId) -> Self {
+        Self::Id(value)
+    }
+}
+
+impl From<CollectionRef> for PathParam {
+    fn from(value: CollectionRef) -> Self {
+        match value {
+            CollectionRef::Root => Self::from("root"),
+            CollectionRef::Id(id) => Self::from(id),
+        }
+    }
+}
+
+impl serde::Serialize for CollectionRef {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Root => serializer.serialize_str("root"),
+            Self::Id(id) => serializer.serialize_i64(id.0),
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for CollectionRef {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            Id(i64),
+            Name(String),
+        }
+
+        match Raw::deserialize(deserializer)? {
+            Raw::Id(id) => Ok(Self::Id(CollectionId(id))),
+            Raw::Name(name) if name == "root" => Ok(Self::Root),
+            // Map keys, e.g. in the collection permissions graph, arrive as strings.
+            Raw::Name(name) => name
+                .parse()
+                .map(|id| Self::Id(CollectionId(id)))
+                .map_err(|_| {
+                    serde::de::Error::custom(format!("unexpected collection id `{name}`"))
+                }),
+        }
+    }
+}
+
+/// A collection, as returned by `GET /api/collection/{id}`, `/root` and `/trash`.
+///
+/// Fields not modelled here are kept in [`extra`](Self::extra).
+#[derive(Clone, Debug, serde::Deserialize)]
+#[non_exhaustive]
+pub struct Collection {
+    pub id: CollectionRef,
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub slug: Option<String>,
+    /// Materialized path of ancestor ids, e.g. `/1/4/`.
+    #[serde(default)]
+    pub location: Option<String>,
+    #[serde(default)]
+    pub archived: bool,
+    #[serde(default)]
+    pub personal_owner_id: Option<UserId>,
+    #[serde(default)]
+    pub namespace: Option<String>,
+    #[serde(default)]
+    pub authority_level: Option<String>,
+    /// e.g. `trash` or `instance-analytics`; `None` for regular collections.
+    #[serde(rename = "type", default)]
+    pub collection_type: Option<String>,
+    #[serde(default)]
+    pub can_write: Option<bool>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Fields shared by every kind of collection item.
+#[derive(Clone, Debug, serde::Deserialize)]
+#[non_exhaustive]
+pub struct CollectionEntry<Id> {
+    pub id: Id,
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub collection_position: Option<i64>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// One entry of `GET /api/collection/{id}/items`, tagged by its `model`.
+#[derive(Clone, Debug, serde::Deserialize)]
+#[serde(try_from = "Map<String, Value>")]
+#[non_exhaustive]
+pub enum CollectionItem {
+    Card(CollectionEntry<CardId>),
+    /// A model (called `dataset` by the API).
+    Dataset(CollectionEntry<CardId>),
+    Metric(CollectionEntry<CardId>),
+    Dashboard(CollectionEntry<DashboardId>),
+    Collection(CollectionEntry<CollectionId>),
+    Pulse(CollectionEntry<PulseId>),
+    Snippet(CollectionEntry<SnippetId>),
+    /// A model this SDK does not know about yet, as received, `model` included.
+    Unknown(Map<String, Value>),
+}
+
+impl TryFrom<Map<String, Value>> for CollectionItem {
+    type Error = serde_json::Error;
+
+    fn try_from(mut map: Map<String, Value>) -> std::result::Result<Self, Self::Error> {
+        fn entry<Id: serde::de::DeserializeOwned>(
+            map: Map<String, Value>,
+        ) -> serde_json::Result<CollectionEntry<Id>> {
+            serde_json::from_value(Value::Object(map))
+        }
+
+        let decode: fn(Map<String, Value>) -> serde_json::Result<Self> =
+            match map.get("model").and_then(Value::as_str) {
+                Some("card") => |map| entry(map).map(Self::Card),
+                Some("dataset") => |map| entry(map).map(Self::Dataset),
+                Some("metric") => |map| entry(map).map(Self::Metric),
+                Some("dashboard") => |map| entry(map).map(Self::Dashboard),
+                Some("collection") => |map| entry(map).map(Self::Collection),
+                Some("pulse") => |map| entry(map).map(Self::Pulse),
+                Some("snippet") => |map| entry(map).map(Self::Snippet),
+                _ => return Ok(Self::Unknown(map)),
+            };
+        map.remove("model");
+        decode(map)
+    }
+}
+
+impl CollectionItem {
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            Self::Card(entry) | Self::Dataset(entry) | Self::Metric(entry) => Some(&entry.name),
+            Self::Dashboard(entry) => Some(&entry.name),
+            Self::Collection(entry) => Some(&entry.name),
+            Self::Pulse(entry) => Some(&entry.name),
+            Self::Snippet(entry) => Some(&entry.name),
+            Self::Unknown(map) => map.get("name").and_then(Value::as_str),
+        }
+    }
+}
+
+/// Response from `GET /api/collection/{id}/items`.
+#[derive(Clone, Debug, serde::Deserialize)]
+#[non_exhaustive]
+pub struct CollectionItems {
+    #[serde(default)]
+    pub total: u64,
+    pub data: Vec<CollectionItem>,
+    /// Models present in the collection, regardless of the `models` filter.
+    #[serde(default)]
+    pub models: Vec<String>,
+    #[serde(default)]
+    pub limit: Option<u64>,
+    #[serde(default)]
+    pub offset: Option<u64>,
+}
+
+/// One node of `GET /api/collection/tree`.
+#[derive(Clone, Debug, serde::Deserialize)]
+#[non_exhaustive]
+pub struct CollectionTreeNode {
+    pub id: CollectionId,
+    pub name: String,
+    #[serde(default)]
+    pub location: Option<String>,
+    #[serde(default)]
+    pub children: Vec<CollectionTreeNode>,
+    /// Item models stored directly in this collection.
+    #[serde(default)]
+    pub here: Vec<String>,
+    /// Item models stored in descendants of this collection.
+    #[serde(default)]
+    pub below: Vec<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl CollectionTreeNode {
+    /// This node or the descendant with `id`.
+    pub fn find(&self, id: CollectionId) -> Option<&CollectionTreeNode> {
+        if self.id == id {
+            return Some(self);
+        }
+        self.children.iter().find_map(|child| child.find(id))
+    }
+
+    /// Ids of this node and all its descendants, parents first.
+    pub fn subtree_ids(&self) -> Vec<CollectionId> {
+        let mut ids = vec![self.id];
+        for child in &self.children {
+            ids.extend(child.subtree_ids());
+        }
+        ids
+    }
+}
+
+/// Item kinds accepted by the `models` filter.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum CollectionItemModel {
+    Card,
+    Dataset,
+    Metric,
+    Dashboard,
+    Collection,
+    Pulse,
+    Snippet,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum PinnedState {
+    All,
+    IsPinned,
+    IsNotPinned,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum CollectionSortColumn {
+    Name,
+    LastEditedAt,
+    LastEditedBy,
+    Model,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortDirection {
+    Asc,
+    Desc,
+}
+
+/// Query parameters for `GET /api/collection/{id}/items`.
+#[derive(Clone, Debug, Default, serde::Serialize)]
+#[non_exhaustive]
+pub struct CollectionItemsQuery {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub models: Vec<CollectionItemModel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archived: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pinned_state: Option<PinnedState>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_column: Option<CollectionSortColumn>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_direction: Option<SortDirection>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u64>,
+}
+
+impl CollectionItemsQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only return items of `model`; may be called repeatedly.
+    pub fn model(mut self, model: CollectionItemModel) -> Self {
+        self.models.push(model);
+        self
+    }
+
+    pub fn archived(mut self, archived: bool) -> Self {
+        self.archived = Some(archived);
+        self
+    }
+
+    pub fn pinned_state(mut self, pinned_state: PinnedState) -> Self {
+        self.pinned_state = Some(pinned_state);
+        self
+    }
+
+    pub fn sort(mut self, column: CollectionSortColumn, direction: SortDirection) -> Self {
+        self.sort_column = Some(column);
+        self.sort_direction = Some(direction);
+        self
+    }
+
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(mut self, offset: u64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+}
+
+string_enum! {
+    /// Access a group has to the items of a collection.
+    pub enum CollectionPermission {
+        Read => "read",
+        Write => "write",
+        None => "none",
+    }
+}
+
+/// Collection permissions per group and collection.
+pub type CollectionGroupPermissions =
+    BTreeMap<GroupId, BTreeMap<CollectionRef, CollectionPermission>>;
+
+/// Response from `GET /api/collection/graph`.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[non_exhaustive]
+pub struct CollectionPermissionsGraph {
+    /// Must be sent back unchanged; the server rejects updates made against a stale graph.
+    pub revision: i64,
+    pub groups: CollectionGroupPermissions,
+}
+
+impl CollectionPermissionsGraph {
+    /// The minimal change set that turns `self` into `target`.
+    pub fn diff(&self, target: &CollectionPermissionsGraph) -> CollectionPermissionsChanges {
+        let mut groups = CollectionGroupPermissions::new();
+        for (group, collections) in &target.groups {
+            let base = self.groups.get(group);
+            for (collection, level) in collections {
+                if base.and_then(|base| base.get(collection)) != Some(level) {
+                    groups
+                        .entry(*group)
+                        .or_default()
+                        .insert(*collection, level.clone());
+                }
+            }
+        }
+        CollectionPermissionsChanges { groups }
+    }
+
+    /// Apply `changes` in place, as the server would.
+    pub fn apply(&mut self, changes: &CollectionPermissionsChanges) {
+        for (group, collections) in &changes.groups {
+            let target = self.groups.entry(*group).or_default();
+            for (collection, level) in collections {
+                target.insert(*collection, level.clone());
+            }
+        }
+    }
+}
+
+/// A partial collection permissions graph: only the entries that change.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize)]
+#[non_exhaustive]
+pub struct CollectionPermissionsChanges {
+    pub groups: CollectionGroupPermissions,
+}
+
+impl CollectionPermissionsChanges {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Give `group` `level` access to `collection` only.
+    pub fn set(
+        mut self,
+        group: GroupId,
+        collection: impl Into<CollectionRef>,
+        level: CollectionPermission,
+    ) -> Self {
+        self.groups
+            .entry(group)
+            .or_default()
+            .insert(collection.into(), level);
+        self
+    }
+
+    /// Give `group` `level` access to `root` and every collection below it.
+    ///
+    /// Take `root` from [`CollectionTreeNode::find`] on the result of
+    /// `GET /api/collection/tree`.
+    pub fn set_subtree(
+        mut self,
+        group: GroupId,
+        root: &CollectionTreeNode,
+        level: CollectionPermission,
+    ) -> Self {
+        let collections = self.groups.entry(group).or_default();
+        for id in root.subtree_ids() {
+            collections.insert(CollectionRef::Id(id), level.clone());
+        }
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+
+    /// The part of these changes that still differs from `current`.
+    pub fn rebase(&self, current: &CollectionPermissionsGraph) -> CollectionPermissionsChanges {
+        let mut target = current.clone();
+        target.apply(self);
+        current.diff(&target)
+    }
+}
+
+impl GraphChanges for CollectionPermissionsChanges {
+    type Graph = CollectionPermissionsGraph;
+
+    fn rebase(&self, current: &CollectionPermissionsGraph) -> Self {
+        CollectionPermissionsChanges::rebase(self, current)
+    }
+
+    fn is_empty(&self) -> bool {
+        CollectionPermissionsChanges::is_empty(self)
+    }
+
+    fn revision(graph: &CollectionPermissionsGraph) -> i64 {
+        graph.revision
+    }
+}
+
+/// Request body for `PUT /api/collection/graph`.
+#[derive(serde::Serialize)]
+pub(crate) struct CollectionPermissionsGraphUpdate<'a> {
+    revision: i64,
+    groups: &'a CollectionGroupPermissions,
+}
+
+impl<'a> CollectionPermissionsGraphUpdate<'a> {
+    pub(crate) fn new(revision: i64, changes: &'a CollectionPermissionsChanges) -> Self {
+        Self {
+            revision,
+            groups: &changes.groups,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error as StdError;
+
+    #[test]
+    fn items_are_tagged_by_model() -> Result<(), Box<dyn StdError + Send + Sync>> {
+        let items: CollectionItems = serde_json::from_value(serde_json::json!({
+            "total": 3,
+            "data": [
+                {"model": "dataset", "id": 1, "name": "Orders model", "collection_position": 1},
+                {"model": "dashboard", "id": 2, "name": "Sales"},
+                {"model": "document", "id": 3, "name": "Notes"}
+            ],
+            "models": ["dataset", "dashboard", "document"]
+        }))?;
+        assert!(matches!(
+            &items.data[0],
+            CollectionItem::Dataset(entry) if entry.id == CardId(1) && entry.collection_position == Some(1)
+        ));
+        assert_eq!(items.data[1].name(), Some("Sales"));
+        assert!(matches!(
+            &items.data[2],
+            CollectionItem::Unknown(item) if item["model"] == "document" && item["id"] == 3
+        ));
+        assert_eq!(items.data[2].name(), Some("Notes"));
+        Ok(())
+    }
+
+    #[test]
+    fn collection_ref_accepts_root_and_ids() -> Result<(), Box<dyn StdError + Send + Sync>> {
+        let root: Collection =
+            serde_json::from_value(serde_json::json!({"id": "root", "name": "Our analytics"}))?;
+        assert_eq!(root.id, CollectionRef::Root);
+        let nested: CollectionRef = serde_json::from_value(serde_json::json!(7))?;
+        assert_eq!(nested, CollectionRef::Id(CollectionId(7)));
+        assert_eq!(PathParam::from(CollectionRef::Root).as_str(), "root");
+        Ok(())
+    }
+
+    #[test]
+    fn graph_subtree_grant_diffs_minimally() -> Result<(), Box<dyn StdError + Send + Sync>> {
+        let graph: CollectionPermissionsGraph = serde_json::from_value(serde_json::json!({
+            "revision": 2,
+            "groups": {"3": {"root": "none", "4": "none", "5": "read", "6": "none"}}
+        }))?;
+        let tree: Vec<CollectionTreeNode> = serde_json::from_value(serde_json::json!([
+            {"id": 4, "name": "Sales", "children": [
+                {"id": 5, "name": "EMEA", "children": []},
+                {"id": 7, "name": "APAC", "children": []}
+            ]},
+            {"id": 6, "name": "Ops", "children": []}
+        ]))?;
+        let sales = tree
+            .iter()
+            .find_map(|node| node.find(CollectionId(4)))
+            .ok_or("missing node")?;
+
+        let changes = CollectionPermissionsChanges::new().set_subtree(
+            GroupId(3),
+            sales,
+            CollectionPermission::Read,
+        );
+        let rebased = changes.rebase(&graph);
+        assert_eq!(
+            serde_json::to_value(&rebased)?,
+            serde_json::json!({"groups": {"3": {"4": "read", "7": "read"}}})
+        );
+        Ok(())
+    }
+}
@@ -1,4 +1,442 @@
-/// A passthrough type for large, evolving JSON payloads.
-#[derive(Clone, Debug, serde::Deserialize)]
-#[serde(transparent)]
-pub struct Dashboard(pub serde_json::Value);
+use std::collections::BTreeMap;
+
+use serde_json::{Map, Value};
+
+use crate::types::ids::{CardId, CollectionId, DashboardId, DashboardTabId, DashcardId};
+
+/// A dashboard, as returned by `GET /api/dashboard/{id}`.
+///
+/// Fields not modelled here are kept in [`extra`](Self::extra) and sent back unchanged
+/// when the dashboard is turned into an [`UpdateDashboard`].
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[non_exhaustive]
+pub struct Dashboard {
+    pub id: DashboardId,
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub collection_id: Option<CollectionId>,
+    #[serde(default)]
+    pub archived: bool,
+    #[serde(default, alias = "ordered_cards")]
+    pub dashcards: Vec<DashboardCard>,
+    #[serde(default)]
+    pub tabs: Vec<DashboardTab>,
+    #[serde(default)]
+    pub parameters: Vec<DashboardParameter>,
+    #[serde(default)]
+    pub enable_embedding: bool,
+    #[serde(default)]
+    pub embedding_params: Option<BTreeMap<String, String>>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// A card placed on a dashboard, with its grid position and size.
+///
+/// New dashcards sent to `PUT /api/dashboard/{id}/cards` use negative ids.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[non_exhaustive]
+pub struct DashboardCard {
+    pub id: DashcardId,
+    /// `None` for text and heading cards.
+    #[serde(default)]
+    pub card_id: Option<CardId>,
+    #[serde(default)]
+    pub dashboard_tab_id: Option<DashboardTabId>,
+    #[serde(default)]
+    pub row: i64,
+    #[serde(default)]
+    pub col: i64,
+    #[serde(default)]
+    pub size_x: i64,
+    #[serde(default)]
+    pub size_y: i64,
+    #[serde(default)]
+    pub parameter_mappings: Vec<ParameterMapping>,
+    #[serde(default)]
+    pub visualization_settings: Map<String, Value>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl DashboardCard {
+    /// A 4x4 card in the top-left corner.
+    pub fn new(id: DashcardId, card_id: Option<CardId>) -> Self {
+        Self {
+            id,
+            card_id,
+            dashboard_tab_id: None,
+            row: 0,
+            col: 0,
+            size_x: 4,
+            size_y: 4,
+            parameter_mappings: Vec::new(),
+            visualization_settings: Map::new(),
+            extra: Map::new(),
+        }
+    }
+
+    pub fn position(mut self, row: i64, col: i64) -> Self {
+        self.row = row;
+        self.col = col;
+        self
+    }
+
+    pub fn size(mut self, size_x: i64, size_y: i64) -> Self {
+        self.size_x = size_x;
+        self.size_y = size_y;
+        self
+    }
+
+    pub fn tab(mut self, tab: DashboardTabId) -> Self {
+        self.dashboard_tab_id = Some(tab);
+        self
+    }
+
+    pub fn parameter_mapping(mut self, mapping: ParameterMapping) -> Self {
+        self.parameter_mappings.push(mapping);
+        self
+    }
+
+    pub fn visualization_settings(mut self, settings: Map<String, Value>) -> Self {
+        self.visualization_settings = settings;
+        self
+    }
+}
+
+/// A dashboard tab.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[non_exhaustive]
+pub struct DashboardTab {
+    pub id: DashboardTabId,
+    pub name: String,
+    #[serde(default)]
+    pub position: i64,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl DashboardTab {
+    pub fn new(id: DashboardTabId, name: impl Into<String>, position: i64) -> Self {
+        Self {
+            id,
+            name: name.into(),
+            position,
+            extra: Map::new(),
+        }
+    }
+}
+
+/// A dashboard filter.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[non_exhaustive]
+pub struct DashboardParameter {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub slug: String,
+    /// e.g. `category`, `date/single`, `string/=`.
+    #[serde(rename = "type")]
+    pub parameter_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<Value>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl DashboardParameter {
+    pub fn new(
+        id: impl Into<String>,
+        name: impl Into<String>,
+        slug: impl Into<String>,
+        parameter_type: impl Into<String>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            name: name.into(),
+            slug: slug.into(),
+            parameter_type: parameter_type.into(),
+            default: None,
+            extra: Map::new(),
+        }
+    }
+
+    pub fn default_value(mut self, default: Value) -> Self {
+        self.default = Some(default);
+        self
+    }
+}
+
+/// Wires a dashboard parameter to a target on one of the dashcard's cards.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[non_exhaustive]
+pub struct ParameterMapping {
+    pub parameter_id: String,
+    #[serde(default)]
+    pub card_id: Option<CardId>,
+    /// MBQL target, e.g. `["dimension", ["field", 10, null]]`.
+    pub target: Value,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl ParameterMapping {
+    pub fn new(parameter_id: impl Into<String>, card_id: CardId, target: Value) -> Self {
+        Self {
+            parameter_id: parameter_id.into(),
+            card_id: Some(card_id),
+            target,
+            extra: Map::new(),
+        }
+    }
+}
+
+/// Request body for `POST /api/dashboard/`.
+#[derive(Clone, Debug, serde::Serialize)]
+#[non_exhaustive]
+pub struct CreateDashboard {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collection_id: Option<CollectionId>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub parameters: Vec<DashboardParameter>,
+}
+
+impl CreateDashboard {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            description: None,
+            collection_id: None,
+            parameters: Vec::new(),
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn collection_id(mut self, collection_id: CollectionId) -> Self {
+        self.collection_id = Some(collection_id);
+        self
+    }
+
+    pub fn parameter(mut self, parameter: DashboardParameter) -> Self {
+        self.parameters.push(parameter);
+        self
+    }
+}
+
+/// Request body for `PUT /api/dashboard/{id}`.
+///
+/// Only fields that were set are sent. Build it from a fetched [`Dashboard`] to send the
+/// whole dashboard back, including fields this SDK does not model.
+#[derive(Clone, Debug, Default, serde::Serialize)]
+#[non_exhaustive]
+pub struct UpdateDashboard {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// `Some(None)` moves the dashboard to the root collection.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collection_id: Option<Option<CollectionId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archived: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dashcards: Option<Vec<DashboardCard>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tabs: Option<Vec<DashboardTab>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<Vec<DashboardParameter>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_embedding: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub embedding_params: Option<BTreeMap<String, String>>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl UpdateDashboard {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn collection_id(mut self, collection_id: Option<CollectionId>) -> Self {
+        self.collection_id = Some(collection_id);
+        self
+    }
+
+    pub fn archived(mut self, archived: bool) -> Self {
+        self.archived = Some(archived);
+        self
+    }
+
+    pub fn dashcards(mut self, dashcards: Vec<DashboardCard>) -> Self {
+        self.dashcards = Some(dashcards);
+        self
+    }
+
+    pub fn tabs(mut self, tabs: Vec<DashboardTab>) -> Self {
+        self.tabs = Some(tabs);
+        self
+    }
+
+    pub fn parameters(mut self, parameters: Vec<DashboardParameter>) -> Self {
+        self.parameters = Some(parameters);
+        self
+    }
+
+    pub fn enable_embedding(mut self, enable_embedding: bool) -> Self {
+        self.enable_embedding = Some(enable_embedding);
+        self
+    }
+
+    pub fn embedding_params(mut self, embedding_params: BTreeMap<String, String>) -> Self {
+        self.embedding_params = Some(embedding_params);
+        self
+    }
+}
+
+impl From<Dashboard> for UpdateDashboard {
+    fn from(dashboard: Dashboard) -> Self {
+        let mut extra = dashboard.extra;
+        // Read-only on the server; sending them back is at best ignored.
+        for key in ["created_at", "updated_at", "creator_id", "last-edit-info"] {
+            extra.remove(key);
+        }
+        Self {
+            name: Some(dashboard.name),
+            description: dashboard.description,
+            collection_id: Some(dashboard.collection_id),
+            archived: Some(dashboard.archived),
+            dashcards: Some(dashboard.dashcards),
+            tabs: Some(dashboard.tabs),
+            parameters: Some(dashboard.parameters),
+            enable_embedding: Some(dashboard.enable_embedding),
+            embedding_params: dashboard.embedding_params,
+            extra,
+        }
+    }
+}
+
+/// Request body for `POST /api/dashboard/{from-dashboard-id}/copy`.
+#[derive(Clone, Debug, Default, serde::Serialize)]
+#[non_exhaustive]
+pub struct CopyDashboard {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collection_id: Option<CollectionId>,
+    /// Also duplicate the cards instead of referencing the originals.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_deep_copy: Option<bool>,
+}
+
+impl CopyDashboard {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn collection_id(mut self, collection_id: CollectionId) -> Self {
+        self.collection_id = Some(collection_id);
+        self
+    }
+
+    pub fn deep_copy(mut self, deep_copy: bool) -> Self {
+        self.is_deep_copy = Some(deep_copy);
+        self
+    }
+}
+
+/// Request and response body for `PUT /api/dashboard/{id}/cards`.
+///
+/// The request replaces the full set of dashcards (and tabs, when given).
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[non_exhaustive]
+pub struct DashboardCards {
+    pub cards: Vec<DashboardCard>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tabs: Option<Vec<DashboardTab>>,
+}
+
+impl DashboardCards {
+    pub fn new(cards: Vec<DashboardCard>) -> Self {
+        Self { cards, tabs: None }
+    }
+
+    pub fn tabs(mut self, tabs: Vec<DashboardTab>) -> Self {
+        self.tabs = Some(tabs);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error as StdError;
+
+    #[test]
+    fn dashboard_round_trips_unknown_fields() -> Result<(), Box<dyn StdError + Send + Sync>> {
+        let dashboard: Dashboard = serde_json::from_value(serde_json::json!({
+            "id": 3,
+            "name": "Sales",
+            "collection_id": 9,
+            "width": "full",
+            "created_at": "2024-01-01T00:00:00Z",
+            "tabs": [{"id": 1, "name": "Overview", "position": 0}],
+            "dashcards": [{
+                "id": 10,
+                "card_id": 5,
+                "dashboard_tab_id": 1,
+                "row": 0, "col": 0, "size_x": 12, "size_y": 6,
+                "parameter_mappings": [{
+                    "parameter_id": "p1",
+                    "card_id": 5,
+                    "target": ["dimension", ["field", 1, null]]
+                }],
+                "series": []
+            }],
+            "parameters": [{"id": "p1", "name": "State", "slug": "state", "type": "category"}]
+        }))?;
+        assert_eq!(dashboard.dashcards[0].card_id, Some(CardId(5)));
+        assert_eq!(dashboard.dashcards[0].parameter_mappings.len(), 1);
+        assert_eq!(dashboard.parameters[0].parameter_type, "category");
+
+        let body = serde_json::to_value(UpdateDashboard::from(dashboard).name("Renamed"))?;
+        assert_eq!(body["name"], "Renamed");
+        assert_eq!(body["width"], "full");
+        assert_eq!(body["collection_id"], 9);
+        assert_eq!(body["dashcards"][0]["series"], serde_json::json!([]));
+        assert!(body.get("created_at").is_none());
+        assert!(body.get("id").is_none());
+        Ok(())
+    }
+}
@@ -0,0 +1,457 @@
+use std::collections::BTreeMap;
+
+use http::StatusCode;
+use secrecy::{ExposeSecret, SecretString};
+use serde::ser::SerializeMap;
+use serde_json::{Map, Value};
+
+use crate::{
+    Error, Result,
+    types::{ids::DatabaseId, schedule::Schedule},
+};
+
+string_enum! {
+    /// A database driver, as named by the `engine` field.
+    pub enum Engine {
+        Postgres => "postgres",
+        Mysql => "mysql",
+        /// The `bigquery-cloud-sdk` driver.
+        BigQuery => "bigquery-cloud-sdk",
+        Snowflake => "snowflake",
+        Redshift => "redshift",
+        H2 => "h2",
+    }
+}
+
+/// A connected database, as returned by `GET /api/database/{id}`.
+///
+/// `details` is returned with secrets obfuscated by the server. Fields not modelled here
+/// are kept in [`extra`](Self::extra).
+#[derive(Clone, Debug, serde::Deserialize)]
+#[non_exhaustive]
+pub struct Database {
+    pub id: DatabaseId,
+    pub name: String,
+    pub engine: Engine,
+    #[serde(default)]
+    pub details: Map<String, Value>,
+    #[serde(default)]
+    pub is_sample: bool,
+    #[serde(default)]
+    pub is_full_sync: bool,
+    #[serde(default)]
+    pub is_on_demand: bool,
+    #[serde(default)]
+    pub auto_run_queries: Option<bool>,
+    #[serde(default)]
+    pub timezone: Option<String>,
+    #[serde(default)]
+    pub initial_sync_status: Option<String>,
+    #[serde(default)]
+    pub features: Vec<String>,
+    /// Quartz cron expression; see [`Self::sync_schedule`].
+    #[serde(default)]
+    pub metadata_sync_schedule: Option<String>,
+    /// Quartz cron expression; see [`Self::field_values_schedule`].
+    #[serde(default)]
+    pub cache_field_values_schedule: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl Database {
+    /// When metadata is synced.
+    pub fn sync_schedule(&self) -> Option<Schedule> {
+        self.metadata_sync_schedule.as_deref().map(Schedule::cron)
+    }
+
+    /// When field values used by filter widgets are rescanned.
+    pub fn field_values_schedule(&self) -> Option<Schedule> {
+        self.cache_field_values_schedule
+            .as_deref()
+            .map(Schedule::cron)
+    }
+}
+
+/// Response from `GET /api/database/`.
+#[derive(Clone, Debug, serde::Deserialize)]
+#[non_exhaustive]
+pub struct DatabaseList {
+    pub data: Vec<Database>,
+    #[serde(default)]
+    pub total: u64,
+}
+
+/// Connection details for one engine.
+///
+/// Passwords, keys and service-account JSON are held as [`SecretString`] and only exposed
+/// when the request body is serialized.
+#[derive(Clone, Debug)]
+pub struct DatabaseDetails {
+    engine: Engine,
+    fields: Map<String, Value>,
+    secrets: BTreeMap<String, SecretString>,
+}
+
+impl DatabaseDetails {
+    /// Details for any engine; add fields with [`field`](Self::field) and
+    /// [`secret`](Self::secret).
+    pub fn new(engine: Engine) -> Self {
+        Self {
+            engine,
+            fields: Map::new(),
+            secrets: BTreeMap::new(),
+        }
+    }
+
+    pub fn postgres(
+        host: impl Into<String>,
+        port: u16,
+        dbname: impl Into<String>,
+        user: impl Into<String>,
+    ) -> Self {
+        Self::new(Engine::Postgres)
+            .field("host", host.into())
+            .field("port", port)
+            .field("dbname", dbname.into())
+            .field("user", user.into())
+    }
+
+    pub fn mysql(
+        host: impl Into<String>,
+        port: u16,
+        dbname: impl Into<String>,
+        user: impl Into<String>,
+    ) -> Self {
+        Self::new(Engine::Mysql)
+            .field("host", host.into())
+            .field("port", port)
+            .field("dbname", dbname.into())
+            .field("user", user.into())
+    }
+
+    pub fn redshift(
+        host: impl Into<String>,
+        port: u16,
+        db: impl Into<String>,
+        user: impl Into<String>,
+    ) -> Self {
+        Self::new(Engine::Redshift)
+            .field("host", host.into())
+            .field("port", port)
+            .field("db", db.into())
+            .field("user", user.into())
+    }
+
+    pub fn snowflake(
+        account: impl Into<String>,
+        user: impl Into<String>,
+        db: impl Into<String>,
+    ) -> Self {
+        Self::new(Engine::Snowflake)
+            .field("account", account.into())
+            .field("user", user.into())
+            .field("db", db.into())
+    }
+
+    /// BigQuery authenticated with a service account key file's JSON contents.
+    pub fn bigquery(service_account_json: SecretString) -> Self {
+        Self::new(Engine::BigQuery).secret("service-account-json", service_account_json)
+    }
+
+    /// An H2 database file, e.g. `file:/data/sample.db`.
+    pub fn h2(db: impl Into<String>) -> Self {
+        Self::new(Engine::H2).field("db", db.into())
+    }
+
+    pub fn engine(&self) -> &Engine {
+        &self.engine
+    }
+
+    pub fn password(self, password: SecretString) -> Self {
+        self.secret("password", password)
+    }
+
+    pub fn ssl(self, ssl: bool) -> Self {
+        self.field("ssl", ssl)
+    }
+
+    /// Snowflake warehouse.
+    pub fn warehouse(self, warehouse: impl Into<String>) -> Self {
+        self.field("warehouse", warehouse.into())
+    }
+
+    /// Snowflake role.
+    pub fn role(self, role: impl Into<String>) -> Self {
+        self.field("role", role.into())
+    }
+
+    /// BigQuery project, when it differs from the service account's.
+    pub fn project_id(self, project_id: impl Into<String>) -> Self {
+        self.field("project-id", project_id.into())
+    }
+
+    /// Set a non-secret connection field, e.g. `additional-options`.
+    pub fn field(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        let key = key.into();
+        self.secrets.remove(&key);
+        self.fields.insert(key, value.into());
+        self
+    }
+
+    /// Set a secret connection field, e.g. `private-key-value`.
+    pub fn secret(mut self, key: impl Into<String>, value: SecretString) -> Self {
+        let key = key.into();
+        self.fields.remove(&key);
+        self.secrets.insert(key, value);
+        self
+    }
+}
+
+impl serde::Serialize for DatabaseDetails {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.fields.len() + self.secrets.len()))?;
+        for (key, value) in &self.fields {
+            map.serialize_entry(key, value)?;
+        }
+        for (key, value) in &self.secrets {
+            map.serialize_entry(key, value.expose_secret())?;
+        }
+        map.end()
+    }
+}
+
+/// Request body for `POST /api/database/`.
+#[derive(Clone, Debug, serde::Serialize)]
+#[non_exhaustive]
+pub struct CreateDatabase {
+    pub name: String,
+    pub engine: Engine,
+    pub details: DatabaseDetails,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_full_sync: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_on_demand: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_run_queries: Option<bool>,
+    #[serde(skip_serializing_if = "DatabaseSchedules::is_empty")]
+    pub schedules: DatabaseSchedules,
+}
+
+/// Sync and scan schedules for `POST /api/database/`.
+///
+/// Only schedules with a `schedule_type` equivalent are accepted; not raw cron.
+#[derive(Clone, Debug, Default, serde::Serialize)]
+#[non_exhaustive]
+pub struct DatabaseSchedules {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata_sync: Option<Schedule>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_field_values: Option<Schedule>,
+}
+
+impl DatabaseSchedules {
+    pub fn is_empty(&self) -> bool {
+        self.metadata_sync.is_none() && self.cache_field_values.is_none()
+    }
+
+    pub(crate) fn validate(&self) -> Result<()> {
+        [&self.metadata_sync, &self.cache_field_values]
+            .into_iter()
+            .flatten()
+            .try_for_each(Schedule::validate_fields)
+    }
+}
+
+impl CreateDatabase {
+    pub fn new(name: impl Into<String>, details: DatabaseDetails) -> Self {
+        Self {
+            name: name.into(),
+            engine: details.engine.clone(),
+            details,
+            is_full_sync: None,
+            is_on_demand: None,
+            auto_run_queries: None,
+            schedules: DatabaseSchedules::default(),
+        }
+    }
+
+    pub fn full_sync(mut self, full_sync: bool) -> Self {
+        self.is_full_sync = Some(full_sync);
+        self
+    }
+
+    pub fn on_demand(mut self, on_demand: bool) -> Self {
+        self.is_on_demand = Some(on_demand);
+        self
+    }
+
+    pub fn auto_run_queries(mut self, auto_run_queries: bool) -> Self {
+        self.auto_run_queries = Some(auto_run_queries);
+        self
+    }
+
+    /// Fails with [`Error::InvalidSchedule`] for [`Schedule::Cron`] and other schedules
+    /// without a `schedule_type` equivalent.
+    pub fn sync_schedule(mut self, schedule: Schedule) -> Result<Self> {
+        schedule.validate_fields()?;
+        self.schedules.metadata_sync = Some(schedule);
+        Ok(self)
+    }
+
+    /// Fails like [`Self::sync_schedule`].
+    pub fn field_values_schedule(mut self, schedule: Schedule) -> Result<Self> {
+        schedule.validate_fields()?;
+        self.schedules.cache_field_values = Some(schedule);
+        Ok(self)
+    }
+}
+
+/// Request body for `POST /api/database/validate`.
+#[derive(serde::Serialize)]
+pub(crate) struct ValidateDatabaseRequest<'a> {
+    details: ValidateDatabaseDetails<'a>,
+}
+
+#[derive(serde::Serialize)]
+struct ValidateDatabaseDetails<'a> {
+    engine: &'a Engine,
+    details: &'a DatabaseDetails,
+}
+
+impl<'a> ValidateDatabaseRequest<'a> {
+    pub(crate) fn new(details: &'a DatabaseDetails) -> Self {
+        Self {
+            details: ValidateDatabaseDetails {
+                engine: &details.engine,
+                details,
+            },
+        }
+    }
+}
+
+/// Outcome of `POST /api/database/validate`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct DatabaseValidation {
+    pub valid: bool,
+    pub message: Option<String>,
+    /// Error message per connection field, e.g. `{"password": "Password is incorrect."}`.
+    pub errors: BTreeMap<String, String>,
+}
+
+impl DatabaseValidation {
+    /// The first connection field the server rejected, if it named one.
+    pub fn invalid_field(&self) -> Option<&str> {
+        self.errors.keys().next().map(String::as_str)
+    }
+
+    /// Servers report a failed validation either in a 200 body or as a 400 whose body
+    /// carries the field errors; both map to `valid: false`.
+    pub(crate) fn from_response(response: Result<Value>) -> Result<Self> {
+        match response {
+            Ok(body) => Ok(Self::from_body(&body, true)),
+            Err(Error::Api(err)) if err.status() == StatusCode::BAD_REQUEST => {
+                match err.body().filter(|body| body.get("errors").is_some()) {
+                    Some(body) => Ok(Self::from_body(body, false)),
+                    None => Err(Error::Api(err)),
+                }
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    fn from_body(body: &Value, default_valid: bool) -> Self {
+        let errors = body
+            .get("errors")
+            .and_then(Value::as_object)
+            .map(|errors| {
+                errors
+                    .iter()
+                    .map(|(field, message)| {
+                        let message = match message {
+                            Value::String(message) => message.clone(),
+                            other => other.to_string(),
+                        };
+                        (field.clone(), message)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        Self {
+            valid: body
+                .get("valid")
+                .and_then(Value::as_bool)
+                .unwrap_or(default_valid),
+            message: body
+                .get("message")
+                .and_then(Value::as_str)
+                .map(str::to_owned),
+            errors,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error as StdError;
+
+    #[test]
+    fn details_expose_secrets_only_when_serialized()
+    -> std::result::Result<(), Box<dyn StdError + Send + Sync>> {
+        let details = DatabaseDetails::postgres("db.internal", 5432, "analytics", "metabase")
+            .password(SecretString::from("hunter2"))
+            .ssl(true);
+        assert!(!format!("{details:?}").contains("hunter2"));
+
+        let body = serde_json::to_value(CreateDatabase::new("Analytics", details))?;
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "name": "Analytics",
+                "engine": "postgres",
+                "details": {
+                    "host": "db.internal",
+                    "port": 5432,
+                    "dbname": "analytics",
+                    "user": "metabase",
+                    "password": "hunter2",
+                    "ssl": true
+                }
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn engine_round_trips_unknown_names() -> std::result::Result<(), Box<dyn StdError + Send + Sync>>
+    {
+        let engine: Engine = serde_json::from_value(serde_json::json!("bigquery-cloud-sdk"))?;
+        assert_eq!(engine, Engine::BigQuery);
+        let engine: Engine = serde_json::from_value(serde_json::json!("clickhouse"))?;
+        assert_eq!(engine, Engine::Unknown("clickhouse".to_owned()));
+        assert_eq!(
+            serde_json::to_value(&engine)?,
+            serde_json::json!("clickhouse")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn validation_reads_field_errors() {
+        let validation = DatabaseValidation::from_body(
+            &serde_json::json!({
+                "valid": false,
+                "message": "Password is incorrect.",
+                "errors": {"password": "Password is incorrect."}
+            }),
+            true,
+        );
+        assert!(!validation.valid);
+        assert_eq!(validation.invalid_field(), Some("password"));
+    }
+}
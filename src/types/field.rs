@@ -0,0 +1,352 @@
+use serde_json::{Map, Value};
+
+use crate::types::ids::{FieldId, TableId};
+
+string_enum! {
+    /// Storage type of a field, e.g. `type/Integer`.
+    pub enum BaseType {
+        Any => "type/*",
+        Array => "type/Array",
+        BigInteger => "type/BigInteger",
+        Boolean => "type/Boolean",
+        Date => "type/Date",
+        DateTime => "type/DateTime",
+        DateTimeWithLocalTz => "type/DateTimeWithLocalTZ",
+        DateTimeWithTz => "type/DateTimeWithTZ",
+        DateTimeWithZoneId => "type/DateTimeWithZoneID",
+        DateTimeWithZoneOffset => "type/DateTimeWithZoneOffset",
+        Decimal => "type/Decimal",
+        Dictionary => "type/Dictionary",
+        Float => "type/Float",
+        Integer => "type/Integer",
+        IpAddress => "type/IPAddress",
+        Json => "type/JSON",
+        Number => "type/Number",
+        SerializedJson => "type/SerializedJSON",
+        Structured => "type/Structured",
+        Text => "type/Text",
+        Time => "type/Time",
+        TimeWithTz => "type/TimeWithTZ",
+        Uuid => "type/UUID",
+    }
+}
+
+string_enum! {
+    /// What a field means, e.g. `type/PK` or `type/Email`.
+    pub enum SemanticType {
+        AvatarUrl => "type/AvatarURL",
+        Birthdate => "type/Birthdate",
+        Category => "type/Category",
+        City => "type/City",
+        Comment => "type/Comment",
+        Company => "type/Company",
+        Country => "type/Country",
+        CreationDate => "type/CreationDate",
+        CreationTimestamp => "type/CreationTimestamp",
+        Currency => "type/Currency",
+        Description => "type/Description",
+        Discount => "type/Discount",
+        Email => "type/Email",
+        ForeignKey => "type/FK",
+        ImageUrl => "type/ImageURL",
+        Income => "type/Income",
+        JoinTimestamp => "type/JoinTimestamp",
+        Latitude => "type/Latitude",
+        Longitude => "type/Longitude",
+        Name => "type/Name",
+        Percentage => "type/Percentage",
+        Price => "type/Price",
+        PrimaryKey => "type/PK",
+        Product => "type/Product",
+        Quantity => "type/Quantity",
+        Score => "type/Score",
+        SerializedJson => "type/SerializedJSON",
+        Source => "type/Source",
+        State => "type/State",
+        Title => "type/Title",
+        UpdatedTimestamp => "type/UpdatedTimestamp",
+        Url => "type/URL",
+        User => "type/User",
+        ZipCode => "type/ZipCode",
+    }
+}
+
+string_enum! {
+    /// Where a field is shown.
+    pub enum FieldVisibility {
+        Normal => "normal",
+        DetailsOnly => "details-only",
+        Hidden => "hidden",
+        Sensitive => "sensitive",
+        Retired => "retired",
+    }
+}
+
+string_enum! {
+    /// How filter widgets on a field get their values.
+    pub enum HasFieldValues {
+        List => "list",
+        Search => "search",
+        None => "none",
+        AutoList => "auto-list",
+    }
+}
+
+string_enum! {
+    pub enum DimensionType {
+        /// Values remapped through `POST /api/field/{id}/values`.
+        Internal => "internal",
+        /// Values remapped to another field, usually across a foreign key.
+        External => "external",
+    }
+}
+
+/// A column of a table.
+///
+/// Fields not modelled here are kept in [`extra`](Self::extra).
+#[derive(Clone, Debug, serde::Deserialize)]
+#[non_exhaustive]
+pub struct Field {
+    pub id: FieldId,
+    pub name: String,
+    #[serde(default)]
+    pub table_id: Option<TableId>,
+    #[serde(default)]
+    pub display_name: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub base_type: Option<BaseType>,
+    #[serde(default)]
+    pub effective_type: Option<BaseType>,
+    #[serde(default)]
+    pub semantic_type: Option<SemanticType>,
+    #[serde(default)]
+    pub visibility_type: Option<FieldVisibility>,
+    #[serde(default)]
+    pub has_field_values: Option<HasFieldValues>,
+    #[serde(default)]
+    pub fk_target_field_id: Option<FieldId>,
+    #[serde(default)]
+    pub database_type: Option<String>,
+    #[serde(default)]
+    pub position: i64,
+    #[serde(default)]
+    pub active: bool,
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub dimensions: Vec<Dimension>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// A remapping of a field's displayed values.
+#[derive(Clone, Debug, serde::Deserialize)]
+#[non_exhaustive]
+pub struct Dimension {
+    pub id: i64,
+    pub field_id: FieldId,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub dimension_type: DimensionType,
+    #[serde(default)]
+    pub human_readable_field_id: Option<FieldId>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Response from `GET /api/field/{id}/values`.
+#[derive(Clone, Debug, serde::Deserialize)]
+#[non_exhaustive]
+pub struct FieldValues {
+    #[serde(default)]
+    pub field_id: Option<FieldId>,
+    pub values: Vec<FieldValue>,
+    #[serde(default)]
+    pub has_more_values: bool,
+}
+
+/// One distinct value, with its remapped display text when there is one.
+///
+/// Sent by the server as `[value]` or `[value, display]`.
+#[derive(Clone, Debug, PartialEq, serde::Deserialize)]
+#[serde(from = "Vec<Value>")]
+#[non_exhaustive]
+pub struct FieldValue {
+    pub value: Value,
+    pub display: Option<String>,
+}
+
+impl From<Vec<Value>> for FieldValue {
+    fn from(pair: Vec<Value>) -> Self {
+        let mut pair = pair.into_iter();
+        Self {
+            value: pair.next().unwrap_or(Value::Null),
+            display: pair.next().and_then(|display| match display {
+                Value::String(display) => Some(display),
+                Value::Null => None,
+                other => Some(other.to_string()),
+            }),
+        }
+    }
+}
+
+/// Response from `GET /api/field/{id}/summary`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Deserialize)]
+#[serde(from = "Vec<(String, Value)>")]
+#[non_exhaustive]
+pub struct FieldSummary {
+    pub count: Option<u64>,
+    pub distincts: Option<u64>,
+}
+
+impl From<Vec<(String, Value)>> for FieldSummary {
+    fn from(pairs: Vec<(String, Value)>) -> Self {
+        let mut summary = Self::default();
+        for (key, value) in pairs {
+            match key.as_str() {
+                "count" => summary.count = value.as_u64(),
+                "distincts" => summary.distincts = value.as_u64(),
+                _ => {}
+            }
+        }
+        summary
+    }
+}
+
+/// Request body for `PUT /api/field/{id}`.
+///
+/// Only fields that were set are sent.
+#[derive(Clone, Debug, Default, serde::Serialize)]
+#[non_exhaustive]
+pub struct UpdateField {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// `Some(None)` clears the semantic type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub semantic_type: Option<Option<SemanticType>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visibility_type: Option<FieldVisibility>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_field_values: Option<HasFieldValues>,
+    /// `Some(None)` clears the foreign key target.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fk_target_field_id: Option<Option<FieldId>>,
+}
+
+impl UpdateField {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn display_name(mut self, display_name: impl Into<String>) -> Self {
+        self.display_name = Some(display_name.into());
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn semantic_type(mut self, semantic_type: Option<SemanticType>) -> Self {
+        self.semantic_type = Some(semantic_type);
+        self
+    }
+
+    pub fn visibility_type(mut self, visibility_type: FieldVisibility) -> Self {
+        self.visibility_type = Some(visibility_type);
+        self
+    }
+
+    pub fn has_field_values(mut self, has_field_values: HasFieldValues) -> Self {
+        self.has_field_values = Some(has_field_values);
+        self
+    }
+
+    pub fn fk_target_field_id(mut self, target: Option<FieldId>) -> Self {
+        self.fk_target_field_id = Some(target);
+        self
+    }
+}
+
+/// `GET /api/field/{id}` returns a single dimension object, `query_metadata` a list.
+fn one_or_many<'de, D>(deserializer: D) -> Result<Vec<Dimension>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(serde::Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        Many(Vec<Dimension>),
+        One(Box<Dimension>),
+        Null,
+    }
+
+    Ok(
+        match <OneOrMany as serde::Deserialize>::deserialize(deserializer)? {
+            OneOrMany::Many(dimensions) => dimensions,
+            OneOrMany::One(dimension) => vec![*dimension],
+            OneOrMany::Null => Vec::new(),
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error as StdError;
+
+    #[test]
+    fn field_decodes_unknown_types() -> Result<(), Box<dyn StdError + Send + Sync>> {
+        let field: Field = serde_json::from_value(serde_json::json!({
+            "id": 12,
+            "table_id": 3,
+            "name": "CATEGORY",
+            "base_type": "type/Text",
+            "effective_type": "type/Text",
+            "semantic_type": "type/Category",
+            "visibility_type": "normal",
+            "has_field_values": "list",
+            "dimensions": []
+        }))?;
+        assert_eq!(field.semantic_type, Some(SemanticType::Category));
+        assert_eq!(field.has_field_values, Some(HasFieldValues::List));
+
+        let field: Field = serde_json::from_value(serde_json::json!({
+            "id": 13,
+            "name": "GEOM",
+            "base_type": "type/PostgresGeometry",
+            "semantic_type": null,
+            "dimensions": {"id": 1, "field_id": 13, "name": "Geom", "type": "internal"}
+        }))?;
+        assert_eq!(
+            field.base_type,
+            Some(BaseType::Unknown("type/PostgresGeometry".to_owned()))
+        );
+        assert_eq!(field.dimensions.len(), 1);
+        assert_eq!(
+            serde_json::to_value(BaseType::from("type/PostgresGeometry"))?,
+            "type/PostgresGeometry"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn values_and_summary_decode_from_pairs() -> Result<(), Box<dyn StdError + Send + Sync>> {
+        let values: FieldValues = serde_json::from_value(serde_json::json!({
+            "field_id": 12,
+            "values": [["Doohickey"], [1, "One"]],
+            "has_more_values": false
+        }))?;
+        assert_eq!(values.values[0].display, None);
+        assert_eq!(values.values[1].display.as_deref(), Some("One"));
+
+        let summary: FieldSummary =
+            serde_json::from_value(serde_json::json!([["count", 200], ["distincts", 4]]))?;
+        assert_eq!(summary.count, Some(200));
+        assert_eq!(summary.distincts, Some(4));
+        Ok(())
+    }
+}
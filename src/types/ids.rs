@@ -1,19 +1,103 @@
 use std::fmt;
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize)]
-pub struct UserId(pub i64);
+/// Define an `i64`-backed entity id newtype usable as a path parameter.
+macro_rules! entity_id {
+    ($($(#[$meta:meta])* $name:ident),+ $(,)?) => {$(
+        $(#[$meta])*
+        #[derive(
+            Clone,
+            Copy,
+            Debug,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Hash,
+            serde::Deserialize,
+            serde::Serialize,
+        )]
+        #[serde(transparent)]
+        pub struct $name(pub i64);
 
-impl fmt::Display for UserId {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
-    }
-}
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+
+        impl From<i64> for $name {
+            fn from(value: i64) -> Self {
+                Self(value)
+            }
+        }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize)]
-pub struct DashboardId(pub i64);
+        impl From<$name> for i64 {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
 
-impl fmt::Display for DashboardId {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
-    }
+        impl From<$name> for crate::types::path::PathParam {
+            fn from(value: $name) -> Self {
+                Self::from(value.0)
+            }
+        }
+    )+};
 }
+
+entity_id!(
+    /// Id of an action (`/api/action/{id}`).
+    ActionId,
+    /// Id of a legacy alert (`/api/alert/{id}`).
+    AlertId,
+    /// Id of an API key (`/api/api-key/{id}`).
+    ApiKeyId,
+    /// Id of a card: a question, model or metric (`/api/card/{id}`).
+    CardId,
+    /// Id of a notification channel (`/api/channel/{id}`).
+    ChannelId,
+    /// Id of a collection (`/api/collection/{id}`).
+    CollectionId,
+    /// Id of a dashboard (`/api/dashboard/{id}`).
+    DashboardId,
+    /// Id of a tab on a dashboard.
+    DashboardTabId,
+    /// Id of a card placed on a dashboard (`{dashcard-id}`).
+    DashcardId,
+    /// Id of a database (`/api/database/{id}`).
+    DatabaseId,
+    /// Id of a document (`/api/ee/document/{id}`).
+    DocumentId,
+    /// Id of a field (`/api/field/{id}`).
+    FieldId,
+    /// Id of a glossary entry (`/api/glossary/{id}`).
+    GlossaryId,
+    /// Id of a permissions group (`/api/permissions/group/{id}`).
+    GroupId,
+    /// Id of a group membership (`/api/permissions/membership/{id}`).
+    MembershipId,
+    /// Id of a model index (`/api/model-index/{id}`).
+    ModelIndexId,
+    /// Id of a notification (`/api/notification/{id}`).
+    NotificationId,
+    /// Id of a persisted model entry (`/api/persist/{persisted-info-id}`).
+    PersistedInfoId,
+    /// Id of a pulse or dashboard subscription (`/api/pulse/{id}`).
+    PulseId,
+    /// Id of a revision (`/api/revision`).
+    RevisionId,
+    /// Id of a segment (`/api/segment/{id}`).
+    SegmentId,
+    /// Id of a native query snippet (`/api/native-query-snippet/{id}`).
+    SnippetId,
+    /// Id of a table (`/api/table/{id}`).
+    TableId,
+    /// Id of a background task run (`/api/task/{id}`).
+    TaskId,
+    /// Id of a timeline event (`/api/timeline-event/{id}`).
+    TimelineEventId,
+    /// Id of a timeline (`/api/timeline/{id}`).
+    TimelineId,
+    /// Id of a user (`/api/user/{id}`).
+    UserId,
+);
@@ -0,0 +1,269 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::types::ids::{FieldId, SegmentId};
+
+string_enum! {
+    /// Operator of a comparison filter clause.
+    pub enum FilterOperator {
+        Eq => "=",
+        Ne => "!=",
+        Lt => "<",
+        Gt => ">",
+        Le => "<=",
+        Ge => ">=",
+        Between => "between",
+        IsNull => "is-null",
+        NotNull => "not-null",
+        IsEmpty => "is-empty",
+        NotEmpty => "not-empty",
+        Contains => "contains",
+        DoesNotContain => "does-not-contain",
+        StartsWith => "starts-with",
+        EndsWith => "ends-with",
+        TimeInterval => "time-interval",
+    }
+}
+
+/// A legacy MBQL filter clause, e.g. `["and", ["=", ["field", 10, null], "CA"], ["segment", 2]]`.
+///
+/// Clauses this SDK does not model are kept as [`Filter::Raw`], so a filter always
+/// serializes back to the JSON it was read from.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Filter {
+    And(Vec<Filter>),
+    Or(Vec<Filter>),
+    Not(Box<Filter>),
+    /// `["segment", id]`: the rows matched by a segment.
+    Segment(SegmentId),
+    /// `[operator, field, args...]`; `args` holds the remaining operands, including a
+    /// trailing options map such as `{"case-sensitive": false}`.
+    Compare {
+        operator: FilterOperator,
+        field: FieldRef,
+        args: Vec<Value>,
+    },
+    Raw(Value),
+}
+
+impl Filter {
+    pub fn compare(operator: FilterOperator, field: FieldRef, args: Vec<Value>) -> Self {
+        Self::Compare {
+            operator,
+            field,
+            args,
+        }
+    }
+
+    /// `["=", field, value]`.
+    pub fn eq(field: FieldRef, value: impl Into<Value>) -> Self {
+        Self::compare(FilterOperator::Eq, field, vec![value.into()])
+    }
+
+    pub fn from_value(value: Value) -> Self {
+        let Value::Array(items) = &value else {
+            return Self::Raw(value);
+        };
+        let Some((Value::String(operator), args)) = items.split_first() else {
+            return Self::Raw(value);
+        };
+        match (operator.as_str(), args) {
+            ("and", _) => Self::And(args.iter().cloned().map(Self::from_value).collect()),
+            ("or", _) => Self::Or(args.iter().cloned().map(Self::from_value).collect()),
+            ("not", [clause]) => Self::Not(Box::new(Self::from_value(clause.clone()))),
+            ("segment", [Value::Number(id)]) => match id.as_i64() {
+                Some(id) => Self::Segment(SegmentId(id)),
+                None => Self::Raw(value),
+            },
+            (operator, [field, rest @ ..]) => {
+                let operator = FilterOperator::from(operator);
+                match FieldRef::from_value(field.clone()) {
+                    FieldRef::Other(_) => Self::Raw(value),
+                    _ if matches!(operator, FilterOperator::Unknown(_)) => Self::Raw(value),
+                    field => Self::compare(operator, field, rest.to_vec()),
+                }
+            }
+            _ => Self::Raw(value),
+        }
+    }
+
+    pub fn to_value(&self) -> Value {
+        let clause = |operator: &str, args: Vec<Value>| {
+            Value::Array(std::iter::once(Value::from(operator)).chain(args).collect())
+        };
+        match self {
+            Self::And(clauses) => clause("and", clauses.iter().map(Self::to_value).collect()),
+            Self::Or(clauses) => clause("or", clauses.iter().map(Self::to_value).collect()),
+            Self::Not(inner) => clause("not", vec![inner.to_value()]),
+            Self::Segment(id) => clause("segment", vec![id.0.into()]),
+            Self::Compare {
+                operator,
+                field,
+                args,
+            } => clause(
+                operator.as_str(),
+                std::iter::once(field.to_value())
+                    .chain(args.iter().cloned())
+                    .collect(),
+            ),
+            Self::Raw(value) => value.clone(),
+        }
+    }
+}
+
+impl Serialize for Filter {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_value().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Filter {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Value::deserialize(deserializer).map(Self::from_value)
+    }
+}
+
+/// A column reference inside a filter clause.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum FieldRef {
+    /// `["field", id, options]`.
+    Id {
+        id: FieldId,
+        options: Option<Map<String, Value>>,
+    },
+    /// `["field", name, options]`, a column of a nested query; `options` carries its
+    /// `base-type`.
+    Name {
+        name: String,
+        options: Option<Map<String, Value>>,
+    },
+    /// `["expression", name]`.
+    Expression(String),
+    Other(Value),
+}
+
+impl FieldRef {
+    /// `["field", id, null]`.
+    pub fn id(id: FieldId) -> Self {
+        Self::Id { id, options: None }
+    }
+
+    pub fn from_value(value: Value) -> Self {
+        let options = |value: &Value| match value {
+            Value::Null => Some(None),
+            Value::Object(options) => Some(Some(options.clone())),
+            _ => None,
+        };
+        match value.as_array().map(Vec::as_slice) {
+            Some([Value::String(kind), Value::Number(id), opts]) if kind == "field" => {
+                match (id.as_i64(), options(opts)) {
+                    (Some(id), Some(options)) => Self::Id {
+                        id: FieldId(id),
+                        options,
+                    },
+                    _ => Self::Other(value),
+                }
+            }
+            Some([Value::String(kind), Value::String(name), opts]) if kind == "field" => {
+                match options(opts) {
+                    Some(options) => Self::Name {
+                        name: name.clone(),
+                        options,
+                    },
+                    None => Self::Other(value),
+                }
+            }
+            Some([Value::String(kind), Value::String(name)]) if kind == "expression" => {
+                Self::Expression(name.clone())
+            }
+            _ => Self::Other(value),
+        }
+    }
+
+    pub fn to_value(&self) -> Value {
+        let options = |options: &Option<Map<String, Value>>| {
+            options.clone().map_or(Value::Null, Value::Object)
+        };
+        match self {
+            Self::Id { id, options: opts } => {
+                Value::Array(vec!["field".into(), id.0.into(), options(opts)])
+            }
+            Self::Name {
+                name,
+                options: opts,
+            } => Value::Array(vec!["field".into(), name.as_str().into(), options(opts)]),
+            Self::Expression(name) => Value::Array(vec!["expression".into(), name.as_str().into()]),
+            Self::Other(value) => value.clone(),
+        }
+    }
+}
+
+/// Whether `query` contains a `["segment", id]` clause anywhere.
+///
+/// Also matches the `["segment", {options}, id]` form of newer queries.
+pub(crate) fn references_segment(query: &Value, segment: SegmentId) -> bool {
+    match query {
+        Value::Array(items) => {
+            let direct = match items.as_slice() {
+                [Value::String(kind), Value::Number(id)]
+                | [Value::String(kind), Value::Object(_), Value::Number(id)] => {
+                    kind == "segment" && id.as_i64() == Some(segment.0)
+                }
+                _ => false,
+            };
+            direct || items.iter().any(|item| references_segment(item, segment))
+        }
+        Value::Object(map) => map.values().any(|item| references_segment(item, segment)),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error as StdError;
+
+    #[test]
+    fn filters_parse_and_round_trip() -> Result<(), Box<dyn StdError + Send + Sync>> {
+        let json = serde_json::json!([
+            "and",
+            ["=", ["field", 10, null], "CA", "NY"],
+            ["contains", ["field", "NAME", {"base-type": "type/Text"}], "inc", {"case-sensitive": false}],
+            ["not", ["segment", 2]],
+            ["inside", ["field", 11, null], ["field", 12, null], 1, 2, 3, 4]
+        ]);
+        let filter: Filter = serde_json::from_value(json.clone())?;
+        let Filter::And(clauses) = &filter else {
+            return Err("expected an and clause".into());
+        };
+        assert_eq!(
+            clauses[0],
+            Filter::compare(
+                FilterOperator::Eq,
+                FieldRef::id(FieldId(10)),
+                vec!["CA".into(), "NY".into()]
+            )
+        );
+        assert!(matches!(
+            &clauses[1],
+            Filter::Compare { operator: FilterOperator::Contains, field: FieldRef::Name { name, .. }, args }
+                if name == "NAME" && args.len() == 2
+        ));
+        assert_eq!(
+            clauses[2],
+            Filter::Not(Box::new(Filter::Segment(SegmentId(2))))
+        );
+        assert!(matches!(clauses[3], Filter::Raw(_)));
+        assert_eq!(serde_json::to_value(&filter)?, json);
+
+        assert!(references_segment(&json, SegmentId(2)));
+        assert!(!references_segment(&json, SegmentId(10)));
+        assert!(references_segment(
+            &serde_json::json!({"stages": [{"filters": [["segment", {"lib/uuid": "x"}, 5]]}]}),
+            SegmentId(5)
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,463 @@
+use serde_json::{Map, Value};
+
+use crate::Result;
+use crate::types::ids::{CardId, ChannelId, DashboardId, GroupId, NotificationId, UserId};
+use crate::types::schedule::Schedule;
+
+string_enum! {
+    pub enum NotificationPayloadType {
+        Card => "notification/card",
+        Dashboard => "notification/dashboard",
+        SystemEvent => "notification/system-event",
+        Testing => "notification/testing",
+    }
+}
+
+string_enum! {
+    /// When a card notification fires.
+    pub enum AlertCondition {
+        /// The question returns any rows.
+        HasResult => "has_result",
+        /// A progress or goal line is crossed upwards.
+        GoalAbove => "goal_above",
+        /// A progress or goal line is crossed downwards.
+        GoalBelow => "goal_below",
+    }
+}
+
+string_enum! {
+    pub enum HandlerChannelType {
+        Email => "channel/email",
+        Slack => "channel/slack",
+        Http => "channel/http",
+    }
+}
+
+/// A notification, as returned by `GET /api/notification/{id}`.
+///
+/// Also accepted by `PUT /api/notification/{id}`. Fields not modelled here are kept in
+/// [`extra`](Self::extra) and sent back unchanged.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[non_exhaustive]
+pub struct Notification {
+    pub id: NotificationId,
+    pub payload_type: NotificationPayloadType,
+    #[serde(default)]
+    pub payload: NotificationPayload,
+    #[serde(default)]
+    pub active: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator_id: Option<UserId>,
+    #[serde(default)]
+    pub handlers: Vec<NotificationHandler>,
+    #[serde(default)]
+    pub subscriptions: Vec<NotificationSubscription>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// What a notification is about. Which fields are set depends on the payload type.
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+#[non_exhaustive]
+pub struct NotificationPayload {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub card_id: Option<CardId>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub send_condition: Option<AlertCondition>,
+    /// Deactivate after the first send.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub send_once: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dashboard_id: Option<DashboardId>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// A delivery channel of a notification and who it goes to.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[non_exhaustive]
+pub struct NotificationHandler {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    pub channel_type: HandlerChannelType,
+    /// Set for `channel/http` handlers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<ChannelId>,
+    #[serde(default)]
+    pub recipients: Vec<NotificationRecipient>,
+    #[serde(default = "active")]
+    pub active: bool,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+fn active() -> bool {
+    true
+}
+
+impl NotificationHandler {
+    pub fn email(recipients: Vec<NotificationRecipient>) -> Self {
+        Self::new(HandlerChannelType::Email, recipients)
+    }
+
+    /// Posts to a Slack channel, e.g. `#alerts`.
+    pub fn slack(channel: impl Into<String>) -> Self {
+        Self::new(
+            HandlerChannelType::Slack,
+            vec![NotificationRecipient::raw(channel)],
+        )
+    }
+
+    pub fn http(channel: ChannelId) -> Self {
+        let mut handler = Self::new(HandlerChannelType::Http, Vec::new());
+        handler.channel_id = Some(channel);
+        handler
+    }
+
+    fn new(channel_type: HandlerChannelType, recipients: Vec<NotificationRecipient>) -> Self {
+        Self {
+            id: None,
+            channel_type,
+            channel_id: None,
+            recipients,
+            active: true,
+            extra: Map::new(),
+        }
+    }
+}
+
+/// A recipient of a notification handler, tagged by its `type`.
+///
+/// Fields not modelled here, such as the recipient's `id`, are kept in `extra` and sent
+/// back unchanged, so updating a notification keeps its existing recipients.
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(try_from = "Map<String, Value>", into = "Map<String, Value>")]
+#[non_exhaustive]
+pub enum NotificationRecipient {
+    User {
+        user_id: UserId,
+        extra: Map<String, Value>,
+    },
+    Group {
+        permissions_group_id: GroupId,
+        extra: Map<String, Value>,
+    },
+    /// An email address, or a Slack channel for Slack handlers.
+    RawValue {
+        details: RawRecipient,
+        extra: Map<String, Value>,
+    },
+    /// A recipient type this SDK does not know about yet, as received.
+    Unknown(Map<String, Value>),
+}
+
+const RECIPIENT_USER: &str = "notification-recipient/user";
+const RECIPIENT_GROUP: &str = "notification-recipient/group";
+const RECIPIENT_RAW_VALUE: &str = "notification-recipient/raw-value";
+
+impl TryFrom<Map<String, Value>> for NotificationRecipient {
+    type Error = serde_json::Error;
+
+    fn try_from(mut map: Map<String, Value>) -> std::result::Result<Self, Self::Error> {
+        fn take<T: serde::de::DeserializeOwned>(
+            map: &mut Map<String, Value>,
+            key: &str,
+        ) -> serde_json::Result<T> {
+            serde_json::from_value(map.remove(key).unwrap_or(Value::Null))
+        }
+
+        let recipient_type = map
+            .get("type")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_owned();
+        let known = [RECIPIENT_USER, RECIPIENT_GROUP, RECIPIENT_RAW_VALUE];
+        if !known.contains(&recipient_type.as_str()) {
+            return Ok(Self::Unknown(map));
+        }
+        map.remove("type");
+        Ok(match recipient_type.as_str() {
+            RECIPIENT_USER => Self::User {
+                user_id: take(&mut map, "user_id")?,
+                extra: map,
+            },
+            RECIPIENT_GROUP => Self::Group {
+                permissions_group_id: take(&mut map, "permissions_group_id")?,
+                extra: map,
+            },
+            _ => Self::RawValue {
+                details: take(&mut map, "details")?,
+                extra: map,
+            },
+        })
+    }
+}
+
+impl From<NotificationRecipient> for Map<String, Value> {
+    fn from(recipient: NotificationRecipient) -> Self {
+        let (recipient_type, key, value, mut map) = match recipient {
+            NotificationRecipient::User { user_id, extra } => {
+                (RECIPIENT_USER, "user_id", Value::from(user_id.0), extra)
+            }
+            NotificationRecipient::Group {
+                permissions_group_id,
+                extra,
+            } => (
+                RECIPIENT_GROUP,
+                "permissions_group_id",
+                Value::from(permissions_group_id.0),
+                extra,
+            ),
+            NotificationRecipient::RawValue { details, extra } => (
+                RECIPIENT_RAW_VALUE,
+                "details",
+                {
+                    let mut map = details.extra;
+                    map.insert("value".to_owned(), Value::from(details.value));
+                    Value::Object(map)
+                },
+                extra,
+            ),
+            NotificationRecipient::Unknown(map) => return map,
+        };
+        map.insert("type".to_owned(), Value::from(recipient_type));
+        map.insert(key.to_owned(), value);
+        map
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[non_exhaustive]
+pub struct RawRecipient {
+    pub value: String,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl NotificationRecipient {
+    pub fn user(user: UserId) -> Self {
+        Self::User {
+            user_id: user,
+            extra: Map::new(),
+        }
+    }
+
+    pub fn group(group: GroupId) -> Self {
+        Self::Group {
+            permissions_group_id: group,
+            extra: Map::new(),
+        }
+    }
+
+    /// An email address or Slack channel.
+    pub fn raw(value: impl Into<String>) -> Self {
+        Self::RawValue {
+            details: RawRecipient {
+                value: value.into(),
+                extra: Map::new(),
+            },
+            extra: Map::new(),
+        }
+    }
+}
+
+/// When a notification is sent.
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+#[non_exhaustive]
+pub struct NotificationSubscription {
+    /// e.g. `notification-subscription/cron`.
+    #[serde(rename = "type")]
+    pub subscription_type: String,
+    /// Quartz cron expression, e.g. `0 0 8 * * ? *`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cron_schedule: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ui_display_type: Option<String>,
+    /// Fields not modelled here, such as the subscription's `id`.
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl NotificationSubscription {
+    pub fn cron(expression: impl Into<String>) -> Self {
+        Self {
+            subscription_type: "notification-subscription/cron".to_owned(),
+            cron_schedule: Some(expression.into()),
+            ui_display_type: None,
+            extra: Map::new(),
+        }
+    }
+
+    pub fn from_schedule(schedule: &Schedule) -> Result<Self> {
+        schedule.to_cron().map(Self::cron)
+    }
+
+    /// The cron schedule, if this is a cron subscription.
+    pub fn schedule(&self) -> Option<Schedule> {
+        self.cron_schedule.as_deref().map(Schedule::cron)
+    }
+}
+
+/// Request body for `POST /api/notification/` and `POST /api/notification/send`.
+#[derive(Clone, Debug, serde::Serialize)]
+#[non_exhaustive]
+pub struct CreateNotification {
+    pub payload_type: NotificationPayloadType,
+    pub payload: NotificationPayload,
+    pub handlers: Vec<NotificationHandler>,
+    pub subscriptions: Vec<NotificationSubscription>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+}
+
+impl CreateNotification {
+    /// An alert on `card` that fires when `condition` holds.
+    pub fn card_alert(card: CardId, condition: AlertCondition) -> Self {
+        Self {
+            payload_type: NotificationPayloadType::Card,
+            payload: NotificationPayload {
+                card_id: Some(card),
+                send_condition: Some(condition),
+                send_once: Some(false),
+                ..NotificationPayload::default()
+            },
+            handlers: Vec::new(),
+            subscriptions: Vec::new(),
+            active: None,
+        }
+    }
+
+    pub fn send_once(mut self, send_once: bool) -> Self {
+        self.payload.send_once = Some(send_once);
+        self
+    }
+
+    pub fn handler(mut self, handler: NotificationHandler) -> Self {
+        self.handlers.push(handler);
+        self
+    }
+
+    pub fn subscription(mut self, subscription: NotificationSubscription) -> Self {
+        self.subscriptions.push(subscription);
+        self
+    }
+
+    pub fn active(mut self, active: bool) -> Self {
+        self.active = Some(active);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error as StdError;
+
+    #[test]
+    fn card_alert_serializes_handlers_and_recipients()
+    -> std::result::Result<(), Box<dyn StdError + Send + Sync>> {
+        let alert = CreateNotification::card_alert(CardId(7), AlertCondition::GoalAbove)
+            .send_once(true)
+            .handler(NotificationHandler::email(vec![
+                NotificationRecipient::user(UserId(2)),
+                NotificationRecipient::raw("ops@example.com"),
+            ]))
+            .handler(NotificationHandler::slack("#alerts"))
+            .subscription(NotificationSubscription::cron("0 0 8 * * ? *"));
+        assert_eq!(
+            serde_json::to_value(&alert)?,
+            serde_json::json!({
+                "payload_type": "notification/card",
+                "payload": {"card_id": 7, "send_condition": "goal_above", "send_once": true},
+                "handlers": [
+                    {
+                        "channel_type": "channel/email",
+                        "recipients": [
+                            {"type": "notification-recipient/user", "user_id": 2},
+                            {
+                                "type": "notification-recipient/raw-value",
+                                "details": {"value": "ops@example.com"}
+                            }
+                        ],
+                        "active": true
+                    },
+                    {
+                        "channel_type": "channel/slack",
+                        "recipients": [{
+                            "type": "notification-recipient/raw-value",
+                            "details": {"value": "#alerts"}
+                        }],
+                        "active": true
+                    }
+                ],
+                "subscriptions": [{
+                    "type": "notification-subscription/cron",
+                    "cron_schedule": "0 0 8 * * ? *"
+                }]
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn notification_decodes_unknown_recipients()
+    -> std::result::Result<(), Box<dyn StdError + Send + Sync>> {
+        let notification: Notification = serde_json::from_value(serde_json::json!({
+            "id": 3,
+            "payload_type": "notification/card",
+            "payload": {"card_id": 7, "send_condition": "has_result", "send_once": false},
+            "active": true,
+            "handlers": [{
+                "id": 1,
+                "channel_type": "channel/email",
+                "recipients": [
+                    {"id": 9, "type": "notification-recipient/user", "user_id": 2, "user": {}},
+                    {"id": 10, "type": "notification-recipient/template", "details": null},
+                    {
+                        "id": 11,
+                        "type": "notification-recipient/raw-value",
+                        "details": {"value": "ops@example.com", "label": "Ops"}
+                    }
+                ]
+            }],
+            "subscriptions": [{
+                "id": 5,
+                "notification_id": 3,
+                "type": "notification-subscription/cron",
+                "cron_schedule": "0 0 8 * * ? *"
+            }]
+        }))?;
+        assert_eq!(
+            notification.payload.send_condition,
+            Some(AlertCondition::HasResult)
+        );
+        let recipients = &notification.handlers[0].recipients;
+        assert!(matches!(
+            &recipients[0],
+            NotificationRecipient::User { user_id: UserId(2), extra } if extra["id"] == 9
+        ));
+        assert!(matches!(&recipients[1], NotificationRecipient::Unknown(_)));
+        assert_eq!(
+            serde_json::to_value(recipients)?,
+            serde_json::json!([
+                {"id": 9, "type": "notification-recipient/user", "user_id": 2, "user": {}},
+                {"id": 10, "type": "notification-recipient/template", "details": null},
+                {
+                    "id": 11,
+                    "type": "notification-recipient/raw-value",
+                    "details": {"value": "ops@example.com", "label": "Ops"}
+                }
+            ])
+        );
+        assert_eq!(
+            serde_json::to_value(&notification.subscriptions)?,
+            serde_json::json!([{
+                "id": 5,
+                "notification_id": 3,
+                "type": "notification-subscription/cron",
+                "cron_schedule": "0 0 8 * * ? *"
+            }])
+        );
+        Ok(())
+    }
+}
@@ -56,15 +56,3 @@ impl From<usize> for PathParam {
         Self(value.to_string())
     }
 }
-
-impl From<crate::types::ids::UserId> for PathParam {
-    fn from(value: crate::types::ids::UserId) -> Self {
-        Self(value.to_string())
-    }
-}
-
-impl From<crate::types::ids::DashboardId> for PathParam {
-    fn from(value: crate::types::ids::DashboardId) -> Self {
-        Self(value.to_string())
-    }
-}
@@ -0,0 +1,521 @@
+use std::collections::BTreeMap;
+
+use serde_json::{Map, Value};
+
+use crate::types::ids::{DatabaseId, GroupId, MembershipId, TableId, UserId};
+
+string_enum! {
+    /// A permission level anywhere in the data permissions graph.
+    pub enum PermissionLevel {
+        /// `view-data`
+        Unrestricted => "unrestricted",
+        /// `view-data`
+        Blocked => "blocked",
+        /// `view-data`
+        LegacyNoSelfService => "legacy-no-self-service",
+        /// `view-data`
+        Impersonated => "impersonated",
+        /// `view-data`
+        Sandboxed => "sandboxed",
+        /// `create-queries`
+        QueryBuilderAndNative => "query-builder-and-native",
+        /// `create-queries`
+        QueryBuilder => "query-builder",
+        /// `download`
+        Full => "full",
+        /// `download`
+        Limited => "limited",
+        /// `data-model` and `details`
+        All => "all",
+        /// `details`
+        Yes => "yes",
+        No => "no",
+        None => "none",
+    }
+}
+
+/// A level granted to a whole database, or broken down per schema.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(untagged)]
+pub enum Granular {
+    All(PermissionLevel),
+    Schemas(BTreeMap<String, SchemaGranular>),
+}
+
+/// A level granted to a whole schema, or broken down per table.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+#[serde(untagged)]
+pub enum SchemaGranular {
+    All(PermissionLevel),
+    Tables(BTreeMap<TableId, PermissionLevel>),
+}
+
+impl<'de> serde::Deserialize<'de> for SchemaGranular {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Untagged enums buffer map keys as strings, which `TableId` can't decode from.
+        #[derive(serde::Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            All(PermissionLevel),
+            Tables(BTreeMap<String, PermissionLevel>),
+        }
+
+        match Raw::deserialize(deserializer)? {
+            Raw::All(level) => Ok(Self::All(level)),
+            Raw::Tables(tables) => tables
+                .into_iter()
+                .map(|(table, level)| {
+                    table.parse().map(|id| (TableId(id), level)).map_err(|_| {
+                        serde::de::Error::custom(format!("invalid table id `{table}`"))
+                    })
+                })
+                .collect::<Result<_, _>>()
+                .map(Self::Tables),
+        }
+    }
+}
+
+/// `download` and `data-model` permissions, nested under a `schemas` key by the API.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[non_exhaustive]
+pub struct SchemasPermission {
+    pub schemas: Granular,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl SchemasPermission {
+    pub fn new(schemas: Granular) -> Self {
+        Self {
+            schemas,
+            extra: Map::new(),
+        }
+    }
+}
+
+/// What one group may do with one database.
+///
+/// Every kind is optional so the same type can describe a full graph entry or only the
+/// kinds that changed.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[non_exhaustive]
+pub struct DatabasePermissions {
+    #[serde(rename = "view-data", default, skip_serializing_if = "Option::is_none")]
+    pub view_data: Option<Granular>,
+    #[serde(
+        rename = "create-queries",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub create_queries: Option<Granular>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub download: Option<SchemasPermission>,
+    #[serde(
+        rename = "data-model",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub data_model: Option<SchemasPermission>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub details: Option<PermissionLevel>,
+    /// Permission kinds this SDK does not model, e.g. from enterprise plugins.
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl DatabasePermissions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn view_data(mut self, level: Granular) -> Self {
+        self.view_data = Some(level);
+        self
+    }
+
+    pub fn create_queries(mut self, level: Granular) -> Self {
+        self.create_queries = Some(level);
+        self
+    }
+
+    pub fn download(mut self, level: Granular) -> Self {
+        self.download = Some(SchemasPermission::new(level));
+        self
+    }
+
+    pub fn data_model(mut self, level: Granular) -> Self {
+        self.data_model = Some(SchemasPermission::new(level));
+        self
+    }
+
+    pub fn details(mut self, level: PermissionLevel) -> Self {
+        self.details = Some(level);
+        self
+    }
+
+    /// The kinds set in `self` whose value differs from `base`.
+    fn changed_from(&self, base: &Self) -> Self {
+        fn changed<T: Clone + PartialEq>(next: &Option<T>, base: &Option<T>) -> Option<T> {
+            match next {
+                Some(next) if base.as_ref() != Some(next) => Some(next.clone()),
+                _ => None,
+            }
+        }
+
+        Self {
+            view_data: changed(&self.view_data, &base.view_data),
+            create_queries: changed(&self.create_queries, &base.create_queries),
+            download: changed(&self.download, &base.download),
+            data_model: changed(&self.data_model, &base.data_model),
+            details: changed(&self.details, &base.details),
+            extra: self
+                .extra
+                .iter()
+                .filter(|(key, value)| base.extra.get(*key) != Some(*value))
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect(),
+        }
+    }
+
+    /// Overwrite the kinds set in `changes`.
+    fn merge(&mut self, changes: &Self) {
+        fn merge<T: Clone>(target: &mut Option<T>, change: &Option<T>) {
+            if let Some(change) = change {
+                *target = Some(change.clone());
+            }
+        }
+
+        merge(&mut self.view_data, &changes.view_data);
+        merge(&mut self.create_queries, &changes.create_queries);
+        merge(&mut self.download, &changes.download);
+        merge(&mut self.data_model, &changes.data_model);
+        merge(&mut self.details, &changes.details);
+        for (key, value) in &changes.extra {
+            self.extra.insert(key.clone(), value.clone());
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Permissions per group and database.
+pub type GroupPermissions = BTreeMap<GroupId, BTreeMap<DatabaseId, DatabasePermissions>>;
+
+/// Response from `GET /api/permissions/graph`.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[non_exhaustive]
+pub struct PermissionsGraph {
+    /// Must be sent back unchanged; the server rejects updates made against a stale graph.
+    pub revision: i64,
+    pub groups: GroupPermissions,
+}
+
+impl PermissionsGraph {
+    /// The minimal change set that turns `self` into `target`.
+    ///
+    /// Only permission kinds that `target` sets and that differ from `self` are included.
+    pub fn diff(&self, target: &PermissionsGraph) -> PermissionsChanges {
+        let mut groups = GroupPermissions::new();
+        for (group, databases) in &target.groups {
+            for (database, permissions) in databases {
+                let base = self
+                    .groups
+                    .get(group)
+                    .and_then(|databases| databases.get(database));
+                let changed = match base {
+                    Some(base) => permissions.changed_from(base),
+                    None => permissions.clone(),
+                };
+                if !changed.is_empty() {
+                    groups.entry(*group).or_default().insert(*database, changed);
+                }
+            }
+        }
+        PermissionsChanges { groups }
+    }
+
+    /// Apply `changes` in place, as the server would.
+    pub fn apply(&mut self, changes: &PermissionsChanges) {
+        for (group, databases) in &changes.groups {
+            let target = self.groups.entry(*group).or_default();
+            for (database, permissions) in databases {
+                target.entry(*database).or_default().merge(permissions);
+            }
+        }
+    }
+}
+
+/// A partial permissions graph: only the groups, databases and kinds that change.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize)]
+#[non_exhaustive]
+pub struct PermissionsChanges {
+    pub groups: GroupPermissions,
+}
+
+impl PermissionsChanges {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set `permissions` for `group` on `database`, merging with earlier calls.
+    pub fn set(
+        mut self,
+        group: GroupId,
+        database: DatabaseId,
+        permissions: DatabasePermissions,
+    ) -> Self {
+        self.groups
+            .entry(group)
+            .or_default()
+            .entry(database)
+            .or_default()
+            .merge(&permissions);
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+
+    /// The part of these changes that still differs from `current`.
+    pub fn rebase(&self, current: &PermissionsGraph) -> PermissionsChanges {
+        let mut target = current.clone();
+        target.apply(self);
+        current.diff(&target)
+    }
+}
+
+/// Changes to a revisioned permissions graph, as resent by the `apply_*_changes` helpers.
+pub(crate) trait GraphChanges: Clone {
+    type Graph;
+
+    /// The part of these changes that still differs from `current`.
+    fn rebase(&self, current: &Self::Graph) -> Self;
+
+    fn is_empty(&self) -> bool;
+
+    fn revision(graph: &Self::Graph) -> i64;
+}
+
+impl GraphChanges for PermissionsChanges {
+    type Graph = PermissionsGraph;
+
+    fn rebase(&self, current: &PermissionsGraph) -> Self {
+        PermissionsChanges::rebase(self, current)
+    }
+
+    fn is_empty(&self) -> bool {
+        PermissionsChanges::is_empty(self)
+    }
+
+    fn revision(graph: &PermissionsGraph) -> i64 {
+        graph.revision
+    }
+}
+
+/// Request body for `PUT /api/permissions/graph`.
+#[derive(serde::Serialize)]
+pub(crate) struct PermissionsGraphUpdate<'a> {
+    revision: i64,
+    groups: &'a GroupPermissions,
+}
+
+impl<'a> PermissionsGraphUpdate<'a> {
+    pub(crate) fn new(revision: i64, changes: &'a PermissionsChanges) -> Self {
+        Self {
+            revision,
+            groups: &changes.groups,
+        }
+    }
+}
+
+/// A permissions group, as returned by `GET /api/permissions/group`.
+///
+/// `members` is only populated by `GET /api/permissions/group/{id}`.
+#[derive(Clone, Debug, serde::Deserialize)]
+#[non_exhaustive]
+pub struct PermissionsGroup {
+    pub id: GroupId,
+    pub name: String,
+    #[serde(default)]
+    pub member_count: Option<u64>,
+    #[serde(default)]
+    pub members: Vec<GroupMember>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// A user listed as a member of a group.
+#[derive(Clone, Debug, serde::Deserialize)]
+#[non_exhaustive]
+pub struct GroupMember {
+    pub membership_id: MembershipId,
+    pub user_id: UserId,
+    #[serde(default)]
+    pub email: Option<String>,
+    #[serde(default)]
+    pub first_name: Option<String>,
+    #[serde(default)]
+    pub last_name: Option<String>,
+    #[serde(default)]
+    pub common_name: Option<String>,
+    #[serde(default)]
+    pub is_group_manager: bool,
+}
+
+/// A user's membership of a group, as returned by `GET /api/permissions/membership`.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize)]
+#[non_exhaustive]
+pub struct Membership {
+    #[serde(alias = "id")]
+    pub membership_id: MembershipId,
+    pub group_id: GroupId,
+    pub user_id: UserId,
+    #[serde(default)]
+    pub is_group_manager: bool,
+}
+
+/// Request body for `POST /api/permissions/membership`.
+#[derive(Clone, Debug, serde::Serialize)]
+#[non_exhaustive]
+pub struct CreateMembership {
+    pub group_id: GroupId,
+    pub user_id: UserId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_group_manager: Option<bool>,
+}
+
+impl CreateMembership {
+    pub fn new(group_id: GroupId, user_id: UserId) -> Self {
+        Self {
+            group_id,
+            user_id,
+            is_group_manager: None,
+        }
+    }
+
+    pub fn manager(mut self, is_group_manager: bool) -> Self {
+        self.is_group_manager = Some(is_group_manager);
+        self
+    }
+}
+
+/// Request body for `POST /api/permissions/group` and `PUT /api/permissions/group/{id}`.
+#[derive(Clone, Debug, serde::Serialize)]
+#[non_exhaustive]
+pub struct GroupName {
+    pub name: String,
+}
+
+impl GroupName {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into() }
+    }
+}
+
+/// Request body for `PUT /api/permissions/membership/{id}`.
+#[derive(serde::Serialize)]
+pub(crate) struct MembershipUpdate {
+    pub(crate) is_group_manager: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error as StdError;
+
+    fn graph() -> Result<PermissionsGraph, serde_json::Error> {
+        serde_json::from_value(serde_json::json!({
+            "revision": 4,
+            "groups": {
+                "1": {
+                    "1": {
+                        "view-data": "unrestricted",
+                        "create-queries": "query-builder-and-native",
+                        "download": {"schemas": "full"},
+                        "data-model": {"schemas": "all"},
+                        "details": "yes"
+                    }
+                },
+                "2": {
+                    "1": {
+                        "view-data": "unrestricted",
+                        "create-queries": {"PUBLIC": {"3": "query-builder", "4": "no"}},
+                        "download": {"schemas": {"PUBLIC": "limited"}}
+                    }
+                }
+            }
+        }))
+    }
+
+    #[test]
+    fn graph_round_trips() -> Result<(), Box<dyn StdError + Send + Sync>> {
+        let graph = graph()?;
+        let permissions = &graph.groups[&GroupId(2)][&DatabaseId(1)];
+        let Some(Granular::Schemas(schemas)) = &permissions.create_queries else {
+            return Err("expected per-schema create-queries".into());
+        };
+        assert_eq!(
+            schemas.get("PUBLIC"),
+            Some(&SchemaGranular::Tables(BTreeMap::from([
+                (TableId(3), PermissionLevel::QueryBuilder),
+                (TableId(4), PermissionLevel::No),
+            ])))
+        );
+        let round_tripped: PermissionsGraph =
+            serde_json::from_value(serde_json::to_value(&graph)?)?;
+        assert_eq!(round_tripped, graph);
+        Ok(())
+    }
+
+    #[test]
+    fn diff_keeps_only_changed_kinds() -> Result<(), Box<dyn StdError + Send + Sync>> {
+        let current = graph()?;
+        let mut target = current.clone();
+        if let Some(permissions) = target
+            .groups
+            .get_mut(&GroupId(2))
+            .and_then(|databases| databases.get_mut(&DatabaseId(1)))
+        {
+            permissions.create_queries = Some(Granular::All(PermissionLevel::QueryBuilder));
+        }
+
+        let changes = current.diff(&target);
+        assert_eq!(
+            serde_json::to_value(&changes)?,
+            serde_json::json!({"groups": {"2": {"1": {"create-queries": "query-builder"}}}})
+        );
+        assert!(current.diff(&current).is_empty());
+
+        let mut applied = current.clone();
+        applied.apply(&changes);
+        assert_eq!(applied, target);
+        Ok(())
+    }
+
+    #[test]
+    fn rebase_drops_changes_already_on_the_server() -> Result<(), Box<dyn StdError + Send + Sync>> {
+        let current = graph()?;
+        let changes = PermissionsChanges::new()
+            .set(
+                GroupId(1),
+                DatabaseId(1),
+                DatabasePermissions::new().details(PermissionLevel::No),
+            )
+            .set(
+                GroupId(2),
+                DatabaseId(1),
+                DatabasePermissions::new().view_data(Granular::All(PermissionLevel::Unrestricted)),
+            );
+
+        let rebased = changes.rebase(&current);
+        assert_eq!(rebased.groups.len(), 1);
+        assert_eq!(
+            rebased.groups[&GroupId(1)][&DatabaseId(1)].details,
+            Some(PermissionLevel::No)
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,480 @@
+use serde_json::{Map, Value};
+
+use crate::types::ids::{
+    AlertId, CardId, ChannelId, CollectionId, DashboardId, DashcardId, PulseId, UserId,
+};
+use crate::types::notification::AlertCondition;
+use crate::types::schedule::Schedule;
+
+string_enum! {
+    /// Where a pulse channel delivers.
+    pub enum ChannelType {
+        Email => "email",
+        Slack => "slack",
+        Http => "http",
+    }
+}
+
+/// A dashboard subscription or legacy pulse, as returned by `GET /api/pulse/{id}`.
+///
+/// Fields not modelled here are kept in [`extra`](Self::extra).
+#[derive(Clone, Debug, serde::Deserialize)]
+#[non_exhaustive]
+pub struct Pulse {
+    pub id: PulseId,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub creator_id: Option<UserId>,
+    #[serde(default)]
+    pub archived: bool,
+    #[serde(default)]
+    pub collection_id: Option<CollectionId>,
+    /// Set for dashboard subscriptions.
+    #[serde(default)]
+    pub dashboard_id: Option<DashboardId>,
+    #[serde(default)]
+    pub skip_if_empty: bool,
+    #[serde(default)]
+    pub cards: Vec<PulseCard>,
+    #[serde(default)]
+    pub channels: Vec<PulseChannel>,
+    /// Dashboard filter values applied to the subscription.
+    #[serde(default)]
+    pub parameters: Vec<Value>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// A card included in a pulse.
+#[derive(Clone, Debug, serde::Deserialize)]
+#[non_exhaustive]
+pub struct PulseCard {
+    pub id: CardId,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub display: Option<String>,
+    #[serde(default)]
+    pub include_csv: bool,
+    #[serde(default)]
+    pub include_xls: bool,
+    #[serde(default)]
+    pub dashboard_card_id: Option<DashcardId>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// A card to include when creating or updating a pulse.
+#[derive(Clone, Debug, serde::Serialize)]
+#[non_exhaustive]
+pub struct PulseCardRef {
+    pub id: CardId,
+    pub include_csv: bool,
+    pub include_xls: bool,
+    /// Required for dashboard subscriptions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dashboard_card_id: Option<DashcardId>,
+}
+
+impl PulseCardRef {
+    pub fn new(card: CardId) -> Self {
+        Self {
+            id: card,
+            include_csv: false,
+            include_xls: false,
+            dashboard_card_id: None,
+        }
+    }
+
+    pub fn dashcard(mut self, dashcard: DashcardId) -> Self {
+        self.dashboard_card_id = Some(dashcard);
+        self
+    }
+
+    pub fn csv(mut self) -> Self {
+        self.include_csv = true;
+        self
+    }
+
+    pub fn xls(mut self) -> Self {
+        self.include_xls = true;
+        self
+    }
+}
+
+impl From<&PulseCard> for PulseCardRef {
+    fn from(card: &PulseCard) -> Self {
+        Self {
+            id: card.id,
+            include_csv: card.include_csv,
+            include_xls: card.include_xls,
+            dashboard_card_id: card.dashboard_card_id,
+        }
+    }
+}
+
+/// A delivery channel of a pulse or alert, with its schedule and recipients.
+///
+/// Used both when reading and when writing pulses. Fields not modelled here are kept in
+/// [`extra`](Self::extra) and sent back unchanged.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[non_exhaustive]
+pub struct PulseChannel {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    pub channel_type: ChannelType,
+    #[serde(default = "enabled")]
+    pub enabled: bool,
+    #[serde(flatten)]
+    pub schedule: Schedule,
+    #[serde(default)]
+    pub recipients: Vec<PulseRecipient>,
+    /// Channel-specific settings, e.g. `{"channel": "#reports"}` for Slack.
+    #[serde(default)]
+    pub details: Map<String, Value>,
+    /// Set for `http` channels.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<ChannelId>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+fn enabled() -> bool {
+    true
+}
+
+impl PulseChannel {
+    /// An email channel, sent daily at 8:00 until another schedule is set.
+    pub fn email(recipients: Vec<PulseRecipient>) -> Self {
+        let mut channel = Self::new(ChannelType::Email);
+        channel.recipients = recipients;
+        channel
+    }
+
+    /// A Slack channel, e.g. `#reports`, sent daily at 8:00 until another schedule is set.
+    pub fn slack(channel: impl Into<String>) -> Self {
+        let mut slack = Self::new(ChannelType::Slack);
+        slack
+            .details
+            .insert("channel".to_owned(), Value::String(channel.into()));
+        slack
+    }
+
+    fn new(channel_type: ChannelType) -> Self {
+        Self {
+            id: None,
+            channel_type,
+            enabled: true,
+            schedule: Schedule::Daily { hour: 8 },
+            recipients: Vec::new(),
+            details: Map::new(),
+            channel_id: None,
+            extra: Map::new(),
+        }
+    }
+
+    /// Fails with [`Error::InvalidSchedule`](crate::Error::InvalidSchedule) for
+    /// [`Schedule::Cron`] and other schedules without a `schedule_type` equivalent.
+    pub fn schedule(mut self, schedule: Schedule) -> crate::Result<Self> {
+        schedule.validate_fields()?;
+        self.schedule = schedule;
+        Ok(self)
+    }
+
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// The Slack channel name, for Slack channels.
+    pub fn slack_channel(&self) -> Option<&str> {
+        self.details.get("channel").and_then(Value::as_str)
+    }
+}
+
+/// An email recipient: either a Metabase user or an external address.
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+#[non_exhaustive]
+pub struct PulseRecipient {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<UserId>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(default, skip_serializing)]
+    pub common_name: Option<String>,
+}
+
+impl PulseRecipient {
+    pub fn user(user: UserId) -> Self {
+        Self {
+            id: Some(user),
+            ..Self::default()
+        }
+    }
+
+    pub fn email(email: impl Into<String>) -> Self {
+        Self {
+            email: Some(email.into()),
+            ..Self::default()
+        }
+    }
+}
+
+/// Query parameters for `GET /api/pulse/`.
+#[derive(Clone, Debug, Default, serde::Serialize)]
+#[non_exhaustive]
+pub struct PulseListQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archived: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dashboard_id: Option<DashboardId>,
+    /// Only pulses the user created or receives.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creator_or_recipient: Option<bool>,
+}
+
+impl PulseListQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn archived(mut self, archived: bool) -> Self {
+        self.archived = Some(archived);
+        self
+    }
+
+    pub fn dashboard(mut self, dashboard: DashboardId) -> Self {
+        self.dashboard_id = Some(dashboard);
+        self
+    }
+
+    pub fn creator_or_recipient(mut self, creator_or_recipient: bool) -> Self {
+        self.creator_or_recipient = Some(creator_or_recipient);
+        self
+    }
+}
+
+/// Request body for `POST /api/pulse/` and `POST /api/pulse/test`.
+#[derive(Clone, Debug, serde::Serialize)]
+#[non_exhaustive]
+pub struct CreatePulse {
+    pub name: String,
+    pub cards: Vec<PulseCardRef>,
+    pub channels: Vec<PulseChannel>,
+    pub skip_if_empty: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dashboard_id: Option<DashboardId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collection_id: Option<CollectionId>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub parameters: Vec<Value>,
+}
+
+impl CreatePulse {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            cards: Vec::new(),
+            channels: Vec::new(),
+            skip_if_empty: false,
+            dashboard_id: None,
+            collection_id: None,
+            parameters: Vec::new(),
+        }
+    }
+
+    /// A subscription to `dashboard`; add its cards with [`Self::card`].
+    pub fn dashboard_subscription(name: impl Into<String>, dashboard: DashboardId) -> Self {
+        Self {
+            dashboard_id: Some(dashboard),
+            ..Self::new(name)
+        }
+    }
+
+    pub fn card(mut self, card: PulseCardRef) -> Self {
+        self.cards.push(card);
+        self
+    }
+
+    pub fn channel(mut self, channel: PulseChannel) -> Self {
+        self.channels.push(channel);
+        self
+    }
+
+    pub fn skip_if_empty(mut self, skip_if_empty: bool) -> Self {
+        self.skip_if_empty = skip_if_empty;
+        self
+    }
+
+    pub fn collection(mut self, collection: CollectionId) -> Self {
+        self.collection_id = Some(collection);
+        self
+    }
+
+    pub fn parameters(mut self, parameters: Vec<Value>) -> Self {
+        self.parameters = parameters;
+        self
+    }
+}
+
+/// Request body for `PUT /api/pulse/{id}`.
+///
+/// Only fields that were set are sent.
+#[derive(Clone, Debug, Default, serde::Serialize)]
+#[non_exhaustive]
+pub struct UpdatePulse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cards: Option<Vec<PulseCardRef>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channels: Option<Vec<PulseChannel>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip_if_empty: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archived: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<Vec<Value>>,
+}
+
+impl UpdatePulse {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn cards(mut self, cards: Vec<PulseCardRef>) -> Self {
+        self.cards = Some(cards);
+        self
+    }
+
+    pub fn channels(mut self, channels: Vec<PulseChannel>) -> Self {
+        self.channels = Some(channels);
+        self
+    }
+
+    pub fn skip_if_empty(mut self, skip_if_empty: bool) -> Self {
+        self.skip_if_empty = Some(skip_if_empty);
+        self
+    }
+
+    pub fn archived(mut self, archived: bool) -> Self {
+        self.archived = Some(archived);
+        self
+    }
+
+    pub fn parameters(mut self, parameters: Vec<Value>) -> Self {
+        self.parameters = Some(parameters);
+        self
+    }
+}
+
+/// A legacy question alert, as returned by `GET /api/alert/{id}`.
+///
+/// Fields not modelled here are kept in [`extra`](Self::extra).
+#[derive(Clone, Debug, serde::Deserialize)]
+#[non_exhaustive]
+pub struct Alert {
+    pub id: AlertId,
+    pub card: PulseCard,
+    #[serde(default)]
+    pub channels: Vec<PulseChannel>,
+    #[serde(default)]
+    pub creator_id: Option<UserId>,
+    /// `rows` or `goal`; see [`Self::condition`].
+    #[serde(default)]
+    pub alert_condition: Option<String>,
+    #[serde(default)]
+    pub alert_above_goal: Option<bool>,
+    /// Stop after the first time the alert fires.
+    #[serde(default)]
+    pub alert_first_only: bool,
+    #[serde(default)]
+    pub archived: bool,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl Alert {
+    /// The condition in the same terms as the notification API.
+    pub fn condition(&self) -> Option<AlertCondition> {
+        match (self.alert_condition.as_deref()?, self.alert_above_goal) {
+            ("rows", _) => Some(AlertCondition::HasResult),
+            ("goal", Some(false)) => Some(AlertCondition::GoalBelow),
+            ("goal", _) => Some(AlertCondition::GoalAbove),
+            (other, _) => Some(AlertCondition::Unknown(other.to_owned())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::schedule::Weekday;
+    use std::error::Error as StdError;
+
+    #[test]
+    fn subscription_channels_serialize_schedule_and_recipients()
+    -> Result<(), Box<dyn StdError + Send + Sync>> {
+        let pulse = CreatePulse::dashboard_subscription("Weekly KPIs", DashboardId(3))
+            .card(PulseCardRef::new(CardId(10)).dashcard(DashcardId(21)).csv())
+            .channel(
+                PulseChannel::email(vec![
+                    PulseRecipient::user(UserId(1)),
+                    PulseRecipient::email("cfo@example.com"),
+                ])
+                .schedule(Schedule::Weekly {
+                    day: Weekday::Monday,
+                    hour: 9,
+                })?,
+            )
+            .channel(PulseChannel::slack("#kpis").schedule(Schedule::Hourly)?);
+        let body = serde_json::to_value(&pulse)?;
+        assert_eq!(
+            body["channels"][0],
+            serde_json::json!({
+                "channel_type": "email",
+                "enabled": true,
+                "schedule_type": "weekly",
+                "schedule_hour": 9,
+                "schedule_day": "mon",
+                "schedule_frame": null,
+                "recipients": [{"id": 1}, {"email": "cfo@example.com"}],
+                "details": {}
+            })
+        );
+        assert_eq!(body["channels"][1]["details"]["channel"], "#kpis");
+        assert_eq!(body["channels"][1]["schedule_hour"], Value::Null);
+        assert_eq!(body["cards"][0]["dashboard_card_id"], 21);
+
+        assert!(matches!(
+            PulseChannel::slack("#kpis").schedule(Schedule::cron("0 30 8 * * ? *")),
+            Err(crate::Error::InvalidSchedule { .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn alert_condition_maps_goal_direction() -> Result<(), Box<dyn StdError + Send + Sync>> {
+        let alert: Alert = serde_json::from_value(serde_json::json!({
+            "id": 4,
+            "alert_condition": "goal",
+            "alert_above_goal": false,
+            "card": {"id": 10, "include_csv": false},
+            "channels": [{
+                "channel_type": "slack",
+                "schedule_type": "daily",
+                "schedule_hour": 8,
+                "details": {"channel": "#ops"}
+            }]
+        }))?;
+        assert_eq!(alert.condition(), Some(AlertCondition::GoalBelow));
+        assert_eq!(alert.channels[0].slack_channel(), Some("#ops"));
+        Ok(())
+    }
+}
@@ -0,0 +1,242 @@
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use time::OffsetDateTime;
+
+use crate::types::card::Card;
+use crate::types::dashboard::Dashboard;
+use crate::types::ids::{CardId, DashboardId, DocumentId, RevisionId, SegmentId};
+use crate::types::segment::Segment;
+use crate::types::user::User;
+
+/// An entity with revision history.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RevisionEntity {
+    Card(CardId),
+    Dashboard(DashboardId),
+    Segment(SegmentId),
+    Document(DocumentId),
+}
+
+impl RevisionEntity {
+    /// The `{entity}` path segment, e.g. `card`.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Card(_) => "card",
+            Self::Dashboard(_) => "dashboard",
+            Self::Segment(_) => "segment",
+            Self::Document(_) => "document",
+        }
+    }
+
+    pub fn id(&self) -> i64 {
+        match self {
+            Self::Card(id) => id.0,
+            Self::Dashboard(id) => id.0,
+            Self::Segment(id) => id.0,
+            Self::Document(id) => id.0,
+        }
+    }
+
+    /// Path prefix and route of the entity's `GET` endpoint.
+    pub(crate) fn endpoint(&self) -> (&'static [&'static str], &'static str) {
+        match self {
+            Self::Card(_) => (&["api", "card"], "/api/card/{id}"),
+            Self::Dashboard(_) => (&["api", "dashboard"], "/api/dashboard/{id}"),
+            Self::Segment(_) => (&["api", "segment"], "/api/segment/{id}"),
+            Self::Document(_) => (&["api", "ee", "document"], "/api/ee/document/{id}"),
+        }
+    }
+}
+
+impl From<CardId> for RevisionEntity {
+    fn from(value: CardId) -> Self {
+        Self::Card(value)
+    }
+}
+
+impl From<DashboardId> for RevisionEntity {
+    fn from(value: DashboardId) -> Self {
+        Self::Dashboard(value)
+    }
+}
+
+impl From<SegmentId> for RevisionEntity {
+    fn from(value: SegmentId) -> Self {
+        Self::Segment(value)
+    }
+}
+
+impl From<DocumentId> for RevisionEntity {
+    fn from(value: DocumentId) -> Self {
+        Self::Document(value)
+    }
+}
+
+/// One entry of `GET /api/revision/{entity}/{id}`, newest first.
+///
+/// Fields not modelled here are kept in [`extra`](Self::extra).
+#[derive(Clone, Debug, serde::Deserialize)]
+#[non_exhaustive]
+pub struct Revision {
+    pub id: RevisionId,
+    /// Who made the change.
+    #[serde(default)]
+    pub user: Option<User>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub timestamp: OffsetDateTime,
+    /// Whether this revision records a revert to an earlier one.
+    #[serde(default)]
+    pub is_reversion: bool,
+    #[serde(default)]
+    pub is_creation: bool,
+    #[serde(default)]
+    pub message: Option<String>,
+    /// Server-rendered summary, e.g. `renamed this`.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Field changes against the previous revision, by field name.
+    #[serde(default, rename = "diff", deserialize_with = "decode_diff")]
+    pub changes: Vec<FieldChange>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// One changed field of a revision.
+///
+/// `before` is `None` for fields that were added and `after` for fields that were removed.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct FieldChange {
+    pub field: String,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+}
+
+#[derive(serde::Deserialize)]
+struct RawDiff {
+    #[serde(default)]
+    before: Option<Map<String, Value>>,
+    #[serde(default)]
+    after: Option<Map<String, Value>>,
+}
+
+/// Merge the `before` and `after` maps of a diff into per-field changes.
+fn decode_diff<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<Vec<FieldChange>, D::Error> {
+    let Some(diff) = Option::<RawDiff>::deserialize(deserializer)? else {
+        return Ok(Vec::new());
+    };
+    let mut changes: BTreeMap<String, FieldChange> = BTreeMap::new();
+    for (field, value) in diff.before.into_iter().flatten() {
+        changes.insert(
+            field.clone(),
+            FieldChange {
+                field,
+                before: Some(value),
+                after: None,
+            },
+        );
+    }
+    for (field, value) in diff.after.into_iter().flatten() {
+        changes
+            .entry(field.clone())
+            .or_insert(FieldChange {
+                field,
+                before: None,
+                after: None,
+            })
+            .after = Some(value);
+    }
+    Ok(changes.into_values().collect())
+}
+
+/// An entity as restored by `revert_to`.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum RevertedEntity {
+    Card(Box<Card>),
+    Dashboard(Box<Dashboard>),
+    Segment(Box<Segment>),
+    Document(Value),
+}
+
+/// Request body for `POST /api/revision/revert`.
+#[derive(serde::Serialize)]
+pub(crate) struct RevertRevision {
+    pub(crate) entity: &'static str,
+    pub(crate) id: i64,
+    pub(crate) revision_id: RevisionId,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error as StdError;
+
+    #[test]
+    fn diff_decodes_into_field_changes() -> Result<(), Box<dyn StdError + Send + Sync>> {
+        let revisions: Vec<Revision> = serde_json::from_value(serde_json::json!([
+            {
+                "id": 12,
+                "is_reversion": true,
+                "is_creation": false,
+                "message": null,
+                "user": {"id": 1, "first_name": "Ada", "common_name": "Ada Lovelace"},
+                "timestamp": "2024-05-01T10:00:00.123Z",
+                "description": "renamed this and archived it.",
+                "has_multiple_changes": true,
+                "diff": {
+                    "before": {"name": "Old", "archived": false, "cache_ttl": 60},
+                    "after": {"name": "New", "archived": true, "description": "Docs"}
+                }
+            },
+            {
+                "id": 11,
+                "is_creation": true,
+                "timestamp": "2024-04-30T08:00:00Z",
+                "diff": null
+            }
+        ]))?;
+        let latest = &revisions[0];
+        assert!(latest.is_reversion);
+        assert_eq!(
+            latest
+                .user
+                .as_ref()
+                .and_then(|user| user.common_name.as_deref()),
+            Some("Ada Lovelace")
+        );
+        assert_eq!(
+            latest.changes,
+            [
+                FieldChange {
+                    field: "archived".to_owned(),
+                    before: Some(Value::Bool(false)),
+                    after: Some(Value::Bool(true)),
+                },
+                FieldChange {
+                    field: "cache_ttl".to_owned(),
+                    before: Some(60.into()),
+                    after: None,
+                },
+                FieldChange {
+                    field: "description".to_owned(),
+                    before: None,
+                    after: Some("Docs".into()),
+                },
+                FieldChange {
+                    field: "name".to_owned(),
+                    before: Some("Old".into()),
+                    after: Some("New".into()),
+                },
+            ]
+        );
+        assert!(latest.extra.contains_key("has_multiple_changes"));
+        assert!(revisions[1].changes.is_empty());
+        Ok(())
+    }
+}
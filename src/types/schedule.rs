@@ -0,0 +1,443 @@
+use std::fmt;
+
+use crate::{Error, Result};
+
+string_enum! {
+    pub enum ScheduleType {
+        Hourly => "hourly",
+        Daily => "daily",
+        Weekly => "weekly",
+        Monthly => "monthly",
+    }
+}
+
+string_enum! {
+    /// Week of the month a monthly schedule runs in.
+    pub enum ScheduleFrame {
+        First => "first",
+        Mid => "mid",
+        Last => "last",
+    }
+}
+
+string_enum! {
+    pub enum Weekday {
+        Sunday => "sun",
+        Monday => "mon",
+        Tuesday => "tue",
+        Wednesday => "wed",
+        Thursday => "thu",
+        Friday => "fri",
+        Saturday => "sat",
+    }
+}
+
+const WEEKDAYS: [Weekday; 7] = [
+    Weekday::Sunday,
+    Weekday::Monday,
+    Weekday::Tuesday,
+    Weekday::Wednesday,
+    Weekday::Thursday,
+    Weekday::Friday,
+    Weekday::Saturday,
+];
+
+impl Weekday {
+    /// Quartz day-of-week number, 1 for Sunday through 7 for Saturday.
+    fn cron_number(&self) -> Option<usize> {
+        WEEKDAYS.iter().position(|day| day == self).map(|i| i + 1)
+    }
+
+    fn from_cron(value: &str) -> Option<Self> {
+        match value.parse::<usize>() {
+            Ok(n) => WEEKDAYS.get(n.checked_sub(1)?).cloned(),
+            Err(_) => WEEKDAYS
+                .iter()
+                .find(|day| day.as_str().eq_ignore_ascii_case(value))
+                .cloned(),
+        }
+    }
+
+    fn name(&self) -> &str {
+        match self {
+            Self::Sunday => "Sunday",
+            Self::Monday => "Monday",
+            Self::Tuesday => "Tuesday",
+            Self::Wednesday => "Wednesday",
+            Self::Thursday => "Thursday",
+            Self::Friday => "Friday",
+            Self::Saturday => "Saturday",
+            Self::Unknown(day) => day,
+        }
+    }
+}
+
+/// When something runs: pulse and subscription deliveries, database syncs, model
+/// refreshes and cache invalidation.
+///
+/// Serializes as Metabase's `schedule_type`/`schedule_hour`/`schedule_day`/`schedule_frame`
+/// map; use [`Self::to_cron`] for endpoints that take a Quartz cron string.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Schedule {
+    /// At the top of every hour.
+    Hourly,
+    Daily {
+        hour: u8,
+    },
+    Weekly {
+        day: Weekday,
+        hour: u8,
+    },
+    /// On `day` of the `frame` week, or without a day on the first, 15th or last day of
+    /// the month.
+    Monthly {
+        frame: ScheduleFrame,
+        day: Option<Weekday>,
+        hour: u8,
+    },
+    /// A Quartz cron expression with no structured equivalent.
+    Cron(String),
+}
+
+impl Schedule {
+    /// Parse a Quartz cron expression, recognising the shapes Metabase generates for
+    /// structured schedules.
+    pub fn cron(expression: impl Into<String>) -> Self {
+        let expression = expression.into();
+        Self::parse_cron(&expression).unwrap_or(Self::Cron(expression))
+    }
+
+    fn parse_cron(expression: &str) -> Option<Self> {
+        let parts: Vec<&str> = expression.split_whitespace().collect();
+        let [
+            second,
+            minute,
+            hour,
+            day_of_month,
+            month,
+            day_of_week,
+            rest @ ..,
+        ] = &parts[..]
+        else {
+            return None;
+        };
+        if *second != "0" || *minute != "0" || *month != "*" || !matches!(rest, [] | ["*"]) {
+            return None;
+        }
+        if *hour == "*" {
+            return matches!((*day_of_month, *day_of_week), ("*", "?")).then_some(Self::Hourly);
+        }
+        let hour = hour.parse::<u8>().ok()?;
+        match (*day_of_month, *day_of_week) {
+            ("*", "?") => Some(Self::Daily { hour }),
+            ("1" | "15" | "L", "?") => Some(Self::Monthly {
+                frame: match *day_of_month {
+                    "1" => ScheduleFrame::First,
+                    "15" => ScheduleFrame::Mid,
+                    _ => ScheduleFrame::Last,
+                },
+                day: None,
+                hour,
+            }),
+            ("?", day) => {
+                if let Some(day) = day.strip_suffix("#1") {
+                    Some(Self::Monthly {
+                        frame: ScheduleFrame::First,
+                        day: Some(Weekday::from_cron(day)?),
+                        hour,
+                    })
+                } else if let Some(day) = day.strip_suffix('L') {
+                    Some(Self::Monthly {
+                        frame: ScheduleFrame::Last,
+                        day: Some(Weekday::from_cron(day)?),
+                        hour,
+                    })
+                } else {
+                    Some(Self::Weekly {
+                        day: Weekday::from_cron(day)?,
+                        hour,
+                    })
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Check hours, days and frames, and that cron expressions have 6 or 7 fields.
+    pub fn validate(&self) -> Result<()> {
+        let invalid = |reason| Err(Error::invalid_schedule(self, reason));
+        match self {
+            Self::Hourly => Ok(()),
+            Self::Daily { hour } | Self::Weekly { hour, .. } | Self::Monthly { hour, .. }
+                if *hour > 23 =>
+            {
+                invalid("hour must be between 0 and 23")
+            }
+            Self::Weekly {
+                day: Weekday::Unknown(_),
+                ..
+            }
+            | Self::Monthly {
+                day: Some(Weekday::Unknown(_)),
+                ..
+            } => invalid("unknown day of the week"),
+            Self::Monthly {
+                frame: ScheduleFrame::Unknown(_),
+                ..
+            } => invalid("unknown monthly frame"),
+            Self::Monthly {
+                frame: ScheduleFrame::Mid,
+                day: Some(_),
+                ..
+            } => invalid("mid-month schedules cannot name a day of the week"),
+            Self::Cron(expression) if !(6..=7).contains(&expression.split_whitespace().count()) => {
+                invalid("cron expressions need 6 or 7 fields")
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// The Quartz cron expression for this schedule, as Metabase would generate it.
+    pub fn to_cron(&self) -> Result<String> {
+        self.validate()?;
+        let day_number = |day: &Weekday| day.cron_number().unwrap_or(1);
+        Ok(match self {
+            Self::Hourly => "0 0 * * * ? *".to_owned(),
+            Self::Daily { hour } => format!("0 0 {hour} * * ? *"),
+            Self::Weekly { day, hour } => format!("0 0 {hour} ? * {} *", day_number(day)),
+            Self::Monthly {
+                frame,
+                day: None,
+                hour,
+            } => {
+                let day_of_month = match frame {
+                    ScheduleFrame::First => "1",
+                    ScheduleFrame::Mid => "15",
+                    _ => "L",
+                };
+                format!("0 0 {hour} {day_of_month} * ? *")
+            }
+            Self::Monthly {
+                frame,
+                day: Some(day),
+                hour,
+            } => {
+                let suffix = if *frame == ScheduleFrame::First {
+                    "#1"
+                } else {
+                    "L"
+                };
+                format!("0 0 {hour} ? * {}{suffix} *", day_number(day))
+            }
+            Self::Cron(expression) => expression.clone(),
+        })
+    }
+
+    /// Like [`Self::validate`], but also rejects cron expressions that have no
+    /// `schedule_type` equivalent.
+    pub(crate) fn validate_fields(&self) -> Result<()> {
+        self.to_fields().map(drop)
+    }
+
+    fn to_fields(&self) -> Result<ScheduleFields> {
+        self.validate()?;
+        let fields = |schedule_type, hour, day, frame| ScheduleFields {
+            schedule_type,
+            schedule_hour: hour,
+            schedule_day: day,
+            schedule_frame: frame,
+        };
+        match self {
+            Self::Hourly => Ok(fields(ScheduleType::Hourly, None, None, None)),
+            Self::Daily { hour } => Ok(fields(ScheduleType::Daily, Some(*hour), None, None)),
+            Self::Weekly { day, hour } => Ok(fields(
+                ScheduleType::Weekly,
+                Some(*hour),
+                Some(day.clone()),
+                None,
+            )),
+            Self::Monthly { frame, day, hour } => Ok(fields(
+                ScheduleType::Monthly,
+                Some(*hour),
+                day.clone(),
+                Some(frame.clone()),
+            )),
+            Self::Cron(_) => Err(Error::invalid_schedule(
+                self,
+                "cron expression has no schedule_type equivalent",
+            )),
+        }
+    }
+
+    fn from_fields(fields: ScheduleFields) -> std::result::Result<Self, String> {
+        let hour = || {
+            fields
+                .schedule_hour
+                .ok_or_else(|| format!("{} schedule without schedule_hour", fields.schedule_type))
+        };
+        match &fields.schedule_type {
+            ScheduleType::Hourly => Ok(Self::Hourly),
+            ScheduleType::Daily => Ok(Self::Daily { hour: hour()? }),
+            ScheduleType::Weekly => Ok(Self::Weekly {
+                day: fields
+                    .schedule_day
+                    .clone()
+                    .ok_or("weekly schedule without schedule_day")?,
+                hour: hour()?,
+            }),
+            ScheduleType::Monthly => Ok(Self::Monthly {
+                frame: fields
+                    .schedule_frame
+                    .clone()
+                    .ok_or("monthly schedule without schedule_frame")?,
+                day: fields.schedule_day.clone(),
+                hour: hour()?,
+            }),
+            ScheduleType::Unknown(other) => Err(format!("unknown schedule_type `{other}`")),
+        }
+    }
+}
+
+impl fmt::Display for Schedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Hourly => f.write_str("Every hour"),
+            Self::Daily { hour } => write!(f, "Daily at {hour:02}:00"),
+            Self::Weekly { day, hour } => write!(f, "Weekly on {} at {hour:02}:00", day.name()),
+            Self::Monthly { frame, day, hour } => {
+                let on = match (frame, day) {
+                    (ScheduleFrame::Mid, _) => "the 15th".to_owned(),
+                    (frame, None) => format!("the {frame} day"),
+                    (frame, Some(day)) => format!("the {frame} {}", day.name()),
+                };
+                write!(f, "Monthly on {on} at {hour:02}:00")
+            }
+            Self::Cron(expression) => write!(f, "On cron schedule `{expression}`"),
+        }
+    }
+}
+
+/// The `schedule_*` map used by pulse channels and database sync schedules.
+#[derive(serde::Deserialize, serde::Serialize)]
+struct ScheduleFields {
+    schedule_type: ScheduleType,
+    #[serde(default)]
+    schedule_hour: Option<u8>,
+    #[serde(default)]
+    schedule_day: Option<Weekday>,
+    #[serde(default)]
+    schedule_frame: Option<ScheduleFrame>,
+}
+
+impl serde::Serialize for Schedule {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        self.to_fields()
+            .map_err(serde::ser::Error::custom)?
+            .serialize(serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Schedule {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        Self::from_fields(ScheduleFields::deserialize(deserializer)?)
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// Request body for `POST /api/persist/set-refresh-schedule`.
+#[derive(serde::Serialize)]
+pub(crate) struct CronSchedule {
+    pub(crate) cron: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error as StdError;
+
+    #[test]
+    fn cron_round_trips_structured_schedules()
+    -> std::result::Result<(), Box<dyn StdError + Send + Sync>> {
+        let schedules = [
+            Schedule::Hourly,
+            Schedule::Daily { hour: 8 },
+            Schedule::Weekly {
+                day: Weekday::Monday,
+                hour: 9,
+            },
+            Schedule::Monthly {
+                frame: ScheduleFrame::First,
+                day: Some(Weekday::Friday),
+                hour: 6,
+            },
+            Schedule::Monthly {
+                frame: ScheduleFrame::Mid,
+                day: None,
+                hour: 0,
+            },
+            Schedule::Monthly {
+                frame: ScheduleFrame::Last,
+                day: Some(Weekday::Sunday),
+                hour: 23,
+            },
+        ];
+        for schedule in schedules {
+            assert_eq!(Schedule::cron(schedule.to_cron()?), schedule);
+        }
+        assert_eq!(
+            Schedule::cron("0 0 9 ? * MON"),
+            Schedule::Weekly {
+                day: Weekday::Monday,
+                hour: 9
+            }
+        );
+        assert_eq!(
+            Schedule::cron("0 30 8 * * ? *"),
+            Schedule::Cron("0 30 8 * * ? *".to_owned())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn fields_serialize_and_validate() -> std::result::Result<(), Box<dyn StdError + Send + Sync>> {
+        let weekly = Schedule::Weekly {
+            day: Weekday::Monday,
+            hour: 9,
+        };
+        let fields = serde_json::to_value(&weekly)?;
+        assert_eq!(
+            fields,
+            serde_json::json!({
+                "schedule_type": "weekly",
+                "schedule_hour": 9,
+                "schedule_day": "mon",
+                "schedule_frame": null
+            })
+        );
+        assert_eq!(serde_json::from_value::<Schedule>(fields)?, weekly);
+        assert_eq!(weekly.to_string(), "Weekly on Monday at 09:00");
+
+        assert!(Schedule::Daily { hour: 24 }.validate().is_err());
+        assert!(
+            Schedule::Monthly {
+                frame: ScheduleFrame::Mid,
+                day: Some(Weekday::Monday),
+                hour: 8
+            }
+            .to_cron()
+            .is_err()
+        );
+        assert!(serde_json::to_value(Schedule::Cron("0 30 8 * * ? *".to_owned())).is_err());
+        assert!(
+            serde_json::from_value::<Schedule>(serde_json::json!({"schedule_type": "daily"}))
+                .is_err()
+        );
+        Ok(())
+    }
+}
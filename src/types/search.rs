@@ -0,0 +1,327 @@
+use serde_json::{Map, Value};
+
+use crate::types::collection::CollectionRef;
+use crate::types::ids::{
+    ActionId, CardId, CollectionId, DashboardId, DatabaseId, SegmentId, TableId, UserId,
+};
+
+/// Item kinds accepted by the search `models` filter.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum SearchModel {
+    Card,
+    Dataset,
+    Metric,
+    Dashboard,
+    Collection,
+    Table,
+    Database,
+    Segment,
+    Action,
+    #[serde(rename = "indexed-entity")]
+    IndexedEntity,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum PersonalCollectionFilter {
+    /// Only items in personal collections.
+    Only,
+    /// Leave out items in personal collections.
+    Exclude,
+}
+
+/// Query parameters for `GET /api/search/`.
+#[derive(Clone, Debug, Default, serde::Serialize)]
+#[non_exhaustive]
+pub struct SearchQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub q: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub models: Vec<SearchModel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archived: Option<bool>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub created_by: Vec<UserId>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub last_edited_by: Vec<UserId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub table_db_id: Option<DatabaseId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verified: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter_items_in_personal_collection: Option<PersonalCollectionFilter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u64>,
+}
+
+impl SearchQuery {
+    pub fn new(q: impl Into<String>) -> Self {
+        Self {
+            q: Some(q.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Only return items of `model`; may be called repeatedly.
+    pub fn model(mut self, model: SearchModel) -> Self {
+        self.models.push(model);
+        self
+    }
+
+    pub fn archived(mut self, archived: bool) -> Self {
+        self.archived = Some(archived);
+        self
+    }
+
+    /// Only return items created by `user`; may be called repeatedly.
+    pub fn created_by(mut self, user: UserId) -> Self {
+        self.created_by.push(user);
+        self
+    }
+
+    /// Only return items last edited by `user`; may be called repeatedly.
+    pub fn last_edited_by(mut self, user: UserId) -> Self {
+        self.last_edited_by.push(user);
+        self
+    }
+
+    pub fn table_db_id(mut self, database: DatabaseId) -> Self {
+        self.table_db_id = Some(database);
+        self
+    }
+
+    pub fn verified(mut self, verified: bool) -> Self {
+        self.verified = Some(verified);
+        self
+    }
+
+    pub fn personal_collections(mut self, filter: PersonalCollectionFilter) -> Self {
+        self.filter_items_in_personal_collection = Some(filter);
+        self
+    }
+
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(mut self, offset: u64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+}
+
+/// Response from `GET /api/search/`.
+#[derive(Clone, Debug, serde::Deserialize)]
+#[non_exhaustive]
+pub struct SearchResults {
+    pub data: Vec<SearchResult>,
+    #[serde(default)]
+    pub total: u64,
+    /// Models with at least one match, regardless of the `models` filter.
+    #[serde(default)]
+    pub available_models: Vec<String>,
+    #[serde(default)]
+    pub limit: Option<u64>,
+    #[serde(default)]
+    pub offset: Option<u64>,
+}
+
+/// One search hit, tagged by its `model`.
+#[derive(Clone, Debug, serde::Deserialize)]
+#[serde(try_from = "Map<String, Value>")]
+#[non_exhaustive]
+pub enum SearchResult {
+    Card(SearchEntry<CardId>),
+    /// A model (called `dataset` by the API).
+    Dataset(SearchEntry<CardId>),
+    Metric(SearchEntry<CardId>),
+    Dashboard(SearchEntry<DashboardId>),
+    Collection(SearchEntry<CollectionId>),
+    Table(SearchEntry<TableId>),
+    Database(SearchEntry<DatabaseId>),
+    Segment(SearchEntry<SegmentId>),
+    Action(SearchEntry<ActionId>),
+    /// A row of an indexed model; `id` is the row's primary key.
+    IndexedEntity(SearchEntry<Value>),
+    /// A model this SDK does not know about yet, as received, `model` included.
+    Unknown(Map<String, Value>),
+}
+
+impl TryFrom<Map<String, Value>> for SearchResult {
+    type Error = serde_json::Error;
+
+    fn try_from(mut map: Map<String, Value>) -> std::result::Result<Self, Self::Error> {
+        fn entry<Id: serde::de::DeserializeOwned>(
+            map: Map<String, Value>,
+        ) -> serde_json::Result<SearchEntry<Id>> {
+            serde_json::from_value(Value::Object(map))
+        }
+
+        let decode: fn(Map<String, Value>) -> serde_json::Result<Self> =
+            match map.get("model").and_then(Value::as_str) {
+                Some("card") => |map| entry(map).map(Self::Card),
+                Some("dataset") => |map| entry(map).map(Self::Dataset),
+                Some("metric") => |map| entry(map).map(Self::Metric),
+                Some("dashboard") => |map| entry(map).map(Self::Dashboard),
+                Some("collection") => |map| entry(map).map(Self::Collection),
+                Some("table") => |map| entry(map).map(Self::Table),
+                Some("database") => |map| entry(map).map(Self::Database),
+                Some("segment") => |map| entry(map).map(Self::Segment),
+                Some("action") => |map| entry(map).map(Self::Action),
+                Some("indexed-entity") => |map| entry(map).map(Self::IndexedEntity),
+                _ => return Ok(Self::Unknown(map)),
+            };
+        map.remove("model");
+        decode(map)
+    }
+}
+
+/// Dispatch `$body` over the entry of every known [`SearchResult`] variant.
+macro_rules! with_entry {
+    ($result:expr, $entry:ident => $body:expr, $raw:ident => $unknown:expr) => {
+        match $result {
+            SearchResult::Card($entry)
+            | SearchResult::Dataset($entry)
+            | SearchResult::Metric($entry) => $body,
+            SearchResult::Dashboard($entry) => $body,
+            SearchResult::Collection($entry) => $body,
+            SearchResult::Table($entry) => $body,
+            SearchResult::Database($entry) => $body,
+            SearchResult::Segment($entry) => $body,
+            SearchResult::Action($entry) => $body,
+            SearchResult::IndexedEntity($entry) => $body,
+            SearchResult::Unknown($raw) => $unknown,
+        }
+    };
+}
+
+impl SearchResult {
+    pub fn name(&self) -> Option<&str> {
+        with_entry!(
+            self,
+            entry => Some(entry.name.as_str()),
+            raw => raw.get("name").and_then(Value::as_str)
+        )
+    }
+
+    pub fn collection(&self) -> Option<&SearchCollection> {
+        with_entry!(self, entry => entry.collection.as_ref(), _raw => None)
+    }
+
+    pub fn scores(&self) -> &[SearchScore] {
+        with_entry!(self, entry => &entry.scores, _raw => &[])
+    }
+}
+
+/// Fields shared by every kind of search hit.
+///
+/// Fields not modelled here are kept in [`extra`](Self::extra).
+#[derive(Clone, Debug, serde::Deserialize)]
+#[non_exhaustive]
+pub struct SearchEntry<Id> {
+    pub id: Id,
+    pub name: String,
+    #[serde(default)]
+    pub display_name: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub collection: Option<SearchCollection>,
+    #[serde(default)]
+    pub archived: bool,
+    /// `verified` for verified content.
+    #[serde(default)]
+    pub moderated_status: Option<String>,
+    #[serde(default)]
+    pub database_id: Option<DatabaseId>,
+    #[serde(default)]
+    pub table_id: Option<TableId>,
+    #[serde(default)]
+    pub updated_at: Option<String>,
+    #[serde(default)]
+    pub scores: Vec<SearchScore>,
+    /// Matching snippets for hits that did not match on name.
+    #[serde(default)]
+    pub context: Option<Value>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// The collection a search hit lives in.
+#[derive(Clone, Debug, serde::Deserialize)]
+#[non_exhaustive]
+pub struct SearchCollection {
+    /// `None` for items in the root collection on some versions.
+    #[serde(default)]
+    pub id: Option<CollectionRef>,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub authority_level: Option<String>,
+    #[serde(rename = "type", default)]
+    pub collection_type: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// One scorer's contribution to a hit's ranking.
+#[derive(Clone, Debug, PartialEq, serde::Deserialize)]
+#[non_exhaustive]
+pub struct SearchScore {
+    pub name: String,
+    #[serde(default)]
+    pub score: f64,
+    #[serde(default)]
+    pub weight: f64,
+    #[serde(default)]
+    pub contribution: Option<f64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error as StdError;
+
+    #[test]
+    fn results_decode_by_model() -> Result<(), Box<dyn StdError + Send + Sync>> {
+        let results: SearchResults = serde_json::from_value(serde_json::json!({
+            "total": 3,
+            "available_models": ["card", "table"],
+            "data": [
+                {
+                    "model": "card",
+                    "id": 5,
+                    "name": "Orders",
+                    "collection": {"id": "root", "name": "Our analytics"},
+                    "scores": [{"name": "text-match", "score": 0.8, "weight": 2}],
+                    "context": null,
+                    "dashboardcard_count": 2
+                },
+                {"model": "table", "id": 9, "name": "ORDERS", "database_id": 1, "table_id": 9},
+                {"model": "transform", "id": 1, "name": "Nightly"}
+            ]
+        }))?;
+        assert_eq!(results.total, 3);
+        assert!(matches!(&results.data[0], SearchResult::Card(card) if card.id == CardId(5)));
+        assert_eq!(
+            results.data[0].collection().and_then(|c| c.id),
+            Some(CollectionRef::Root)
+        );
+        assert_eq!(results.data[0].scores()[0].weight, 2.0);
+        assert!(
+            matches!(&results.data[1], SearchResult::Table(table) if table.database_id == Some(DatabaseId(1)))
+        );
+        assert!(matches!(
+            &results.data[2],
+            SearchResult::Unknown(hit) if hit["model"] == "transform" && hit["id"] == 1
+        ));
+        assert_eq!(results.data[2].name(), Some("Nightly"));
+        Ok(())
+    }
+}
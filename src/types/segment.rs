@@ -0,0 +1,163 @@
+use serde_json::{Map, Value};
+
+use crate::types::card::Card;
+use crate::types::ids::{SegmentId, TableId, UserId};
+use crate::types::mbql::{Filter, references_segment};
+
+/// A segment, as returned by `GET /api/segment/{id}`.
+///
+/// Fields not modelled here are kept in [`extra`](Self::extra).
+#[derive(Clone, Debug, serde::Deserialize)]
+#[non_exhaustive]
+pub struct Segment {
+    pub id: SegmentId,
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    pub table_id: TableId,
+    #[serde(default)]
+    pub definition: SegmentDefinition,
+    /// Server-rendered summary of the definition.
+    #[serde(default)]
+    pub definition_description: Option<String>,
+    #[serde(default)]
+    pub archived: bool,
+    #[serde(default)]
+    pub creator_id: Option<UserId>,
+    #[serde(default)]
+    pub created_at: Option<String>,
+    #[serde(default)]
+    pub updated_at: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl Segment {
+    /// Whether `card`'s query uses this segment as a filter.
+    pub fn is_referenced_by(&self, card: &Card) -> bool {
+        references_segment(&card.dataset_query, self.id)
+    }
+}
+
+/// The MBQL query a segment stands for: a filter on its table.
+///
+/// Parts not modelled here, such as a legacy metric's `aggregation`, are kept in
+/// [`extra`](Self::extra).
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+#[non_exhaustive]
+pub struct SegmentDefinition {
+    #[serde(
+        rename = "source-table",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub source_table: Option<TableId>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter: Option<Filter>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl SegmentDefinition {
+    pub fn new(table: TableId, filter: Filter) -> Self {
+        Self {
+            source_table: Some(table),
+            filter: Some(filter),
+            extra: Map::new(),
+        }
+    }
+}
+
+/// Request body for `POST /api/segment/`.
+///
+/// Takes a `revision_message` like [`UpdateSegment`], so every change to a segment is
+/// described in its history.
+#[derive(Clone, Debug, serde::Serialize)]
+#[non_exhaustive]
+pub struct CreateSegment {
+    pub name: String,
+    pub table_id: TableId,
+    pub definition: SegmentDefinition,
+    pub revision_message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl CreateSegment {
+    /// A segment of `table` matching `filter`.
+    pub fn new(
+        name: impl Into<String>,
+        table: TableId,
+        filter: Filter,
+        revision_message: impl Into<String>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            table_id: table,
+            definition: SegmentDefinition::new(table, filter),
+            revision_message: revision_message.into(),
+            description: None,
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+/// Request body for `PUT /api/segment/{id}`.
+///
+/// Only fields that were set are sent; the server rejects updates without a
+/// `revision_message`.
+#[derive(Clone, Debug, serde::Serialize)]
+#[non_exhaustive]
+pub struct UpdateSegment {
+    pub revision_message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub definition: Option<SegmentDefinition>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archived: Option<bool>,
+}
+
+impl UpdateSegment {
+    pub fn new(revision_message: impl Into<String>) -> Self {
+        Self {
+            revision_message: revision_message.into(),
+            name: None,
+            description: None,
+            definition: None,
+            archived: None,
+        }
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn definition(mut self, definition: SegmentDefinition) -> Self {
+        self.definition = Some(definition);
+        self
+    }
+
+    pub fn archived(mut self, archived: bool) -> Self {
+        self.archived = Some(archived);
+        self
+    }
+}
+
+/// Query parameters for `DELETE /api/segment/{id}`.
+#[derive(serde::Serialize)]
+pub(crate) struct ArchiveSegment<'a> {
+    pub(crate) revision_message: &'a str,
+}
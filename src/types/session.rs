@@ -1,4 +1,12 @@
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::fmt;
+
 use secrecy::{ExposeSecret, SecretString};
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use crate::types::setting::Setting;
 
 /// Request body for `POST /api/session`.
 #[derive(Clone, Debug, serde::Serialize)]
@@ -18,7 +26,10 @@ impl CreateSessionRequest {
     }
 }
 
-fn serialize_secret_string<S>(secret: &SecretString, serializer: S) -> Result<S::Ok, S::Error>
+pub(crate) fn serialize_secret_string<S>(
+    secret: &SecretString,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
 where
     S: serde::Serializer,
 {
@@ -31,3 +42,243 @@ where
 pub struct CreateSessionResponse {
     pub id: String,
 }
+
+/// Response from `GET /api/session/properties`: the server version, licensed features
+/// and public settings.
+///
+/// Settings not modelled here are kept in [`extra`](Self::extra); read them with
+/// [`Self::setting`].
+#[derive(Clone, Debug, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+pub struct SessionProperties {
+    #[serde(default)]
+    pub version: VersionInfo,
+    /// Premium features and whether the license enables them, e.g. `sso_jwt`.
+    #[serde(default)]
+    pub token_features: BTreeMap<String, bool>,
+    /// Set until the first admin completes setup.
+    #[serde(default)]
+    pub setup_token: Option<SecretString>,
+    #[serde(default)]
+    pub has_user_setup: bool,
+    /// Available database drivers, by engine name, e.g. `postgres`.
+    #[serde(default)]
+    pub engines: BTreeMap<String, Engine>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl SessionProperties {
+    /// The parsed server version; `None` for development builds without a release tag.
+    pub fn version(&self) -> Option<MetabaseVersion> {
+        self.version.parsed()
+    }
+
+    /// Whether the license enables a premium feature, e.g. `sso_jwt` or `audit_app`.
+    pub fn has_feature(&self, feature: &str) -> bool {
+        self.token_features.get(feature).copied().unwrap_or(false)
+    }
+
+    /// A public setting; `None` if it is unset, not public or not of `S::Value`'s type.
+    pub fn setting<S: Setting>(&self) -> Option<S::Value> {
+        self.extra
+            .get(S::KEY)
+            .filter(|value| !value.is_null())
+            .and_then(|value| S::Value::deserialize(value).ok())
+    }
+}
+
+/// Build information of the server.
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+#[non_exhaustive]
+pub struct VersionInfo {
+    /// e.g. `v0.50.3` or `v1.50.3` for the enterprise edition.
+    #[serde(default)]
+    pub tag: Option<String>,
+    #[serde(default)]
+    pub date: Option<String>,
+    #[serde(default)]
+    pub hash: Option<String>,
+    #[serde(default)]
+    pub branch: Option<String>,
+}
+
+impl VersionInfo {
+    pub fn parsed(&self) -> Option<MetabaseVersion> {
+        self.tag.as_deref().and_then(MetabaseVersion::parse)
+    }
+}
+
+/// A database driver offered by the server.
+#[derive(Clone, Debug, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+pub struct Engine {
+    #[serde(default)]
+    pub driver_name: Option<String>,
+    /// The engine that replaces this deprecated one.
+    #[serde(default)]
+    pub superseded_by: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum Edition {
+    /// Open source; tags start with `v0.`.
+    Oss,
+    /// Enterprise or Pro; tags start with `v1.`.
+    Enterprise,
+}
+
+/// A Metabase release, parsed from a tag such as `v0.50.3`, `v1.50.3.1` or `v0.51.0-RC2`.
+///
+/// Versions order by release, so editions of the same release compare as neighbours:
+/// `v1.50.3` is greater than `v0.50.3` but less than `v0.50.4`. Pre-releases order
+/// before their release.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct MetabaseVersion {
+    pub edition: Edition,
+    /// The release line, e.g. `50` in `v0.50.3`.
+    pub major: u32,
+    /// The point release, e.g. `3` in `v0.50.3`.
+    pub minor: u32,
+    /// The hotfix, e.g. `1` in `v0.50.3.1`.
+    pub patch: u32,
+    /// e.g. `RC2` or `beta`.
+    pub pre: Option<String>,
+}
+
+impl MetabaseVersion {
+    /// An open source release, for comparisons such as `version >= MetabaseVersion::new(50, 3, 0)`.
+    pub fn new(major: u32, minor: u32, patch: u32) -> Self {
+        Self {
+            edition: Edition::Oss,
+            major,
+            minor,
+            patch,
+            pre: None,
+        }
+    }
+
+    /// Parse a release tag; `None` if it is not one, e.g. `vUNKNOWN`.
+    pub fn parse(tag: &str) -> Option<Self> {
+        let tag = tag.trim();
+        let tag = tag.strip_prefix('v').unwrap_or(tag);
+        let (release, pre) = match tag.split_once('-') {
+            Some((release, pre)) => (release, Some(pre.to_owned())),
+            None => (tag, None),
+        };
+        let numbers = release
+            .split('.')
+            .map(|part| part.parse::<u32>().ok())
+            .collect::<Option<Vec<_>>>()?;
+        let (edition, major, minor, patch) = match numbers.as_slice() {
+            [edition, major, minor] => (*edition, *major, *minor, 0),
+            [edition, major, minor, patch] => (*edition, *major, *minor, *patch),
+            _ => return None,
+        };
+        let edition = match edition {
+            0 => Edition::Oss,
+            1 => Edition::Enterprise,
+            _ => return None,
+        };
+        Some(Self {
+            edition,
+            major,
+            minor,
+            patch,
+            pre,
+        })
+    }
+
+    /// Order key of the pre-release: releases last, then by label and number, so `RC10`
+    /// sorts after `RC2`.
+    fn pre_key(&self) -> (bool, String, u64) {
+        match &self.pre {
+            None => (true, String::new(), 0),
+            Some(pre) => {
+                let label = pre.trim_end_matches(|c: char| c.is_ascii_digit());
+                let number = pre[label.len()..].parse().unwrap_or(0);
+                (false, label.to_ascii_lowercase(), number)
+            }
+        }
+    }
+}
+
+impl Ord for MetabaseVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.major, self.minor, self.patch)
+            .cmp(&(other.major, other.minor, other.patch))
+            .then_with(|| self.pre_key().cmp(&other.pre_key()))
+            .then_with(|| self.edition.cmp(&other.edition))
+            .then_with(|| self.pre.cmp(&other.pre))
+    }
+}
+
+impl PartialOrd for MetabaseVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for MetabaseVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let edition = match self.edition {
+            Edition::Oss => 0,
+            Edition::Enterprise => 1,
+        };
+        write!(f, "v{edition}.{}.{}", self.major, self.minor)?;
+        if self.patch > 0 {
+            write!(f, ".{}", self.patch)?;
+        }
+        if let Some(pre) = &self.pre {
+            write!(f, "-{pre}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error as StdError;
+
+    #[test]
+    fn versions_parse_and_order_by_release() -> Result<(), Box<dyn StdError + Send + Sync>> {
+        let parse = |tag: &str| MetabaseVersion::parse(tag).ok_or(format!("unparsed: {tag}"));
+        let mut versions = [
+            parse("v0.50.3")?,
+            parse("v1.50.3.1")?,
+            parse("v0.51.0-RC10")?,
+            parse("v0.51.0-RC2")?,
+            parse("v0.51.0-beta")?,
+            parse("v1.50.3")?,
+            parse("v0.51.0")?,
+            parse("v0.9.1")?,
+        ];
+        versions.sort();
+        let tags: Vec<String> = versions.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            tags,
+            [
+                "v0.9.1",
+                "v0.50.3",
+                "v1.50.3",
+                "v1.50.3.1",
+                "v0.51.0-beta",
+                "v0.51.0-RC2",
+                "v0.51.0-RC10",
+                "v0.51.0"
+            ]
+        );
+        assert!(parse("v1.50.0")? >= MetabaseVersion::new(50, 0, 0));
+        assert!(parse("v0.50.0-RC1")? < MetabaseVersion::new(50, 0, 0));
+        assert_eq!(MetabaseVersion::parse("vUNKNOWN"), None);
+        assert_eq!(MetabaseVersion::parse("v2.50.1"), None);
+        Ok(())
+    }
+}
@@ -0,0 +1,212 @@
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use serde_json::{Map, Value};
+
+use crate::{Error, Result};
+
+/// An admin setting with a known key and value type.
+///
+/// Used with `SettingService::get_setting` and `set_setting`, and with
+/// [`SettingsUpdate::set`].
+pub trait Setting {
+    const KEY: &'static str;
+    type Value: Serialize + DeserializeOwned;
+}
+
+/// Define marker types for known settings, plus a lookup used to check untyped values.
+macro_rules! settings {
+    ($($(#[$meta:meta])* $name:ident => $key:literal: $value:ty,)+) => {
+        $(
+            $(#[$meta])*
+            #[derive(Clone, Copy, Debug)]
+            pub struct $name;
+
+            impl Setting for $name {
+                const KEY: &'static str = $key;
+                type Value = $value;
+            }
+        )+
+
+        /// Check `value` against the type of a known setting; unknown keys pass.
+        fn check_known(key: &str, value: &Value) -> Result<()> {
+            match key {
+                $($key => check::<$name>(value),)+
+                _ => Ok(()),
+            }
+        }
+    };
+}
+
+settings! {
+    /// Base URL used in links, emails and embeds.
+    SiteUrl => "site-url": String,
+    SiteName => "site-name": String,
+    /// Default language, e.g. `en` or `pt_BR`.
+    SiteLocale => "site-locale": String,
+    AdminEmail => "admin-email": String,
+    ApplicationName => "application-name": String,
+    /// Time zone queries run in, e.g. `Europe/Berlin`; unset uses the database's.
+    ReportTimezone => "report-timezone": String,
+    /// e.g. `sunday` or `monday`.
+    StartOfWeek => "start-of-week": String,
+    EnableEmbedding => "enable-embedding": bool,
+    EnablePublicSharing => "enable-public-sharing": bool,
+    EnableNestedQueries => "enable-nested-queries": bool,
+    EnableXrays => "enable-xrays": bool,
+    EnablePasswordLogin => "enable-password-login": bool,
+    EnableQueryCaching => "enable-query-caching": bool,
+    /// Minimum query duration, in seconds, before results are cached.
+    QueryCachingMinTtl => "query-caching-min-ttl": f64,
+    QueryCachingTtlRatio => "query-caching-ttl-ratio": u64,
+    PersistedModelsEnabled => "persisted-models-enabled": bool,
+    SearchTypeaheadEnabled => "search-typeahead-enabled": bool,
+    AnonTrackingEnabled => "anon-tracking-enabled": bool,
+    CheckForUpdates => "check-for-updates": bool,
+    EmailSmtpHost => "email-smtp-host": String,
+    EmailSmtpPort => "email-smtp-port": u16,
+    EmailFromAddress => "email-from-address": String,
+    EmailFromName => "email-from-name": String,
+}
+
+/// Request body for `PUT /api/setting/`: several settings written at once.
+///
+/// Values for known keys are checked against their types before the request is sent.
+#[derive(Clone, Debug, Default, serde::Serialize)]
+#[serde(transparent)]
+#[non_exhaustive]
+pub struct SettingsUpdate {
+    pub values: Map<String, Value>,
+}
+
+impl SettingsUpdate {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fails with [`Error::InvalidSetting`] if `value` does not serialize, or serializes to
+    /// `null` (e.g. `f64::NAN`), which the server would take as a reset.
+    pub fn set<S: Setting>(mut self, value: S::Value) -> Result<Self> {
+        let value = setting_value::<S>(&value)?;
+        self.values.insert(S::KEY.to_owned(), value);
+        Ok(self)
+    }
+
+    /// Reset a setting to its default.
+    pub fn reset<S: Setting>(mut self) -> Self {
+        self.values.insert(S::KEY.to_owned(), Value::Null);
+        self
+    }
+
+    /// Set a setting by key, for settings without a marker type.
+    pub fn set_value(mut self, key: impl Into<String>, value: Value) -> Self {
+        self.values.insert(key.into(), value);
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Check every value for a known key against that setting's type.
+    pub fn validate(&self) -> Result<()> {
+        self.values
+            .iter()
+            .try_for_each(|(key, value)| check_known(key, value))
+    }
+}
+
+/// Request body for `PUT /api/setting/{key}`.
+#[derive(serde::Serialize)]
+pub(crate) struct SettingValue {
+    pub(crate) value: Value,
+}
+
+/// Serialize a value for `S`, refusing values that would be sent as `null`.
+pub(crate) fn setting_value<S: Setting>(value: &S::Value) -> Result<Value> {
+    match serde_json::to_value(value) {
+        Ok(Value::Null) => Err(Error::invalid_setting(
+            S::KEY,
+            serde::ser::Error::custom("value serializes to null, which resets the setting"),
+        )),
+        Ok(value) => Ok(value),
+        Err(source) => Err(Error::invalid_setting(S::KEY, source)),
+    }
+}
+
+/// Decode the body of `GET /api/setting/{key}`.
+///
+/// Strings may come back as bare text rather than JSON; unset settings as an empty body.
+pub(crate) fn decode_setting<S: Setting>(body: &[u8]) -> Result<Option<S::Value>> {
+    if body.iter().all(u8::is_ascii_whitespace) {
+        return Ok(None);
+    }
+    serde_json::from_slice(body)
+        .or_else(|err| {
+            let text = String::from_utf8_lossy(body).into_owned();
+            serde_json::from_value(Value::String(text)).map_err(|_| err)
+        })
+        .map_err(|source| Error::invalid_setting(S::KEY, source))
+}
+
+fn check<S: Setting>(value: &Value) -> Result<()> {
+    if value.is_null() {
+        return Ok(());
+    }
+    <S::Value as Deserialize>::deserialize(value)
+        .map(drop)
+        .map_err(|source| Error::invalid_setting(S::KEY, source))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error as StdError;
+
+    #[test]
+    fn update_validates_known_keys() -> std::result::Result<(), Box<dyn StdError + Send + Sync>> {
+        let update = SettingsUpdate::new()
+            .set::<SiteUrl>("https://bi.example.com".to_owned())?
+            .reset::<ReportTimezone>()
+            .set_value("custom-homepage", Value::Bool(true));
+        update.validate()?;
+        assert_eq!(
+            serde_json::to_value(&update)?,
+            serde_json::json!({
+                "site-url": "https://bi.example.com",
+                "report-timezone": null,
+                "custom-homepage": true
+            })
+        );
+
+        let err = SettingsUpdate::new()
+            .set_value("enable-embedding", Value::from("yes"))
+            .validate();
+        assert!(matches!(
+            err,
+            Err(Error::InvalidSetting { ref key, .. }) if key == "enable-embedding"
+        ));
+
+        let err = SettingsUpdate::new().set::<QueryCachingMinTtl>(f64::NAN);
+        assert!(matches!(
+            err,
+            Err(Error::InvalidSetting { ref key, .. }) if key == "query-caching-min-ttl"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn decode_accepts_bare_text_and_empty_bodies()
+    -> std::result::Result<(), Box<dyn StdError + Send + Sync>> {
+        assert_eq!(
+            decode_setting::<SiteUrl>(b"https://bi.example.com")?.as_deref(),
+            Some("https://bi.example.com")
+        );
+        assert_eq!(
+            decode_setting::<SiteUrl>(b"\"https://bi.example.com\"")?.as_deref(),
+            Some("https://bi.example.com")
+        );
+        assert_eq!(decode_setting::<EnableEmbedding>(b"true")?, Some(true));
+        assert_eq!(decode_setting::<EmailSmtpPort>(b"")?, None);
+        assert!(decode_setting::<EmailSmtpPort>(b"smtp").is_err());
+        Ok(())
+    }
+}
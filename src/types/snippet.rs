@@ -0,0 +1,239 @@
+use serde_json::{Map, Value};
+
+use crate::types::card::Card;
+use crate::types::ids::{CollectionId, SnippetId, UserId};
+
+/// A native query snippet, as returned by `GET /api/native-query-snippet/{id}`.
+///
+/// Fields not modelled here are kept in [`extra`](Self::extra).
+#[derive(Clone, Debug, serde::Deserialize)]
+#[non_exhaustive]
+pub struct Snippet {
+    pub id: SnippetId,
+    /// The name native queries reference it by, as `{{snippet: name}}`.
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// The SQL text substituted for the reference.
+    pub content: String,
+    /// The snippet folder it lives in; `None` for the root folder.
+    #[serde(default)]
+    pub collection_id: Option<CollectionId>,
+    #[serde(default)]
+    pub archived: bool,
+    #[serde(default)]
+    pub creator_id: Option<UserId>,
+    #[serde(default)]
+    pub created_at: Option<String>,
+    #[serde(default)]
+    pub updated_at: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl Snippet {
+    /// Whether `card`'s native query references this snippet.
+    ///
+    /// A reference is a `snippet` template tag pointing at this snippet's id or name, or a
+    /// `{{snippet: name}}` placeholder in the query text. Cards with MBQL queries never match.
+    pub fn is_referenced_by(&self, card: &Card) -> bool {
+        native_stages(&card.dataset_query).any(|(text, tags)| {
+            let tagged = tags.into_iter().flat_map(Map::values).any(|tag| {
+                tag.get("type").and_then(Value::as_str) == Some("snippet")
+                    && (tag.get("snippet-id").and_then(Value::as_i64) == Some(self.id.0)
+                        || tag.get("snippet-name").and_then(Value::as_str)
+                            == Some(self.name.as_str()))
+            });
+            tagged || snippet_names(text).any(|name| name == self.name)
+        })
+    }
+}
+
+/// The native query text and template tags of each native stage of `dataset_query`.
+///
+/// Handles both the legacy `{"native": {"query": ..}}` shape and `stages` queries.
+fn native_stages(
+    dataset_query: &Value,
+) -> impl Iterator<Item = (&str, Option<&Map<String, Value>>)> {
+    let legacy = dataset_query.get("native").and_then(|native| {
+        let text = native.get("query")?.as_str()?;
+        Some((text, native.get("template-tags").and_then(Value::as_object)))
+    });
+    let stages = dataset_query
+        .get("stages")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|stage| {
+            let text = stage.get("native")?.as_str()?;
+            Some((text, stage.get("template-tags").and_then(Value::as_object)))
+        });
+    legacy.into_iter().chain(stages)
+}
+
+/// Names referenced by `{{snippet: name}}` placeholders in `text`.
+fn snippet_names(text: &str) -> impl Iterator<Item = &str> {
+    text.split("{{").skip(1).filter_map(|rest| {
+        let (tag, _) = rest.split_once("}}")?;
+        Some(tag.trim().strip_prefix("snippet:")?.trim())
+    })
+}
+
+/// Query parameters for `GET /api/native-query-snippet/`.
+#[derive(Clone, Debug, Default, serde::Serialize)]
+#[non_exhaustive]
+pub struct SnippetListQuery {
+    /// List archived snippets instead of active ones.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archived: Option<bool>,
+}
+
+impl SnippetListQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn archived(mut self, archived: bool) -> Self {
+        self.archived = Some(archived);
+        self
+    }
+}
+
+/// Request body for `POST /api/native-query-snippet/`.
+#[derive(Clone, Debug, serde::Serialize)]
+#[non_exhaustive]
+pub struct CreateSnippet {
+    pub name: String,
+    pub content: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collection_id: Option<CollectionId>,
+}
+
+impl CreateSnippet {
+    pub fn new(name: impl Into<String>, content: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            content: content.into(),
+            description: None,
+            collection_id: None,
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Put the snippet in a snippet folder.
+    pub fn collection(mut self, collection: CollectionId) -> Self {
+        self.collection_id = Some(collection);
+        self
+    }
+}
+
+/// Request body for `PUT /api/native-query-snippet/{id}`.
+///
+/// Only fields that were set are sent. Renaming breaks `{{snippet: name}}` placeholders
+/// in existing questions; check them first with `dependent_cards`.
+#[derive(Clone, Debug, Default, serde::Serialize)]
+#[non_exhaustive]
+pub struct UpdateSnippet {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// `Some(None)` moves the snippet to the root folder.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collection_id: Option<Option<CollectionId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archived: Option<bool>,
+}
+
+impl UpdateSnippet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn content(mut self, content: impl Into<String>) -> Self {
+        self.content = Some(content.into());
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn collection(mut self, collection: Option<CollectionId>) -> Self {
+        self.collection_id = Some(collection);
+        self
+    }
+
+    pub fn archived(mut self, archived: bool) -> Self {
+        self.archived = Some(archived);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error as StdError;
+
+    #[test]
+    fn references_match_tags_and_placeholders() -> Result<(), Box<dyn StdError + Send + Sync>> {
+        let snippet: Snippet = serde_json::from_value(serde_json::json!({
+            "id": 4,
+            "name": "active users",
+            "content": "users.active = true"
+        }))?;
+        let card = |dataset_query: Value| -> serde_json::Result<Card> {
+            serde_json::from_value(serde_json::json!({
+                "id": 1,
+                "name": "q",
+                "dataset_query": dataset_query
+            }))
+        };
+
+        let by_id = card(serde_json::json!({
+            "type": "native",
+            "native": {
+                "query": "select * from users where {{snippet: renamed}}",
+                "template-tags": {
+                    "snippet: renamed": {
+                        "type": "snippet",
+                        "snippet-name": "renamed",
+                        "snippet-id": 4
+                    }
+                }
+            }
+        }))?;
+        let by_text = card(serde_json::json!({
+            "lib/type": "mbql/query",
+            "stages": [{
+                "lib/type": "mbql.stage/native",
+                "native": "select count(*) from users where {{ snippet:active users }}"
+            }]
+        }))?;
+        let other = card(serde_json::json!({
+            "type": "native",
+            "native": {"query": "select {{snippet: active users v2}}, {{active users}}"}
+        }))?;
+        let mbql = card(serde_json::json!({"type": "query", "query": {"source-table": 2}}))?;
+
+        assert!(snippet.is_referenced_by(&by_id));
+        assert!(snippet.is_referenced_by(&by_text));
+        assert!(!snippet.is_referenced_by(&other));
+        assert!(!snippet.is_referenced_by(&mbql));
+        Ok(())
+    }
+}
@@ -0,0 +1,119 @@
+use serde_json::{Map, Value};
+
+use crate::types::field::Field;
+use crate::types::ids::{DatabaseId, FieldId, TableId};
+
+string_enum! {
+    /// Why a table is hidden; visible tables have no visibility type.
+    pub enum TableVisibility {
+        Hidden => "hidden",
+        Technical => "technical",
+        Cruft => "cruft",
+    }
+}
+
+/// A table, as returned by `GET /api/table/{id}` and `/query_metadata`.
+///
+/// `fields` is only populated by `query_metadata`. Fields not modelled here are kept in
+/// [`extra`](Self::extra).
+#[derive(Clone, Debug, serde::Deserialize)]
+#[non_exhaustive]
+pub struct Table {
+    pub id: TableId,
+    pub name: String,
+    #[serde(default)]
+    pub db_id: Option<DatabaseId>,
+    #[serde(default)]
+    pub schema: Option<String>,
+    #[serde(default)]
+    pub display_name: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// e.g. `entity/UserTable`, `entity/TransactionTable`.
+    #[serde(default)]
+    pub entity_type: Option<String>,
+    #[serde(default)]
+    pub visibility_type: Option<TableVisibility>,
+    #[serde(default)]
+    pub active: bool,
+    #[serde(default)]
+    pub fields: Vec<Field>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// One entry of `GET /api/table/{id}/fks`: a field elsewhere that points at this table.
+#[derive(Clone, Debug, serde::Deserialize)]
+#[non_exhaustive]
+pub struct ForeignKey {
+    pub origin_id: FieldId,
+    pub origin: Field,
+    pub destination_id: FieldId,
+    pub destination: Field,
+    #[serde(default)]
+    pub relationship: Option<String>,
+}
+
+/// Response from `GET /api/table/{id}/related` and `GET /api/field/{id}/related`.
+///
+/// Other related entities (segments, metrics, cards) are kept in
+/// [`extra`](Self::extra).
+#[derive(Clone, Debug, serde::Deserialize)]
+#[non_exhaustive]
+pub struct RelatedEntities {
+    #[serde(default)]
+    pub table: Option<Table>,
+    #[serde(default)]
+    pub tables: Vec<Table>,
+    #[serde(default)]
+    pub fields: Vec<Field>,
+    #[serde(rename = "linked-from", default)]
+    pub linked_from: Vec<Table>,
+    #[serde(rename = "linking-to", default)]
+    pub linking_to: Vec<Table>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Request body for `PUT /api/table/{id}`.
+///
+/// Only fields that were set are sent.
+#[derive(Clone, Debug, Default, serde::Serialize)]
+#[non_exhaustive]
+pub struct UpdateTable {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity_type: Option<String>,
+    /// `Some(None)` makes the table visible again.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visibility_type: Option<Option<TableVisibility>>,
+}
+
+impl UpdateTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn display_name(mut self, display_name: impl Into<String>) -> Self {
+        self.display_name = Some(display_name.into());
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn entity_type(mut self, entity_type: impl Into<String>) -> Self {
+        self.entity_type = Some(entity_type.into());
+        self
+    }
+
+    pub fn visibility_type(mut self, visibility_type: Option<TableVisibility>) -> Self {
+        self.visibility_type = Some(visibility_type);
+        self
+    }
+}
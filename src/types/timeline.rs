@@ -0,0 +1,498 @@
+use serde_json::{Map, Value};
+use time::{Date, OffsetDateTime, Time};
+
+use crate::types::ids::{CollectionId, TimelineEventId, TimelineId, UserId};
+
+string_enum! {
+    pub enum TimelineIcon {
+        Star => "star",
+        Cake => "cake",
+        Mail => "mail",
+        Warning => "warning",
+        Bell => "bell",
+        Cloud => "cloud",
+    }
+}
+
+/// A timeline, as returned by `GET /api/timeline/{id}`.
+///
+/// `events` is only populated when events were requested with
+/// [`TimelineQuery::events`]. Fields not modelled here are kept in [`extra`](Self::extra).
+#[derive(Clone, Debug, serde::Deserialize)]
+#[non_exhaustive]
+pub struct Timeline {
+    pub id: TimelineId,
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub icon: Option<TimelineIcon>,
+    /// `None` for timelines in the root collection.
+    #[serde(default)]
+    pub collection_id: Option<CollectionId>,
+    #[serde(default)]
+    pub archived: bool,
+    /// Whether this is the collection's automatically created timeline.
+    #[serde(default)]
+    pub default: bool,
+    #[serde(default)]
+    pub creator_id: Option<UserId>,
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub created_at: Option<OffsetDateTime>,
+    #[serde(default)]
+    pub events: Vec<TimelineEvent>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// An event on a timeline, as returned by `GET /api/timeline-event/{id}`.
+///
+/// Fields not modelled here are kept in [`extra`](Self::extra).
+#[derive(Clone, Debug, serde::Deserialize)]
+#[non_exhaustive]
+pub struct TimelineEvent {
+    pub id: TimelineEventId,
+    pub timeline_id: TimelineId,
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub timestamp: OffsetDateTime,
+    /// `false` for events that only have a date; see [`Self::date`].
+    #[serde(default)]
+    pub time_matters: bool,
+    /// IANA zone the event is shown in, e.g. `Europe/Berlin`.
+    #[serde(default)]
+    pub timezone: Option<String>,
+    #[serde(default)]
+    pub icon: Option<TimelineIcon>,
+    #[serde(default)]
+    pub archived: bool,
+    #[serde(default)]
+    pub creator_id: Option<UserId>,
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub created_at: Option<OffsetDateTime>,
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub updated_at: Option<OffsetDateTime>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl TimelineEvent {
+    /// The calendar date of the event, in the offset the server sent the timestamp with.
+    pub fn date(&self) -> Date {
+        self.timestamp.date()
+    }
+
+    /// The key events are matched on by `upsert_timeline_events`.
+    pub fn key(&self) -> (TimelineId, &str, OffsetDateTime) {
+        (self.timeline_id, &self.name, self.timestamp)
+    }
+}
+
+/// Query parameters for the timeline list and get endpoints.
+#[derive(Clone, Debug, Default, serde::Serialize)]
+#[non_exhaustive]
+pub struct TimelineQuery {
+    /// `events` to include each timeline's events.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archived: Option<bool>,
+}
+
+impl TimelineQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Include each timeline's events.
+    pub fn events(mut self) -> Self {
+        self.include = Some("events");
+        self
+    }
+
+    pub fn archived(mut self, archived: bool) -> Self {
+        self.archived = Some(archived);
+        self
+    }
+}
+
+/// Request body for `POST /api/timeline/`.
+#[derive(Clone, Debug, serde::Serialize)]
+#[non_exhaustive]
+pub struct CreateTimeline {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon: Option<TimelineIcon>,
+    /// `None` puts the timeline in the root collection.
+    pub collection_id: Option<CollectionId>,
+}
+
+impl CreateTimeline {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            description: None,
+            icon: None,
+            collection_id: None,
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn icon(mut self, icon: TimelineIcon) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    pub fn collection(mut self, collection: CollectionId) -> Self {
+        self.collection_id = Some(collection);
+        self
+    }
+}
+
+/// Request body for `PUT /api/timeline/{id}`.
+///
+/// Only fields that were set are sent.
+#[derive(Clone, Debug, Default, serde::Serialize)]
+#[non_exhaustive]
+pub struct UpdateTimeline {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon: Option<TimelineIcon>,
+    /// `Some(None)` moves the timeline to the root collection.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collection_id: Option<Option<CollectionId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archived: Option<bool>,
+}
+
+impl UpdateTimeline {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn icon(mut self, icon: TimelineIcon) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    pub fn collection(mut self, collection: Option<CollectionId>) -> Self {
+        self.collection_id = Some(collection);
+        self
+    }
+
+    pub fn archived(mut self, archived: bool) -> Self {
+        self.archived = Some(archived);
+        self
+    }
+}
+
+/// Request body for `POST /api/timeline-event/`.
+#[derive(Clone, Debug, serde::Serialize)]
+#[non_exhaustive]
+pub struct CreateTimelineEvent {
+    pub timeline_id: TimelineId,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub timestamp: OffsetDateTime,
+    pub time_matters: bool,
+    /// IANA zone the event is shown in; the server requires one.
+    pub timezone: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon: Option<TimelineIcon>,
+}
+
+impl CreateTimelineEvent {
+    /// An event at an exact instant, shown in UTC until [`Self::timezone`] is set.
+    pub fn new(timeline: TimelineId, name: impl Into<String>, timestamp: OffsetDateTime) -> Self {
+        Self {
+            timeline_id: timeline,
+            name: name.into(),
+            description: None,
+            timestamp,
+            time_matters: true,
+            timezone: "UTC".to_owned(),
+            icon: None,
+        }
+    }
+
+    /// A date-only event, stored as midnight UTC with `time_matters: false`.
+    pub fn on_date(timeline: TimelineId, name: impl Into<String>, date: Date) -> Self {
+        let mut event = Self::new(timeline, name, date.with_time(Time::MIDNIGHT).assume_utc());
+        event.time_matters = false;
+        event
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// The IANA zone the event is shown in, e.g. `Europe/Berlin`. The timestamp itself
+    /// is an absolute instant and is not shifted.
+    pub fn timezone(mut self, timezone: impl Into<String>) -> Self {
+        self.timezone = timezone.into();
+        self
+    }
+
+    pub fn icon(mut self, icon: TimelineIcon) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// The key events are matched on by `upsert_timeline_events`.
+    pub fn key(&self) -> (TimelineId, &str, OffsetDateTime) {
+        (self.timeline_id, &self.name, self.timestamp)
+    }
+
+    /// Whether `event` already carries everything this request would write.
+    ///
+    /// Unset optional fields are not sent by the update, so they match anything.
+    fn matches(&self, event: &TimelineEvent) -> bool {
+        event.key() == self.key()
+            && (self.description.is_none() || event.description == self.description)
+            && event.time_matters == self.time_matters
+            && event.timezone.as_deref() == Some(self.timezone.as_str())
+            && (self.icon.is_none() || event.icon == self.icon)
+            && !event.archived
+    }
+}
+
+/// Request body for `PUT /api/timeline-event/{id}`.
+///
+/// Only fields that were set are sent.
+#[derive(Clone, Debug, Default, serde::Serialize)]
+#[non_exhaustive]
+pub struct UpdateTimelineEvent {
+    /// Moves the event to another timeline.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeline_id: Option<TimelineId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        with = "time::serde::rfc3339::option"
+    )]
+    pub timestamp: Option<OffsetDateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_matters: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon: Option<TimelineIcon>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archived: Option<bool>,
+}
+
+impl UpdateTimelineEvent {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn timestamp(mut self, timestamp: OffsetDateTime, time_matters: bool) -> Self {
+        self.timestamp = Some(timestamp);
+        self.time_matters = Some(time_matters);
+        self
+    }
+
+    pub fn timezone(mut self, timezone: impl Into<String>) -> Self {
+        self.timezone = Some(timezone.into());
+        self
+    }
+
+    pub fn icon(mut self, icon: TimelineIcon) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    pub fn archived(mut self, archived: bool) -> Self {
+        self.archived = Some(archived);
+        self
+    }
+}
+
+impl From<&CreateTimelineEvent> for UpdateTimelineEvent {
+    fn from(event: &CreateTimelineEvent) -> Self {
+        Self {
+            timeline_id: Some(event.timeline_id),
+            name: Some(event.name.clone()),
+            description: event.description.clone(),
+            timestamp: Some(event.timestamp),
+            time_matters: Some(event.time_matters),
+            timezone: Some(event.timezone.clone()),
+            icon: event.icon.clone(),
+            archived: Some(false),
+        }
+    }
+}
+
+/// What `upsert_timeline_events` does for one requested event.
+#[derive(Debug)]
+pub(crate) enum EventUpsert<'a> {
+    Unchanged(&'a TimelineEvent),
+    Update(TimelineEventId, &'a CreateTimelineEvent),
+    Create(&'a CreateTimelineEvent),
+}
+
+/// The distinct timelines of `events`, in order of first appearance.
+pub(crate) fn event_timelines(events: &[CreateTimelineEvent]) -> Vec<TimelineId> {
+    let mut timelines = Vec::new();
+    for event in events {
+        if !timelines.contains(&event.timeline_id) {
+            timelines.push(event.timeline_id);
+        }
+    }
+    timelines
+}
+
+/// Match `desired` against `existing` by (timeline, name, timestamp).
+pub(crate) fn plan_event_upserts<'a>(
+    existing: &'a [TimelineEvent],
+    desired: &'a [CreateTimelineEvent],
+) -> Vec<EventUpsert<'a>> {
+    desired
+        .iter()
+        .map(
+            |event| match existing.iter().find(|current| current.key() == event.key()) {
+                Some(current) if event.matches(current) => EventUpsert::Unchanged(current),
+                Some(current) => EventUpsert::Update(current.id, event),
+                None => EventUpsert::Create(event),
+            },
+        )
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error as StdError;
+    use time::{Month, format_description::well_known::Rfc3339};
+
+    #[test]
+    fn events_parse_timestamps_and_plan_upserts() -> Result<(), Box<dyn StdError + Send + Sync>> {
+        let existing: Vec<TimelineEvent> = serde_json::from_value(serde_json::json!([
+            {
+                "id": 1,
+                "timeline_id": 3,
+                "name": "Launch",
+                "timestamp": "2024-05-01T09:00:00+02:00",
+                "time_matters": true,
+                "timezone": "Europe/Berlin",
+                "icon": "star",
+                "created_at": "2024-04-30T12:00:00.123Z"
+            },
+            {
+                "id": 2,
+                "timeline_id": 3,
+                "name": "Outage",
+                "timestamp": "2024-06-01T00:00:00Z",
+                "time_matters": false,
+                "timezone": "UTC",
+                "icon": "warning"
+            }
+        ]))?;
+        assert_eq!(
+            existing[1].date(),
+            Date::from_calendar_date(2024, Month::June, 1)?
+        );
+
+        let launch = OffsetDateTime::parse("2024-05-01T07:00:00Z", &Rfc3339)?;
+        let desired = vec![
+            CreateTimelineEvent::new(TimelineId(3), "Launch", launch)
+                .timezone("Europe/Berlin")
+                .icon(TimelineIcon::Star),
+            CreateTimelineEvent::on_date(
+                TimelineId(3),
+                "Outage",
+                Date::from_calendar_date(2024, Month::June, 1)?,
+            )
+            .description("Postmortem in the wiki"),
+            CreateTimelineEvent::on_date(
+                TimelineId(3),
+                "Migration",
+                Date::from_calendar_date(2024, Month::July, 1)?,
+            ),
+        ];
+        let plan = plan_event_upserts(&existing, &desired);
+        assert!(matches!(plan[0], EventUpsert::Unchanged(event) if event.id == TimelineEventId(1)));
+        assert!(matches!(plan[1], EventUpsert::Update(id, _) if id == TimelineEventId(2)));
+        assert!(matches!(plan[2], EventUpsert::Create(_)));
+
+        // Once the plan is applied, repeating it changes nothing, including for events
+        // whose description was set elsewhere.
+        let mut applied: Vec<TimelineEvent> = serde_json::from_value(serde_json::json!([
+            {
+                "id": 2,
+                "timeline_id": 3,
+                "name": "Outage",
+                "description": "Postmortem in the wiki",
+                "timestamp": "2024-06-01T00:00:00Z",
+                "time_matters": false,
+                "timezone": "UTC",
+                "icon": "warning"
+            },
+            {
+                "id": 4,
+                "timeline_id": 3,
+                "name": "Migration",
+                "description": "Added in the UI",
+                "timestamp": "2024-07-01T00:00:00Z",
+                "time_matters": false,
+                "timezone": "UTC",
+                "icon": "star"
+            }
+        ]))?;
+        applied.insert(0, existing[0].clone());
+        let plan = plan_event_upserts(&applied, &desired);
+        assert!(
+            plan.iter()
+                .all(|upsert| matches!(upsert, EventUpsert::Unchanged(_)))
+        );
+
+        assert_eq!(
+            serde_json::to_value(&desired[2])?,
+            serde_json::json!({
+                "timeline_id": 3,
+                "name": "Migration",
+                "timestamp": "2024-07-01T00:00:00Z",
+                "time_matters": false,
+                "timezone": "UTC"
+            })
+        );
+        Ok(())
+    }
+}
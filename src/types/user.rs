@@ -1,12 +1,297 @@
-/// Response body from `GET /api/user/current`.
+use secrecy::{ExposeSecret, SecretString};
+use serde_json::{Map, Value};
+
+use crate::types::ids::{CollectionId, GroupId, UserId};
+use crate::types::session::serialize_secret_string;
+
+/// A user, as returned by `GET /api/user/current` and `GET /api/user/{id}`.
+///
+/// Fields not modelled here are kept in [`extra`](Self::extra).
 #[derive(Clone, Debug, serde::Deserialize)]
 #[non_exhaustive]
 pub struct User {
-    pub id: i64,
+    pub id: UserId,
     #[serde(default)]
     pub email: Option<String>,
     #[serde(default)]
     pub first_name: Option<String>,
     #[serde(default)]
     pub last_name: Option<String>,
+    #[serde(default)]
+    pub common_name: Option<String>,
+    #[serde(default)]
+    pub is_active: bool,
+    #[serde(default)]
+    pub is_superuser: bool,
+    #[serde(default)]
+    pub locale: Option<String>,
+    /// e.g. `google`, `ldap`, `saml` or `jwt`; `None` for password logins.
+    #[serde(default)]
+    pub sso_source: Option<String>,
+    #[serde(default)]
+    pub date_joined: Option<String>,
+    #[serde(default)]
+    pub last_login: Option<String>,
+    #[serde(default)]
+    pub personal_collection_id: Option<CollectionId>,
+    #[serde(default)]
+    pub user_group_memberships: Vec<UserGroupMembership>,
+    #[serde(default)]
+    pub login_attributes: Option<Map<String, Value>>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// A group a user belongs to, as listed on the user.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[non_exhaustive]
+pub struct UserGroupMembership {
+    pub id: GroupId,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_group_manager: Option<bool>,
+}
+
+impl UserGroupMembership {
+    pub fn new(group: GroupId) -> Self {
+        Self {
+            id: group,
+            is_group_manager: None,
+        }
+    }
+
+    pub fn manager(mut self, is_group_manager: bool) -> Self {
+        self.is_group_manager = Some(is_group_manager);
+        self
+    }
+}
+
+/// Response from `GET /api/user/`.
+#[derive(Clone, Debug, serde::Deserialize)]
+#[non_exhaustive]
+pub struct UserList {
+    pub data: Vec<User>,
+    #[serde(default)]
+    pub total: u64,
+    #[serde(default)]
+    pub limit: Option<u64>,
+    #[serde(default)]
+    pub offset: Option<u64>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum UserStatus {
+    Active,
+    Deactivated,
+    All,
+}
+
+/// Query parameters for `GET /api/user/`.
+#[derive(Clone, Debug, Default, serde::Serialize)]
+#[non_exhaustive]
+pub struct UserListQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<UserStatus>,
+    /// Matches name or email.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_id: Option<GroupId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u64>,
+}
+
+impl UserListQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn status(mut self, status: UserStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn query(mut self, query: impl Into<String>) -> Self {
+        self.query = Some(query.into());
+        self
+    }
+
+    pub fn group_id(mut self, group_id: GroupId) -> Self {
+        self.group_id = Some(group_id);
+        self
+    }
+
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(mut self, offset: u64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+}
+
+/// Request body for `POST /api/user/`.
+#[derive(Clone, Debug, serde::Serialize)]
+#[non_exhaustive]
+pub struct CreateUser {
+    pub email: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    /// Without a password the user is sent an invite to set one.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_optional_secret"
+    )]
+    pub password: Option<SecretString>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub user_group_memberships: Vec<UserGroupMembership>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub login_attributes: Option<Map<String, Value>>,
+}
+
+impl CreateUser {
+    pub fn new(email: impl Into<String>) -> Self {
+        Self {
+            email: email.into(),
+            first_name: None,
+            last_name: None,
+            password: None,
+            user_group_memberships: Vec::new(),
+            login_attributes: None,
+        }
+    }
+
+    pub fn name(mut self, first_name: impl Into<String>, last_name: impl Into<String>) -> Self {
+        self.first_name = Some(first_name.into());
+        self.last_name = Some(last_name.into());
+        self
+    }
+
+    pub fn password(mut self, password: SecretString) -> Self {
+        self.password = Some(password);
+        self
+    }
+
+    pub fn group(mut self, membership: UserGroupMembership) -> Self {
+        self.user_group_memberships.push(membership);
+        self
+    }
+
+    pub fn login_attributes(mut self, attributes: Map<String, Value>) -> Self {
+        self.login_attributes = Some(attributes);
+        self
+    }
+}
+
+/// Request body for `PUT /api/user/{id}`.
+///
+/// Only fields that were set are sent.
+#[derive(Clone, Debug, Default, serde::Serialize)]
+#[non_exhaustive]
+pub struct UpdateUser {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_superuser: Option<bool>,
+    /// `Some(None)` resets to the instance locale.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<Option<String>>,
+    /// Replaces the user's group memberships.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_group_memberships: Option<Vec<UserGroupMembership>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub login_attributes: Option<Map<String, Value>>,
+}
+
+impl UpdateUser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn email(mut self, email: impl Into<String>) -> Self {
+        self.email = Some(email.into());
+        self
+    }
+
+    pub fn first_name(mut self, first_name: impl Into<String>) -> Self {
+        self.first_name = Some(first_name.into());
+        self
+    }
+
+    pub fn last_name(mut self, last_name: impl Into<String>) -> Self {
+        self.last_name = Some(last_name.into());
+        self
+    }
+
+    pub fn superuser(mut self, is_superuser: bool) -> Self {
+        self.is_superuser = Some(is_superuser);
+        self
+    }
+
+    pub fn locale(mut self, locale: Option<String>) -> Self {
+        self.locale = Some(locale);
+        self
+    }
+
+    pub fn groups(mut self, memberships: Vec<UserGroupMembership>) -> Self {
+        self.user_group_memberships = Some(memberships);
+        self
+    }
+
+    pub fn login_attributes(mut self, attributes: Map<String, Value>) -> Self {
+        self.login_attributes = Some(attributes);
+        self
+    }
+}
+
+/// Request body for `PUT /api/user/{id}/password`.
+#[derive(Clone, Debug, serde::Serialize)]
+#[non_exhaustive]
+pub struct ChangePassword {
+    #[serde(serialize_with = "serialize_secret_string")]
+    pub password: SecretString,
+    /// Required when users change their own password.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_optional_secret"
+    )]
+    pub old_password: Option<SecretString>,
+}
+
+impl ChangePassword {
+    pub fn new(password: SecretString) -> Self {
+        Self {
+            password,
+            old_password: None,
+        }
+    }
+
+    pub fn old_password(mut self, old_password: SecretString) -> Self {
+        self.old_password = Some(old_password);
+        self
+    }
+}
+
+fn serialize_optional_secret<S>(
+    secret: &Option<SecretString>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    match secret {
+        Some(secret) => serializer.serialize_str(secret.expose_secret()),
+        None => serializer.serialize_none(),
+    }
 }
@@ -5,7 +5,7 @@ use serde::Serialize;
 use serde_json::Value;
 use url::Url;
 
-use crate::error::Error;
+use crate::{client::RedactionPolicy, error::Error};
 
 pub(crate) const DEFAULT_BODY_SNIPPET_LIMIT: usize = 4 * 1024;
 
@@ -17,6 +17,30 @@ pub(crate) fn normalize_base_url(mut base_url: Url) -> Url {
     base_url
 }
 
+pub(crate) fn parse_base_url(base_url: &str) -> Result<Url, Error> {
+    let parsed =
+        Url::parse(base_url).map_err(|source| Error::invalid_base_url(base_url, source))?;
+    if parsed.cannot_be_a_base() {
+        return Err(Error::invalid_base_url_config(
+            base_url,
+            "base_url must be hierarchical",
+        ));
+    }
+    if parsed.query().is_some() {
+        return Err(Error::invalid_base_url_config(
+            base_url,
+            "base_url must not include a query string",
+        ));
+    }
+    if parsed.fragment().is_some() {
+        return Err(Error::invalid_base_url_config(
+            base_url,
+            "base_url must not include a fragment",
+        ));
+    }
+    Ok(normalize_base_url(parsed))
+}
+
 pub(crate) fn build_url(base_url: &Url, path: &[&str]) -> Result<Url, Error> {
     let mut url = base_url.clone();
 
@@ -175,17 +199,16 @@ pub(crate) fn body_snippet(bytes: &[u8], limit: usize) -> String {
     }
 }
 
-pub(crate) fn capture_body_snippet(bytes: &[u8], limit: usize, redact: bool) -> String {
+pub(crate) fn capture_body_snippet(
+    bytes: &[u8],
+    limit: usize,
+    redaction: Option<&RedactionPolicy>,
+) -> String {
     let snippet = body_snippet(bytes, limit);
-    if !redact {
-        return snippet;
-    }
-
-    if !looks_sensitive(&snippet) {
-        return snippet;
+    match redaction {
+        Some(redaction) => redaction.redact_snippet(&snippet),
+        None => snippet,
     }
-
-    redact_body_snippet(&snippet)
 }
 
 pub(crate) fn extract_request_id(headers: &HeaderMap) -> Option<String> {
@@ -208,60 +231,6 @@ pub(crate) fn extract_request_id(headers: &HeaderMap) -> Option<String> {
     None
 }
 
-fn looks_sensitive(snippet: &str) -> bool {
-    let lower = snippet.to_ascii_lowercase();
-    const MARKERS: [&str; 8] = [
-        "token",
-        "password",
-        "secret",
-        "api_key",
-        "api-key",
-        "authorization",
-        "cookie",
-        "session",
-    ];
-
-    MARKERS.iter().any(|m| lower.contains(m))
-}
-
-fn redact_body_snippet(snippet: &str) -> String {
-    match serde_json::from_str::<Value>(snippet) {
-        Ok(mut value) => {
-            redact_json_value(&mut value);
-            serde_json::to_string(&value).unwrap_or_else(|_| "<redacted>".to_owned())
-        }
-        Err(_) => "<redacted>".to_owned(),
-    }
-}
-
-fn redact_json_value(value: &mut Value) {
-    match value {
-        Value::Object(map) => {
-            for (key, value) in map.iter_mut() {
-                if is_sensitive_key(key) {
-                    *value = Value::String("<redacted>".to_owned());
-                    continue;
-                }
-                redact_json_value(value);
-            }
-        }
-        Value::Array(items) => {
-            for item in items {
-                redact_json_value(item);
-            }
-        }
-        _ => {}
-    }
-}
-
-fn is_sensitive_key(key: &str) -> bool {
-    let key = key.to_ascii_lowercase();
-    const KEYS: [&str; 6] = [
-        "password", "token", "secret", "api_key", "api-key", "session",
-    ];
-    KEYS.iter().any(|k| key.contains(k))
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -315,7 +284,7 @@ mod tests {
     #[test]
     fn capture_body_snippet_redacts_sensitive_json() {
         let bytes = br#"{"token":"abcd","nested":{"password":"pw"}}"#;
-        let snippet = capture_body_snippet(bytes, 1024, true);
+        let snippet = capture_body_snippet(bytes, 1024, Some(&RedactionPolicy::standard()));
         assert!(snippet.contains("<redacted>"));
         assert!(!snippet.contains("abcd"));
         assert!(!snippet.contains("pw"));
@@ -324,14 +293,14 @@ mod tests {
     #[test]
     fn capture_body_snippet_leaves_nonsensitive_content() {
         let bytes = br#"{"message":"boom"}"#;
-        let snippet = capture_body_snippet(bytes, 1024, true);
+        let snippet = capture_body_snippet(bytes, 1024, Some(&RedactionPolicy::standard()));
         assert_eq!(snippet, r#"{"message":"boom"}"#);
     }
 
     #[test]
     fn capture_body_snippet_redacts_sensitive_text() {
         let bytes = b"token=abcd";
-        let snippet = capture_body_snippet(bytes, 1024, true);
+        let snippet = capture_body_snippet(bytes, 1024, Some(&RedactionPolicy::standard()));
         assert_eq!(snippet, "<redacted>");
     }
 }
@@ -14,8 +14,19 @@ use std::{
 };
 
 use metabase::{
-    BlockingClient, Error, IdempotencyKey, Jitter, RequestOptions, RetryPolicy,
-    types::session::CreateSessionRequest,
+    BlockingClient, Error, ErrorKind, IdempotencyKey, Jitter, RequestOptions, RetryPolicy,
+    types::{
+        collection::{CollectionPermission, CollectionPermissionsChanges, CollectionRef},
+        dashboard::{DashboardCard, DashboardCards},
+        database::DatabaseDetails,
+        ids::{CardId, CollectionId, DashboardId, DashcardId, GroupId, SnippetId, UserId},
+        pulse::{CreatePulse, PulseCardRef, PulseChannel, PulseRecipient},
+        revision::RevertedEntity,
+        schedule::Schedule,
+        session::CreateSessionRequest,
+        snippet::UpdateSnippet,
+        user::ChangePassword,
+    },
 };
 use secrecy::SecretString;
 
@@ -345,3 +356,477 @@ fn blocking_post_is_retried_with_idempotency_key() -> TestResult<()> {
     assert_eq!(response.id, "TOKEN");
     Ok(())
 }
+
+#[test]
+fn blocking_stats_track_routes_retries_and_errors() -> TestResult<()> {
+    let mut first = Response::json(503, r#"{"message":"unavailable"}"#);
+    first
+        .headers
+        .push(("Retry-After".to_owned(), "0".to_owned()));
+
+    let server = TestServer::new(vec![
+        first,
+        Response::json(200, r#"{"status":"ok"}"#),
+        Response::json(404, r#"{"message":"missing"}"#),
+    ])?;
+
+    let client = BlockingClient::builder(server.base_url())?
+        .collect_stats(true)
+        .build()?;
+
+    client.health().get()?;
+    assert!(client.card().get_by_id(7, None).is_err());
+
+    let stats = client
+        .stats()
+        .ok_or_else(|| std::io::Error::other("stats disabled"))?;
+    assert_eq!(stats.requests, 2);
+    assert_eq!(stats.retries, 1);
+    assert_eq!(stats.errors.get(&ErrorKind::NotFound), Some(&1));
+
+    let routes: Vec<&str> = stats.routes.iter().map(|route| route.route).collect();
+    assert_eq!(routes, ["/api/card/{id}", "/api/health"]);
+    assert_eq!(stats.routes[1].latency.samples, 1);
+
+    let disabled = BlockingClient::builder(server.base_url())?.build()?;
+    assert!(disabled.stats().is_none());
+    Ok(())
+}
+
+#[test]
+fn blocking_dashboard_typed_get_and_update_cards() -> TestResult<()> {
+    let server = TestServer::new(vec![
+        Response::json(
+            200,
+            r#"{"id":3,"name":"Sales","dashcards":[{"id":10,"card_id":5,"row":0,"col":0,"size_x":6,"size_y":4}],"tabs":[]}"#,
+        ),
+        Response::json(
+            200,
+            r#"{"cards":[{"id":10,"card_id":5,"row":0,"col":0,"size_x":6,"size_y":4},{"id":11,"card_id":6,"row":4,"col":0,"size_x":4,"size_y":4}],"tabs":[]}"#,
+        ),
+    ])?;
+
+    let client = BlockingClient::builder(server.base_url())?.build()?;
+    let dashboard = client.dashboard().get_dashboard(DashboardId(3))?;
+    assert_eq!(dashboard.dashcards.len(), 1);
+
+    let mut cards = dashboard.dashcards;
+    cards.push(DashboardCard::new(DashcardId(-1), Some(CardId(6))).position(4, 0));
+    let updated = client
+        .dashboard()
+        .update_dashboard_cards(dashboard.id, &DashboardCards::new(cards))?;
+    assert_eq!(updated.cards[1].id, DashcardId(11));
+
+    let requests = server.requests();
+    assert_eq!(requests[1].method, "PUT");
+    assert_eq!(requests[1].path, "/api/dashboard/3/cards");
+    Ok(())
+}
+
+#[test]
+fn blocking_database_validate_reports_invalid_field() -> TestResult<()> {
+    let server = TestServer::new(vec![
+        Response::json(200, r#"{"valid":true}"#),
+        Response::json(
+            400,
+            r#"{"valid":false,"message":"Password is incorrect.","errors":{"password":"Password is incorrect."}}"#,
+        ),
+    ])?;
+
+    let client = BlockingClient::builder(server.base_url())?.build()?;
+    let details = DatabaseDetails::postgres("db.internal", 5432, "analytics", "metabase")
+        .password(SecretString::from("hunter2"));
+
+    let ok = client.database().validate_database(&details)?;
+    assert!(ok.valid);
+
+    let rejected = client.database().validate_database(&details)?;
+    assert!(!rejected.valid);
+    assert_eq!(rejected.invalid_field(), Some("password"));
+    assert_eq!(rejected.message.as_deref(), Some("Password is incorrect."));
+
+    let requests = server.requests();
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].path, "/api/database/validate");
+    Ok(())
+}
+
+#[test]
+fn blocking_collection_graph_apply_retries_after_conflict() -> TestResult<()> {
+    let server = TestServer::new(vec![
+        Response::json(409, r#"{"message":"out of date"}"#),
+        Response::json(
+            200,
+            r#"{"revision":8,"groups":{"3":{"root":"none","4":"none"}}}"#,
+        ),
+        Response::json(
+            200,
+            r#"{"revision":9,"groups":{"3":{"root":"none","4":"write"}}}"#,
+        ),
+    ])?;
+
+    let client = BlockingClient::builder(server.base_url())?.build()?;
+    let changes = CollectionPermissionsChanges::new().set(
+        GroupId(3),
+        CollectionId(4),
+        CollectionPermission::Write,
+    );
+    let graph = client
+        .collection()
+        .apply_collection_permissions_changes(7, &changes)?;
+    assert_eq!(graph.revision, 9);
+    assert_eq!(
+        graph.groups[&GroupId(3)].get(&CollectionRef::Id(CollectionId(4))),
+        Some(&CollectionPermission::Write)
+    );
+
+    let methods: Vec<String> = server
+        .requests()
+        .into_iter()
+        .map(|request| request.method)
+        .collect();
+    assert_eq!(methods, ["PUT", "GET", "PUT"]);
+    Ok(())
+}
+
+#[test]
+fn blocking_user_lifecycle_calls_discard_success_bodies() -> TestResult<()> {
+    let server = TestServer::new(vec![
+        Response::json(200, r#"{"success":true}"#),
+        Response::json(200, ""),
+        Response::json(
+            200,
+            r#"{"id":7,"email":"ada@example.com","is_active":true}"#,
+        ),
+    ])?;
+
+    let client = BlockingClient::builder(server.base_url())?.build()?;
+    client.user().change_password(
+        UserId(7),
+        &ChangePassword::new(SecretString::from("n3w-passw0rd")),
+    )?;
+    client.user().deactivate_user(UserId(7))?;
+    let user = client.user().reactivate_user(UserId(7))?;
+    assert!(user.is_active);
+
+    let paths: Vec<String> = server
+        .requests()
+        .into_iter()
+        .map(|request| format!("{} {}", request.method, request.path))
+        .collect();
+    assert_eq!(
+        paths,
+        [
+            "PUT /api/user/7/password",
+            "DELETE /api/user/7",
+            "PUT /api/user/7/reactivate"
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn blocking_dashboard_subscription_test_send_and_create() -> TestResult<()> {
+    let server = TestServer::new(vec![
+        Response::json(200, r#"{"ok":true}"#),
+        Response::json(
+            200,
+            r##"{"id":5,"name":"KPIs","dashboard_id":3,"cards":[{"id":10,"include_csv":true,"dashboard_card_id":21}],"channels":[{"channel_type":"slack","schedule_type":"hourly","details":{"channel":"#kpis"}}]}"##,
+        ),
+    ])?;
+
+    let client = BlockingClient::builder(server.base_url())?.build()?;
+    let subscription = CreatePulse::dashboard_subscription("KPIs", DashboardId(3))
+        .card(PulseCardRef::new(CardId(10)).dashcard(DashcardId(21)).csv())
+        .channel(
+            PulseChannel::email(vec![PulseRecipient::email("cfo@example.com")])
+                .schedule(Schedule::Daily { hour: 7 })?,
+        )
+        .channel(PulseChannel::slack("#kpis").schedule(Schedule::Hourly)?);
+    client.pulse().test_pulse(&subscription)?;
+    let pulse = client.pulse().create_pulse(&subscription)?;
+    assert_eq!(pulse.dashboard_id, Some(DashboardId(3)));
+    assert_eq!(pulse.channels[0].slack_channel(), Some("#kpis"));
+
+    let paths: Vec<String> = server
+        .requests()
+        .into_iter()
+        .map(|request| format!("{} {}", request.method, request.path))
+        .collect();
+    assert_eq!(paths, ["POST /api/pulse/test", "POST /api/pulse"]);
+    Ok(())
+}
+
+#[test]
+fn blocking_snippet_rename_checks_dependent_cards() -> TestResult<()> {
+    let server = TestServer::new(vec![
+        Response::json(
+            200,
+            r#"{"id":4,"name":"active users","content":"users.active = true","collection_id":null}"#,
+        ),
+        Response::json(
+            200,
+            r#"[{"id":1,"name":"Active","dataset_query":{"type":"native","native":{"query":"select * from users where {{snippet: active users}}"}}},{"id":2,"name":"All","dataset_query":{"type":"query","query":{"source-table":2}}}]"#,
+        ),
+        Response::json(
+            200,
+            r#"{"id":4,"name":"enabled users","content":"users.active = true"}"#,
+        ),
+    ])?;
+
+    let client = BlockingClient::builder(server.base_url())?.build()?;
+    let snippets = client.native_query_snippet();
+    let snippet = snippets.get_snippet(SnippetId(4))?;
+    let dependents = snippets.dependent_cards(&snippet)?;
+    assert_eq!(
+        dependents.iter().map(|card| card.id).collect::<Vec<_>>(),
+        [CardId(1)]
+    );
+    let renamed =
+        snippets.update_snippet(snippet.id, &UpdateSnippet::new().name("enabled users"))?;
+    assert_eq!(renamed.name, "enabled users");
+
+    let paths: Vec<String> = server
+        .requests()
+        .into_iter()
+        .map(|request| format!("{} {}", request.method, request.path))
+        .collect();
+    assert_eq!(
+        paths,
+        [
+            "GET /api/native-query-snippet/4",
+            "GET /api/card",
+            "PUT /api/native-query-snippet/4"
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn blocking_revert_to_returns_restored_card() -> TestResult<()> {
+    let server = TestServer::new(vec![
+        Response::json(
+            200,
+            r#"[{"id":31,"is_reversion":false,"is_creation":false,"timestamp":"2024-05-01T10:00:00Z","user":{"id":1},"diff":{"before":{"name":"Revenue"},"after":{"name":"Sales"}}}]"#,
+        ),
+        Response::json(
+            200,
+            r#"{"id":32,"is_reversion":true,"timestamp":"2024-05-02T10:00:00Z","diff":null}"#,
+        ),
+        Response::json(
+            200,
+            r#"{"id":7,"name":"Revenue","display":"line","dataset_query":{}}"#,
+        ),
+    ])?;
+
+    let client = BlockingClient::builder(server.base_url())?.build()?;
+    let revisions = client.revision().list_revisions(CardId(7))?;
+    assert_eq!(revisions[0].changes[0].field, "name");
+
+    let restored = client.revision().revert_to(CardId(7), revisions[0].id)?;
+    assert!(matches!(restored, RevertedEntity::Card(card) if card.name == "Revenue"));
+
+    let paths: Vec<String> = server
+        .requests()
+        .into_iter()
+        .map(|request| format!("{} {}", request.method, request.path))
+        .collect();
+    assert_eq!(
+        paths,
+        [
+            "GET /api/revision/card/7",
+            "POST /api/revision/revert",
+            "GET /api/card/7"
+        ]
+    );
+    Ok(())
+}
+
+/// Fields of every span, indexed by span id - 1.
+#[cfg(feature = "tracing")]
+#[derive(Clone, Default)]
+struct SpanRecorder {
+    spans: Arc<Mutex<Vec<HashMap<String, String>>>>,
+}
+
+#[cfg(feature = "tracing")]
+struct FieldVisitor<'a>(&'a mut HashMap<String, String>);
+
+#[cfg(feature = "tracing")]
+impl tracing::field::Visit for FieldVisitor<'_> {
+    fn record_str(&mut self, field: &tracing::field::Field, value: &str) {
+        self.0.insert(field.name().to_owned(), value.to_owned());
+    }
+
+    fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+        self.0.insert(field.name().to_owned(), format!("{value:?}"));
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl tracing::Subscriber for SpanRecorder {
+    fn enabled(&self, _: &tracing::Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, attributes: &tracing::span::Attributes<'_>) -> tracing::span::Id {
+        let mut fields = HashMap::new();
+        attributes.record(&mut FieldVisitor(&mut fields));
+        let mut spans = self.spans.lock().unwrap_or_else(|error| error.into_inner());
+        spans.push(fields);
+        tracing::span::Id::from_u64(spans.len() as u64)
+    }
+
+    fn record(&self, span: &tracing::span::Id, values: &tracing::span::Record<'_>) {
+        let mut spans = self.spans.lock().unwrap_or_else(|error| error.into_inner());
+        if let Some(fields) = spans.get_mut(span.into_u64() as usize - 1) {
+            values.record(&mut FieldVisitor(fields));
+        }
+    }
+
+    fn record_follows_from(&self, _: &tracing::span::Id, _: &tracing::span::Id) {}
+
+    fn event(&self, _: &tracing::Event<'_>) {}
+
+    fn enter(&self, _: &tracing::span::Id) {}
+
+    fn exit(&self, _: &tracing::span::Id) {}
+}
+
+#[cfg(feature = "tracing")]
+#[test]
+fn blocking_spans_carry_the_route_and_only_opt_in_paths() -> TestResult<()> {
+    for record_path in [false, true] {
+        let server = TestServer::new(vec![Response::json(200, r#"{"data":{}}"#)])?;
+        let client = BlockingClient::builder(server.base_url())?
+            .record_request_path(record_path)
+            .build()?;
+        let recorder = SpanRecorder::default();
+        tracing::subscriber::with_default(recorder.clone(), || {
+            client.card().post_by_card_id_query(123, None)
+        })?;
+
+        let spans = recorder
+            .spans
+            .lock()
+            .unwrap_or_else(|error| error.into_inner())
+            .clone();
+        assert_eq!(spans.len(), 1);
+        assert_eq!(
+            spans[0].get("route").map(String::as_str),
+            Some("/api/card/{card-id}/query")
+        );
+        assert_eq!(
+            spans[0].get("path").map(String::as_str),
+            record_path.then_some("/api/card/123/query")
+        );
+    }
+    Ok(())
+}
+
+/// A metric name and its labels.
+#[cfg(feature = "metrics")]
+type MetricLabels = (String, Vec<(String, String)>);
+
+/// Label sets of every metric registered through it.
+#[cfg(feature = "metrics")]
+#[derive(Default)]
+struct LabelRecorder {
+    labels: Mutex<Vec<MetricLabels>>,
+}
+
+#[cfg(feature = "metrics")]
+impl LabelRecorder {
+    fn push(&self, key: &metrics::Key) {
+        let labels = key
+            .labels()
+            .map(|label| (label.key().to_owned(), label.value().to_owned()))
+            .collect();
+        self.labels
+            .lock()
+            .unwrap_or_else(|error| error.into_inner())
+            .push((key.name().to_owned(), labels));
+    }
+}
+
+#[cfg(feature = "metrics")]
+impl metrics::Recorder for LabelRecorder {
+    fn describe_counter(
+        &self,
+        _: metrics::KeyName,
+        _: Option<metrics::Unit>,
+        _: metrics::SharedString,
+    ) {
+    }
+
+    fn describe_gauge(
+        &self,
+        _: metrics::KeyName,
+        _: Option<metrics::Unit>,
+        _: metrics::SharedString,
+    ) {
+    }
+
+    fn describe_histogram(
+        &self,
+        _: metrics::KeyName,
+        _: Option<metrics::Unit>,
+        _: metrics::SharedString,
+    ) {
+    }
+
+    fn register_counter(&self, key: &metrics::Key, _: &metrics::Metadata<'_>) -> metrics::Counter {
+        self.push(key);
+        metrics::Counter::noop()
+    }
+
+    fn register_gauge(&self, key: &metrics::Key, _: &metrics::Metadata<'_>) -> metrics::Gauge {
+        self.push(key);
+        metrics::Gauge::noop()
+    }
+
+    fn register_histogram(
+        &self,
+        key: &metrics::Key,
+        _: &metrics::Metadata<'_>,
+    ) -> metrics::Histogram {
+        self.push(key);
+        metrics::Histogram::noop()
+    }
+}
+
+#[cfg(feature = "metrics")]
+#[test]
+fn blocking_metrics_are_labelled_with_the_route_template() -> TestResult<()> {
+    let server = TestServer::new(vec![Response::json(200, r#"{"data":{}}"#)])?;
+    let client = BlockingClient::builder(server.base_url())?
+        .record_request_path(true)
+        .build()?;
+    let recorder = LabelRecorder::default();
+    metrics::with_local_recorder(&recorder, || client.card().post_by_card_id_query(123, None))?;
+
+    let labels = recorder
+        .labels
+        .lock()
+        .unwrap_or_else(|error| error.into_inner())
+        .clone();
+    let requests = labels
+        .iter()
+        .find(|(name, _)| name == "metabase_requests_total")
+        .map(|(_, labels)| labels.clone());
+    assert_eq!(
+        requests,
+        Some(vec![
+            ("method".to_owned(), "POST".to_owned()),
+            ("route".to_owned(), "/api/card/{card-id}/query".to_owned()),
+            ("outcome".to_owned(), "ok".to_owned()),
+        ])
+    );
+    assert!(
+        labels
+            .iter()
+            .flat_map(|(_, labels)| labels)
+            .all(|(_, value)| !value.contains("123"))
+    );
+    Ok(())
+}
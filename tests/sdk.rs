@@ -6,10 +6,37 @@ use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::{Duration, SystemTime};
 
 use http::StatusCode;
+use metabase::types::action::{ActionKind, ActionParameters, ImplicitActionKind};
+use metabase::types::api_key::CreateApiKey;
+use metabase::types::cache::{CacheConfig, CacheModel, CacheStrategy};
+use metabase::types::card::{CardType, CreateCard, UpdateCard};
+use metabase::types::collection::{
+    CollectionItem, CollectionItemModel, CollectionItemsQuery, CollectionRef, CollectionSortColumn,
+    PinnedState, SortDirection,
+};
+use metabase::types::field::{HasFieldValues, SemanticType};
+use metabase::types::ids::{
+    ActionId, CardId, CollectionId, DashboardId, DatabaseId, FieldId, GroupId, TableId,
+    TimelineEventId, TimelineId, UserId,
+};
+use metabase::types::mbql::{FieldRef, Filter, FilterOperator};
+use metabase::types::permissions::{
+    DatabasePermissions, Granular, PermissionLevel, PermissionsChanges,
+};
+use metabase::types::schedule::{Schedule, ScheduleFrame, Weekday};
+use metabase::types::search::{PersonalCollectionFilter, SearchModel, SearchQuery, SearchResult};
+use metabase::types::segment::CreateSegment;
+use metabase::types::session::{Edition, MetabaseVersion};
+use metabase::types::setting::{
+    EnableEmbedding, ReportTimezone, SettingsUpdate, SiteName, SiteUrl,
+};
+use metabase::types::timeline::{CreateTimelineEvent, TimelineIcon, TimelineQuery};
+use metabase::types::user::{UserListQuery, UserStatus};
 use metabase::{Auth, Client, Error, IdempotencyKey, Jitter, RequestOptions, RetryPolicy};
+use time::{Date, Month, OffsetDateTime, format_description::well_known::Rfc3339};
 use wiremock::{
     Mock, MockServer, Request, Respond, ResponseTemplate,
-    matchers::{header, method, path},
+    matchers::{body_json, body_partial_json, header, method, path, query_param},
 };
 
 type TestResult<T> = std::result::Result<T, Box<dyn StdError + Send + Sync>>;
@@ -140,6 +167,36 @@ async fn decode_error_exposes_status_and_body_snippet() -> TestResult<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn body_snippets_stay_redacted_with_a_disabled_policy() -> TestResult<()> {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/session"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+            "errors": {"password": "did not match stored password for hunter2"}
+        })))
+        .mount(&server)
+        .await;
+
+    let client = Client::builder(server.uri())?
+        .redaction_policy(metabase::RedactionPolicy::disabled())
+        .redact_body_snippet(true)
+        .build()?;
+    let err = client
+        .session()
+        .post(Some(
+            &serde_json::json!({"username": "a", "password": "hunter2"}),
+        ))
+        .await;
+    let snippet = err
+        .err()
+        .and_then(|err| err.body_snippet().map(ToOwned::to_owned))
+        .ok_or("expected an error with a body snippet")?;
+    assert_eq!(snippet, r#"{"errors":{"password":"<redacted>"}}"#);
+    Ok(())
+}
+
 #[tokio::test]
 async fn retries_429_retry_after_seconds() -> TestResult<()> {
     let server = MockServer::start().await;
@@ -309,3 +366,926 @@ async fn post_is_retried_with_idempotency_key() -> TestResult<()> {
     assert_eq!(requests.len(), 2);
     Ok(())
 }
+
+#[tokio::test]
+async fn error_path_redacts_embed_token() -> TestResult<()> {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/embed/card/SECRET-JWT"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+            "message": "bad token"
+        })))
+        .mount(&server)
+        .await;
+
+    let client = Client::builder(server.uri())?.build()?;
+
+    let err = match client.embed().get_card_by_token("SECRET-JWT").await {
+        Ok(_) => {
+            return Err(std::io::Error::other("expected error").into());
+        }
+        Err(err) => err,
+    };
+
+    assert_eq!(err.path(), Some("/api/embed/card/<redacted>"));
+    assert!(!format!("{err} {err:?}").contains("SECRET-JWT"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn stats_count_transport_retries_once() -> TestResult<()> {
+    let addr = std::net::TcpListener::bind("127.0.0.1:0")?.local_addr()?;
+
+    let retry_policy = RetryPolicy::conservative()
+        .max_retries(2)
+        .base_delay(Duration::from_secs(0))
+        .max_delay(Duration::from_secs(0))
+        .jitter(Jitter::None);
+    let client = Client::builder(format!("http://{addr}"))?
+        .retry_policy(retry_policy)
+        .collect_stats(true)
+        .build()?;
+
+    assert!(client.health().get().await.is_err());
+
+    let stats = client
+        .stats()
+        .ok_or_else(|| std::io::Error::other("stats disabled"))?;
+    assert_eq!(stats.requests, 1);
+    assert_eq!(stats.retries, 2);
+    assert_eq!(stats.errors.get(&metabase::ErrorKind::Transport), Some(&1));
+    Ok(())
+}
+
+#[tokio::test]
+async fn debug_recorder_captures_redacted_exchanges() -> TestResult<()> {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/embed/card/SECRET-JWT"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "name": "Orders"
+        })))
+        .mount(&server)
+        .await;
+
+    let recorder = metabase::DebugRecorder::new();
+    let client = Client::builder(server.uri())?
+        .auth(Auth::api_key("mb_secret"))
+        .debug_recorder(recorder.clone())
+        .build()?;
+    client.embed().get_card_by_token("SECRET-JWT").await?;
+
+    let exchanges = recorder.exchanges();
+    assert_eq!(exchanges.len(), 1);
+    let exchange = &exchanges[0];
+    assert_eq!(exchange.route, "/api/embed/card/{token}");
+    assert_eq!(
+        exchange.response.as_ref().map(|response| response.status),
+        Some(StatusCode::OK)
+    );
+
+    let curl = exchange.to_curl();
+    assert!(curl.starts_with("curl -X GET '"));
+    assert!(curl.contains("/api/embed/card/<redacted>'"));
+    assert!(curl.contains("-H 'x-api-key: <redacted>'"));
+
+    let har = recorder.to_har().to_string();
+    assert!(har.contains("Orders"));
+    assert!(!har.contains("SECRET-JWT"));
+    assert!(!har.contains("mb_secret"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn idempotent_request_fails_over_to_next_base_url() -> TestResult<()> {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/health"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "status": "ok"
+        })))
+        .mount(&server)
+        .await;
+
+    let dead = std::net::TcpListener::bind("127.0.0.1:0")?.local_addr()?;
+    let retry_policy = RetryPolicy::conservative()
+        .max_retries(1)
+        .base_delay(Duration::from_secs(0))
+        .max_delay(Duration::from_secs(0))
+        .jitter(Jitter::None);
+    let client = Client::builder_with_base_urls([format!("http://{dead}"), server.uri()])?
+        .retry_policy(retry_policy)
+        .build()?;
+
+    let health = client.health().get().await?;
+    assert_eq!(health.status, "ok");
+
+    let endpoints = client.endpoints();
+    assert!(!endpoints[0].healthy);
+    assert!(endpoints[1].healthy);
+
+    let probed = client.probe_endpoints().await;
+    assert_eq!(
+        probed.iter().map(|e| e.healthy).collect::<Vec<_>>(),
+        [false, true]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn typed_id_variants_build_the_same_path() -> TestResult<()> {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/card/42"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": 42,
+            "collection_id": 7
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = Client::builder(server.uri())?.build()?;
+    let card = client.card().get_by_id_typed(CardId(42), None).await?;
+    let collection_id: Option<CollectionId> =
+        serde_json::from_value(card.get("collection_id").cloned().unwrap_or_default())?;
+    assert_eq!(collection_id, Some(CollectionId(7)));
+    Ok(())
+}
+
+#[tokio::test]
+async fn card_typed_create_and_update() -> TestResult<()> {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/card"))
+        .and(body_partial_json(serde_json::json!({
+            "name": "Orders",
+            "type": "model",
+            "display": "table",
+            "visualization_settings": {}
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": 5,
+            "name": "Orders",
+            "type": "model",
+            "display": "table",
+            "dataset_query": {"type": "native", "database": 1}
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/api/card/5"))
+        .and(body_json(serde_json::json!({"archived": true})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": 5,
+            "name": "Orders",
+            "archived": true
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = Client::builder(server.uri())?.build()?;
+    let card = client
+        .card()
+        .create_card(
+            &CreateCard::new(
+                "Orders",
+                serde_json::json!({"type": "native", "database": 1}),
+            )
+            .card_type(CardType::Model),
+        )
+        .await?;
+    assert_eq!(card.id, CardId(5));
+    assert_eq!(card.card_type, Some(CardType::Model));
+
+    let card = client
+        .card()
+        .update_card(card.id, &UpdateCard::new().archived(true))
+        .await?;
+    assert!(card.archived);
+    Ok(())
+}
+
+#[tokio::test]
+async fn collection_items_sends_typed_query() -> TestResult<()> {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/collection/root/items"))
+        .and(query_param("models", "dashboard"))
+        .and(query_param("pinned_state", "is_pinned"))
+        .and(query_param("sort_column", "last_edited_at"))
+        .and(query_param("sort_direction", "desc"))
+        .and(query_param("limit", "10"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "total": 1,
+            "data": [{"model": "dashboard", "id": 4, "name": "Sales"}],
+            "models": ["dashboard"]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = Client::builder(server.uri())?.build()?;
+    let query = CollectionItemsQuery::new()
+        .model(CollectionItemModel::Dashboard)
+        .pinned_state(PinnedState::IsPinned)
+        .sort(CollectionSortColumn::LastEditedAt, SortDirection::Desc)
+        .limit(10);
+    let items = client
+        .collection()
+        .collection_items(CollectionRef::Root, &query)
+        .await?;
+    assert_eq!(items.total, 1);
+    assert!(matches!(
+        &items.data[0],
+        CollectionItem::Dashboard(entry) if entry.id == DashboardId(4)
+    ));
+    Ok(())
+}
+
+#[tokio::test]
+async fn table_query_metadata_decodes_typed_fields() -> TestResult<()> {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/table/3/query_metadata"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": 3,
+            "db_id": 1,
+            "name": "ORDERS",
+            "schema": "PUBLIC",
+            "visibility_type": null,
+            "fields": [
+                {
+                    "id": 30,
+                    "table_id": 3,
+                    "name": "ID",
+                    "base_type": "type/BigInteger",
+                    "semantic_type": "type/PK",
+                    "visibility_type": "normal",
+                    "has_field_values": "none",
+                    "dimensions": []
+                },
+                {
+                    "id": 31,
+                    "table_id": 3,
+                    "name": "USER_ID",
+                    "base_type": "type/Integer",
+                    "semantic_type": "type/FK",
+                    "fk_target_field_id": 40,
+                    "has_field_values": "brand-new-mode",
+                    "dimensions": [{"id": 2, "field_id": 31, "name": "User", "type": "external", "human_readable_field_id": 41}]
+                }
+            ]
+        })))
+        .mount(&server)
+        .await;
+
+    let client = Client::builder(server.uri())?.build()?;
+    let table = client
+        .table()
+        .table_query_metadata(TableId(3), None)
+        .await?;
+    assert_eq!(table.db_id, Some(DatabaseId(1)));
+    assert_eq!(
+        table.fields[0].semantic_type,
+        Some(SemanticType::PrimaryKey)
+    );
+    assert_eq!(table.fields[1].fk_target_field_id, Some(FieldId(40)));
+    assert_eq!(
+        table.fields[1].has_field_values,
+        Some(HasFieldValues::Unknown("brand-new-mode".to_owned()))
+    );
+    assert_eq!(
+        table.fields[1].dimensions[0].human_readable_field_id,
+        Some(FieldId(41))
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn permissions_apply_rebases_on_conflict() -> TestResult<()> {
+    let server = MockServer::start().await;
+    let fresh = serde_json::json!({
+        "revision": 5,
+        "groups": {"2": {"1": {"view-data": "unrestricted", "create-queries": "no"}}}
+    });
+
+    Mock::given(method("PUT"))
+        .and(path("/api/permissions/graph"))
+        .and(body_partial_json(serde_json::json!({"revision": 4})))
+        .respond_with(ResponseTemplate::new(409).set_body_json(serde_json::json!({
+            "message": "Looks like someone else edited the permissions and your data is out of date."
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/permissions/graph"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(fresh))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/api/permissions/graph"))
+        .and(body_json(serde_json::json!({
+            "revision": 5,
+            "groups": {"2": {"1": {"create-queries": "query-builder"}}}
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "revision": 6,
+            "groups": {"2": {"1": {"view-data": "unrestricted", "create-queries": "query-builder"}}}
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = Client::builder(server.uri())?.build()?;
+    let changes = PermissionsChanges::new().set(
+        GroupId(2),
+        DatabaseId(1),
+        DatabasePermissions::new()
+            .view_data(Granular::All(PermissionLevel::Unrestricted))
+            .create_queries(Granular::All(PermissionLevel::QueryBuilder)),
+    );
+    let graph = client
+        .permissions()
+        .apply_permissions_changes(4, &changes)
+        .await?;
+    assert_eq!(graph.revision, 6);
+    Ok(())
+}
+
+#[tokio::test]
+async fn users_and_memberships_decode_typed() -> TestResult<()> {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/user"))
+        .and(query_param("status", "deactivated"))
+        .and(query_param("query", "ada"))
+        .and(query_param("group_id", "3"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": [{
+                "id": 7,
+                "email": "ada@example.com",
+                "first_name": "Ada",
+                "is_active": false,
+                "user_group_memberships": [{"id": 1}, {"id": 3, "is_group_manager": true}],
+                "sso_source": null
+            }],
+            "total": 1,
+            "limit": null,
+            "offset": null
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/permissions/membership"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "7": [{"membership_id": 11, "group_id": 3, "user_id": 7, "is_group_manager": true}]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = Client::builder(server.uri())?.build()?;
+    let users = client
+        .user()
+        .list_users(
+            &UserListQuery::new()
+                .status(UserStatus::Deactivated)
+                .query("ada")
+                .group_id(GroupId(3)),
+        )
+        .await?;
+    assert_eq!(users.total, 1);
+    assert_eq!(users.data[0].id, UserId(7));
+    assert_eq!(
+        users.data[0].user_group_memberships[1].is_group_manager,
+        Some(true)
+    );
+
+    let memberships = client.permissions().list_memberships().await?;
+    assert_eq!(memberships[&UserId(7)][0].group_id, GroupId(3));
+    Ok(())
+}
+
+#[tokio::test]
+async fn settings_typed_get_and_bulk_put() -> TestResult<()> {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/setting/site-url"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_raw("https://bi.example.com", "text/plain"),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/api/setting"))
+        .and(body_json(serde_json::json!({
+            "enable-embedding": true,
+            "report-timezone": null
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = Client::builder(server.uri())?.build()?;
+    let site_url = client.setting().get_setting::<SiteUrl>().await?;
+    assert_eq!(site_url.as_deref(), Some("https://bi.example.com"));
+
+    client
+        .setting()
+        .put_settings(
+            &SettingsUpdate::new()
+                .set::<EnableEmbedding>(true)?
+                .reset::<ReportTimezone>(),
+        )
+        .await?;
+
+    let err = client
+        .setting()
+        .put_settings(&SettingsUpdate::new().set_value("enable-embedding", "yes".into()))
+        .await;
+    assert!(matches!(err, Err(Error::InvalidSetting { .. })));
+    Ok(())
+}
+
+#[tokio::test]
+async fn search_sends_repeated_filters() -> TestResult<()> {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/search"))
+        .and(query_param("q", "orders"))
+        .and(query_param("models", "dataset"))
+        .and(query_param("created_by", "3"))
+        .and(query_param(
+            "filter_items_in_personal_collection",
+            "exclude",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "total": 1,
+            "data": [{
+                "model": "dataset",
+                "id": 12,
+                "name": "Orders model",
+                "collection": {"id": 4, "name": "Finance", "authority_level": "official"}
+            }]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = Client::builder(server.uri())?.build()?;
+    let results = client
+        .search()
+        .search(
+            &SearchQuery::new("orders")
+                .model(SearchModel::Dataset)
+                .model(SearchModel::Metric)
+                .created_by(UserId(3))
+                .personal_collections(PersonalCollectionFilter::Exclude),
+        )
+        .await?;
+    assert!(matches!(&results.data[0], SearchResult::Dataset(model) if model.id == CardId(12)));
+
+    let requests = server.received_requests().await.unwrap_or_default();
+    let query = requests
+        .first()
+        .and_then(|request| request.url.query())
+        .unwrap_or_default();
+    assert!(query.contains("models=dataset&models=metric"), "{query}");
+    Ok(())
+}
+
+#[tokio::test]
+async fn schedules_convert_to_each_wire_format() -> TestResult<()> {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/persist/set-refresh-schedule"))
+        .and(body_json(serde_json::json!({"cron": "0 0 6 ? * 2 *"})))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/api/cache"))
+        .and(body_json(serde_json::json!({
+            "model": "dashboard",
+            "model_id": 4,
+            "strategy": {
+                "type": "schedule",
+                "schedule": "0 0 5 ? * 1L *",
+                "refresh_automatically": false
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({"id": 1})))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = Client::builder(server.uri())?.build()?;
+    client
+        .persist()
+        .set_refresh_schedule(&Schedule::Weekly {
+            day: Weekday::Monday,
+            hour: 6,
+        })
+        .await?;
+
+    let last_sunday = Schedule::Monthly {
+        frame: ScheduleFrame::Last,
+        day: Some(Weekday::Sunday),
+        hour: 5,
+    };
+    let strategy = CacheStrategy::schedule(&last_sunday)?;
+    assert_eq!(strategy.invalidation_schedule(), Some(last_sunday));
+    client
+        .cache()
+        .set_cache_config(&CacheConfig::new(
+            CacheModel::Dashboard,
+            DashboardId(4),
+            strategy,
+        ))
+        .await?;
+
+    let err = client
+        .persist()
+        .set_refresh_schedule(&Schedule::Daily { hour: 25 })
+        .await;
+    assert!(matches!(err, Err(Error::InvalidSchedule { .. })));
+    Ok(())
+}
+
+#[tokio::test]
+async fn timeline_events_upsert_idempotently() -> TestResult<()> {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/timeline/collection/root"))
+        .and(query_param("include", "events"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            {"id": 3, "name": "Releases", "icon": "star", "collection_id": null, "default": false}
+        ])))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/timeline/3"))
+        .and(query_param("include", "events"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": 3,
+            "name": "Releases",
+            "icon": "star",
+            "collection_id": null,
+            "events": [
+                {
+                    "id": 10,
+                    "timeline_id": 3,
+                    "name": "v1.0",
+                    "timestamp": "2024-05-01T09:00:00+02:00",
+                    "time_matters": true,
+                    "timezone": "Europe/Berlin",
+                    "icon": "star"
+                },
+                {
+                    "id": 11,
+                    "timeline_id": 3,
+                    "name": "v1.1",
+                    "timestamp": "2024-06-01T00:00:00Z",
+                    "time_matters": false,
+                    "timezone": "UTC",
+                    "icon": "star"
+                }
+            ]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/api/timeline-event/11"))
+        .and(body_partial_json(serde_json::json!({
+            "description": "Hotfix",
+            "timestamp": "2024-06-01T00:00:00Z"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": 11,
+            "timeline_id": 3,
+            "name": "v1.1",
+            "description": "Hotfix",
+            "timestamp": "2024-06-01T00:00:00Z",
+            "time_matters": false,
+            "timezone": "UTC"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/api/timeline-event"))
+        .and(body_json(serde_json::json!({
+            "timeline_id": 3,
+            "name": "v2.0",
+            "timestamp": "2024-07-01T04:30:00-04:00",
+            "time_matters": true,
+            "timezone": "America/New_York"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": 12,
+            "timeline_id": 3,
+            "name": "v2.0",
+            "timestamp": "2024-07-01T08:30:00Z",
+            "time_matters": true,
+            "timezone": "America/New_York"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = Client::builder(server.uri())?.build()?;
+    let timelines = client
+        .timeline()
+        .collection_timelines(CollectionRef::Root, &TimelineQuery::new().events())
+        .await?;
+    assert_eq!(timelines[0].icon, Some(TimelineIcon::Star));
+    assert_eq!(timelines[0].collection_id, None);
+
+    let events = client
+        .timeline_event()
+        .upsert_timeline_events(&[
+            CreateTimelineEvent::new(
+                TimelineId(3),
+                "v1.0",
+                OffsetDateTime::parse("2024-05-01T07:00:00Z", &Rfc3339)?,
+            )
+            .timezone("Europe/Berlin")
+            .icon(TimelineIcon::Star),
+            CreateTimelineEvent::on_date(
+                TimelineId(3),
+                "v1.1",
+                Date::from_calendar_date(2024, Month::June, 1)?,
+            )
+            .description("Hotfix"),
+            CreateTimelineEvent::new(
+                TimelineId(3),
+                "v2.0",
+                OffsetDateTime::parse("2024-07-01T04:30:00-04:00", &Rfc3339)?,
+            )
+            .timezone("America/New_York"),
+        ])
+        .await?;
+    let ids: Vec<_> = events.iter().map(|event| event.id).collect();
+    assert_eq!(
+        ids,
+        [
+            TimelineEventId(10),
+            TimelineEventId(11),
+            TimelineEventId(12)
+        ]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn actions_validate_parameters_before_execute() -> TestResult<()> {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/action/5"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": 5,
+            "name": "Update order",
+            "type": "implicit",
+            "kind": "row/update",
+            "model_id": 3,
+            "parameters": [
+                {"id": "id", "type": "type/BigInteger", "required": true},
+                {"id": "status", "type": "type/Text"}
+            ],
+            "visualization_settings": {"fields": {"status": {"inputType": "select"}}}
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/api/action/5/execute"))
+        .and(body_json(serde_json::json!({
+            "parameters": {"id": 12, "status": "shipped"}
+        })))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({"rows-updated": [1]})),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = Client::builder(server.uri())?.build()?;
+    let action = client.action().get_action(ActionId(5)).await?;
+    assert!(matches!(
+        action.kind,
+        ActionKind::Implicit {
+            kind: ImplicitActionKind::Update
+        }
+    ));
+
+    let err = client
+        .action()
+        .execute_action(&action, &ActionParameters::new().set("status", "shipped"))
+        .await;
+    assert!(matches!(
+        err,
+        Err(Error::InvalidActionParameter { ref parameter, .. }) if parameter == "id"
+    ));
+
+    let result = client
+        .action()
+        .execute_action(
+            &action,
+            &ActionParameters::new()
+                .set("id", 12)
+                .set("status", "shipped"),
+        )
+        .await?;
+    assert_eq!(result.row_count(), Some(1));
+    Ok(())
+}
+
+#[tokio::test]
+async fn segments_send_filters_and_find_dependent_cards() -> TestResult<()> {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/segment"))
+        .and(body_json(serde_json::json!({
+            "name": "Californians",
+            "table_id": 2,
+            "definition": {
+                "source-table": 2,
+                "filter": ["=", ["field", 10, null], "CA"]
+            },
+            "revision_message": "Initial definition"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": 6,
+            "name": "Californians",
+            "table_id": 2,
+            "definition": {"source-table": 2, "filter": ["=", ["field", 10, null], "CA"]}
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/card"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            {
+                "id": 1,
+                "name": "CA orders",
+                "dataset_query": {
+                    "type": "query",
+                    "query": {"source-table": 2, "filter": ["and", ["segment", 6], [">", ["field", 11, null], 100]]}
+                }
+            },
+            {
+                "id": 2,
+                "name": "Other segment",
+                "dataset_query": {"type": "query", "query": {"source-table": 2, "filter": ["segment", 60]}}
+            }
+        ])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = Client::builder(server.uri())?.build()?;
+    let segment = client
+        .segment()
+        .create_segment(&CreateSegment::new(
+            "Californians",
+            TableId(2),
+            Filter::eq(FieldRef::id(FieldId(10)), "CA"),
+            "Initial definition",
+        ))
+        .await?;
+    assert!(matches!(
+        segment.definition.filter,
+        Some(Filter::Compare {
+            operator: FilterOperator::Eq,
+            ..
+        })
+    ));
+
+    let dependents = client.segment().dependent_cards(segment.id).await?;
+    assert_eq!(
+        dependents.iter().map(|card| card.id).collect::<Vec<_>>(),
+        [CardId(1)]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn created_api_key_authenticates_a_new_client() -> TestResult<()> {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/api-key"))
+        .and(body_json(serde_json::json!({"name": "etl", "group_id": 4})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": 2,
+            "name": "etl",
+            "group": {"id": 4, "name": "ETL"},
+            "masked_key": "mb_Ab**********",
+            "unmasked_key": "mb_AbCdEfGh123",
+            "user_id": 15
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/api-key/count"))
+        .and(header("x-api-key", "mb_AbCdEfGh123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!(1)))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let recorder = metabase::DebugRecorder::new();
+    let admin = Client::builder(server.uri())?
+        .debug_recorder(recorder.clone())
+        .build()?;
+    let key = admin
+        .api_key()
+        .create_api_key(&CreateApiKey::new("etl", GroupId(4)))
+        .await?;
+    assert_eq!(key.group.map(|group| group.id), Some(GroupId(4)));
+    let secret = key.unmasked_key.ok_or("missing unmasked key")?;
+    assert!(!format!("{secret:?}").contains("mb_AbCdEfGh123"));
+
+    let captured = recorder
+        .last()
+        .and_then(|exchange| exchange.response)
+        .ok_or("no response captured")?;
+    let body: serde_json::Value = serde_json::from_str(&captured.body)?;
+    assert_eq!(body["unmasked_key"], "<redacted>");
+    assert!(!recorder.to_har().to_string().contains("mb_AbCdEfGh123"));
+
+    let etl = Client::builder(server.uri())?
+        .auth(Auth::api_key_secret(secret))
+        .build()?;
+    assert_eq!(etl.api_key().count_api_keys().await?, 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn server_info_is_fetched_once_and_cached() -> TestResult<()> {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/session/properties"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "version": {"tag": "v1.50.3.1", "date": "2024-07-01", "hash": "abc123"},
+            "token-features": {"sso_jwt": true, "audit_app": false},
+            "setup-token": null,
+            "has-user-setup": true,
+            "engines": {"postgres": {"driver-name": "PostgreSQL", "superseded-by": null}},
+            "site-name": "Acme Analytics",
+            "enable-embedding": true
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = Client::builder(server.uri())?.build()?;
+    let info = client.server_info().await?;
+    let version = info.version().ok_or("unparsed version")?;
+    assert_eq!(version.edition, Edition::Enterprise);
+    assert!(version >= MetabaseVersion::new(50, 3, 0));
+    assert!(version < MetabaseVersion::new(51, 0, 0));
+    assert!(info.has_feature("sso_jwt"));
+    assert!(!info.has_feature("audit_app"));
+    assert_eq!(
+        info.engines["postgres"].driver_name.as_deref(),
+        Some("PostgreSQL")
+    );
+    assert_eq!(
+        info.setting::<SiteName>().as_deref(),
+        Some("Acme Analytics")
+    );
+    assert_eq!(info.setting::<EnableEmbedding>(), Some(true));
+
+    let again = client.server_info().await?;
+    assert!(Arc::ptr_eq(&info, &again));
+    Ok(())
+}